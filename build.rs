@@ -142,6 +142,44 @@ fn main() {
         use_feature("linux_kernel");
     }
 
+    // `fcntl`-style locks aren't implemented everywhere.
+    if os != "emscripten"
+        && os != "espidf"
+        && os != "fuchsia"
+        && os != "redox"
+        && os != "vita"
+        && os != "wasi"
+    {
+        use_feature("fcntl_lock");
+    }
+
+    // `kexec_file_load` is only implemented on some architectures.
+    if os == "linux"
+        && (arch == "aarch64"
+            || arch == "loongarch64"
+            || arch == "powerpc64"
+            || arch == "riscv64"
+            || arch == "s390x"
+            || arch == "x86_64")
+    {
+        use_feature("kexec_file_load");
+    }
+
+    // Modules with `ioctl`s that don't fit the `Ioctl` patterns use
+    // `ioctl::ioctl_with_opcode`.
+    let feature = |name: &str| var(format!("CARGO_FEATURE_{}", name)).is_ok();
+    if (os == "android" || os == "linux")
+        && ((feature("DEVICE_MAPPER") && feature("ALLOC"))
+            || feature("HIDRAW")
+            || feature("INPUT")
+            || feature("LOOP_DEVICE")
+            || feature("SPI")
+            || feature("USBDEVFS")
+            || (feature("VHOST") && feature("ALLOC")))
+    {
+        use_feature("ioctl_with_opcode");
+    }
+
     // These platforms have a 32-bit `time_t`.
     if libc
         && (arch == "arm"
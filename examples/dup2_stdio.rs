@@ -0,0 +1,42 @@
+//! This is an example of how to use `dup2_stdio` to replace all of the stdio
+//! file descriptors at once, even when the new file descriptors are
+//! themselves stdio file descriptors.
+
+#[cfg(all(not(windows), feature = "fs", feature = "pipe", feature = "stdio"))]
+fn main() -> std::io::Result<()> {
+    use rustix::fs::fstat;
+    use rustix::pipe::pipe;
+    use rustix::stdio::{dup2_stdio, stderr, stdout};
+
+    // Create some new file descriptors that we'll use to replace stdin and
+    // stdout with.
+    let (reader, writer) = pipe()?;
+    let old_stdout = fstat(stdout())?;
+
+    // Send stderr to where stdout currently goes. Replacing the streams one
+    // at a time would install `writer` on stdout first, and then copy that
+    // over stderr too. `dup2_stdio` takes care of the ordering.
+    dup2_stdio(&reader, &writer, stdout())?;
+    drop(reader);
+    drop(writer);
+
+    let new_stderr = fstat(stderr())?;
+    assert_eq!(
+        (new_stderr.st_dev, new_stderr.st_ino),
+        (old_stdout.st_dev, old_stdout.st_ino)
+    );
+
+    // Now stdout goes to our pipe, and stdin reads from it.
+    println!("hello, world!");
+
+    let mut s = String::new();
+    std::io::stdin().read_line(&mut s)?;
+    assert_eq!(s, "hello, world!\n");
+
+    Ok(())
+}
+
+#[cfg(not(all(not(windows), feature = "fs", feature = "pipe", feature = "stdio")))]
+fn main() -> Result<(), &'static str> {
+    Err("This example requires --features=fs,pipe,stdio and is not supported on Windows.")
+}
@@ -0,0 +1,305 @@
+//! Linux native asynchronous I/O.
+//!
+//! These functions wrap the kernel's native AIO interface (`io_setup`,
+//! `io_submit`, `io_getevents`, and `io_destroy`), which is what libaio
+//! provides. It's mostly useful for files opened with `O_DIRECT`; for other
+//! files, the kernel may perform the I/O synchronously within `io_submit`.
+//!
+//! # References
+//!  - [Linux]
+//!
+//! [Linux]: https://man7.org/linux/man-pages/man7/aio.7.html
+
+#![allow(unsafe_code)]
+
+use crate::backend::aio::syscalls;
+use crate::backend::c;
+use crate::fd::{AsFd, AsRawFd, BorrowedFd};
+use crate::io::{self, IoSlice, IoSliceMut, ReadWriteFlags};
+use core::fmt;
+use core::mem::ManuallyDrop;
+
+pub use crate::timespec::Timespec;
+
+const IOCB_CMD_PREAD: u16 = 0;
+const IOCB_CMD_PWRITE: u16 = 1;
+const IOCB_CMD_FSYNC: u16 = 2;
+const IOCB_CMD_FDSYNC: u16 = 3;
+const IOCB_CMD_PREADV: u16 = 7;
+const IOCB_CMD_PWRITEV: u16 = 8;
+
+const IOCB_FLAG_RESFD: u32 = 1 << 0;
+
+/// `aio_context_t`—An owned AIO context.
+///
+/// This is closed with `io_destroy` when dropped. To observe errors from
+/// `io_destroy`, use [`io_destroy`].
+#[repr(transparent)]
+#[doc(alias = "aio_context_t")]
+pub struct AioContext(c::c_ulong);
+
+impl fmt::Debug for AioContext {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("AioContext")
+            .field(&format_args!("{:#x}", self.0))
+            .finish()
+    }
+}
+
+impl Drop for AioContext {
+    #[inline]
+    fn drop(&mut self) {
+        let _ = syscalls::io_destroy(self.0);
+    }
+}
+
+/// `struct iocb`—An AIO control block, describing one I/O operation.
+///
+/// The constructors record raw pointers to the buffers they're given, so the
+/// buffers must remain valid until the operation completes; see
+/// [`io_submit`].
+#[repr(C)]
+#[derive(Clone, Debug)]
+#[doc(alias = "iocb")]
+pub struct Iocb {
+    aio_data: u64,
+    #[cfg(target_endian = "little")]
+    aio_key: u32,
+    aio_rw_flags: ReadWriteFlags,
+    #[cfg(target_endian = "big")]
+    aio_key: u32,
+    aio_lio_opcode: u16,
+    aio_reqprio: i16,
+    aio_fildes: u32,
+    aio_buf: u64,
+    aio_nbytes: u64,
+    aio_offset: i64,
+    aio_reserved2: u64,
+    aio_flags: u32,
+    aio_resfd: u32,
+}
+
+impl Iocb {
+    fn new(fd: BorrowedFd<'_>, opcode: u16, buf: u64, nbytes: u64, offset: u64) -> Self {
+        Self {
+            aio_data: 0,
+            aio_key: 0,
+            aio_rw_flags: ReadWriteFlags::empty(),
+            aio_lio_opcode: opcode,
+            aio_reqprio: 0,
+            aio_fildes: fd.as_raw_fd() as u32,
+            aio_buf: buf,
+            aio_nbytes: nbytes,
+            aio_offset: offset as i64,
+            aio_reserved2: 0,
+            aio_flags: 0,
+            aio_resfd: 0,
+        }
+    }
+
+    /// `IOCB_CMD_PREAD`—Reads from `fd` at `offset` into `buf`.
+    #[inline]
+    #[doc(alias = "IOCB_CMD_PREAD")]
+    pub fn pread<Fd: AsFd>(fd: Fd, buf: &mut [u8], offset: u64) -> Self {
+        Self::new(
+            fd.as_fd(),
+            IOCB_CMD_PREAD,
+            buf.as_mut_ptr() as usize as u64,
+            buf.len() as u64,
+            offset,
+        )
+    }
+
+    /// `IOCB_CMD_PWRITE`—Writes `buf` to `fd` at `offset`.
+    #[inline]
+    #[doc(alias = "IOCB_CMD_PWRITE")]
+    pub fn pwrite<Fd: AsFd>(fd: Fd, buf: &[u8], offset: u64) -> Self {
+        Self::new(
+            fd.as_fd(),
+            IOCB_CMD_PWRITE,
+            buf.as_ptr() as usize as u64,
+            buf.len() as u64,
+            offset,
+        )
+    }
+
+    /// `IOCB_CMD_PREADV`—Reads from `fd` at `offset` into `bufs`.
+    #[inline]
+    #[doc(alias = "IOCB_CMD_PREADV")]
+    pub fn preadv<Fd: AsFd>(fd: Fd, bufs: &mut [IoSliceMut<'_>], offset: u64) -> Self {
+        Self::new(
+            fd.as_fd(),
+            IOCB_CMD_PREADV,
+            bufs.as_mut_ptr() as usize as u64,
+            bufs.len() as u64,
+            offset,
+        )
+    }
+
+    /// `IOCB_CMD_PWRITEV`—Writes `bufs` to `fd` at `offset`.
+    #[inline]
+    #[doc(alias = "IOCB_CMD_PWRITEV")]
+    pub fn pwritev<Fd: AsFd>(fd: Fd, bufs: &[IoSlice<'_>], offset: u64) -> Self {
+        Self::new(
+            fd.as_fd(),
+            IOCB_CMD_PWRITEV,
+            bufs.as_ptr() as usize as u64,
+            bufs.len() as u64,
+            offset,
+        )
+    }
+
+    /// `IOCB_CMD_FSYNC`—Flushes `fd`'s data and metadata to storage.
+    #[inline]
+    #[doc(alias = "IOCB_CMD_FSYNC")]
+    pub fn fsync<Fd: AsFd>(fd: Fd) -> Self {
+        Self::new(fd.as_fd(), IOCB_CMD_FSYNC, 0, 0, 0)
+    }
+
+    /// `IOCB_CMD_FDSYNC`—Flushes `fd`'s data to storage.
+    #[inline]
+    #[doc(alias = "IOCB_CMD_FDSYNC")]
+    pub fn fdatasync<Fd: AsFd>(fd: Fd) -> Self {
+        Self::new(fd.as_fd(), IOCB_CMD_FDSYNC, 0, 0, 0)
+    }
+
+    /// Returns the value set by [`Iocb::set_user_data`].
+    #[inline]
+    pub const fn user_data(&self) -> u64 {
+        self.aio_data
+    }
+
+    /// Sets the `aio_data` value, which is passed through to the completion
+    /// event's [`IoEvent::user_data`].
+    #[inline]
+    pub fn set_user_data(&mut self, user_data: u64) {
+        self.aio_data = user_data;
+    }
+
+    /// Sets the `aio_rw_flags` value, which has the same meaning as the
+    /// flags argument to [`preadv2`] and [`pwritev2`].
+    ///
+    /// [`preadv2`]: crate::io::preadv2
+    /// [`pwritev2`]: crate::io::pwritev2
+    #[inline]
+    pub fn set_rw_flags(&mut self, flags: ReadWriteFlags) {
+        self.aio_rw_flags = flags;
+    }
+
+    /// `IOCB_FLAG_RESFD`—Requests that the eventfd `eventfd` be signaled
+    /// when this operation completes.
+    #[inline]
+    #[doc(alias = "IOCB_FLAG_RESFD")]
+    pub fn set_eventfd<Fd: AsFd>(&mut self, eventfd: Fd) {
+        self.aio_flags |= IOCB_FLAG_RESFD;
+        self.aio_resfd = eventfd.as_fd().as_raw_fd() as u32;
+    }
+}
+
+/// `struct io_event`—A completion event for an AIO operation.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[doc(alias = "io_event")]
+pub struct IoEvent {
+    data: u64,
+    obj: u64,
+    res: i64,
+    res2: i64,
+}
+
+impl IoEvent {
+    /// Returns the [`Iocb::user_data`] value of the completed operation.
+    #[inline]
+    pub const fn user_data(&self) -> u64 {
+        self.data
+    }
+
+    /// Returns the address of the `Iocb` that was submitted for the
+    /// completed operation.
+    #[inline]
+    pub const fn iocb(&self) -> *const Iocb {
+        self.obj as usize as *const Iocb
+    }
+
+    /// Returns the result of the completed operation: the number of bytes
+    /// transferred, or an error.
+    #[inline]
+    pub fn result(&self) -> io::Result<usize> {
+        if self.res < 0 {
+            Err(io::Errno::from_raw_os_error(self.res.wrapping_neg() as i32))
+        } else {
+            Ok(self.res as usize)
+        }
+    }
+}
+
+/// `io_setup(nr_events, &ctx)`—Creates an AIO context able to hold at least
+/// `nr_events` in-flight operations.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/io_setup.2.html
+#[inline]
+pub fn io_setup(nr_events: u32) -> io::Result<AioContext> {
+    syscalls::io_setup(nr_events).map(AioContext)
+}
+
+/// `io_destroy(ctx)`—Destroys an AIO context.
+///
+/// This waits for in-flight operations to complete, and discards their
+/// completion events. Dropping an [`AioContext`] does the same thing, but
+/// ignores any error.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/io_destroy.2.html
+#[inline]
+pub fn io_destroy(ctx: AioContext) -> io::Result<()> {
+    let ctx = ManuallyDrop::new(ctx);
+    syscalls::io_destroy(ctx.0)
+}
+
+/// `io_submit(ctx, iocbs.len(), iocbs)`—Submits AIO operations.
+///
+/// Returns the number of operations submitted, which may be less than
+/// `iocbs.len()`. The kernel copies the `Iocb`s during this call, so they
+/// may be reused afterwards.
+///
+/// # Safety
+///
+/// The buffers referred to by each submitted `Iocb` must remain valid, and
+/// must not be otherwise accessed in a way that conflicts with the operation,
+/// until its completion event is retrieved with [`io_getevents`] or `ctx` is
+/// destroyed.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/io_submit.2.html
+#[inline]
+pub unsafe fn io_submit(ctx: &AioContext, iocbs: &mut [&mut Iocb]) -> io::Result<usize> {
+    syscalls::io_submit(ctx.0, iocbs.as_mut_ptr().cast(), iocbs.len())
+}
+
+/// `io_getevents(ctx, min_nr, events.len(), events, timeout)`—Waits for AIO
+/// completion events.
+///
+/// Waits until at least `min_nr` events are available or `timeout` elapses,
+/// and returns the number of events written to the start of `events`. A
+/// `timeout` of `None` waits indefinitely.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/io_getevents.2.html
+#[inline]
+pub fn io_getevents(
+    ctx: &AioContext,
+    min_nr: usize,
+    events: &mut [IoEvent],
+    timeout: Option<&Timespec>,
+) -> io::Result<usize> {
+    syscalls::io_getevents(ctx.0, min_nr, events, timeout)
+}
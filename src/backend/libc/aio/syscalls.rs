@@ -0,0 +1,164 @@
+//! libc syscalls supporting `rustix::aio`.
+
+use crate::aio::{IoEvent, Iocb, Timespec};
+use crate::backend::c;
+use crate::backend::conv::{ret, ret_usize};
+use crate::io;
+use crate::utils::option_as_ptr;
+
+#[inline]
+pub(crate) fn io_setup(nr_events: u32) -> io::Result<c::c_ulong> {
+    syscall! {
+        fn io_setup(
+            nr_events: c::c_uint,
+            ctxp: *mut c::c_ulong
+        ) via SYS_io_setup -> c::c_int
+    }
+
+    let mut ctx: c::c_ulong = 0;
+    unsafe {
+        ret(io_setup(nr_events, &mut ctx))?;
+    }
+    Ok(ctx)
+}
+
+#[inline]
+pub(crate) fn io_destroy(ctx: c::c_ulong) -> io::Result<()> {
+    syscall! {
+        fn io_destroy(ctx: c::c_ulong) via SYS_io_destroy -> c::c_int
+    }
+
+    unsafe { ret(io_destroy(ctx)) }
+}
+
+#[inline]
+pub(crate) unsafe fn io_submit(
+    ctx: c::c_ulong,
+    iocbs: *mut *mut Iocb,
+    nr: usize,
+) -> io::Result<usize> {
+    syscall! {
+        fn io_submit(
+            ctx: c::c_ulong,
+            nr: c::c_long,
+            iocbs: *mut *mut Iocb
+        ) via SYS_io_submit -> c::ssize_t
+    }
+
+    ret_usize(io_submit(ctx, nr as c::c_long, iocbs))
+}
+
+#[inline]
+pub(crate) fn io_getevents(
+    ctx: c::c_ulong,
+    min_nr: usize,
+    events: &mut [IoEvent],
+    timeout: Option<&Timespec>,
+) -> io::Result<usize> {
+    let timeout = option_as_ptr(timeout);
+
+    #[cfg(all(
+        target_pointer_width = "32",
+        not(any(target_arch = "aarch64", target_arch = "x86_64"))
+    ))]
+    unsafe {
+        // TODO: Upstream this to the libc crate.
+        #[allow(non_upper_case_globals)]
+        const SYS_io_pgetevents_time64: i32 =
+            linux_raw_sys::general::__NR_io_pgetevents_time64 as i32;
+
+        syscall! {
+            fn io_pgetevents_time64(
+                ctx: c::c_ulong,
+                min_nr: c::c_long,
+                nr: c::c_long,
+                events: *mut IoEvent,
+                timeout: *const Timespec,
+                usig: *const c::c_void
+            ) via SYS_io_pgetevents_time64 -> c::ssize_t
+        }
+
+        ret_usize(io_pgetevents_time64(
+            ctx,
+            min_nr as c::c_long,
+            events.len() as c::c_long,
+            events.as_mut_ptr(),
+            timeout,
+            core::ptr::null(),
+        ))
+        .or_else(|err| {
+            // See the comments in `rustix_clock_gettime_via_syscall` about
+            // emulation.
+            if err == io::Errno::NOSYS {
+                io_getevents_old(ctx, min_nr, events, timeout)
+            } else {
+                Err(err)
+            }
+        })
+    }
+
+    #[cfg(any(
+        target_pointer_width = "64",
+        target_arch = "aarch64",
+        target_arch = "x86_64"
+    ))]
+    unsafe {
+        syscall! {
+            fn io_getevents(
+                ctx: c::c_ulong,
+                min_nr: c::c_long,
+                nr: c::c_long,
+                events: *mut IoEvent,
+                timeout: *const linux_raw_sys::general::__kernel_timespec
+            ) via SYS_io_getevents -> c::ssize_t
+        }
+
+        ret_usize(io_getevents(
+            ctx,
+            min_nr as c::c_long,
+            events.len() as c::c_long,
+            events.as_mut_ptr(),
+            timeout.cast(),
+        ))
+    }
+}
+
+#[cfg(all(
+    target_pointer_width = "32",
+    not(any(target_arch = "aarch64", target_arch = "x86_64"))
+))]
+unsafe fn io_getevents_old(
+    ctx: c::c_ulong,
+    min_nr: usize,
+    events: &mut [IoEvent],
+    timeout: *const Timespec,
+) -> io::Result<usize> {
+    syscall! {
+        fn io_getevents(
+            ctx: c::c_ulong,
+            min_nr: c::c_long,
+            nr: c::c_long,
+            events: *mut IoEvent,
+            timeout: *const linux_raw_sys::general::timespec
+        ) via SYS_io_getevents -> c::ssize_t
+    }
+
+    let old_timeout = if timeout.is_null() {
+        None
+    } else {
+        Some(linux_raw_sys::general::timespec {
+            tv_sec: (*timeout).tv_sec.try_into().map_err(|_| io::Errno::INVAL)?,
+            tv_nsec: (*timeout)
+                .tv_nsec
+                .try_into()
+                .map_err(|_| io::Errno::INVAL)?,
+        })
+    };
+    ret_usize(io_getevents(
+        ctx,
+        min_nr as c::c_long,
+        events.len() as c::c_long,
+        events.as_mut_ptr(),
+        option_as_ptr(old_timeout.as_ref()),
+    ))
+}
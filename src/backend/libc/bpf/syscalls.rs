@@ -0,0 +1,32 @@
+//! libc syscalls supporting `rustix::bpf`.
+
+use crate::backend::c;
+use crate::backend::conv::{ret_c_int, ret_owned_fd};
+use crate::fd::OwnedFd;
+use crate::io;
+
+syscall! {
+    fn sys_bpf(
+        cmd: c::c_uint,
+        attr: *mut c::c_void,
+        size: c::c_uint
+    ) via SYS_bpf -> c::c_int
+}
+
+#[inline]
+pub(crate) unsafe fn bpf(
+    cmd: c::c_uint,
+    attr: *mut c::c_void,
+    size: c::c_uint,
+) -> io::Result<c::c_int> {
+    ret_c_int(sys_bpf(cmd, attr, size))
+}
+
+#[inline]
+pub(crate) unsafe fn bpf_fd(
+    cmd: c::c_uint,
+    attr: *mut c::c_void,
+    size: c::c_uint,
+) -> io::Result<OwnedFd> {
+    ret_owned_fd(sys_bpf(cmd, attr, size))
+}
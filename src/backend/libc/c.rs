@@ -14,6 +14,16 @@ pub(crate) const PROC_SUPER_MAGIC: u32 = 0x0000_9fa0;
 #[cfg(all(linux_kernel, target_env = "musl"))]
 pub(crate) const NFS_SUPER_MAGIC: u32 = 0x0000_6969;
 
+/// `XATTR_CREATE`—FreeBSD has no `setxattr`, so use the Linux value for our
+/// emulation of it.
+#[cfg(all(feature = "fs", target_os = "freebsd"))]
+pub(crate) const XATTR_CREATE: c_int = 0x1;
+
+/// `XATTR_REPLACE`—FreeBSD has no `setxattr`, so use the Linux value for our
+/// emulation of it.
+#[cfg(all(feature = "fs", target_os = "freebsd"))]
+pub(crate) const XATTR_REPLACE: c_int = 0x2;
+
 #[cfg(feature = "process")]
 #[cfg(not(any(target_os = "espidf", target_os = "wasi")))]
 pub(crate) const EXIT_SIGNALED_SIGABRT: c_int = 128 + SIGABRT as c_int;
@@ -131,7 +141,7 @@ pub(super) use libc::{
     ftruncate64 as ftruncate, getrlimit64 as getrlimit, ino_t, lseek64 as lseek, mmap,
     off64_t as off_t, openat, posix_fadvise64 as posix_fadvise, preadv, pwritev,
     rlimit64 as rlimit, setrlimit64 as setrlimit, stat64at as fstatat, statfs64 as statfs,
-    statvfs64 as statvfs, RLIM_INFINITY,
+    statvfs64 as statvfs, truncate64 as truncate, RLIM_INFINITY,
 };
 #[cfg(any(linux_like, target_os = "hurd"))]
 pub(super) use libc::{
@@ -139,7 +149,7 @@ pub(super) use libc::{
     ftruncate64 as ftruncate, getrlimit64 as getrlimit, ino64_t as ino_t, lseek64 as lseek,
     mmap64 as mmap, off64_t as off_t, openat64 as openat, posix_fadvise64 as posix_fadvise,
     rlimit64 as rlimit, setrlimit64 as setrlimit, statfs64 as statfs, statvfs64 as statvfs,
-    RLIM64_INFINITY as RLIM_INFINITY,
+    truncate64 as truncate, RLIM64_INFINITY as RLIM_INFINITY,
 };
 #[cfg(apple)]
 pub(super) use libc::{
@@ -165,6 +175,17 @@ pub(super) use libc::{lstat64 as lstat, stat64 as stat};
 pub(super) use libc::{pread64 as pread, pwrite64 as pwrite};
 #[cfg(any(target_os = "linux", target_os = "hurd", target_os = "emscripten"))]
 pub(super) use libc::{preadv64 as preadv, pwritev64 as pwritev};
+// The libc crate doesn't define the `F_*LK64` commands, or `flock64` on all
+// architectures, so use the kernel's definitions, which `fcntl` passes
+// through as-is.
+#[cfg(all(linux_kernel, target_pointer_width = "32"))]
+pub(super) use linux_raw_sys::general::flock64 as flock;
+#[cfg(all(linux_kernel, target_pointer_width = "32"))]
+pub(super) const F_GETLK: c_int = linux_raw_sys::general::F_GETLK64 as _;
+#[cfg(all(linux_kernel, target_pointer_width = "32"))]
+pub(super) const F_SETLK: c_int = linux_raw_sys::general::F_SETLK64 as _;
+#[cfg(all(linux_kernel, target_pointer_width = "32"))]
+pub(super) const F_SETLKW: c_int = linux_raw_sys::general::F_SETLKW64 as _;
 
 #[cfg(all(target_os = "linux", any(target_env = "gnu", target_env = "uclibc")))]
 pub(super) unsafe fn prlimit(
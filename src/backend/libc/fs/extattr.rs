@@ -0,0 +1,240 @@
+//! Linux-style `*xattr` functions implemented with FreeBSD's `extattr_*`
+//! functions.
+//!
+//! FreeBSD keeps attribute names in separate namespaces rather than encoding
+//! the namespace in the name, so names such as `user.foo` and `system.foo`
+//! are split into a namespace and a bare name, and listed names are given
+//! the corresponding prefix.
+
+use crate::backend::c;
+use crate::backend::conv::{borrowed_fd, ret_usize};
+use crate::fd::BorrowedFd;
+use crate::ffi::CStr;
+use crate::fs::XattrFlags;
+use crate::io;
+use core::ptr::{null, null_mut};
+
+/// The namespaces we support, and the name prefixes that select them.
+const NAMESPACES: [(c::c_int, &[u8]); 2] = [
+    (c::EXTATTR_NAMESPACE_USER, b"user."),
+    (c::EXTATTR_NAMESPACE_SYSTEM, b"system."),
+];
+
+/// The object whose attributes are being accessed.
+#[derive(Copy, Clone)]
+pub(super) enum Target<'a> {
+    /// A path, following symlinks.
+    File(&'a CStr),
+
+    /// A path, not following symlinks in the last component.
+    Link(&'a CStr),
+
+    /// An open file descriptor.
+    Fd(BorrowedFd<'a>),
+}
+
+/// Splits a name such as `user.foo` into a namespace and the name within it.
+fn split_name(name: &CStr) -> io::Result<(c::c_int, &CStr)> {
+    let bytes = name.to_bytes_with_nul();
+    for (namespace, prefix) in NAMESPACES {
+        if let Some(rest) = bytes.strip_prefix(prefix) {
+            // SAFETY: `rest` is a suffix of a NUL-terminated string, so it
+            // ends with the only NUL.
+            let rest = unsafe { CStr::from_bytes_with_nul_unchecked(rest) };
+            return Ok((namespace, rest));
+        }
+    }
+
+    // Linux fails with `EOPNOTSUPP` for unknown namespaces too.
+    Err(io::Errno::OPNOTSUPP)
+}
+
+unsafe fn raw_get(
+    target: Target<'_>,
+    namespace: c::c_int,
+    name: &CStr,
+    data: *mut c::c_void,
+    nbytes: usize,
+) -> io::Result<usize> {
+    ret_usize(match target {
+        Target::File(path) => {
+            c::extattr_get_file(path.as_ptr(), namespace, name.as_ptr(), data, nbytes)
+        }
+        Target::Link(path) => {
+            c::extattr_get_link(path.as_ptr(), namespace, name.as_ptr(), data, nbytes)
+        }
+        Target::Fd(fd) => {
+            c::extattr_get_fd(borrowed_fd(fd), namespace, name.as_ptr(), data, nbytes)
+        }
+    })
+}
+
+unsafe fn raw_list(
+    target: Target<'_>,
+    namespace: c::c_int,
+    data: *mut c::c_void,
+    nbytes: usize,
+) -> io::Result<usize> {
+    ret_usize(match target {
+        Target::File(path) => c::extattr_list_file(path.as_ptr(), namespace, data, nbytes),
+        Target::Link(path) => c::extattr_list_link(path.as_ptr(), namespace, data, nbytes),
+        Target::Fd(fd) => c::extattr_list_fd(borrowed_fd(fd), namespace, data, nbytes),
+    })
+}
+
+pub(super) fn get(target: Target<'_>, name: &CStr, value: &mut [u8]) -> io::Result<usize> {
+    let (namespace, name) = split_name(name)?;
+
+    unsafe {
+        if value.is_empty() {
+            return raw_get(target, namespace, name, null_mut(), 0);
+        }
+
+        // `extattr_get_*` silently truncates the value if the buffer is too
+        // small, so check for that and fail with `ERANGE` like Linux does.
+        let len = raw_get(
+            target,
+            namespace,
+            name,
+            value.as_mut_ptr().cast(),
+            value.len(),
+        )?;
+        if len == value.len() && raw_get(target, namespace, name, null_mut(), 0)? > len {
+            return Err(io::Errno::RANGE);
+        }
+        Ok(len)
+    }
+}
+
+pub(super) fn set(
+    target: Target<'_>,
+    name: &CStr,
+    value: &[u8],
+    flags: XattrFlags,
+) -> io::Result<()> {
+    let (namespace, name) = split_name(name)?;
+
+    unsafe {
+        // `extattr_set_*` has no flags, so check for existence first. This
+        // is racy, but it's the best we can do.
+        if flags.intersects(XattrFlags::CREATE | XattrFlags::REPLACE) {
+            let exists = match raw_get(target, namespace, name, null_mut(), 0) {
+                Ok(_) => true,
+                Err(io::Errno::NOATTR) => false,
+                Err(err) => return Err(err),
+            };
+            if exists && flags.contains(XattrFlags::CREATE) {
+                return Err(io::Errno::EXIST);
+            }
+            if !exists && flags.contains(XattrFlags::REPLACE) {
+                return Err(io::Errno::NOATTR);
+            }
+        }
+
+        let data = if value.is_empty() {
+            null()
+        } else {
+            value.as_ptr().cast()
+        };
+        ret_usize(match target {
+            Target::File(path) => {
+                c::extattr_set_file(path.as_ptr(), namespace, name.as_ptr(), data, value.len())
+            }
+            Target::Link(path) => {
+                c::extattr_set_link(path.as_ptr(), namespace, name.as_ptr(), data, value.len())
+            }
+            Target::Fd(fd) => {
+                c::extattr_set_fd(borrowed_fd(fd), namespace, name.as_ptr(), data, value.len())
+            }
+        })?;
+    }
+    Ok(())
+}
+
+pub(super) fn remove(target: Target<'_>, name: &CStr) -> io::Result<()> {
+    let (namespace, name) = split_name(name)?;
+
+    unsafe {
+        crate::backend::conv::ret(match target {
+            Target::File(path) => c::extattr_delete_file(path.as_ptr(), namespace, name.as_ptr()),
+            Target::Link(path) => c::extattr_delete_link(path.as_ptr(), namespace, name.as_ptr()),
+            Target::Fd(fd) => c::extattr_delete_fd(borrowed_fd(fd), namespace, name.as_ptr()),
+        })
+    }
+}
+
+/// Lists attribute names in all namespaces, in the Linux format of
+/// NUL-terminated names with namespace prefixes.
+///
+/// `extattr_list_*` produces names preceded by a length byte and without
+/// prefixes, so each namespace's list is read into `list` and then expanded
+/// in place. When `list` is empty, the returned size is an upper bound,
+/// since computing the exact size would require reading the names.
+pub(super) fn list(target: Target<'_>, list: &mut [c::c_char]) -> io::Result<usize> {
+    // SAFETY: `c_char` is either `u8` or `i8`, which have the same layout.
+    let buf =
+        unsafe { core::slice::from_raw_parts_mut(list.as_mut_ptr().cast::<u8>(), list.len()) };
+    let mut total = 0;
+
+    for (namespace, prefix) in NAMESPACES {
+        let needed = match unsafe { raw_list(target, namespace, null_mut(), 0) } {
+            Ok(needed) => needed,
+            // Unprivileged processes can't list the system namespace.
+            Err(io::Errno::PERM) if namespace == c::EXTATTR_NAMESPACE_SYSTEM => continue,
+            Err(err) => return Err(err),
+        };
+
+        if buf.is_empty() {
+            // Each entry is at least a length byte and one name byte, and
+            // grows by the length of the prefix.
+            total += needed + needed / 2 * prefix.len();
+            continue;
+        }
+
+        let rest = &mut buf[total..];
+        if needed > rest.len() {
+            return Err(io::Errno::RANGE);
+        }
+        let raw = unsafe { raw_list(target, namespace, rest.as_mut_ptr().cast(), rest.len())? };
+        total += expand(&mut rest[..], raw, prefix)?;
+    }
+
+    Ok(total)
+}
+
+/// Converts the `raw` bytes of length-prefixed names at the start of `buf`
+/// into prefixed NUL-terminated names, returning the new length.
+fn expand(buf: &mut [u8], raw: usize, prefix: &[u8]) -> io::Result<usize> {
+    // Count the entries and check that they fit.
+    let mut count = 0;
+    let mut pos = 0;
+    while pos < raw {
+        pos += 1 + usize::from(buf[pos]);
+        count += 1;
+    }
+    if pos != raw {
+        // The list changed while we were reading it.
+        return Err(io::Errno::RANGE);
+    }
+    let len = raw + count * prefix.len();
+    if len > buf.len() {
+        return Err(io::Errno::RANGE);
+    }
+
+    // Each entry moves to a later position, so convert them from last to
+    // first so that no entry is overwritten before it's moved.
+    for index in (0..count).rev() {
+        let mut input = 0;
+        for _ in 0..index {
+            input += 1 + usize::from(buf[input]);
+        }
+        let name_len = usize::from(buf[input]);
+        let output = input + index * prefix.len();
+
+        buf.copy_within(input + 1..input + 1 + name_len, output + prefix.len());
+        buf[output + prefix.len() + name_len] = 0;
+        buf[output..output + prefix.len()].copy_from_slice(prefix);
+    }
+
+    Ok(len)
+}
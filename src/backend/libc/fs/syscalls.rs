@@ -1,5 +1,7 @@
 //! libc syscalls supporting `rustix::fs`.
 
+#[cfg(target_os = "freebsd")]
+use super::extattr;
 use crate::backend::c;
 #[cfg(any(
     not(target_os = "redox"),
@@ -28,7 +30,6 @@ use crate::fs::Advice;
 #[cfg(not(any(target_os = "espidf", target_os = "redox")))]
 use crate::fs::AtFlags;
 #[cfg(not(any(
-    netbsdlike,
     solarish,
     target_os = "aix",
     target_os = "dragonfly",
@@ -38,6 +39,8 @@ use crate::fs::AtFlags;
     target_os = "vita",
 )))]
 use crate::fs::FallocateFlags;
+#[cfg(bsd)]
+use crate::fs::FileFlags;
 #[cfg(not(any(target_os = "espidf", target_os = "vita", target_os = "wasi")))]
 use crate::fs::FlockOperation;
 #[cfg(any(linux_kernel, target_os = "freebsd"))]
@@ -48,15 +51,18 @@ use crate::fs::SealFlags;
     solarish,
     target_os = "espidf",
     target_os = "haiku",
-    target_os = "netbsd",
     target_os = "nto",
     target_os = "redox",
     target_os = "vita",
     target_os = "wasi",
 )))]
 use crate::fs::StatFs;
+#[cfg(linux_kernel)]
+use crate::fs::SyncFileRangeFlags;
 #[cfg(not(any(target_os = "espidf", target_os = "vita")))]
 use crate::fs::Timestamps;
+#[cfg(any(apple, linux_kernel, target_os = "freebsd"))]
+use crate::fs::XattrFlags;
 #[cfg(not(any(
     apple,
     target_os = "espidf",
@@ -73,16 +79,25 @@ use crate::io;
 use crate::timespec::LibcTimespec;
 #[cfg(not(target_os = "wasi"))]
 use crate::ugid::{Gid, Uid};
+#[cfg(fcntl_lock)]
+use crate::{
+    fs::{Flock, FlockOffsetType, FlockType},
+    pid::Pid,
+};
 #[cfg(all(apple, feature = "alloc"))]
 use alloc::vec;
+#[cfg(any(apple, freebsdlike))]
+use core::cmp::min;
+#[cfg(any(apple, linux_kernel))]
+use core::mem::size_of;
 use core::mem::MaybeUninit;
+#[cfg(any(apple, freebsdlike, linux_kernel))]
+use core::ptr::null_mut;
 #[cfg(apple)]
 use {
     crate::backend::conv::nonnegative_ret,
     crate::fs::{copyfile_state_t, CloneFlags, CopyfileFlags},
 };
-#[cfg(any(apple, linux_kernel))]
-use {crate::fs::XattrFlags, core::mem::size_of, core::ptr::null_mut};
 #[cfg(linux_kernel)]
 use {
     crate::fs::{RenameFlags, ResolveFlags, Statx, StatxFlags, CWD},
@@ -264,6 +279,16 @@ pub(crate) fn statfs(filename: &CStr) -> io::Result<StatFs> {
     }
 }
 
+#[cfg(target_os = "netbsd")]
+#[inline]
+pub(crate) fn statfs(filename: &CStr) -> io::Result<StatFs> {
+    unsafe {
+        let mut result = MaybeUninit::<StatFs>::uninit();
+        ret(c::statvfs(c_str(filename), result.as_mut_ptr()))?;
+        Ok(result.assume_init())
+    }
+}
+
 #[cfg(not(any(target_os = "haiku", target_os = "redox", target_os = "wasi")))]
 #[inline]
 pub(crate) fn statvfs(filename: &CStr) -> io::Result<StatVfs> {
@@ -778,6 +803,37 @@ pub(crate) fn accessat(
         ret(c::access(c_str(path), bitflags_bits!(access)))
     }
 
+    // Linux's `faccessat` syscall doesn't have a flags argument, and libc
+    // implementations emulate the flags in different ways, some of which
+    // ignore ACLs and other security policies. So if we have any flags, use
+    // the newer `faccessat2` introduced in Linux 5.8 directly, and only fall
+    // back to libc's emulation if it's not available. Unless we're on Android
+    // where using newer system calls can cause seccomp to abort the process.
+    #[cfg(all(linux_kernel, not(target_os = "android")))]
+    if !flags.is_empty() {
+        syscall! {
+            fn faccessat2(
+                dirfd_: BorrowedFd<'_>,
+                pathname: *const c::c_char,
+                mode: c::c_int,
+                flags: c::c_int
+            ) via SYS_faccessat2 -> c::c_int
+        }
+
+        unsafe {
+            match ret(faccessat2(
+                dirfd,
+                c_str(path),
+                bitflags_bits!(access),
+                bitflags_bits!(flags),
+            )) {
+                Ok(()) => return Ok(()),
+                Err(io::Errno::NOSYS) => {}
+                Err(other) => return Err(other),
+            }
+        }
+    }
+
     #[cfg(not(target_os = "macos"))]
     unsafe {
         ret(c::faccessat(
@@ -1117,6 +1173,23 @@ pub(crate) fn chownat(
     }
 }
 
+#[cfg(any(freebsdlike, target_os = "openbsd"))]
+pub(crate) fn chflagsat(
+    dirfd: BorrowedFd<'_>,
+    path: &CStr,
+    flags: FileFlags,
+    atflags: AtFlags,
+) -> io::Result<()> {
+    unsafe {
+        ret(c::chflagsat(
+            borrowed_fd(dirfd),
+            c_str(path),
+            flags.bits() as _,
+            bitflags_bits!(atflags),
+        ))
+    }
+}
+
 #[cfg(not(any(
     apple,
     target_os = "espidf",
@@ -1254,14 +1327,7 @@ pub(crate) fn fcntl_add_seals(fd: BorrowedFd<'_>, seals: SealFlags) -> io::Resul
     unsafe { ret(c::fcntl(borrowed_fd(fd), c::F_ADD_SEALS, seals.bits())) }
 }
 
-#[cfg(not(any(
-    target_os = "emscripten",
-    target_os = "espidf",
-    target_os = "fuchsia",
-    target_os = "redox",
-    target_os = "vita",
-    target_os = "wasi"
-)))]
+#[cfg(fcntl_lock)]
 #[inline]
 pub(crate) fn fcntl_lock(fd: BorrowedFd<'_>, operation: FlockOperation) -> io::Result<()> {
     use c::{flock, F_RDLCK, F_SETLK, F_SETLKW, F_UNLCK, F_WRLCK, SEEK_SET};
@@ -1290,6 +1356,73 @@ pub(crate) fn fcntl_lock(fd: BorrowedFd<'_>, operation: FlockOperation) -> io::R
     }
 }
 
+#[cfg(fcntl_lock)]
+#[inline]
+pub(crate) fn fcntl_setlk(fd: BorrowedFd<'_>, lock: &Flock) -> io::Result<()> {
+    fcntl_flock(fd, c::F_SETLK, lock).map(drop)
+}
+
+#[cfg(fcntl_lock)]
+#[inline]
+pub(crate) fn fcntl_setlkw(fd: BorrowedFd<'_>, lock: &Flock) -> io::Result<()> {
+    fcntl_flock(fd, c::F_SETLKW, lock).map(drop)
+}
+
+#[cfg(fcntl_lock)]
+#[inline]
+pub(crate) fn fcntl_getlk(fd: BorrowedFd<'_>, lock: &Flock) -> io::Result<Flock> {
+    fcntl_flock(fd, c::F_GETLK, lock)
+}
+
+#[cfg(linux_kernel)]
+#[inline]
+pub(crate) fn fcntl_ofd_setlk(fd: BorrowedFd<'_>, lock: &Flock) -> io::Result<()> {
+    fcntl_flock(fd, c::F_OFD_SETLK, lock).map(drop)
+}
+
+#[cfg(linux_kernel)]
+#[inline]
+pub(crate) fn fcntl_ofd_setlkw(fd: BorrowedFd<'_>, lock: &Flock) -> io::Result<()> {
+    fcntl_flock(fd, c::F_OFD_SETLKW, lock).map(drop)
+}
+
+#[cfg(linux_kernel)]
+#[inline]
+pub(crate) fn fcntl_ofd_getlk(fd: BorrowedFd<'_>, lock: &Flock) -> io::Result<Flock> {
+    fcntl_flock(fd, c::F_OFD_GETLK, lock)
+}
+
+/// Performs a record lock `fcntl` command, and returns the lock the kernel
+/// wrote back, which is only meaningful for the `GETLK` commands.
+#[cfg(fcntl_lock)]
+fn fcntl_flock(fd: BorrowedFd<'_>, cmd: c::c_int, lock: &Flock) -> io::Result<Flock> {
+    unsafe {
+        let mut raw: c::flock = core::mem::zeroed();
+        raw.l_type = lock.typ as _;
+        raw.l_whence = lock.offset_type as _;
+        raw.l_start = lock.start;
+        raw.l_len = lock.length;
+
+        ret(c::fcntl(borrowed_fd(fd), cmd, &mut raw))?;
+
+        Ok(Flock {
+            start: raw.l_start,
+            length: raw.l_len,
+            pid: Pid::from_raw(raw.l_pid),
+            typ: match raw.l_type as i32 {
+                l_type if l_type == FlockType::ReadLock as i32 => FlockType::ReadLock,
+                l_type if l_type == FlockType::WriteLock as i32 => FlockType::WriteLock,
+                _ => FlockType::Unlocked,
+            },
+            offset_type: match raw.l_whence as i32 {
+                whence if whence == FlockOffsetType::Current as i32 => FlockOffsetType::Current,
+                whence if whence == FlockOffsetType::End as i32 => FlockOffsetType::End,
+                _ => FlockOffsetType::Set,
+            },
+        })
+    }
+}
+
 pub(crate) fn seek(fd: BorrowedFd<'_>, pos: SeekFrom) -> io::Result<u64> {
     let (whence, offset) = match pos {
         SeekFrom::Start(pos) => {
@@ -1338,6 +1471,21 @@ pub(crate) fn fchmod(fd: BorrowedFd<'_>, mode: Mode) -> io::Result<()> {
     unsafe { ret(fchmod(borrowed_fd(fd), mode.bits() as c::mode_t)) }
 }
 
+#[cfg(bsd)]
+pub(crate) fn chflags(path: &CStr, flags: FileFlags) -> io::Result<()> {
+    unsafe { ret(c::chflags(c_str(path), flags.bits() as _)) }
+}
+
+#[cfg(any(apple, freebsdlike, target_os = "netbsd"))]
+pub(crate) fn lchflags(path: &CStr, flags: FileFlags) -> io::Result<()> {
+    unsafe { ret(c::lchflags(c_str(path), flags.bits() as _)) }
+}
+
+#[cfg(bsd)]
+pub(crate) fn fchflags(fd: BorrowedFd<'_>, flags: FileFlags) -> io::Result<()> {
+    unsafe { ret(c::fchflags(borrowed_fd(fd), flags.bits() as _)) }
+}
+
 #[cfg(not(target_os = "wasi"))]
 pub(crate) fn chown(path: &CStr, owner: Option<Uid>, group: Option<Gid>) -> io::Result<()> {
     unsafe {
@@ -1484,6 +1632,15 @@ pub(crate) fn fstatfs(fd: BorrowedFd<'_>) -> io::Result<StatFs> {
     }
 }
 
+#[cfg(target_os = "netbsd")]
+pub(crate) fn fstatfs(fd: BorrowedFd<'_>) -> io::Result<StatFs> {
+    let mut statfs = MaybeUninit::<StatFs>::uninit();
+    unsafe {
+        ret(c::fstatvfs(borrowed_fd(fd), statfs.as_mut_ptr()))?;
+        Ok(statfs.assume_init())
+    }
+}
+
 #[cfg(not(any(target_os = "haiku", target_os = "redox", target_os = "wasi")))]
 pub(crate) fn fstatvfs(fd: BorrowedFd<'_>) -> io::Result<StatVfs> {
     let mut statvfs = MaybeUninit::<c::statvfs>::uninit();
@@ -1614,12 +1771,12 @@ fn futimens_old(fd: BorrowedFd<'_>, times: &Timestamps) -> io::Result<()> {
 
 #[cfg(not(any(
     apple,
-    netbsdlike,
     solarish,
     target_os = "aix",
     target_os = "dragonfly",
     target_os = "espidf",
     target_os = "nto",
+    target_os = "openbsd",
     target_os = "redox",
     target_os = "vita",
 )))]
@@ -1645,8 +1802,16 @@ pub(crate) fn fallocate(
 
     #[cfg(not(any(linux_kernel, target_os = "fuchsia")))]
     {
+        // The libc crate doesn't declare NetBSD's `posix_fallocate`.
+        #[cfg(target_os = "netbsd")]
+        extern "C" {
+            fn posix_fallocate(fd: c::c_int, offset: c::off_t, len: c::off_t) -> c::c_int;
+        }
+        #[cfg(not(target_os = "netbsd"))]
+        use c::posix_fallocate;
+
         assert!(mode.is_empty());
-        let err = unsafe { c::posix_fallocate(borrowed_fd(fd), offset, len) };
+        let err = unsafe { posix_fallocate(borrowed_fd(fd), offset, len) };
 
         // `posix_fallocate` returns its error status rather than using
         // `errno`.
@@ -1705,11 +1870,92 @@ pub(crate) fn fdatasync(fd: BorrowedFd<'_>) -> io::Result<()> {
     unsafe { ret(c::fdatasync(borrowed_fd(fd))) }
 }
 
+#[cfg(linux_kernel)]
+pub(crate) fn sync_file_range(
+    fd: BorrowedFd<'_>,
+    offset: u64,
+    nbytes: u64,
+    flags: SyncFileRangeFlags,
+) -> io::Result<()> {
+    // Silently cast; we'll get `EINVAL` if the value is negative.
+    let offset = offset as i64;
+    let nbytes = nbytes as i64;
+
+    #[cfg(target_os = "linux")]
+    unsafe {
+        ret(c::sync_file_range(
+            borrowed_fd(fd),
+            offset,
+            nbytes,
+            bitflags_bits!(flags),
+        ))
+    }
+
+    // Android's libc only has `sync_file_range` since API level 26.
+    #[cfg(target_os = "android")]
+    unsafe {
+        weak! {
+            fn sync_file_range(c::c_int, i64, i64, c::c_uint) -> c::c_int
+        }
+
+        match sync_file_range.get() {
+            Some(sync_file_range) => ret(sync_file_range(
+                borrowed_fd(fd),
+                offset,
+                nbytes,
+                bitflags_bits!(flags),
+            )),
+            None => Err(io::Errno::NOSYS),
+        }
+    }
+}
+
+#[cfg(linux_kernel)]
+pub(crate) fn readahead(fd: BorrowedFd<'_>, offset: u64, count: usize) -> io::Result<()> {
+    // Silently cast; we'll get `EINVAL` if the value is negative.
+    let offset = offset as i64;
+
+    #[cfg(target_os = "linux")]
+    unsafe {
+        ret_usize(c::readahead(borrowed_fd(fd), offset, count)).map(|_| ())
+    }
+
+    // The `libc` crate doesn't declare `readahead` for Android.
+    #[cfg(target_os = "android")]
+    unsafe {
+        weak! {
+            fn readahead(c::c_int, i64, usize) -> c::ssize_t
+        }
+
+        match readahead.get() {
+            Some(readahead) => ret_usize(readahead(borrowed_fd(fd), offset, count)).map(|_| ()),
+            None => Err(io::Errno::NOSYS),
+        }
+    }
+}
+
+#[cfg(apple)]
+pub(crate) fn fdatasync(fd: BorrowedFd<'_>) -> io::Result<()> {
+    // Apple platforms have no public `fdatasync`, and their `fsync` doesn't
+    // flush the drive's cache, so use `F_FULLFSYNC`, as std's `sync_data`
+    // does. Some filesystems, such as network filesystems, don't support
+    // `F_FULLFSYNC`; fall back to `fsync` for those.
+    match fcntl_fullfsync(fd) {
+        Err(io::Errno::INVAL | io::Errno::NOTSUP | io::Errno::NOTTY) => fsync(fd),
+        result => result,
+    }
+}
+
 pub(crate) fn ftruncate(fd: BorrowedFd<'_>, length: u64) -> io::Result<()> {
     let length = length.try_into().map_err(|_overflow_err| io::Errno::FBIG)?;
     unsafe { ret(c::ftruncate(borrowed_fd(fd), length)) }
 }
 
+pub(crate) fn truncate(path: &CStr, length: u64) -> io::Result<()> {
+    let length = length.try_into().map_err(|_overflow_err| io::Errno::FBIG)?;
+    unsafe { ret(c::truncate(c_str(path), length)) }
+}
+
 #[cfg(any(linux_kernel, target_os = "freebsd"))]
 pub(crate) fn memfd_create(name: &CStr, flags: MemfdFlags) -> io::Result<OwnedFd> {
     #[cfg(target_os = "freebsd")]
@@ -1771,7 +2017,7 @@ const SYS_OPENAT2: i32 = 437;
 #[cfg(all(linux_kernel, target_pointer_width = "64"))]
 const SYS_OPENAT2: i64 = 437;
 
-#[cfg(target_os = "linux")]
+#[cfg(linux_kernel)]
 pub(crate) fn sendfile(
     out_fd: BorrowedFd<'_>,
     in_fd: BorrowedFd<'_>,
@@ -1788,6 +2034,74 @@ pub(crate) fn sendfile(
     }
 }
 
+#[cfg(any(apple, freebsdlike))]
+pub(crate) fn sendfile(
+    out_fd: BorrowedFd<'_>,
+    in_fd: BorrowedFd<'_>,
+    offset: Option<&mut u64>,
+    count: usize,
+) -> io::Result<usize> {
+    // The BSD `sendfile`s treat a count of 0 as "until the end of the file".
+    if count == 0 {
+        return Ok(0);
+    }
+
+    // The BSD `sendfile`s always take an explicit offset, and never use or
+    // update the file offset, so do that ourselves when `offset` is `None`.
+    // Unlike Linux's `sendfile`, this isn't atomic with respect to other
+    // users of the open file description.
+    let start = match &offset {
+        Some(offset) => **offset,
+        None => tell(in_fd)?,
+    };
+    let start: c::off_t = start.try_into().map_err(|_| io::Errno::INVAL)?;
+    let count = min(count, c::off_t::MAX as usize);
+
+    #[cfg(apple)]
+    let (result, sent) = unsafe {
+        let mut len = count as c::off_t;
+        let result = c::sendfile(
+            borrowed_fd(in_fd),
+            borrowed_fd(out_fd),
+            start,
+            &mut len,
+            null_mut(),
+            0,
+        );
+        (result, len)
+    };
+
+    #[cfg(freebsdlike)]
+    let (result, sent) = unsafe {
+        let mut sbytes = 0;
+        let result = c::sendfile(
+            borrowed_fd(in_fd),
+            borrowed_fd(out_fd),
+            start,
+            count,
+            null_mut(),
+            &mut sbytes,
+            0,
+        );
+        (result, sbytes)
+    };
+
+    // If the socket is non-blocking or a signal arrives, these fail with
+    // `EAGAIN` or `EINTR` after a partial send, so report the partial send
+    // instead, as Linux does.
+    if sent == 0 {
+        ret(result)?;
+    }
+
+    match offset {
+        Some(offset) => *offset += sent as u64,
+        None => {
+            seek(in_fd, SeekFrom::Start((start + sent) as u64))?;
+        }
+    }
+    Ok(sent as usize)
+}
+
 /// Convert from a Linux `statx` value to rustix's `Stat`.
 #[cfg(all(linux_kernel, target_pointer_width = "32"))]
 #[allow(deprecated)] // for `st_[amc]time` u64->i64 transition
@@ -2241,11 +2555,12 @@ struct Attrlist {
     forkattr: Attrgroup,
 }
 
-#[cfg(any(apple, linux_kernel))]
+#[cfg(any(apple, linux_kernel, target_os = "freebsd"))]
 pub(crate) fn getxattr(path: &CStr, name: &CStr, value: &mut [u8]) -> io::Result<usize> {
+    #[cfg(any(apple, linux_kernel))]
     let value_ptr = value.as_mut_ptr();
 
-    #[cfg(not(apple))]
+    #[cfg(linux_kernel)]
     unsafe {
         ret_usize(c::getxattr(
             path.as_ptr(),
@@ -2266,13 +2581,19 @@ pub(crate) fn getxattr(path: &CStr, name: &CStr, value: &mut [u8]) -> io::Result
             0,
         ))
     }
+
+    #[cfg(target_os = "freebsd")]
+    {
+        extattr::get(extattr::Target::File(path), name, value)
+    }
 }
 
-#[cfg(any(apple, linux_kernel))]
+#[cfg(any(apple, linux_kernel, target_os = "freebsd"))]
 pub(crate) fn lgetxattr(path: &CStr, name: &CStr, value: &mut [u8]) -> io::Result<usize> {
+    #[cfg(any(apple, linux_kernel))]
     let value_ptr = value.as_mut_ptr();
 
-    #[cfg(not(apple))]
+    #[cfg(linux_kernel)]
     unsafe {
         ret_usize(c::lgetxattr(
             path.as_ptr(),
@@ -2302,13 +2623,19 @@ pub(crate) fn lgetxattr(path: &CStr, name: &CStr, value: &mut [u8]) -> io::Resul
             ))
         }
     }
+
+    #[cfg(target_os = "freebsd")]
+    {
+        extattr::get(extattr::Target::Link(path), name, value)
+    }
 }
 
-#[cfg(any(apple, linux_kernel))]
+#[cfg(any(apple, linux_kernel, target_os = "freebsd"))]
 pub(crate) fn fgetxattr(fd: BorrowedFd<'_>, name: &CStr, value: &mut [u8]) -> io::Result<usize> {
+    #[cfg(any(apple, linux_kernel))]
     let value_ptr = value.as_mut_ptr();
 
-    #[cfg(not(apple))]
+    #[cfg(linux_kernel)]
     unsafe {
         ret_usize(c::fgetxattr(
             borrowed_fd(fd),
@@ -2329,16 +2656,21 @@ pub(crate) fn fgetxattr(fd: BorrowedFd<'_>, name: &CStr, value: &mut [u8]) -> io
             0,
         ))
     }
+
+    #[cfg(target_os = "freebsd")]
+    {
+        extattr::get(extattr::Target::Fd(fd), name, value)
+    }
 }
 
-#[cfg(any(apple, linux_kernel))]
+#[cfg(any(apple, linux_kernel, target_os = "freebsd"))]
 pub(crate) fn setxattr(
     path: &CStr,
     name: &CStr,
     value: &[u8],
     flags: XattrFlags,
 ) -> io::Result<()> {
-    #[cfg(not(apple))]
+    #[cfg(linux_kernel)]
     unsafe {
         ret(c::setxattr(
             path.as_ptr(),
@@ -2360,16 +2692,21 @@ pub(crate) fn setxattr(
             flags.bits() as i32,
         ))
     }
+
+    #[cfg(target_os = "freebsd")]
+    {
+        extattr::set(extattr::Target::File(path), name, value, flags)
+    }
 }
 
-#[cfg(any(apple, linux_kernel))]
+#[cfg(any(apple, linux_kernel, target_os = "freebsd"))]
 pub(crate) fn lsetxattr(
     path: &CStr,
     name: &CStr,
     value: &[u8],
     flags: XattrFlags,
 ) -> io::Result<()> {
-    #[cfg(not(apple))]
+    #[cfg(linux_kernel)]
     unsafe {
         ret(c::lsetxattr(
             path.as_ptr(),
@@ -2391,16 +2728,21 @@ pub(crate) fn lsetxattr(
             flags.bits() as i32 | c::XATTR_NOFOLLOW,
         ))
     }
+
+    #[cfg(target_os = "freebsd")]
+    {
+        extattr::set(extattr::Target::Link(path), name, value, flags)
+    }
 }
 
-#[cfg(any(apple, linux_kernel))]
+#[cfg(any(apple, linux_kernel, target_os = "freebsd"))]
 pub(crate) fn fsetxattr(
     fd: BorrowedFd<'_>,
     name: &CStr,
     value: &[u8],
     flags: XattrFlags,
 ) -> io::Result<()> {
-    #[cfg(not(apple))]
+    #[cfg(linux_kernel)]
     unsafe {
         ret(c::fsetxattr(
             borrowed_fd(fd),
@@ -2422,11 +2764,16 @@ pub(crate) fn fsetxattr(
             flags.bits() as i32,
         ))
     }
+
+    #[cfg(target_os = "freebsd")]
+    {
+        extattr::set(extattr::Target::Fd(fd), name, value, flags)
+    }
 }
 
-#[cfg(any(apple, linux_kernel))]
+#[cfg(any(apple, linux_kernel, target_os = "freebsd"))]
 pub(crate) fn listxattr(path: &CStr, list: &mut [c::c_char]) -> io::Result<usize> {
-    #[cfg(not(apple))]
+    #[cfg(linux_kernel)]
     unsafe {
         ret_usize(c::listxattr(path.as_ptr(), list.as_mut_ptr(), list.len()))
     }
@@ -2440,11 +2787,16 @@ pub(crate) fn listxattr(path: &CStr, list: &mut [c::c_char]) -> io::Result<usize
             0,
         ))
     }
+
+    #[cfg(target_os = "freebsd")]
+    {
+        extattr::list(extattr::Target::File(path), list)
+    }
 }
 
-#[cfg(any(apple, linux_kernel))]
+#[cfg(any(apple, linux_kernel, target_os = "freebsd"))]
 pub(crate) fn llistxattr(path: &CStr, list: &mut [c::c_char]) -> io::Result<usize> {
-    #[cfg(not(apple))]
+    #[cfg(linux_kernel)]
     unsafe {
         ret_usize(c::llistxattr(path.as_ptr(), list.as_mut_ptr(), list.len()))
     }
@@ -2458,13 +2810,19 @@ pub(crate) fn llistxattr(path: &CStr, list: &mut [c::c_char]) -> io::Result<usiz
             c::XATTR_NOFOLLOW,
         ))
     }
+
+    #[cfg(target_os = "freebsd")]
+    {
+        extattr::list(extattr::Target::Link(path), list)
+    }
 }
 
-#[cfg(any(apple, linux_kernel))]
+#[cfg(any(apple, linux_kernel, target_os = "freebsd"))]
 pub(crate) fn flistxattr(fd: BorrowedFd<'_>, list: &mut [c::c_char]) -> io::Result<usize> {
+    #[cfg(any(apple, linux_kernel))]
     let fd = borrowed_fd(fd);
 
-    #[cfg(not(apple))]
+    #[cfg(linux_kernel)]
     unsafe {
         ret_usize(c::flistxattr(fd, list.as_mut_ptr(), list.len()))
     }
@@ -2473,11 +2831,16 @@ pub(crate) fn flistxattr(fd: BorrowedFd<'_>, list: &mut [c::c_char]) -> io::Resu
     unsafe {
         ret_usize(c::flistxattr(fd, list.as_mut_ptr(), list.len(), 0))
     }
+
+    #[cfg(target_os = "freebsd")]
+    {
+        extattr::list(extattr::Target::Fd(fd), list)
+    }
 }
 
-#[cfg(any(apple, linux_kernel))]
+#[cfg(any(apple, linux_kernel, target_os = "freebsd"))]
 pub(crate) fn removexattr(path: &CStr, name: &CStr) -> io::Result<()> {
-    #[cfg(not(apple))]
+    #[cfg(linux_kernel)]
     unsafe {
         ret(c::removexattr(path.as_ptr(), name.as_ptr()))
     }
@@ -2486,11 +2849,16 @@ pub(crate) fn removexattr(path: &CStr, name: &CStr) -> io::Result<()> {
     unsafe {
         ret(c::removexattr(path.as_ptr(), name.as_ptr(), 0))
     }
+
+    #[cfg(target_os = "freebsd")]
+    {
+        extattr::remove(extattr::Target::File(path), name)
+    }
 }
 
-#[cfg(any(apple, linux_kernel))]
+#[cfg(any(apple, linux_kernel, target_os = "freebsd"))]
 pub(crate) fn lremovexattr(path: &CStr, name: &CStr) -> io::Result<()> {
-    #[cfg(not(apple))]
+    #[cfg(linux_kernel)]
     unsafe {
         ret(c::lremovexattr(path.as_ptr(), name.as_ptr()))
     }
@@ -2503,13 +2871,19 @@ pub(crate) fn lremovexattr(path: &CStr, name: &CStr) -> io::Result<()> {
             c::XATTR_NOFOLLOW,
         ))
     }
+
+    #[cfg(target_os = "freebsd")]
+    {
+        extattr::remove(extattr::Target::Link(path), name)
+    }
 }
 
-#[cfg(any(apple, linux_kernel))]
+#[cfg(any(apple, linux_kernel, target_os = "freebsd"))]
 pub(crate) fn fremovexattr(fd: BorrowedFd<'_>, name: &CStr) -> io::Result<()> {
+    #[cfg(any(apple, linux_kernel))]
     let fd = borrowed_fd(fd);
 
-    #[cfg(not(apple))]
+    #[cfg(linux_kernel)]
     unsafe {
         ret(c::fremovexattr(fd, name.as_ptr()))
     }
@@ -2518,6 +2892,11 @@ pub(crate) fn fremovexattr(fd: BorrowedFd<'_>, name: &CStr) -> io::Result<()> {
     unsafe {
         ret(c::fremovexattr(fd, name.as_ptr(), 0))
     }
+
+    #[cfg(target_os = "freebsd")]
+    {
+        extattr::remove(extattr::Target::Fd(fd), name)
+    }
 }
 
 #[test]
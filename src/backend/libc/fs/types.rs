@@ -397,6 +397,101 @@ bitflags! {
     }
 }
 
+#[cfg(bsd)]
+bitflags! {
+    /// `UF_*` and `SF_*` constants for use with [`chflags`], [`fchflags`],
+    /// and the `st_flags` field of [`Stat`].
+    ///
+    /// The `UF_*` flags may be changed by the file's owner; the `SF_*` flags
+    /// may only be changed by the superuser.
+    ///
+    /// [`chflags`]: crate::fs::chflags
+    /// [`fchflags`]: crate::fs::fchflags
+    #[repr(transparent)]
+    #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    pub struct FileFlags: u32 {
+        /// `UF_NODUMP`
+        const UF_NODUMP = c::UF_NODUMP as u32;
+
+        /// `UF_IMMUTABLE`
+        const UF_IMMUTABLE = c::UF_IMMUTABLE as u32;
+
+        /// `UF_APPEND`
+        const UF_APPEND = c::UF_APPEND as u32;
+
+        /// `UF_OPAQUE`
+        const UF_OPAQUE = c::UF_OPAQUE as u32;
+
+        /// `UF_NOUNLINK`
+        #[cfg(freebsdlike)]
+        const UF_NOUNLINK = c::UF_NOUNLINK as u32;
+
+        /// `UF_COMPRESSED`
+        #[cfg(apple)]
+        const UF_COMPRESSED = c::UF_COMPRESSED as u32;
+
+        /// `UF_TRACKED`
+        #[cfg(apple)]
+        const UF_TRACKED = c::UF_TRACKED as u32;
+
+        /// `UF_SYSTEM`
+        #[cfg(target_os = "freebsd")]
+        const UF_SYSTEM = c::UF_SYSTEM as u32;
+
+        /// `UF_SPARSE`
+        #[cfg(target_os = "freebsd")]
+        const UF_SPARSE = c::UF_SPARSE as u32;
+
+        /// `UF_OFFLINE`
+        #[cfg(target_os = "freebsd")]
+        const UF_OFFLINE = c::UF_OFFLINE as u32;
+
+        /// `UF_REPARSE`
+        #[cfg(target_os = "freebsd")]
+        const UF_REPARSE = c::UF_REPARSE as u32;
+
+        /// `UF_ARCHIVE`
+        #[cfg(target_os = "freebsd")]
+        const UF_ARCHIVE = c::UF_ARCHIVE as u32;
+
+        /// `UF_READONLY`
+        #[cfg(target_os = "freebsd")]
+        const UF_READONLY = c::UF_READONLY as u32;
+
+        /// `UF_HIDDEN`
+        #[cfg(any(apple, target_os = "freebsd"))]
+        const UF_HIDDEN = c::UF_HIDDEN as u32;
+
+        /// `SF_ARCHIVED`
+        const SF_ARCHIVED = c::SF_ARCHIVED as u32;
+
+        /// `SF_IMMUTABLE`
+        const SF_IMMUTABLE = c::SF_IMMUTABLE as u32;
+
+        /// `SF_APPEND`
+        const SF_APPEND = c::SF_APPEND as u32;
+
+        /// `SF_NOUNLINK`
+        #[cfg(freebsdlike)]
+        const SF_NOUNLINK = c::SF_NOUNLINK as u32;
+
+        /// `SF_SNAPSHOT`
+        #[cfg(any(target_os = "freebsd", target_os = "netbsd"))]
+        const SF_SNAPSHOT = c::SF_SNAPSHOT as u32;
+
+        /// `SF_LOG`
+        #[cfg(target_os = "netbsd")]
+        const SF_LOG = c::SF_LOG as u32;
+
+        /// `SF_SNAPINVAL`
+        #[cfg(target_os = "netbsd")]
+        const SF_SNAPINVAL = c::SF_SNAPINVAL as u32;
+
+        /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+        const _ = !0;
+    }
+}
+
 #[cfg(linux_kernel)]
 bitflags! {
     /// `RESOLVE_*` constants for use with [`openat2`].
@@ -436,13 +531,16 @@ bitflags! {
     #[repr(transparent)]
     #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
     pub struct RenameFlags: c::c_uint {
-        /// `RENAME_EXCHANGE`
+        /// `RENAME_EXCHANGE`—Atomically exchange the old and new paths,
+        /// which must both exist.
         const EXCHANGE = bitcast!(c::RENAME_EXCHANGE);
 
-        /// `RENAME_NOREPLACE`
+        /// `RENAME_NOREPLACE`—Fail with `EEXIST` instead of replacing the
+        /// new path if it exists.
         const NOREPLACE = bitcast!(c::RENAME_NOREPLACE);
 
-        /// `RENAME_WHITEOUT`
+        /// `RENAME_WHITEOUT`—Leave a whiteout object at the old path, for
+        /// overlay and union filesystems.
         const WHITEOUT = bitcast!(c::RENAME_WHITEOUT);
 
         /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
@@ -786,12 +884,51 @@ bitflags! {
     }
 }
 
+#[cfg(linux_kernel)]
+bitflags! {
+    /// `STATX_ATTR_*` constants for use with the `stx_attributes` and
+    /// `stx_attributes_mask` fields of [`Statx`].
+    #[repr(transparent)]
+    #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    pub struct StatxAttributes: u64 {
+        /// `STATX_ATTR_COMPRESSED`
+        const COMPRESSED = linux_raw_sys::general::STATX_ATTR_COMPRESSED as u64;
+
+        /// `STATX_ATTR_IMMUTABLE`
+        const IMMUTABLE = linux_raw_sys::general::STATX_ATTR_IMMUTABLE as u64;
+
+        /// `STATX_ATTR_APPEND`
+        const APPEND = linux_raw_sys::general::STATX_ATTR_APPEND as u64;
+
+        /// `STATX_ATTR_NODUMP`
+        const NODUMP = linux_raw_sys::general::STATX_ATTR_NODUMP as u64;
+
+        /// `STATX_ATTR_ENCRYPTED`
+        const ENCRYPTED = linux_raw_sys::general::STATX_ATTR_ENCRYPTED as u64;
+
+        /// `STATX_ATTR_AUTOMOUNT`
+        const AUTOMOUNT = linux_raw_sys::general::STATX_ATTR_AUTOMOUNT as u64;
+
+        /// `STATX_ATTR_MOUNT_ROOT` (since Linux 5.8)
+        const MOUNT_ROOT = linux_raw_sys::general::STATX_ATTR_MOUNT_ROOT as u64;
+
+        /// `STATX_ATTR_VERITY` (since Linux 5.5)
+        const VERITY = linux_raw_sys::general::STATX_ATTR_VERITY as u64;
+
+        /// `STATX_ATTR_DAX` (since Linux 5.8)
+        const DAX = linux_raw_sys::general::STATX_ATTR_DAX as u64;
+
+        /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+        const _ = !0;
+    }
+}
+
 #[cfg(not(any(
-    netbsdlike,
     solarish,
     target_os = "aix",
     target_os = "espidf",
     target_os = "nto",
+    target_os = "openbsd",
     target_os = "redox",
     target_os = "vita"
 )))]
@@ -879,6 +1016,26 @@ bitflags! {
     }
 }
 
+#[cfg(linux_kernel)]
+bitflags! {
+    /// `SYNC_FILE_RANGE_*` constants for use with [`sync_file_range`].
+    ///
+    /// [`sync_file_range`]: crate::fs::sync_file_range
+    #[repr(transparent)]
+    #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    pub struct SyncFileRangeFlags: u32 {
+        /// `SYNC_FILE_RANGE_WAIT_BEFORE`
+        const WAIT_BEFORE = linux_raw_sys::general::SYNC_FILE_RANGE_WAIT_BEFORE;
+        /// `SYNC_FILE_RANGE_WRITE`
+        const WRITE = linux_raw_sys::general::SYNC_FILE_RANGE_WRITE;
+        /// `SYNC_FILE_RANGE_WAIT_AFTER`
+        const WAIT_AFTER = linux_raw_sys::general::SYNC_FILE_RANGE_WAIT_AFTER;
+
+        /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+        const _ = !0;
+    }
+}
+
 #[cfg(not(any(target_os = "haiku", target_os = "redox", target_os = "wasi")))]
 bitflags! {
     /// `ST_*` constants for use with [`StatVfs`].
@@ -953,6 +1110,36 @@ pub enum FlockOperation {
     NonBlockingUnlock = bitcast!(c::LOCK_UN | c::LOCK_NB),
 }
 
+/// `F_*LCK` constants for use with [`Flock`].
+///
+/// [`Flock`]: crate::fs::Flock
+#[cfg(fcntl_lock)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[repr(i32)]
+pub enum FlockType {
+    /// `F_RDLCK`
+    ReadLock = c::F_RDLCK as i32,
+    /// `F_WRLCK`
+    WriteLock = c::F_WRLCK as i32,
+    /// `F_UNLCK`
+    Unlocked = c::F_UNLCK as i32,
+}
+
+/// `SEEK_*` constants for use with [`Flock`].
+///
+/// [`Flock`]: crate::fs::Flock
+#[cfg(fcntl_lock)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[repr(i32)]
+pub enum FlockOffsetType {
+    /// `SEEK_SET`
+    Set = c::SEEK_SET as i32,
+    /// `SEEK_CUR`
+    Current = c::SEEK_CUR as i32,
+    /// `SEEK_END`
+    End = c::SEEK_END as i32,
+}
+
 /// `struct stat` for use with [`statat`] and [`fstat`].
 ///
 /// [`statat`]: crate::fs::statat
@@ -1030,6 +1217,17 @@ pub type StatFs = c::statfs;
 #[cfg(linux_like)]
 pub type StatFs = c::statfs64;
 
+/// `struct statfs` for use with [`statfs`] and [`fstatfs`].
+///
+/// NetBSD replaced `struct statfs` with `struct statvfs`, which holds the
+/// same information, so this is an alias for that.
+///
+/// [`statfs`]: crate::fs::statfs
+/// [`fstatfs`]: crate::fs::fstatfs
+#[cfg(target_os = "netbsd")]
+#[allow(clippy::module_name_repetitions)]
+pub type StatFs = c::statvfs;
+
 /// `struct statvfs` for use with [`statvfs`] and [`fstatvfs`].
 ///
 /// [`statvfs`]: crate::fs::statvfs
@@ -217,7 +217,7 @@ pub(crate) unsafe fn ioctl_readonly(
     ioctl(fd, request, arg)
 }
 
-#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+#[cfg(not(target_os = "wasi"))]
 #[cfg(all(feature = "fs", feature = "net"))]
 pub(crate) fn is_read_write(fd: BorrowedFd<'_>) -> io::Result<(bool, bool)> {
     use core::mem::MaybeUninit;
@@ -267,8 +267,11 @@ pub(crate) fn is_read_write(fd: BorrowedFd<'_>) -> io::Result<(bool, bool)> {
 
 #[cfg(target_os = "wasi")]
 #[cfg(all(feature = "fs", feature = "net"))]
-pub(crate) fn is_read_write(_fd: BorrowedFd<'_>) -> io::Result<(bool, bool)> {
-    todo!("Implement is_read_write for WASI in terms of fd_fdstat_get");
+pub(crate) fn is_read_write(fd: BorrowedFd<'_>) -> io::Result<(bool, bool)> {
+    // WASI has no `MSG_DONTWAIT`, so a `MSG_PEEK` probe could block, so fall
+    // back to the file's access mode, which doesn't reflect whether a socket
+    // has been shut down.
+    crate::fs::fd::_is_file_read_write(fd)
 }
 
 pub(crate) fn fcntl_getfd(fd: BorrowedFd<'_>) -> io::Result<FdFlags> {
@@ -280,6 +283,22 @@ pub(crate) fn fcntl_setfd(fd: BorrowedFd<'_>, flags: FdFlags) -> io::Result<()>
     unsafe { ret(c::fcntl(borrowed_fd(fd), c::F_SETFD, flags.bits())) }
 }
 
+#[cfg(not(any(linux_kernel, windows)))]
+pub(crate) fn fcntl_set_nonblocking(fd: BorrowedFd<'_>, nonblocking: bool) -> io::Result<()> {
+    unsafe {
+        let flags = ret_c_int(c::fcntl(borrowed_fd(fd), c::F_GETFL))?;
+        let new_flags = if nonblocking {
+            flags | c::O_NONBLOCK
+        } else {
+            flags & !c::O_NONBLOCK
+        };
+        if new_flags == flags {
+            return Ok(());
+        }
+        ret(c::fcntl(borrowed_fd(fd), c::F_SETFL, new_flags))
+    }
+}
+
 #[cfg(not(any(target_os = "espidf", target_os = "wasi")))]
 pub(crate) fn fcntl_dupfd_cloexec(fd: BorrowedFd<'_>, min: RawFd) -> io::Result<OwnedFd> {
     unsafe { ret_owned_fd(c::fcntl(borrowed_fd(fd), c::F_DUPFD_CLOEXEC, min)) }
@@ -324,17 +343,38 @@ pub(crate) fn dup3(fd: BorrowedFd<'_>, new: &mut OwnedFd, flags: DupFlags) -> io
     }
 }
 
+#[allow(clippy::needless_pass_by_ref_mut)]
+#[cfg(target_os = "android")]
+pub(crate) fn dup3(fd: BorrowedFd<'_>, new: &mut OwnedFd, flags: DupFlags) -> io::Result<()> {
+    // Android 5.0 has `dup3`, but libc doesn't have bindings, so make the
+    // syscall directly. Emulating it with `dup2` would drop `flags`.
+    syscall! {
+        fn dup3(
+            oldfd: c::c_int,
+            newfd: c::c_int,
+            flags: c::c_int
+        ) via SYS_dup3 -> c::c_int
+    }
+
+    unsafe {
+        ret_discarded_fd(dup3(
+            borrowed_fd(fd),
+            borrowed_fd(new.as_fd()),
+            bitflags_bits!(flags),
+        ))
+    }
+}
+
 #[cfg(any(
     apple,
-    target_os = "android",
     target_os = "dragonfly",
     target_os = "haiku",
     target_os = "redox",
 ))]
 pub(crate) fn dup3(fd: BorrowedFd<'_>, new: &mut OwnedFd, _flags: DupFlags) -> io::Result<()> {
-    // Android 5.0 has `dup3`, but libc doesn't have bindings. Emulate it
-    // using `dup2`. We don't need to worry about the difference between
-    // `dup2` and `dup3` when the file descriptors are equal because we
-    // have an `&mut OwnedFd` which means `fd` doesn't alias it.
+    // Emulate `dup3` using `dup2`. We don't need to worry about the
+    // difference between `dup2` and `dup3` when the file descriptors are
+    // equal because we have an `&mut OwnedFd` which means `fd` doesn't alias
+    // it.
     dup2(fd, new)
 }
@@ -0,0 +1,82 @@
+//! libc syscalls supporting `rustix::keyctl`.
+
+use crate::backend::c;
+use crate::backend::conv::{c_str, ret_c_int, ret_usize};
+use crate::ffi::CStr;
+use crate::io;
+use core::ptr::null;
+
+#[inline]
+pub(crate) fn add_key(
+    type_: &CStr,
+    description: &CStr,
+    payload: &[u8],
+    keyring: c::c_int,
+) -> io::Result<c::c_int> {
+    syscall! {
+        fn add_key(
+            type_: *const c::c_char,
+            description: *const c::c_char,
+            payload: *const c::c_void,
+            plen: usize,
+            keyring: c::c_int
+        ) via SYS_add_key -> c::c_int
+    }
+
+    unsafe {
+        ret_c_int(add_key(
+            c_str(type_),
+            c_str(description),
+            payload.as_ptr().cast(),
+            payload.len(),
+            keyring,
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn request_key(
+    type_: &CStr,
+    description: &CStr,
+    callout_info: Option<&CStr>,
+    dest_keyring: c::c_int,
+) -> io::Result<c::c_int> {
+    syscall! {
+        fn request_key(
+            type_: *const c::c_char,
+            description: *const c::c_char,
+            callout_info: *const c::c_char,
+            dest_keyring: c::c_int
+        ) via SYS_request_key -> c::c_int
+    }
+
+    unsafe {
+        ret_c_int(request_key(
+            c_str(type_),
+            c_str(description),
+            callout_info.map_or(null(), c_str),
+            dest_keyring,
+        ))
+    }
+}
+
+#[inline]
+pub(crate) unsafe fn keyctl(
+    option: c::c_int,
+    arg2: *mut c::c_void,
+    arg3: *mut c::c_void,
+    arg4: *mut c::c_void,
+    arg5: *mut c::c_void,
+) -> io::Result<usize> {
+    syscall! {
+        fn keyctl(
+            option: c::c_int,
+            arg2: *mut c::c_void,
+            arg3: *mut c::c_void,
+            arg4: *mut c::c_void,
+            arg5: *mut c::c_void
+        ) via SYS_keyctl -> c::c_long
+    }
+
+    ret_usize(keyctl(option, arg2, arg3, arg4, arg5) as c::ssize_t)
+}
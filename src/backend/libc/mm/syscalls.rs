@@ -8,15 +8,17 @@ use super::types::MlockAllFlags;
 use super::types::MremapFlags;
 use super::types::{MapFlags, MprotectFlags, MsyncFlags, ProtFlags};
 #[cfg(linux_kernel)]
-use super::types::{MlockFlags, UserfaultfdFlags};
+use super::types::{MlockFlags, PkeyRights, UserfaultfdFlags};
 use crate::backend::c;
-#[cfg(linux_kernel)]
-use crate::backend::conv::ret_owned_fd;
 use crate::backend::conv::{borrowed_fd, no_fd, ret};
+#[cfg(linux_kernel)]
+use crate::backend::conv::{ret_c_int, ret_owned_fd, ret_usize};
 use crate::fd::BorrowedFd;
 #[cfg(linux_kernel)]
 use crate::fd::OwnedFd;
 use crate::io;
+#[cfg(linux_kernel)]
+use crate::mm::RemoteIoVec;
 
 #[cfg(not(target_os = "redox"))]
 pub(crate) fn madvise(addr: *mut c::c_void, len: usize, advice: Advice) -> io::Result<()> {
@@ -52,6 +54,42 @@ pub(crate) fn madvise(addr: *mut c::c_void, len: usize, advice: Advice) -> io::R
     }
 }
 
+#[cfg(linux_kernel)]
+pub(crate) fn process_madvise(
+    pidfd: BorrowedFd<'_>,
+    ranges: &[RemoteIoVec],
+    advice: Advice,
+) -> io::Result<usize> {
+    syscall! {
+        fn process_madvise(
+            pidfd: c::c_int,
+            iovec: *const c::iovec,
+            vlen: c::size_t,
+            advice: c::c_int,
+            flags: c::c_uint
+        ) via SYS_process_madvise -> c::ssize_t
+    }
+
+    // On Linux platforms, `MADV_DONTNEED` has the same value as
+    // `POSIX_MADV_DONTNEED` but different behavior. We remap it to a different
+    // value, and check for it here.
+    let advice = match advice {
+        #[cfg(target_os = "linux")]
+        Advice::LinuxDontNeed => c::MADV_DONTNEED,
+        _ => advice as c::c_int,
+    };
+
+    unsafe {
+        ret_usize(process_madvise(
+            borrowed_fd(pidfd),
+            ranges.as_ptr().cast::<c::iovec>(),
+            ranges.len(),
+            advice,
+            0,
+        ))
+    }
+}
+
 pub(crate) unsafe fn msync(addr: *mut c::c_void, len: usize, flags: MsyncFlags) -> io::Result<()> {
     let err = c::msync(addr, len, bitflags_bits!(flags));
 
@@ -213,6 +251,64 @@ pub(crate) unsafe fn munlock(addr: *mut c::c_void, length: usize) -> io::Result<
     ret(c::munlock(addr, length))
 }
 
+/// # Safety
+///
+/// `vec` must have at least one element for each page in the range starting
+/// at `addr` of length `length`.
+#[cfg(linux_kernel)]
+#[inline]
+pub(crate) unsafe fn mincore(addr: *mut c::c_void, length: usize, vec: *mut u8) -> io::Result<()> {
+    ret(c::mincore(addr, length, vec))
+}
+
+#[cfg(linux_kernel)]
+pub(crate) fn pkey_alloc(rights: PkeyRights) -> io::Result<u32> {
+    syscall! {
+        fn pkey_alloc(
+            flags: c::c_uint,
+            access_rights: c::c_uint
+        ) via SYS_pkey_alloc -> c::c_int
+    }
+    unsafe { ret_c_int(pkey_alloc(0, rights.bits())).map(|pkey| pkey as u32) }
+}
+
+#[cfg(linux_kernel)]
+pub(crate) fn pkey_free(pkey: u32) -> io::Result<()> {
+    syscall! {
+        fn pkey_free(
+            pkey: c::c_int
+        ) via SYS_pkey_free -> c::c_int
+    }
+    unsafe { ret(pkey_free(pkey as c::c_int)) }
+}
+
+/// # Safety
+///
+/// `pkey_mprotect` is primarily unsafe due to the `addr` parameter, as
+/// anything working with memory pointed to by raw pointers is unsafe.
+#[cfg(linux_kernel)]
+pub(crate) unsafe fn pkey_mprotect(
+    addr: *mut c::c_void,
+    len: usize,
+    flags: MprotectFlags,
+    pkey: u32,
+) -> io::Result<()> {
+    syscall! {
+        fn pkey_mprotect(
+            addr: *mut c::c_void,
+            len: c::size_t,
+            prot: c::c_int,
+            pkey: c::c_int
+        ) via SYS_pkey_mprotect -> c::c_int
+    }
+    ret(pkey_mprotect(
+        addr,
+        len,
+        bitflags_bits!(flags),
+        pkey as c::c_int,
+    ))
+}
+
 #[cfg(linux_kernel)]
 pub(crate) unsafe fn userfaultfd(flags: UserfaultfdFlags) -> io::Result<OwnedFd> {
     syscall! {
@@ -443,6 +443,25 @@ bitflags! {
     }
 }
 
+#[cfg(linux_kernel)]
+bitflags! {
+    /// `PKEY_*` access rights for use with [`pkey_alloc`] and [`pkey_set`].
+    ///
+    /// [`pkey_alloc`]: crate::mm::pkey_alloc
+    /// [`pkey_set`]: crate::mm::pkey_set
+    #[repr(transparent)]
+    #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    pub struct PkeyRights: u32 {
+        /// `PKEY_DISABLE_ACCESS`
+        const DISABLE_ACCESS = linux_raw_sys::general::PKEY_DISABLE_ACCESS;
+        /// `PKEY_DISABLE_WRITE`
+        const DISABLE_WRITE = linux_raw_sys::general::PKEY_DISABLE_WRITE;
+
+        /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+        const _ = !0;
+    }
+}
+
 #[cfg(any(linux_kernel, freebsdlike, netbsdlike))]
 bitflags! {
     /// `MCL_*` flags for use with [`mlockall`].
@@ -102,6 +102,12 @@ pub(crate) mod fd {
 #[cfg_attr(windows, path = "winsock_c.rs")]
 pub(crate) mod c;
 
+#[cfg(linux_kernel)]
+#[cfg(feature = "aio")]
+pub(crate) mod aio;
+#[cfg(linux_kernel)]
+#[cfg(feature = "bpf")]
+pub(crate) mod bpf;
 #[cfg(feature = "event")]
 pub(crate) mod event;
 #[cfg(not(windows))]
@@ -111,6 +117,9 @@ pub(crate) mod io;
 #[cfg(linux_kernel)]
 #[cfg(feature = "io_uring")]
 pub(crate) mod io_uring;
+#[cfg(linux_kernel)]
+#[cfg(feature = "keyctl")]
+pub(crate) mod keyctl;
 #[cfg(not(any(windows, target_os = "espidf", target_os = "vita", target_os = "wasi")))]
 #[cfg(feature = "mm")]
 pub(crate) mod mm;
@@ -125,12 +134,16 @@ pub(crate) mod mount; // for deprecated mount functions in "fs"
 pub(crate) mod net;
 #[cfg(not(any(windows, target_os = "espidf")))]
 #[cfg(any(
+    all(linux_kernel, feature = "mm"),
     feature = "param",
     feature = "runtime",
     feature = "time",
     target_arch = "x86",
 ))]
 pub(crate) mod param;
+#[cfg(linux_kernel)]
+#[cfg(feature = "perf_event")]
+pub(crate) mod perf_event;
 #[cfg(not(windows))]
 #[cfg(feature = "pipe")]
 pub(crate) mod pipe;
@@ -145,6 +158,9 @@ pub(crate) mod pty;
 #[cfg(feature = "rand")]
 pub(crate) mod rand;
 #[cfg(not(windows))]
+#[cfg(any(feature = "fs", feature = "net"))]
+pub(crate) mod raw;
+#[cfg(not(windows))]
 #[cfg(not(target_os = "wasi"))]
 #[cfg(feature = "system")]
 pub(crate) mod system;
@@ -198,10 +214,10 @@ pub(crate) mod shm;
 pub(crate) mod ugid;
 
 #[cfg(bsd)]
-const MAX_IOV: usize = c::IOV_MAX as usize;
+pub(crate) const MAX_IOV: usize = c::IOV_MAX as usize;
 
 #[cfg(any(linux_kernel, target_os = "emscripten", target_os = "nto"))]
-const MAX_IOV: usize = c::UIO_MAXIOV as usize;
+pub(crate) const MAX_IOV: usize = c::UIO_MAXIOV as usize;
 
 #[cfg(not(any(
     bsd,
@@ -212,4 +228,4 @@ const MAX_IOV: usize = c::UIO_MAXIOV as usize;
     target_os = "nto",
     target_os = "horizon",
 )))]
-const MAX_IOV: usize = 16; // The minimum value required by POSIX.
+pub(crate) const MAX_IOV: usize = 16; // The minimum value required by POSIX.
@@ -138,6 +138,45 @@ syscall! {
     ) via SYS_fsconfig -> c::c_int
 }
 
+#[cfg(linux_kernel)]
+#[cfg(feature = "mount")]
+pub(crate) fn mount_setattr(
+    dfd: BorrowedFd<'_>,
+    path: &CStr,
+    flags: super::types::MountSetattrFlags,
+    attr: &crate::mount::MountAttr<'_>,
+) -> io::Result<()> {
+    use linux_raw_sys::general::mount_attr;
+
+    syscall! {
+        fn mount_setattr(
+            dfd: c::c_int,
+            path: *const c::c_char,
+            flags: c::c_uint,
+            attr: *const mount_attr,
+            size: usize
+        ) via SYS_mount_setattr -> c::c_int
+    }
+
+    let attr = mount_attr {
+        attr_set: attr.attr_set.bits().into(),
+        attr_clr: attr.attr_clr.bits().into(),
+        propagation: attr.propagation.bits().into(),
+        userns_fd: attr
+            .userns_fd
+            .map_or(0, |fd| crate::fd::AsRawFd::as_raw_fd(&fd) as u64),
+    };
+    unsafe {
+        ret(mount_setattr(
+            borrowed_fd(dfd),
+            c_str(path),
+            flags.bits(),
+            &attr,
+            core::mem::size_of::<mount_attr>(),
+        ))
+    }
+}
+
 #[cfg(linux_kernel)]
 #[cfg(feature = "mount")]
 pub(crate) fn fsconfig_set_flag(fs_fd: BorrowedFd<'_>, key: &CStr) -> io::Result<()> {
@@ -14,10 +14,13 @@ use {
 
 /// `struct sockaddr_un`
 #[cfg(unix)]
+#[repr(C)]
 #[derive(Clone)]
 #[doc(alias = "sockaddr_un")]
 pub struct SocketAddrUnix {
     pub(crate) unix: c::sockaddr_un,
+    /// Always NUL, to terminate a path that fills `sun_path`.
+    nul: u8,
     #[cfg(not(any(bsd, target_os = "haiku")))]
     len: c::socklen_t,
 }
@@ -33,23 +36,26 @@ impl SocketAddrUnix {
     #[inline]
     fn _new(path: &CStr) -> io::Result<Self> {
         let mut unix = Self::init();
-        let bytes = path.to_bytes_with_nul();
+        let bytes = path.to_bytes();
         if bytes.len() > unix.sun_path.len() {
             return Err(io::Errno::NAMETOOLONG);
         }
         for (i, b) in bytes.iter().enumerate() {
             unix.sun_path[i] = *b as c::c_char;
         }
+        // A path may fill `sun_path` without a NUL terminator.
+        let len = offsetof_sun_path() + (bytes.len() + 1).min(unix.sun_path.len());
 
         #[cfg(any(bsd, target_os = "haiku"))]
         {
-            unix.sun_len = (offsetof_sun_path() + bytes.len()).try_into().unwrap();
+            unix.sun_len = len.try_into().unwrap();
         }
 
         Ok(Self {
             unix,
+            nul: 0,
             #[cfg(not(any(bsd, target_os = "haiku")))]
-            len: (offsetof_sun_path() + bytes.len()).try_into().unwrap(),
+            len: len.try_into().unwrap(),
         })
     }
 
@@ -69,6 +75,7 @@ impl SocketAddrUnix {
         let len = len.try_into().unwrap();
         Ok(Self {
             unix,
+            nul: 0,
             #[cfg(not(any(bsd, target_os = "haiku")))]
             len,
         })
@@ -98,16 +105,29 @@ impl SocketAddrUnix {
         let len = self.len();
         if len != 0 && self.unix.sun_path[0] != 0 {
             let end = len as usize - offsetof_sun_path();
-            let bytes = &self.unix.sun_path[..end];
-            // SAFETY: `from_raw_parts` to convert from `&[c_char]` to `&[u8]`.
-            // And `from_bytes_with_nul_unchecked` since the string is
+            let bytes = if self.unix.sun_path[end - 1] == 0 {
+                let bytes = &self.unix.sun_path[..end];
+                // SAFETY: `from_raw_parts` to convert from `&[c_char]` to
+                // `&[u8]`.
+                unsafe { slice::from_raw_parts(bytes.as_ptr().cast(), bytes.len()) }
+            } else {
+                // The path fills `sun_path` without a NUL terminator, so
+                // include the `nul` field after it. `SocketAddrUnix` is
+                // `repr(C)`, with `unix` first.
+                let start = offsetof_sun_path();
+                debug_assert_eq!(
+                    start + end,
+                    core::ptr::addr_of!(self.nul) as usize - (self as *const Self as usize)
+                );
+                // SAFETY: `sun_path` is followed immediately by `nul`, and
+                // the pointer is derived from all of `self`.
+                unsafe {
+                    slice::from_raw_parts((self as *const Self).cast::<u8>().add(start), end + 1)
+                }
+            };
+            // SAFETY: `from_bytes_with_nul_unchecked` since the string is
             // NUL-terminated.
-            unsafe {
-                Some(CStr::from_bytes_with_nul_unchecked(slice::from_raw_parts(
-                    bytes.as_ptr().cast(),
-                    bytes.len(),
-                )))
-            }
+            unsafe { Some(CStr::from_bytes_with_nul_unchecked(bytes)) }
         } else {
             None
         }
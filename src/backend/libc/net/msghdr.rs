@@ -15,7 +15,7 @@ use crate::net::xdp::SocketAddrXdp;
 use crate::net::{RecvAncillaryBuffer, SendAncillaryBuffer, SocketAddrV4, SocketAddrV6};
 use crate::utils::as_ptr;
 
-use core::mem::{size_of, zeroed, MaybeUninit};
+use core::mem::{size_of, size_of_val, zeroed, MaybeUninit};
 
 /// Create a message header intended to receive a datagram.
 pub(crate) fn with_recv_msghdr<R>(
@@ -78,7 +78,7 @@ pub(crate) fn with_v4_msghdr<R>(
     f({
         let mut h = zero_msghdr();
         h.msg_name = as_ptr(&encoded) as _;
-        h.msg_namelen = size_of::<SocketAddrV4>() as _;
+        h.msg_namelen = size_of_val(&encoded) as _;
         h.msg_iov = iov.as_ptr() as _;
         h.msg_iovlen = msg_iov_len(iov.len());
         h.msg_control = control.as_control_ptr().cast();
@@ -99,7 +99,7 @@ pub(crate) fn with_v6_msghdr<R>(
     f({
         let mut h = zero_msghdr();
         h.msg_name = as_ptr(&encoded) as _;
-        h.msg_namelen = size_of::<SocketAddrV6>() as _;
+        h.msg_namelen = size_of_val(&encoded) as _;
         h.msg_iov = iov.as_ptr() as _;
         h.msg_iovlen = msg_iov_len(iov.len());
         h.msg_control = control.as_control_ptr().cast();
@@ -141,7 +141,7 @@ pub(crate) fn with_xdp_msghdr<R>(
     f({
         let mut h = zero_msghdr();
         h.msg_name = as_ptr(&encoded) as _;
-        h.msg_namelen = size_of::<SocketAddrXdp>() as _;
+        h.msg_namelen = size_of_val(&encoded) as _;
         h.msg_iov = iov.as_ptr() as _;
         h.msg_iovlen = msg_iov_len(iov.len());
         h.msg_control = control.as_control_ptr().cast();
@@ -5,13 +5,13 @@
 use super::addr::SocketAddrUnix;
 use super::ext::{in6_addr_s6_addr, in_addr_s_addr, sockaddr_in6_sin6_scope_id};
 use crate::backend::c;
-#[cfg(not(windows))]
-use crate::ffi::CStr;
 use crate::io;
 #[cfg(target_os = "linux")]
 use crate::net::xdp::{SockaddrXdpFlags, SocketAddrXdp};
 use crate::net::{Ipv4Addr, Ipv6Addr, SocketAddrAny, SocketAddrV4, SocketAddrV6};
 use core::mem::size_of;
+#[cfg(unix)]
+use core::slice;
 
 // This must match the header of `sockaddr`.
 #[repr(C)]
@@ -166,33 +166,9 @@ pub(crate) unsafe fn read_sockaddr(
                     .map(SocketAddrAny::Unix);
                 }
 
-                // Otherwise we expect a NUL-terminated filesystem path.
-
-                // Trim off unused bytes from the end of `path_bytes`.
-                let path_bytes = if cfg!(any(solarish, target_os = "freebsd")) {
-                    // FreeBSD and illumos sometimes set the length to longer
-                    // than the length of the NUL-terminated string. Find the
-                    // NUL and truncate the string accordingly.
-                    &decode.sun_path[..decode
-                        .sun_path
-                        .iter()
-                        .position(|b| *b == 0)
-                        .ok_or(io::Errno::INVAL)?]
-                } else {
-                    // Otherwise, use the provided length.
-                    let provided_len = len - 1 - offsetof_sun_path;
-                    if decode.sun_path[provided_len] != 0 {
-                        return Err(io::Errno::INVAL);
-                    }
-                    debug_assert_eq!(
-                        CStr::from_ptr(decode.sun_path.as_ptr()).to_bytes().len(),
-                        provided_len
-                    );
-                    &decode.sun_path[..provided_len]
-                };
-
-                SocketAddrUnix::new(core::mem::transmute::<&[c::c_char], &[u8]>(path_bytes))
-                    .map(SocketAddrAny::Unix)
+                // Otherwise we expect a filesystem path.
+                let path_bytes = unix_path_bytes(decode, len - offsetof_sun_path);
+                SocketAddrUnix::new(path_bytes).map(SocketAddrAny::Unix)
             }
         }
         #[cfg(target_os = "linux")]
@@ -300,20 +276,9 @@ unsafe fn inner_read_sockaddr_os(
                     );
                 }
 
-                // Otherwise we expect a NUL-terminated filesystem path.
-                assert_eq!(decode.sun_path[len - 1 - offsetof_sun_path], 0);
-                let path_bytes = &decode.sun_path[..len - 1 - offsetof_sun_path];
-
-                // FreeBSD and illumos sometimes set the length to longer than
-                // the length of the NUL-terminated string. Find the NUL and
-                // truncate the string accordingly.
-                #[cfg(any(solarish, target_os = "freebsd"))]
-                let path_bytes = &path_bytes[..path_bytes.iter().position(|b| *b == 0).unwrap()];
-
-                SocketAddrAny::Unix(
-                    SocketAddrUnix::new(core::mem::transmute::<&[c::c_char], &[u8]>(path_bytes))
-                        .unwrap(),
-                )
+                // Otherwise we expect a filesystem path.
+                let path_bytes = unix_path_bytes(decode, len - offsetof_sun_path);
+                SocketAddrAny::Unix(SocketAddrUnix::new(path_bytes).unwrap())
             }
         }
         #[cfg(target_os = "linux")]
@@ -330,3 +295,23 @@ unsafe fn inner_read_sockaddr_os(
         other => unimplemented!("{:?}", other),
     }
 }
+
+/// Return the filesystem path in `decode`, given the length of `sun_path`
+/// reported by the OS.
+///
+/// A path may fill `sun_path` without a NUL terminator, Linux reports a
+/// length that includes a NUL beyond the end of `sun_path` for such a path,
+/// and FreeBSD and illumos sometimes report a length that extends past the
+/// NUL. So the path ends at the first NUL, or at the end of `sun_path`.
+#[cfg(unix)]
+fn unix_path_bytes(decode: &c::sockaddr_un, len: usize) -> &[u8] {
+    let bytes = &decode.sun_path[..len.min(decode.sun_path.len())];
+
+    // SAFETY: Convert `&[c_char]` to `&[u8]`.
+    let bytes = unsafe { slice::from_raw_parts(bytes.as_ptr().cast::<u8>(), bytes.len()) };
+
+    match bytes.iter().position(|b| *b == 0) {
+        Some(nul) => &bytes[..nul],
+        None => bytes,
+    }
+}
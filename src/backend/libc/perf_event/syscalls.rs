@@ -0,0 +1,41 @@
+//! libc syscalls supporting `rustix::perf_event`.
+
+use crate::backend::c;
+use crate::backend::conv::ret_owned_fd;
+use crate::fd::{AsRawFd, BorrowedFd, OwnedFd};
+use crate::io;
+use crate::perf_event::{PerfEventAttr, PerfEventOpenFlags};
+use crate::pid::RawPid;
+
+#[inline]
+pub(crate) fn perf_event_open(
+    attr: &PerfEventAttr,
+    pid: RawPid,
+    cpu: c::c_int,
+    group_fd: Option<BorrowedFd<'_>>,
+    flags: PerfEventOpenFlags,
+) -> io::Result<OwnedFd> {
+    syscall! {
+        fn perf_event_open(
+            attr: *mut PerfEventAttr,
+            pid: c::pid_t,
+            cpu: c::c_int,
+            group_fd: c::c_int,
+            flags: c::c_ulong
+        ) via SYS_perf_event_open -> c::c_int
+    }
+
+    // The kernel may write the size it supports back into `attr`, so pass it
+    // a copy.
+    let mut attr = attr.clone();
+    let group_fd = group_fd.map_or(-1, |fd| fd.as_raw_fd());
+    unsafe {
+        ret_owned_fd(perf_event_open(
+            &mut attr,
+            pid,
+            cpu,
+            group_fd,
+            flags.bits() as c::c_ulong,
+        ))
+    }
+}
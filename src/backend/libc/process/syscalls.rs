@@ -66,6 +66,8 @@ use crate::process::{Resource, Rlimit};
     target_os = "wasi"
 )))]
 use crate::process::{WaitId, WaitidOptions, WaitidStatus};
+#[cfg(any(linux_kernel, bsd))]
+use crate::{process::Rusage, timespec::Timespec};
 use core::mem::MaybeUninit;
 #[cfg(target_os = "linux")]
 use {
@@ -80,6 +82,26 @@ pub(crate) fn sched_getcpu() -> usize {
     r as usize
 }
 
+#[cfg(linux_kernel)]
+#[inline]
+pub(crate) fn getcpu() -> (usize, usize) {
+    syscall! {
+        fn getcpu(
+            cpu: *mut u32,
+            node: *mut u32,
+            unused: *mut c::c_void
+        ) via SYS_getcpu -> c::c_int
+    }
+
+    let mut cpu = MaybeUninit::<u32>::uninit();
+    let mut node = MaybeUninit::<u32>::uninit();
+    unsafe {
+        let r = getcpu(cpu.as_mut_ptr(), node.as_mut_ptr(), core::ptr::null_mut());
+        debug_assert_eq!(r, 0);
+        (cpu.assume_init() as usize, node.assume_init() as usize)
+    }
+}
+
 #[cfg(feature = "fs")]
 #[cfg(not(target_os = "wasi"))]
 pub(crate) fn chdir(path: &CStr) -> io::Result<()> {
@@ -220,6 +242,12 @@ pub(crate) fn sched_yield() {
     }
 }
 
+#[cfg(not(any(target_os = "espidf", target_os = "vita", target_os = "wasi")))]
+#[inline]
+pub(crate) fn alarm(seconds: u32) -> u32 {
+    unsafe { c::alarm(seconds) }
+}
+
 #[cfg(not(target_os = "wasi"))]
 #[cfg(feature = "fs")]
 #[inline]
@@ -457,6 +485,59 @@ pub(crate) fn _waitpid(
     }
 }
 
+#[cfg(any(linux_kernel, bsd))]
+#[inline]
+pub(crate) fn wait4(
+    pid: Option<Pid>,
+    waitopts: WaitOptions,
+) -> io::Result<Option<(Pid, WaitStatus, Rusage)>> {
+    let pid = match pid {
+        Some(pid) => pid.as_raw_nonzero().get(),
+        None => -1,
+    };
+    unsafe {
+        let mut status: c::c_int = 0;
+        let mut rusage = MaybeUninit::<c::rusage>::uninit();
+        let pid = ret_c_int(c::wait4(
+            pid as _,
+            &mut status,
+            waitopts.bits() as _,
+            rusage.as_mut_ptr(),
+        ))?;
+        Ok(Pid::from_raw(pid).map(|pid| {
+            let rusage = rusage.assume_init();
+            (
+                pid,
+                WaitStatus::new(status as _),
+                Rusage {
+                    utime: Timespec {
+                        tv_sec: rusage.ru_utime.tv_sec as _,
+                        tv_nsec: (rusage.ru_utime.tv_usec * 1000) as _,
+                    },
+                    stime: Timespec {
+                        tv_sec: rusage.ru_stime.tv_sec as _,
+                        tv_nsec: (rusage.ru_stime.tv_usec * 1000) as _,
+                    },
+                    maxrss: rusage.ru_maxrss.into(),
+                    ixrss: rusage.ru_ixrss.into(),
+                    idrss: rusage.ru_idrss.into(),
+                    isrss: rusage.ru_isrss.into(),
+                    minflt: rusage.ru_minflt.into(),
+                    majflt: rusage.ru_majflt.into(),
+                    nswap: rusage.ru_nswap.into(),
+                    inblock: rusage.ru_inblock.into(),
+                    oublock: rusage.ru_oublock.into(),
+                    msgsnd: rusage.ru_msgsnd.into(),
+                    msgrcv: rusage.ru_msgrcv.into(),
+                    nsignals: rusage.ru_nsignals.into(),
+                    nvcsw: rusage.ru_nvcsw.into(),
+                    nivcsw: rusage.ru_nivcsw.into(),
+                },
+            )
+        }))
+    }
+}
+
 #[cfg(not(any(
     target_os = "espidf",
     target_os = "redox",
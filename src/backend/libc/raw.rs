@@ -0,0 +1,13 @@
+//! The libc backend's raw OS types, re-exported by `rustix::raw`.
+
+#[cfg(not(any(linux_like, target_os = "hurd")))]
+#[cfg(feature = "fs")]
+pub use libc::stat;
+// Use the large-file-support variant, as `fstat` and friends do.
+#[cfg(any(linux_like, target_os = "hurd"))]
+#[cfg(feature = "fs")]
+pub use libc::stat64 as stat;
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+#[cfg(feature = "net")]
+pub use libc::sockaddr_storage;
+pub use libc::timespec;
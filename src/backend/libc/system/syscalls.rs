@@ -19,6 +19,8 @@ use {
     target_os = "wasi"
 )))]
 use {crate::backend::conv::ret, crate::io};
+#[cfg(kexec_file_load)]
+use {crate::fd::AsRawFd, crate::system::KexecFileFlags};
 
 #[cfg(not(target_os = "wasi"))]
 #[inline]
@@ -68,6 +70,45 @@ pub(crate) fn reboot(cmd: RebootCommand) -> io::Result<()> {
     unsafe { ret(c::reboot(cmd as i32)) }
 }
 
+#[cfg(kexec_file_load)]
+#[inline]
+pub(crate) fn kexec_file_load(
+    kernel_fd: BorrowedFd<'_>,
+    initrd_fd: Option<BorrowedFd<'_>>,
+    cmdline: &CStr,
+    flags: KexecFileFlags,
+) -> io::Result<()> {
+    // TODO: Upstream this to the libc crate.
+    #[cfg(any(
+        target_arch = "riscv64",
+        all(target_arch = "aarch64", target_env = "musl")
+    ))]
+    #[allow(non_upper_case_globals)]
+    const SYS_kexec_file_load: c::c_long =
+        linux_raw_sys::general::__NR_kexec_file_load as c::c_long;
+
+    syscall! {
+        fn kexec_file_load(
+            kernel_fd: c::c_int,
+            initrd_fd: c::c_int,
+            cmdline_len: c::c_ulong,
+            cmdline: *const c::c_char,
+            flags: c::c_ulong
+        ) via SYS_kexec_file_load -> c::c_int
+    }
+
+    let initrd_fd = initrd_fd.map_or(-1, |fd| fd.as_raw_fd());
+    unsafe {
+        ret(kexec_file_load(
+            kernel_fd.as_raw_fd(),
+            initrd_fd,
+            cmdline.to_bytes_with_nul().len() as _,
+            c_str(cmdline),
+            flags.bits() as _,
+        ))
+    }
+}
+
 #[cfg(linux_kernel)]
 #[inline]
 pub(crate) fn init_module(image: &[u8], param_values: &CStr) -> io::Result<()> {
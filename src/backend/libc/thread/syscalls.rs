@@ -13,6 +13,7 @@ use {
     crate::backend::conv::{borrowed_fd, ret_c_int, ret_usize},
     crate::fd::BorrowedFd,
     crate::pid::Pid,
+    crate::signal::Signal,
     crate::thread::{FutexFlags, FutexOperation},
     crate::utils::as_mut_ptr,
 };
@@ -28,6 +29,8 @@ use {
     target_os = "wasi",
 )))]
 use {crate::thread::ClockId, core::ptr::null_mut};
+#[cfg(all(linux_kernel, target_has_atomic = "64"))]
+use {crate::thread::Rseq, core::mem::size_of};
 
 #[cfg(all(target_env = "gnu", fix_y2038))]
 weak!(fn __clock_nanosleep_time64(c::clockid_t, c::c_int, *const LibcTimespec, *mut LibcTimespec) -> c::c_int);
@@ -301,6 +304,70 @@ pub(crate) fn gettid() -> Pid {
     }
 }
 
+#[cfg(linux_kernel)]
+#[inline]
+pub(crate) fn tgkill(tid: Pid, sig: Signal) -> io::Result<()> {
+    // `tgkill` wasn't supported in glibc until 2.30, so use `syscall`.
+    weak_or_syscall! {
+        fn tgkill(pid: c::pid_t, tid: c::pid_t, sig: c::c_int) via SYS_tgkill -> c::c_int
+    }
+
+    unsafe {
+        ret(tgkill(
+            c::getpid(),
+            tid.as_raw_nonzero().get(),
+            sig as c::c_int,
+        ))
+    }
+}
+
+#[cfg(linux_kernel)]
+#[inline]
+pub(crate) fn tgsigqueueinfo(tid: Pid, sig: Signal, value: usize) -> io::Result<()> {
+    use linux_raw_sys::general::{
+        __sifields, __sifields__bindgen_ty_3, siginfo__bindgen_ty_1__bindgen_ty_1, siginfo_t,
+        sigval, SI_QUEUE,
+    };
+
+    syscall! {
+        fn rt_tgsigqueueinfo(
+            pid: c::pid_t,
+            tid: c::pid_t,
+            sig: c::c_int,
+            info: *const siginfo_t
+        ) via SYS_rt_tgsigqueueinfo -> c::c_int
+    }
+
+    unsafe {
+        let pid = c::getpid();
+
+        // `siginfo_t` is a plain C struct, for which all-zeros is a valid
+        // value.
+        let mut info: siginfo_t = core::mem::zeroed();
+        info.__bindgen_anon_1.__bindgen_anon_1 = siginfo__bindgen_ty_1__bindgen_ty_1 {
+            si_signo: sig as c::c_int,
+            si_errno: 0,
+            si_code: SI_QUEUE,
+            _sifields: __sifields {
+                _rt: __sifields__bindgen_ty_3 {
+                    _pid: pid,
+                    _uid: c::getuid(),
+                    _sigval: sigval {
+                        sival_ptr: value as *mut c::c_void,
+                    },
+                },
+            },
+        };
+
+        ret(rt_tgsigqueueinfo(
+            pid,
+            tid.as_raw_nonzero().get(),
+            sig as c::c_int,
+            &info,
+        ))
+    }
+}
+
 #[cfg(linux_kernel)]
 #[inline]
 pub(crate) fn setns(fd: BorrowedFd<'_>, nstype: c::c_int) -> io::Result<c::c_int> {
@@ -531,3 +598,19 @@ unsafe fn futex_old(
         val3,
     ) as isize)
 }
+
+#[cfg(linux_kernel)]
+#[cfg(target_has_atomic = "64")]
+#[inline]
+pub(crate) unsafe fn rseq(area: &Rseq, flags: u32, sig: u32) -> io::Result<()> {
+    syscall! {
+        fn rseq(
+            rseq: *const Rseq,
+            rseq_len: u32,
+            flags: c::c_int,
+            sig: u32
+        ) via SYS_rseq -> c::c_int
+    }
+
+    ret(rseq(area, size_of::<Rseq>() as u32, flags as c::c_int, sig))
+}
@@ -450,3 +450,55 @@ fn timerfd_gettime_old(fd: BorrowedFd<'_>) -> io::Result<Itimerspec> {
         },
     })
 }
+
+#[cfg(linux_kernel)]
+#[cfg(feature = "time")]
+pub(crate) fn setitimer(
+    which: crate::time::IntervalTimer,
+    new_value: &crate::time::Itimerval,
+) -> io::Result<crate::time::Itimerval> {
+    let new_value = c::itimerval {
+        it_interval: duration_to_timeval(new_value.interval)?,
+        it_value: duration_to_timeval(new_value.value)?,
+    };
+    let mut old_value = MaybeUninit::<c::itimerval>::uninit();
+    unsafe {
+        ret(c::setitimer(which as _, &new_value, old_value.as_mut_ptr()))?;
+        Ok(itimerval_to_itimerval(old_value.assume_init()))
+    }
+}
+
+#[cfg(linux_kernel)]
+#[cfg(feature = "time")]
+pub(crate) fn getitimer(which: crate::time::IntervalTimer) -> io::Result<crate::time::Itimerval> {
+    let mut curr_value = MaybeUninit::<c::itimerval>::uninit();
+    unsafe {
+        ret(c::getitimer(which as _, curr_value.as_mut_ptr()))?;
+        Ok(itimerval_to_itimerval(curr_value.assume_init()))
+    }
+}
+
+#[cfg(linux_kernel)]
+#[cfg(feature = "time")]
+fn duration_to_timeval(duration: core::time::Duration) -> io::Result<c::timeval> {
+    let (secs, micros) = crate::time::duration_to_timeval_parts(duration);
+    Ok(c::timeval {
+        tv_sec: secs.try_into().map_err(|_| io::Errno::INVAL)?,
+        tv_usec: micros as _,
+    })
+}
+
+#[cfg(linux_kernel)]
+#[cfg(feature = "time")]
+fn itimerval_to_itimerval(value: c::itimerval) -> crate::time::Itimerval {
+    crate::time::Itimerval {
+        interval: crate::time::timeval_parts_to_duration(
+            value.it_interval.tv_sec.into(),
+            value.it_interval.tv_usec.into(),
+        ),
+        value: crate::time::timeval_parts_to_duration(
+            value.it_value.tv_sec.into(),
+            value.it_value.tv_usec.into(),
+        ),
+    }
+}
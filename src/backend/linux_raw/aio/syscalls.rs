@@ -0,0 +1,116 @@
+//! linux_raw syscalls supporting `rustix::aio`.
+//!
+//! # Safety
+//!
+//! See the `rustix::backend::syscalls` module documentation for details.
+#![allow(unsafe_code, clippy::undocumented_unsafe_blocks)]
+
+use crate::aio::{IoEvent, Iocb, Timespec};
+use crate::backend::c;
+use crate::backend::conv::{by_mut, c_uint, pass_usize, ret, ret_usize};
+use crate::io;
+use crate::utils::option_as_ptr;
+#[cfg(target_pointer_width = "32")]
+use {crate::backend::conv::zero, linux_raw_sys::general::timespec as __kernel_old_timespec};
+
+#[inline]
+pub(crate) fn io_setup(nr_events: u32) -> io::Result<c::c_ulong> {
+    let mut ctx: c::c_ulong = 0;
+    unsafe {
+        ret(syscall!(__NR_io_setup, c_uint(nr_events), by_mut(&mut ctx)))?;
+    }
+    Ok(ctx)
+}
+
+#[inline]
+pub(crate) fn io_destroy(ctx: c::c_ulong) -> io::Result<()> {
+    // This is not `_readonly` because `io_destroy` waits for in-flight I/O
+    // to complete, and I/O could involve writing to memory buffers.
+    unsafe { ret(syscall!(__NR_io_destroy, pass_usize(ctx as usize))) }
+}
+
+#[inline]
+pub(crate) unsafe fn io_submit(
+    ctx: c::c_ulong,
+    iocbs: *mut *mut Iocb,
+    nr: usize,
+) -> io::Result<usize> {
+    ret_usize(syscall!(
+        __NR_io_submit,
+        pass_usize(ctx as usize),
+        pass_usize(nr),
+        iocbs
+    ))
+}
+
+#[inline]
+pub(crate) fn io_getevents(
+    ctx: c::c_ulong,
+    min_nr: usize,
+    events: &mut [IoEvent],
+    timeout: Option<&Timespec>,
+) -> io::Result<usize> {
+    let timeout = option_as_ptr(timeout);
+
+    #[cfg(target_pointer_width = "32")]
+    unsafe {
+        ret_usize(syscall!(
+            __NR_io_pgetevents_time64,
+            pass_usize(ctx as usize),
+            pass_usize(min_nr),
+            pass_usize(events.len()),
+            events.as_mut_ptr(),
+            timeout,
+            zero()
+        ))
+        .or_else(|err| {
+            // See the comments in `rustix_clock_gettime_via_syscall` about
+            // emulation.
+            if err == io::Errno::NOSYS {
+                io_getevents_old(ctx, min_nr, events, timeout)
+            } else {
+                Err(err)
+            }
+        })
+    }
+
+    #[cfg(target_pointer_width = "64")]
+    unsafe {
+        ret_usize(syscall!(
+            __NR_io_getevents,
+            pass_usize(ctx as usize),
+            pass_usize(min_nr),
+            pass_usize(events.len()),
+            events.as_mut_ptr(),
+            timeout
+        ))
+    }
+}
+
+#[cfg(target_pointer_width = "32")]
+unsafe fn io_getevents_old(
+    ctx: c::c_ulong,
+    min_nr: usize,
+    events: &mut [IoEvent],
+    timeout: *const Timespec,
+) -> io::Result<usize> {
+    let old_timeout = if timeout.is_null() {
+        None
+    } else {
+        Some(__kernel_old_timespec {
+            tv_sec: (*timeout).tv_sec.try_into().map_err(|_| io::Errno::INVAL)?,
+            tv_nsec: (*timeout)
+                .tv_nsec
+                .try_into()
+                .map_err(|_| io::Errno::INVAL)?,
+        })
+    };
+    ret_usize(syscall!(
+        __NR_io_getevents,
+        pass_usize(ctx as usize),
+        pass_usize(min_nr),
+        pass_usize(events.len()),
+        events.as_mut_ptr(),
+        option_as_ptr(old_timeout.as_ref())
+    ))
+}
@@ -0,0 +1,29 @@
+//! linux_raw syscalls supporting `rustix::bpf`.
+//!
+//! # Safety
+//!
+//! See the `rustix::backend` module documentation for details.
+#![allow(unsafe_code, clippy::undocumented_unsafe_blocks)]
+
+use crate::backend::c;
+use crate::backend::conv::{c_uint, ret_c_int, ret_owned_fd};
+use crate::fd::OwnedFd;
+use crate::io;
+
+#[inline]
+pub(crate) unsafe fn bpf(
+    cmd: c::c_uint,
+    attr: *mut c::c_void,
+    size: c::c_uint,
+) -> io::Result<c::c_int> {
+    ret_c_int(syscall!(__NR_bpf, c_uint(cmd), attr, c_uint(size)))
+}
+
+#[inline]
+pub(crate) unsafe fn bpf_fd(
+    cmd: c::c_uint,
+    attr: *mut c::c_void,
+    size: c::c_uint,
+) -> io::Result<OwnedFd> {
+    ret_owned_fd(syscall!(__NR_bpf, c_uint(cmd), attr, c_uint(size)))
+}
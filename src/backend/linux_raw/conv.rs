@@ -35,11 +35,16 @@ use crate::clockid::ClockId;
 use crate::fd::OwnedFd;
 use crate::ffi::CStr;
 use crate::io;
-#[cfg(any(feature = "process", feature = "runtime", feature = "termios"))]
+#[cfg(any(
+    feature = "process",
+    feature = "runtime",
+    feature = "termios",
+    feature = "thread"
+))]
 use crate::pid::Pid;
 #[cfg(feature = "process")]
 use crate::process::Resource;
-#[cfg(any(feature = "process", feature = "runtime"))]
+#[cfg(any(feature = "process", feature = "runtime", feature = "thread"))]
 use crate::signal::Signal;
 use crate::utils::{as_mut_ptr, as_ptr};
 use core::mem::MaybeUninit;
@@ -302,6 +307,7 @@ pub(super) fn socklen_t<'a, Num: ArgNumber>(i: socklen_t) -> ArgReg<'a, Num> {
         not(feature = "use-libc-auxv"),
         not(feature = "use-explicitly-provided-auxv"),
         any(
+            feature = "mm",
             feature = "param",
             feature = "process",
             feature = "runtime",
@@ -497,6 +503,14 @@ impl<'a, Num: ArgNumber> From<crate::backend::mount::types::OpenTreeFlags> for A
     }
 }
 
+#[cfg(feature = "mount")]
+impl<'a, Num: ArgNumber> From<crate::backend::mount::types::MountSetattrFlags> for ArgReg<'a, Num> {
+    #[inline]
+    fn from(flags: crate::backend::mount::types::MountSetattrFlags) -> Self {
+        c_uint(flags.bits())
+    }
+}
+
 #[cfg(feature = "mount")]
 impl<'a, Num: ArgNumber> From<crate::backend::mount::types::FsPickFlags> for ArgReg<'a, Num> {
     #[inline]
@@ -693,7 +707,12 @@ impl<'a, Num: ArgNumber> From<Resource> for ArgReg<'a, Num> {
     }
 }
 
-#[cfg(any(feature = "process", feature = "runtime", feature = "termios"))]
+#[cfg(any(
+    feature = "process",
+    feature = "runtime",
+    feature = "termios",
+    feature = "thread"
+))]
 impl<'a, Num: ArgNumber> From<Pid> for ArgReg<'a, Num> {
     #[inline]
     fn from(pid: Pid) -> Self {
@@ -707,7 +726,7 @@ pub(super) fn negative_pid<'a, Num: ArgNumber>(pid: Pid) -> ArgReg<'a, Num> {
     pass_usize(pid.as_raw_nonzero().get().wrapping_neg() as usize)
 }
 
-#[cfg(any(feature = "process", feature = "runtime"))]
+#[cfg(any(feature = "process", feature = "runtime", feature = "thread"))]
 impl<'a, Num: ArgNumber> From<Signal> for ArgReg<'a, Num> {
     #[inline]
     fn from(sig: Signal) -> Self {
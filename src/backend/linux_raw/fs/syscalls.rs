@@ -11,13 +11,15 @@ use crate::backend::conv::fs::oflags_for_open_how;
 #[cfg(any(
     not(feature = "linux_4_11"),
     target_arch = "aarch64",
+    target_arch = "arm",
     target_arch = "riscv64",
     target_arch = "mips",
     target_arch = "mips32r6",
+    target_arch = "powerpc",
 ))]
 use crate::backend::conv::zero;
 use crate::backend::conv::{
-    by_ref, c_int, c_uint, dev_t, opt_mut, pass_usize, raw_fd, ret, ret_c_int, ret_c_uint,
+    by_mut, by_ref, c_int, c_uint, dev_t, opt_mut, pass_usize, raw_fd, ret, ret_c_int, ret_c_uint,
     ret_infallible, ret_owned_fd, ret_usize, size_of, slice, slice_mut,
 };
 #[cfg(target_pointer_width = "64")]
@@ -35,11 +37,13 @@ use crate::ffi::CStr;
 #[cfg(any(target_arch = "aarch64", target_arch = "riscv64"))]
 use crate::fs::CWD;
 use crate::fs::{
-    inotify, Access, Advice, AtFlags, FallocateFlags, FileType, FlockOperation, Gid, MemfdFlags,
-    Mode, OFlags, RenameFlags, ResolveFlags, SealFlags, SeekFrom, Stat, StatFs, StatVfs,
-    StatVfsMountFlags, StatxFlags, Timestamps, Uid, XattrFlags,
+    inotify, Access, Advice, AtFlags, FallocateFlags, FileType, Flock, FlockOffsetType,
+    FlockOperation, FlockType, Gid, MemfdFlags, Mode, OFlags, RenameFlags, ResolveFlags, SealFlags,
+    SeekFrom, Stat, StatFs, StatVfs, StatVfsMountFlags, StatxFlags, SyncFileRangeFlags, Timestamps,
+    Uid, XattrFlags,
 };
 use crate::io;
+use crate::pid::Pid;
 use core::mem::MaybeUninit;
 #[cfg(any(target_arch = "mips64", target_arch = "mips64r6"))]
 use linux_raw_sys::general::stat as linux_stat64;
@@ -325,6 +329,54 @@ pub(crate) fn ftruncate(fd: BorrowedFd<'_>, length: u64) -> io::Result<()> {
     }
 }
 
+#[inline]
+pub(crate) fn truncate(path: &CStr, length: u64) -> io::Result<()> {
+    // See the comments in `ftruncate` about the register pair alignment.
+    #[cfg(all(
+        target_pointer_width = "32",
+        any(
+            target_arch = "arm",
+            target_arch = "mips",
+            target_arch = "mips32r6",
+            target_arch = "powerpc"
+        ),
+    ))]
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_truncate64,
+            path,
+            zero(),
+            hi(length),
+            lo(length)
+        ))
+    }
+    #[cfg(all(
+        target_pointer_width = "32",
+        not(any(
+            target_arch = "arm",
+            target_arch = "mips",
+            target_arch = "mips32r6",
+            target_arch = "powerpc"
+        )),
+    ))]
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_truncate64,
+            path,
+            hi(length),
+            lo(length)
+        ))
+    }
+    #[cfg(target_pointer_width = "64")]
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_truncate,
+            path,
+            loff_t_from_u64(length)
+        ))
+    }
+}
+
 #[inline]
 pub(crate) fn fallocate(
     fd: BorrowedFd<'_>,
@@ -449,6 +501,154 @@ pub(crate) fn fdatasync(fd: BorrowedFd<'_>) -> io::Result<()> {
     unsafe { ret(syscall_readonly!(__NR_fdatasync, fd)) }
 }
 
+#[inline]
+pub(crate) fn sync_file_range(
+    fd: BorrowedFd<'_>,
+    offset: u64,
+    nbytes: u64,
+    flags: SyncFileRangeFlags,
+) -> io::Result<()> {
+    // On ARM, the flags are moved up so that the offset and nbytes argument
+    // pairs are aligned. And ARM has a custom syscall code for this.
+    #[cfg(target_arch = "arm")]
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_arm_sync_file_range,
+            fd,
+            c_uint(flags.bits()),
+            hi(offset),
+            lo(offset),
+            hi(nbytes),
+            lo(nbytes)
+        ))
+    }
+
+    // On powerpc, the arguments are reordered as on ARM.
+    #[cfg(target_arch = "powerpc")]
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_sync_file_range2,
+            fd,
+            c_uint(flags.bits()),
+            hi(offset),
+            lo(offset),
+            hi(nbytes),
+            lo(nbytes)
+        ))
+    }
+
+    // On powerpc64, only the reordered `sync_file_range2` is available.
+    #[cfg(target_arch = "powerpc64")]
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_sync_file_range2,
+            fd,
+            c_uint(flags.bits()),
+            loff_t_from_u64(offset),
+            loff_t_from_u64(nbytes)
+        ))
+    }
+
+    // On mips, the arguments are not reordered, and padding is inserted
+    // instead to ensure alignment.
+    #[cfg(any(target_arch = "mips", target_arch = "mips32r6"))]
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_sync_file_range,
+            fd,
+            zero(),
+            hi(offset),
+            lo(offset),
+            hi(nbytes),
+            lo(nbytes),
+            c_uint(flags.bits())
+        ))
+    }
+
+    #[cfg(all(
+        target_pointer_width = "32",
+        not(any(
+            target_arch = "arm",
+            target_arch = "mips",
+            target_arch = "mips32r6",
+            target_arch = "powerpc"
+        )),
+    ))]
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_sync_file_range,
+            fd,
+            hi(offset),
+            lo(offset),
+            hi(nbytes),
+            lo(nbytes),
+            c_uint(flags.bits())
+        ))
+    }
+
+    #[cfg(all(target_pointer_width = "64", not(target_arch = "powerpc64")))]
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_sync_file_range,
+            fd,
+            loff_t_from_u64(offset),
+            loff_t_from_u64(nbytes),
+            c_uint(flags.bits())
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn readahead(fd: BorrowedFd<'_>, offset: u64, count: usize) -> io::Result<()> {
+    // On ARM, mips, and powerpc, padding is inserted so that the offset
+    // argument pair is aligned.
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "mips",
+        target_arch = "mips32r6",
+        target_arch = "powerpc"
+    ))]
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_readahead,
+            fd,
+            zero(),
+            hi(offset),
+            lo(offset),
+            pass_usize(count)
+        ))
+    }
+
+    #[cfg(all(
+        target_pointer_width = "32",
+        not(any(
+            target_arch = "arm",
+            target_arch = "mips",
+            target_arch = "mips32r6",
+            target_arch = "powerpc"
+        )),
+    ))]
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_readahead,
+            fd,
+            hi(offset),
+            lo(offset),
+            pass_usize(count)
+        ))
+    }
+
+    #[cfg(target_pointer_width = "64")]
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_readahead,
+            fd,
+            loff_t_from_u64(offset),
+            pass_usize(count)
+        ))
+    }
+}
+
 #[inline]
 pub(crate) fn flock(fd: BorrowedFd<'_>, operation: FlockOperation) -> io::Result<()> {
     unsafe {
@@ -1090,6 +1290,97 @@ pub(crate) fn fcntl_lock(fd: BorrowedFd<'_>, operation: FlockOperation) -> io::R
     }
 }
 
+#[inline]
+pub(crate) fn fcntl_setlk(fd: BorrowedFd<'_>, lock: &Flock) -> io::Result<()> {
+    #[cfg(target_pointer_width = "64")]
+    use linux_raw_sys::general::F_SETLK;
+    #[cfg(target_pointer_width = "32")]
+    use linux_raw_sys::general::F_SETLK64 as F_SETLK;
+
+    fcntl_flock(fd, F_SETLK, lock).map(drop)
+}
+
+#[inline]
+pub(crate) fn fcntl_setlkw(fd: BorrowedFd<'_>, lock: &Flock) -> io::Result<()> {
+    #[cfg(target_pointer_width = "64")]
+    use linux_raw_sys::general::F_SETLKW;
+    #[cfg(target_pointer_width = "32")]
+    use linux_raw_sys::general::F_SETLKW64 as F_SETLKW;
+
+    fcntl_flock(fd, F_SETLKW, lock).map(drop)
+}
+
+#[inline]
+pub(crate) fn fcntl_getlk(fd: BorrowedFd<'_>, lock: &Flock) -> io::Result<Flock> {
+    #[cfg(target_pointer_width = "64")]
+    use linux_raw_sys::general::F_GETLK;
+    #[cfg(target_pointer_width = "32")]
+    use linux_raw_sys::general::F_GETLK64 as F_GETLK;
+
+    fcntl_flock(fd, F_GETLK, lock)
+}
+
+#[inline]
+pub(crate) fn fcntl_ofd_setlk(fd: BorrowedFd<'_>, lock: &Flock) -> io::Result<()> {
+    fcntl_flock(fd, linux_raw_sys::general::F_OFD_SETLK, lock).map(drop)
+}
+
+#[inline]
+pub(crate) fn fcntl_ofd_setlkw(fd: BorrowedFd<'_>, lock: &Flock) -> io::Result<()> {
+    fcntl_flock(fd, linux_raw_sys::general::F_OFD_SETLKW, lock).map(drop)
+}
+
+#[inline]
+pub(crate) fn fcntl_ofd_getlk(fd: BorrowedFd<'_>, lock: &Flock) -> io::Result<Flock> {
+    fcntl_flock(fd, linux_raw_sys::general::F_OFD_GETLK, lock)
+}
+
+/// Performs a record lock `fcntl` command, and returns the lock the kernel
+/// wrote back, which is only meaningful for the `GETLK` commands.
+fn fcntl_flock(fd: BorrowedFd<'_>, cmd: u32, lock: &Flock) -> io::Result<Flock> {
+    #[cfg(target_pointer_width = "64")]
+    use linux_raw_sys::general::flock;
+    #[cfg(target_pointer_width = "32")]
+    use linux_raw_sys::general::flock64 as flock;
+    use linux_raw_sys::general::{F_RDLCK, F_WRLCK};
+
+    let mut raw = flock {
+        l_type: lock.typ as _,
+        l_whence: lock.offset_type as _,
+        l_start: lock.start,
+        l_len: lock.length,
+
+        // This must be 0 for open file description locks, and is ignored
+        // otherwise.
+        l_pid: 0,
+    };
+
+    #[cfg(target_pointer_width = "32")]
+    unsafe {
+        ret(syscall!(__NR_fcntl64, fd, c_uint(cmd), by_mut(&mut raw)))?;
+    }
+    #[cfg(target_pointer_width = "64")]
+    unsafe {
+        ret(syscall!(__NR_fcntl, fd, c_uint(cmd), by_mut(&mut raw)))?;
+    }
+
+    Ok(Flock {
+        start: raw.l_start,
+        length: raw.l_len,
+        pid: Pid::from_raw(raw.l_pid),
+        typ: match raw.l_type as u32 {
+            F_RDLCK => FlockType::ReadLock,
+            F_WRLCK => FlockType::WriteLock,
+            _ => FlockType::Unlocked,
+        },
+        offset_type: match raw.l_whence as u32 {
+            SEEK_CUR => FlockOffsetType::Current,
+            SEEK_END => FlockOffsetType::End,
+            _ => FlockOffsetType::Set,
+        },
+    })
+}
+
 #[inline]
 pub(crate) fn rename(old_path: &CStr, new_path: &CStr) -> io::Result<()> {
     #[cfg(target_arch = "riscv64")]
@@ -292,13 +292,16 @@ bitflags! {
     #[repr(transparent)]
     #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
     pub struct RenameFlags: c::c_uint {
-        /// `RENAME_EXCHANGE`
+        /// `RENAME_EXCHANGE`—Atomically exchange the old and new paths,
+        /// which must both exist.
         const EXCHANGE = linux_raw_sys::general::RENAME_EXCHANGE;
 
-        /// `RENAME_NOREPLACE`
+        /// `RENAME_NOREPLACE`—Fail with `EEXIST` instead of replacing the
+        /// new path if it exists.
         const NOREPLACE = linux_raw_sys::general::RENAME_NOREPLACE;
 
-        /// `RENAME_WHITEOUT`
+        /// `RENAME_WHITEOUT`—Leave a whiteout object at the old path, for
+        /// overlay and union filesystems.
         const WHITEOUT = linux_raw_sys::general::RENAME_WHITEOUT;
 
         /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
@@ -548,6 +551,44 @@ bitflags! {
     }
 }
 
+bitflags! {
+    /// `STATX_ATTR_*` constants for use with the `stx_attributes` and
+    /// `stx_attributes_mask` fields of [`Statx`].
+    #[repr(transparent)]
+    #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    pub struct StatxAttributes: u64 {
+        /// `STATX_ATTR_COMPRESSED`
+        const COMPRESSED = linux_raw_sys::general::STATX_ATTR_COMPRESSED as u64;
+
+        /// `STATX_ATTR_IMMUTABLE`
+        const IMMUTABLE = linux_raw_sys::general::STATX_ATTR_IMMUTABLE as u64;
+
+        /// `STATX_ATTR_APPEND`
+        const APPEND = linux_raw_sys::general::STATX_ATTR_APPEND as u64;
+
+        /// `STATX_ATTR_NODUMP`
+        const NODUMP = linux_raw_sys::general::STATX_ATTR_NODUMP as u64;
+
+        /// `STATX_ATTR_ENCRYPTED`
+        const ENCRYPTED = linux_raw_sys::general::STATX_ATTR_ENCRYPTED as u64;
+
+        /// `STATX_ATTR_AUTOMOUNT`
+        const AUTOMOUNT = linux_raw_sys::general::STATX_ATTR_AUTOMOUNT as u64;
+
+        /// `STATX_ATTR_MOUNT_ROOT` (since Linux 5.8)
+        const MOUNT_ROOT = linux_raw_sys::general::STATX_ATTR_MOUNT_ROOT as u64;
+
+        /// `STATX_ATTR_VERITY` (since Linux 5.5)
+        const VERITY = linux_raw_sys::general::STATX_ATTR_VERITY as u64;
+
+        /// `STATX_ATTR_DAX` (since Linux 5.8)
+        const DAX = linux_raw_sys::general::STATX_ATTR_DAX as u64;
+
+        /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+        const _ = !0;
+    }
+}
+
 bitflags! {
     /// `FALLOC_FL_*` constants for use with [`fallocate`].
     ///
@@ -575,6 +616,25 @@ bitflags! {
     }
 }
 
+bitflags! {
+    /// `SYNC_FILE_RANGE_*` constants for use with [`sync_file_range`].
+    ///
+    /// [`sync_file_range`]: crate::fs::sync_file_range
+    #[repr(transparent)]
+    #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    pub struct SyncFileRangeFlags: u32 {
+        /// `SYNC_FILE_RANGE_WAIT_BEFORE`
+        const WAIT_BEFORE = linux_raw_sys::general::SYNC_FILE_RANGE_WAIT_BEFORE;
+        /// `SYNC_FILE_RANGE_WRITE`
+        const WRITE = linux_raw_sys::general::SYNC_FILE_RANGE_WRITE;
+        /// `SYNC_FILE_RANGE_WAIT_AFTER`
+        const WAIT_AFTER = linux_raw_sys::general::SYNC_FILE_RANGE_WAIT_AFTER;
+
+        /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+        const _ = !0;
+    }
+}
+
 bitflags! {
     /// `ST_*` constants for use with [`StatVfs`].
     #[repr(transparent)]
@@ -633,6 +693,34 @@ pub enum FlockOperation {
     NonBlockingUnlock = linux_raw_sys::general::LOCK_UN | linux_raw_sys::general::LOCK_NB,
 }
 
+/// `F_*LCK` constants for use with [`Flock`].
+///
+/// [`Flock`]: crate::fs::Flock
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[repr(u32)]
+pub enum FlockType {
+    /// `F_RDLCK`
+    ReadLock = linux_raw_sys::general::F_RDLCK,
+    /// `F_WRLCK`
+    WriteLock = linux_raw_sys::general::F_WRLCK,
+    /// `F_UNLCK`
+    Unlocked = linux_raw_sys::general::F_UNLCK,
+}
+
+/// `SEEK_*` constants for use with [`Flock`].
+///
+/// [`Flock`]: crate::fs::Flock
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[repr(u32)]
+pub enum FlockOffsetType {
+    /// `SEEK_SET`
+    Set = linux_raw_sys::general::SEEK_SET,
+    /// `SEEK_CUR`
+    Current = linux_raw_sys::general::SEEK_CUR,
+    /// `SEEK_END`
+    End = linux_raw_sys::general::SEEK_END,
+}
+
 /// `struct stat` for use with [`statat`] and [`fstat`].
 ///
 /// [`statat`]: crate::fs::statat
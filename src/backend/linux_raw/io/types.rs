@@ -21,19 +21,33 @@ bitflags! {
     /// `RWF_*` constants for use with [`preadv2`] and [`pwritev2`].
     ///
     /// [`preadv2`]: crate::io::preadv2
-    /// [`pwritev2`]: crate::io::pwritev
+    /// [`pwritev2`]: crate::io::pwritev2
     #[repr(transparent)]
     #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
     pub struct ReadWriteFlags: c::c_uint {
         /// `RWF_DSYNC` (since Linux 4.7)
+        ///
+        /// Like opening the file with `O_DSYNC`, for this call only: the
+        /// written data is flushed to storage before the write returns.
         const DSYNC = linux_raw_sys::general::RWF_DSYNC;
         /// `RWF_HIPRI` (since Linux 4.6)
         const HIPRI = linux_raw_sys::general::RWF_HIPRI;
         /// `RWF_SYNC` (since Linux 4.7)
+        ///
+        /// Like opening the file with `O_SYNC`, for this call only: the
+        /// written data and associated metadata are flushed to storage before
+        /// the write returns.
         const SYNC = linux_raw_sys::general::RWF_SYNC;
         /// `RWF_NOWAIT` (since Linux 4.14)
         const NOWAIT = linux_raw_sys::general::RWF_NOWAIT;
         /// `RWF_APPEND` (since Linux 4.16)
+        ///
+        /// Like opening the file with `O_APPEND`, for this call only: the
+        /// data is atomically appended to the end of the file. If the offset
+        /// is not `u64::MAX`, the file offset is not updated. See
+        /// [`append_write`].
+        ///
+        /// [`append_write`]: crate::io::append_write
         const APPEND = linux_raw_sys::general::RWF_APPEND;
 
         /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
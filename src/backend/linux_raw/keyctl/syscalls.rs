@@ -0,0 +1,60 @@
+//! linux_raw syscalls supporting `rustix::keyctl`.
+//!
+//! # Safety
+//!
+//! See the `rustix::backend` module documentation for details.
+#![allow(unsafe_code, clippy::undocumented_unsafe_blocks)]
+
+use crate::backend::c;
+use crate::backend::conv::{c_int, ret_c_int, ret_usize, slice};
+use crate::ffi::CStr;
+use crate::io;
+
+#[inline]
+pub(crate) fn add_key(
+    type_: &CStr,
+    description: &CStr,
+    payload: &[u8],
+    keyring: c::c_int,
+) -> io::Result<c::c_int> {
+    let (payload_addr, payload_len) = slice(payload);
+    unsafe {
+        ret_c_int(syscall_readonly!(
+            __NR_add_key,
+            type_,
+            description,
+            payload_addr,
+            payload_len,
+            c_int(keyring)
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn request_key(
+    type_: &CStr,
+    description: &CStr,
+    callout_info: Option<&CStr>,
+    dest_keyring: c::c_int,
+) -> io::Result<c::c_int> {
+    unsafe {
+        ret_c_int(syscall_readonly!(
+            __NR_request_key,
+            type_,
+            description,
+            callout_info,
+            c_int(dest_keyring)
+        ))
+    }
+}
+
+#[inline]
+pub(crate) unsafe fn keyctl(
+    option: c::c_int,
+    arg2: *mut c::c_void,
+    arg3: *mut c::c_void,
+    arg4: *mut c::c_void,
+    arg5: *mut c::c_void,
+) -> io::Result<usize> {
+    ret_usize(syscall!(__NR_keyctl, c_int(option), arg2, arg3, arg4, arg5))
+}
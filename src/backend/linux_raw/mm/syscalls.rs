@@ -7,15 +7,18 @@
 #![allow(clippy::undocumented_unsafe_blocks)]
 
 use super::types::{
-    Advice, MapFlags, MlockAllFlags, MlockFlags, MprotectFlags, MremapFlags, MsyncFlags, ProtFlags,
-    UserfaultfdFlags,
+    Advice, MapFlags, MlockAllFlags, MlockFlags, MprotectFlags, MremapFlags, MsyncFlags,
+    PkeyRights, ProtFlags, UserfaultfdFlags,
 };
 use crate::backend::c;
 #[cfg(target_pointer_width = "64")]
 use crate::backend::conv::loff_t_from_u64;
-use crate::backend::conv::{c_uint, no_fd, pass_usize, ret, ret_owned_fd, ret_void_star};
+use crate::backend::conv::{
+    c_int, c_uint, no_fd, pass_usize, ret, ret_c_int, ret_owned_fd, ret_usize, ret_void_star, slice,
+};
 use crate::fd::{BorrowedFd, OwnedFd};
 use crate::io;
+use crate::mm::RemoteIoVec;
 use linux_raw_sys::general::MAP_ANONYMOUS;
 
 #[inline]
@@ -30,6 +33,25 @@ pub(crate) fn madvise(addr: *mut c::c_void, len: usize, advice: Advice) -> io::R
     }
 }
 
+#[inline]
+pub(crate) fn process_madvise(
+    pidfd: BorrowedFd<'_>,
+    ranges: &[RemoteIoVec],
+    advice: Advice,
+) -> io::Result<usize> {
+    let (ranges_addr, ranges_len) = slice(ranges);
+    unsafe {
+        ret_usize(syscall!(
+            __NR_process_madvise,
+            pidfd,
+            ranges_addr,
+            ranges_len,
+            c_uint(advice as c::c_uint),
+            c_uint(0)
+        ))
+    }
+}
+
 #[inline]
 pub(crate) unsafe fn msync(addr: *mut c::c_void, len: usize, flags: MsyncFlags) -> io::Result<()> {
     ret(syscall!(__NR_msync, addr, pass_usize(len), flags))
@@ -206,6 +228,52 @@ pub(crate) unsafe fn munlock(addr: *mut c::c_void, length: usize) -> io::Result<
     ret(syscall!(__NR_munlock, addr, pass_usize(length)))
 }
 
+/// # Safety
+///
+/// `vec` must have at least one element for each page in the range starting
+/// at `addr` of length `length`.
+#[inline]
+pub(crate) unsafe fn mincore(addr: *mut c::c_void, length: usize, vec: *mut u8) -> io::Result<()> {
+    ret(syscall!(__NR_mincore, addr, pass_usize(length), vec))
+}
+
+#[inline]
+pub(crate) fn pkey_alloc(rights: PkeyRights) -> io::Result<u32> {
+    unsafe {
+        ret_c_int(syscall_readonly!(
+            __NR_pkey_alloc,
+            c_uint(0),
+            c_uint(rights.bits())
+        ))
+        .map(|pkey| pkey as u32)
+    }
+}
+
+#[inline]
+pub(crate) fn pkey_free(pkey: u32) -> io::Result<()> {
+    unsafe { ret(syscall_readonly!(__NR_pkey_free, c_int(pkey as c::c_int))) }
+}
+
+/// # Safety
+///
+/// `pkey_mprotect` is primarily unsafe due to the `addr` parameter, as
+/// anything working with memory pointed to by raw pointers is unsafe.
+#[inline]
+pub(crate) unsafe fn pkey_mprotect(
+    addr: *mut c::c_void,
+    len: usize,
+    flags: MprotectFlags,
+    pkey: u32,
+) -> io::Result<()> {
+    ret(syscall!(
+        __NR_pkey_mprotect,
+        addr,
+        pass_usize(len),
+        flags,
+        c_int(pkey as c::c_int)
+    ))
+}
+
 #[inline]
 pub(crate) unsafe fn userfaultfd(flags: UserfaultfdFlags) -> io::Result<OwnedFd> {
     ret_owned_fd(syscall_readonly!(__NR_userfaultfd, flags))
@@ -23,6 +23,10 @@ mod vdso;
 #[cfg(any(feature = "time", feature = "process", target_arch = "x86"))]
 mod vdso_wrappers;
 
+#[cfg(feature = "aio")]
+pub(crate) mod aio;
+#[cfg(feature = "bpf")]
+pub(crate) mod bpf;
 #[cfg(feature = "event")]
 pub(crate) mod event;
 #[cfg(any(
@@ -31,6 +35,7 @@ pub(crate) mod event;
         not(feature = "use-libc-auxv"),
         not(feature = "use-explicitly-provided-auxv"),
         any(
+            feature = "mm",
             feature = "param",
             feature = "process",
             feature = "runtime",
@@ -43,6 +48,8 @@ pub(crate) mod fs;
 pub(crate) mod io;
 #[cfg(feature = "io_uring")]
 pub(crate) mod io_uring;
+#[cfg(feature = "keyctl")]
+pub(crate) mod keyctl;
 #[cfg(feature = "mm")]
 pub(crate) mod mm;
 #[cfg(feature = "mount")]
@@ -52,6 +59,7 @@ pub(crate) mod mount; // for deprecated mount functions in "fs"
 #[cfg(feature = "net")]
 pub(crate) mod net;
 #[cfg(any(
+    feature = "mm",
     feature = "param",
     feature = "process",
     feature = "runtime",
@@ -59,6 +67,8 @@ pub(crate) mod net;
     target_arch = "x86",
 ))]
 pub(crate) mod param;
+#[cfg(feature = "perf_event")]
+pub(crate) mod perf_event;
 #[cfg(feature = "pipe")]
 pub(crate) mod pipe;
 #[cfg(feature = "process")]
@@ -67,6 +77,8 @@ pub(crate) mod process;
 pub(crate) mod pty;
 #[cfg(feature = "rand")]
 pub(crate) mod rand;
+#[cfg(any(feature = "fs", feature = "net"))]
+pub(crate) mod raw;
 #[cfg(feature = "runtime")]
 pub(crate) mod runtime;
 #[cfg(feature = "shm")]
@@ -77,7 +89,7 @@ pub(crate) mod system;
 pub(crate) mod termios;
 #[cfg(feature = "thread")]
 pub(crate) mod thread;
-#[cfg(feature = "time")]
+#[cfg(any(feature = "process", feature = "time"))]
 pub(crate) mod time;
 
 pub(crate) mod fd {
@@ -91,7 +103,12 @@ pub(crate) mod fd {
 pub(crate) mod c;
 
 // Private modules used by multiple public modules.
-#[cfg(any(feature = "procfs", feature = "process", feature = "runtime"))]
+#[cfg(any(
+    feature = "procfs",
+    feature = "process",
+    feature = "runtime",
+    feature = "thread"
+))]
 pub(crate) mod pid;
 #[cfg(any(feature = "process", feature = "thread"))]
 pub(crate) mod prctl;
@@ -103,6 +120,7 @@ pub(crate) mod prctl;
         not(feature = "use-libc-auxv"),
         not(feature = "use-explicitly-provided-auxv"),
         any(
+            feature = "mm",
             feature = "param",
             feature = "runtime",
             feature = "time",
@@ -114,4 +132,4 @@ pub(crate) mod ugid;
 
 /// The maximum number of buffers that can be passed into a vectored I/O system
 /// call on the current platform.
-const MAX_IOV: usize = linux_raw_sys::general::UIO_MAXIOV as usize;
+pub(crate) const MAX_IOV: usize = linux_raw_sys::general::UIO_MAXIOV as usize;
@@ -8,7 +8,7 @@
 
 use crate::backend::conv::ret;
 #[cfg(feature = "mount")]
-use crate::backend::conv::{ret_owned_fd, slice, zero};
+use crate::backend::conv::{by_ref, ret_owned_fd, size_of, slice, zero};
 #[cfg(feature = "mount")]
 use crate::fd::{BorrowedFd, OwnedFd};
 use crate::ffi::CStr;
@@ -96,6 +96,36 @@ pub(crate) fn fspick(
     unsafe { ret_owned_fd(syscall_readonly!(__NR_fspick, dfd, path, flags)) }
 }
 
+#[cfg(feature = "mount")]
+#[inline]
+pub(crate) fn mount_setattr(
+    dfd: BorrowedFd<'_>,
+    path: &CStr,
+    flags: super::types::MountSetattrFlags,
+    attr: &crate::mount::MountAttr<'_>,
+) -> io::Result<()> {
+    use linux_raw_sys::general::mount_attr;
+
+    let attr = mount_attr {
+        attr_set: attr.attr_set.bits().into(),
+        attr_clr: attr.attr_clr.bits().into(),
+        propagation: attr.propagation.bits().into(),
+        userns_fd: attr
+            .userns_fd
+            .map_or(0, |fd| crate::fd::AsRawFd::as_raw_fd(&fd) as u64),
+    };
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_mount_setattr,
+            dfd,
+            path,
+            flags,
+            by_ref(&attr),
+            size_of::<mount_attr, _>()
+        ))
+    }
+}
+
 #[cfg(feature = "mount")]
 #[inline]
 pub(crate) fn fsconfig_set_flag(fs_fd: BorrowedFd<'_>, key: &CStr) -> io::Result<()> {
@@ -3,7 +3,8 @@
 //! # Safety
 //!
 //! This file uses `CStr::from_bytes_with_nul_unchecked` on a string it knows
-//! to be NUL-terminated.
+//! to be NUL-terminated, and reads a path that fills `sun_path` together with
+//! the NUL byte that follows it in `SocketAddrUnix`.
 #![allow(unsafe_code)]
 
 use crate::backend::c;
@@ -15,10 +16,13 @@ use core::hash::{Hash, Hasher};
 use core::slice;
 
 /// `struct sockaddr_un`
+#[repr(C)]
 #[derive(Clone)]
 #[doc(alias = "sockaddr_un")]
 pub struct SocketAddrUnix {
     pub(crate) unix: c::sockaddr_un,
+    /// Always NUL, to terminate a path that fills `sun_path`.
+    nul: u8,
     len: c::socklen_t,
 }
 
@@ -32,16 +36,18 @@ impl SocketAddrUnix {
     #[inline]
     fn _new(path: &CStr) -> io::Result<Self> {
         let mut unix = Self::init();
-        let bytes = path.to_bytes_with_nul();
+        let bytes = path.to_bytes();
         if bytes.len() > unix.sun_path.len() {
             return Err(io::Errno::NAMETOOLONG);
         }
         for (i, b) in bytes.iter().enumerate() {
             unix.sun_path[i] = *b as _;
         }
-        let len = offsetof_sun_path() + bytes.len();
+        // Linux accepts a path that fills `sun_path` without a NUL
+        // terminator.
+        let len = offsetof_sun_path() + (bytes.len() + 1).min(unix.sun_path.len());
         let len = len.try_into().unwrap();
-        Ok(Self { unix, len })
+        Ok(Self { unix, nul: 0, len })
     }
 
     /// Construct a new abstract Unix-domain address from a byte slice.
@@ -57,7 +63,7 @@ impl SocketAddrUnix {
             id.copy_from_slice(name);
             let len = offsetof_sun_path() + 1 + name.len();
             let len = len.try_into().unwrap();
-            Ok(Self { unix, len })
+            Ok(Self { unix, nul: 0, len })
         } else {
             Err(io::Errno::NAMETOOLONG)
         }
@@ -76,10 +82,27 @@ impl SocketAddrUnix {
         let len = self.len();
         if len != 0 && self.unix.sun_path[0] as u8 != b'\0' {
             let end = len as usize - offsetof_sun_path();
-            let bytes = &self.unix.sun_path[..end];
-
-            // SAFETY: Convert `&[c_char]` to `&[u8]`.
-            let bytes = unsafe { slice::from_raw_parts(bytes.as_ptr().cast::<u8>(), bytes.len()) };
+            let bytes = if self.unix.sun_path[end - 1] == 0 {
+                let bytes = &self.unix.sun_path[..end];
+
+                // SAFETY: Convert `&[c_char]` to `&[u8]`.
+                unsafe { slice::from_raw_parts(bytes.as_ptr().cast::<u8>(), bytes.len()) }
+            } else {
+                // The path fills `sun_path` without a NUL terminator, so
+                // include the `nul` field after it. `SocketAddrUnix` is
+                // `repr(C)`, with `unix` first.
+                let start = offsetof_sun_path();
+                debug_assert_eq!(
+                    start + end,
+                    core::ptr::addr_of!(self.nul) as usize - (self as *const Self as usize)
+                );
+
+                // SAFETY: `sun_path` is followed immediately by `nul`, and
+                // the pointer is derived from all of `self`.
+                unsafe {
+                    slice::from_raw_parts((self as *const Self).cast::<u8>().add(start), end + 1)
+                }
+            };
 
             // SAFETY: `from_bytes_with_nul_unchecked` since the string is
             // NUL-terminated.
@@ -16,7 +16,7 @@ use crate::net::xdp::SocketAddrXdp;
 use crate::net::{RecvAncillaryBuffer, SendAncillaryBuffer, SocketAddrV4, SocketAddrV6};
 use crate::utils::as_ptr;
 
-use core::mem::{size_of, MaybeUninit};
+use core::mem::{size_of, size_of_val, MaybeUninit};
 use core::ptr::null_mut;
 
 fn msg_iov_len(len: usize) -> c::size_t {
@@ -89,7 +89,7 @@ pub(crate) fn with_v4_msghdr<R>(
 
     f(c::msghdr {
         msg_name: as_ptr(&encoded) as _,
-        msg_namelen: size_of::<SocketAddrV4>() as _,
+        msg_namelen: size_of_val(&encoded) as _,
         msg_iov: iov.as_ptr() as _,
         msg_iovlen: msg_iov_len(iov.len()),
         msg_control: control.as_control_ptr().cast(),
@@ -109,7 +109,7 @@ pub(crate) fn with_v6_msghdr<R>(
 
     f(c::msghdr {
         msg_name: as_ptr(&encoded) as _,
-        msg_namelen: size_of::<SocketAddrV6>() as _,
+        msg_namelen: size_of_val(&encoded) as _,
         msg_iov: iov.as_ptr() as _,
         msg_iovlen: msg_iov_len(iov.len()),
         msg_control: control.as_control_ptr().cast(),
@@ -148,7 +148,7 @@ pub(crate) fn with_xdp_msghdr<R>(
 
     f(c::msghdr {
         msg_name: as_ptr(&encoded) as _,
-        msg_namelen: size_of::<SocketAddrXdp>() as _,
+        msg_namelen: size_of_val(&encoded) as _,
         msg_iov: iov.as_ptr() as _,
         msg_iovlen: msg_iov_len(iov.len()),
         msg_control: control.as_control_ptr().cast(),
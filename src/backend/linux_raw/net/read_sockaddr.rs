@@ -104,13 +104,8 @@ pub(crate) unsafe fn read_sockaddr(
                     return SocketAddrUnix::new_abstract_name(bytes).map(SocketAddrAny::Unix);
                 }
 
-                // Otherwise we expect a NUL-terminated filesystem path.
-                let bytes = &decode.sun_path[..len - 1 - offsetof_sun_path];
-
-                // SAFETY: Convert `&[c_char]` to `&[u8]`.
-                let bytes = slice::from_raw_parts(bytes.as_ptr().cast::<u8>(), bytes.len());
-
-                assert_eq!(decode.sun_path[len - 1 - offsetof_sun_path], 0);
+                // Otherwise we expect a filesystem path.
+                let bytes = unix_path_bytes(decode, len - offsetof_sun_path);
                 Ok(SocketAddrAny::Unix(SocketAddrUnix::new(bytes)?))
             }
         }
@@ -194,14 +189,8 @@ pub(crate) unsafe fn read_sockaddr_os(storage: *const c::sockaddr, len: usize) -
                     return SocketAddrAny::Unix(SocketAddrUnix::new_abstract_name(bytes).unwrap());
                 }
 
-                // Otherwise we expect a NUL-terminated filesystem path.
-                assert_eq!(decode.sun_path[len - 1 - offsetof_sun_path], 0);
-
-                let bytes = &decode.sun_path[..len - 1 - offsetof_sun_path];
-
-                // SAFETY: Convert `&[c_char]` to `&[u8]`.
-                let bytes = slice::from_raw_parts(bytes.as_ptr().cast::<u8>(), bytes.len());
-
+                // Otherwise we expect a filesystem path.
+                let bytes = unix_path_bytes(decode, len - offsetof_sun_path);
                 SocketAddrAny::Unix(SocketAddrUnix::new(bytes).unwrap())
             }
         }
@@ -219,3 +208,21 @@ pub(crate) unsafe fn read_sockaddr_os(storage: *const c::sockaddr, len: usize) -
         other => unimplemented!("{:?}", other),
     }
 }
+
+/// Return the filesystem path in `decode`, given the length of `sun_path`
+/// reported by the OS.
+///
+/// A path may fill `sun_path` without a NUL terminator, and for such a path,
+/// Linux reports a length that includes a NUL beyond the end of `sun_path`.
+/// So the path ends at the first NUL, or at the end of `sun_path`.
+fn unix_path_bytes(decode: &c::sockaddr_un, len: usize) -> &[u8] {
+    let bytes = &decode.sun_path[..len.min(decode.sun_path.len())];
+
+    // SAFETY: Convert `&[c_char]` to `&[u8]`.
+    let bytes = unsafe { slice::from_raw_parts(bytes.as_ptr().cast::<u8>(), bytes.len()) };
+
+    match bytes.iter().position(|b| *b == 0) {
+        Some(nul) => &bytes[..nul],
+        None => bytes,
+    }
+}
@@ -618,6 +618,26 @@ pub(crate) fn get_ipv6_recvtclass(fd: BorrowedFd<'_>) -> io::Result<bool> {
     getsockopt(fd, c::IPPROTO_IPV6, c::IPV6_RECVTCLASS).map(to_bool)
 }
 
+#[inline]
+pub(crate) fn set_ip_pktinfo(fd: BorrowedFd<'_>, value: bool) -> io::Result<()> {
+    setsockopt(fd, c::IPPROTO_IP, c::IP_PKTINFO, from_bool(value))
+}
+
+#[inline]
+pub(crate) fn get_ip_pktinfo(fd: BorrowedFd<'_>) -> io::Result<bool> {
+    getsockopt(fd, c::IPPROTO_IP, c::IP_PKTINFO).map(to_bool)
+}
+
+#[inline]
+pub(crate) fn set_ipv6_recvpktinfo(fd: BorrowedFd<'_>, value: bool) -> io::Result<()> {
+    setsockopt(fd, c::IPPROTO_IPV6, c::IPV6_RECVPKTINFO, from_bool(value))
+}
+
+#[inline]
+pub(crate) fn get_ipv6_recvpktinfo(fd: BorrowedFd<'_>) -> io::Result<bool> {
+    getsockopt(fd, c::IPPROTO_IPV6, c::IPV6_RECVPKTINFO).map(to_bool)
+}
+
 #[inline]
 pub(crate) fn set_ip_freebind(fd: BorrowedFd<'_>, value: bool) -> io::Result<()> {
     setsockopt(fd, c::IPPROTO_IP, c::IP_FREEBIND, from_bool(value))
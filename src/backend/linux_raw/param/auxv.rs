@@ -31,7 +31,7 @@ use linux_raw_sys::general::{
 #[cfg(feature = "alloc")]
 use {alloc::borrow::Cow, alloc::vec};
 
-#[cfg(feature = "param")]
+#[cfg(any(feature = "mm", feature = "param"))]
 #[inline]
 pub(crate) fn page_size() -> usize {
     let mut page_size = PAGE_SIZE.load(Relaxed);
@@ -20,7 +20,7 @@ use linux_raw_sys::general::{
 #[cfg(feature = "runtime")]
 use linux_raw_sys::general::{AT_ENTRY, AT_PHDR, AT_PHENT, AT_PHNUM, AT_RANDOM, AT_SECURE};
 
-#[cfg(feature = "param")]
+#[cfg(any(feature = "mm", feature = "param"))]
 #[inline]
 pub(crate) fn page_size() -> usize {
     unsafe { PAGE_SIZE.load(Ordering::Relaxed) }
@@ -74,7 +74,7 @@ fn test_abi() {
     const_assert_eq!(self::AT_RANDOM, ::libc::AT_RANDOM);
 }
 
-#[cfg(feature = "param")]
+#[cfg(any(feature = "mm", feature = "param"))]
 #[inline]
 pub(crate) fn page_size() -> usize {
     unsafe { sysconf(_SC_PAGESIZE) as usize }
@@ -0,0 +1,37 @@
+//! linux_raw syscalls supporting `rustix::perf_event`.
+//!
+//! # Safety
+//!
+//! See the `rustix::backend` module documentation for details.
+#![allow(unsafe_code, clippy::undocumented_unsafe_blocks)]
+
+use crate::backend::c;
+use crate::backend::conv::{by_mut, c_int, pass_usize, ret_owned_fd};
+use crate::fd::{AsRawFd, BorrowedFd, OwnedFd};
+use crate::io;
+use crate::perf_event::{PerfEventAttr, PerfEventOpenFlags};
+use crate::pid::RawPid;
+
+#[inline]
+pub(crate) fn perf_event_open(
+    attr: &PerfEventAttr,
+    pid: RawPid,
+    cpu: c::c_int,
+    group_fd: Option<BorrowedFd<'_>>,
+    flags: PerfEventOpenFlags,
+) -> io::Result<OwnedFd> {
+    // The kernel may write the size it supports back into `attr`, so pass it
+    // a copy.
+    let mut attr = attr.clone();
+    let group_fd = group_fd.map_or(-1, |fd| fd.as_raw_fd());
+    unsafe {
+        ret_owned_fd(syscall!(
+            __NR_perf_event_open,
+            by_mut(&mut attr),
+            c_int(pid),
+            c_int(cpu),
+            c_int(group_fd),
+            pass_usize(flags.bits() as usize)
+        ))
+    }
+}
@@ -114,22 +114,25 @@ pub(crate) fn fcntl_getpipe_sz(fd: BorrowedFd<'_>) -> io::Result<usize> {
 pub(crate) fn fcntl_setpipe_sz(fd: BorrowedFd<'_>, size: usize) -> io::Result<()> {
     let size: c::c_int = size.try_into().map_err(|_| io::Errno::PERM)?;
 
+    // `F_SETPIPE_SZ` returns the new capacity on success.
     #[cfg(target_pointer_width = "32")]
     unsafe {
-        ret(syscall_readonly!(
+        ret_usize(syscall_readonly!(
             __NR_fcntl64,
             fd,
             c_uint(F_SETPIPE_SZ),
             c_int(size)
         ))
+        .map(|_size| ())
     }
     #[cfg(target_pointer_width = "64")]
     unsafe {
-        ret(syscall_readonly!(
+        ret_usize(syscall_readonly!(
             __NR_fcntl,
             fd,
             c_uint(F_SETPIPE_SZ),
             c_int(size)
         ))
+        .map(|_size| ())
     }
 }
@@ -21,9 +21,10 @@ use crate::io;
 use crate::pid::RawPid;
 use crate::process::{
     Cpuid, MembarrierCommand, MembarrierQuery, Pid, PidfdFlags, PidfdGetfdFlags, Resource, Rlimit,
-    Uid, WaitId, WaitOptions, WaitStatus, WaitidOptions, WaitidStatus,
+    Rusage, Uid, WaitId, WaitOptions, WaitStatus, WaitidOptions, WaitidStatus,
 };
 use crate::signal::Signal;
+use crate::timespec::Timespec;
 use crate::utils::as_mut_ptr;
 use core::mem::MaybeUninit;
 use core::ptr::{null, null_mut};
@@ -43,7 +44,7 @@ use {crate::backend::conv::slice_just_addr_mut, crate::process::Gid};
     target_arch = "riscv64",
     target_arch = "powerpc64"
 ))]
-pub(crate) use crate::backend::vdso_wrappers::sched_getcpu;
+pub(crate) use crate::backend::vdso_wrappers::{getcpu, sched_getcpu};
 
 // `sched_getcpu` on platforms without a vDSO entry for it.
 #[cfg(not(any(
@@ -62,6 +63,24 @@ pub(crate) fn sched_getcpu() -> usize {
     }
 }
 
+// `getcpu` on platforms without a vDSO entry for it.
+#[cfg(not(any(
+    target_arch = "x86_64",
+    target_arch = "x86",
+    target_arch = "riscv64",
+    target_arch = "powerpc64"
+)))]
+#[inline]
+pub(crate) fn getcpu() -> (usize, usize) {
+    let mut cpu = MaybeUninit::<u32>::uninit();
+    let mut node = MaybeUninit::<u32>::uninit();
+    unsafe {
+        let r = ret(syscall!(__NR_getcpu, &mut cpu, &mut node, zero()));
+        debug_assert!(r.is_ok());
+        (cpu.assume_init() as usize, node.assume_init() as usize)
+    }
+}
+
 #[cfg(feature = "fs")]
 #[inline]
 pub(crate) fn chdir(filename: &CStr) -> io::Result<()> {
@@ -205,6 +224,38 @@ pub(crate) fn sched_yield() {
     }
 }
 
+#[inline]
+pub(crate) fn alarm(seconds: u32) -> u32 {
+    use linux_raw_sys::general::{__kernel_old_itimerval, __kernel_old_timeval, ITIMER_REAL};
+
+    // Not all architectures have an `alarm` syscall, so implement it with
+    // `setitimer`, as libc implementations do.
+    let new_value = __kernel_old_itimerval {
+        it_interval: __kernel_old_timeval {
+            tv_sec: 0,
+            tv_usec: 0,
+        },
+        it_value: __kernel_old_timeval {
+            // On 32-bit platforms, clamp rather than wrapping around.
+            tv_sec: seconds.min(c::c_long::MAX as u32) as c::c_long,
+            tv_usec: 0,
+        },
+    };
+
+    // `ITIMER_REAL` with a valid `itimerval` can't fail.
+    let old_value = match crate::backend::time::syscalls::setitimer_old(ITIMER_REAL, &new_value) {
+        Ok(old_value) => old_value.it_value,
+        Err(_) => unreachable!(),
+    };
+
+    // Round to the nearest second, but don't report a pending alarm as `0`.
+    let mut secs = old_value.tv_sec as u32;
+    if old_value.tv_usec >= 500_000 || (secs == 0 && old_value.tv_usec != 0) {
+        secs = secs.saturating_add(1);
+    }
+    secs
+}
+
 #[cfg(feature = "fs")]
 #[inline]
 pub(crate) fn umask(mode: Mode) -> Mode {
@@ -411,6 +462,59 @@ pub(crate) fn _waitpid(
     }
 }
 
+#[inline]
+pub(crate) fn wait4(
+    pid: Option<Pid>,
+    waitopts: WaitOptions,
+) -> io::Result<Option<(Pid, WaitStatus, Rusage)>> {
+    let pid = match pid {
+        Some(pid) => pid.as_raw_nonzero().get(),
+        None => -1,
+    };
+    unsafe {
+        let mut status = MaybeUninit::<u32>::uninit();
+        let mut rusage = MaybeUninit::<linux_raw_sys::general::rusage>::uninit();
+        let pid = ret_c_int(syscall!(
+            __NR_wait4,
+            c_int(pid as _),
+            &mut status,
+            c_int(waitopts.bits() as _),
+            &mut rusage
+        ))?;
+        Ok(Pid::from_raw(pid).map(|pid| {
+            let rusage = rusage.assume_init();
+            (
+                pid,
+                WaitStatus::new(status.assume_init()),
+                Rusage {
+                    utime: Timespec {
+                        tv_sec: rusage.ru_utime.tv_sec as _,
+                        tv_nsec: (rusage.ru_utime.tv_usec * 1000) as _,
+                    },
+                    stime: Timespec {
+                        tv_sec: rusage.ru_stime.tv_sec as _,
+                        tv_nsec: (rusage.ru_stime.tv_usec * 1000) as _,
+                    },
+                    maxrss: rusage.ru_maxrss.into(),
+                    ixrss: rusage.ru_ixrss.into(),
+                    idrss: rusage.ru_idrss.into(),
+                    isrss: rusage.ru_isrss.into(),
+                    minflt: rusage.ru_minflt.into(),
+                    majflt: rusage.ru_majflt.into(),
+                    nswap: rusage.ru_nswap.into(),
+                    inblock: rusage.ru_inblock.into(),
+                    oublock: rusage.ru_oublock.into(),
+                    msgsnd: rusage.ru_msgsnd.into(),
+                    msgrcv: rusage.ru_msgrcv.into(),
+                    nsignals: rusage.ru_nsignals.into(),
+                    nvcsw: rusage.ru_nvcsw.into(),
+                    nivcsw: rusage.ru_nivcsw.into(),
+                },
+            )
+        }))
+    }
+}
+
 #[inline]
 pub(crate) fn waitid(id: WaitId<'_>, options: WaitidOptions) -> io::Result<Option<WaitidStatus>> {
     // Get the id to wait on.
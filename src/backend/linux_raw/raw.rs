@@ -0,0 +1,12 @@
+//! The linux_raw backend's raw OS types, re-exported by `rustix::raw`.
+
+#[cfg(target_pointer_width = "64")]
+#[cfg(feature = "fs")]
+pub use linux_raw_sys::general::stat;
+// Use the large-file-support variant, as `fstat` and friends do.
+#[cfg(target_pointer_width = "32")]
+#[cfg(feature = "fs")]
+pub use linux_raw_sys::general::stat64 as stat;
+pub use linux_raw_sys::general::__kernel_timespec as timespec;
+#[cfg(feature = "net")]
+pub use linux_raw_sys::net::__kernel_sockaddr_storage as sockaddr_storage;
@@ -14,6 +14,8 @@ use crate::ffi::CStr;
 use crate::io;
 use crate::system::{RebootCommand, Sysinfo};
 use core::mem::MaybeUninit;
+#[cfg(kexec_file_load)]
+use {crate::backend::conv::pass_usize, crate::fd::AsRawFd, crate::system::KexecFileFlags};
 
 #[inline]
 pub(crate) fn uname() -> RawUname {
@@ -51,6 +53,27 @@ pub(crate) fn reboot(cmd: RebootCommand) -> io::Result<()> {
     }
 }
 
+#[cfg(kexec_file_load)]
+#[inline]
+pub(crate) fn kexec_file_load(
+    kernel_fd: BorrowedFd<'_>,
+    initrd_fd: Option<BorrowedFd<'_>>,
+    cmdline: &CStr,
+    flags: KexecFileFlags,
+) -> io::Result<()> {
+    let initrd_fd = initrd_fd.map_or(-1, |fd| fd.as_raw_fd());
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_kexec_file_load,
+            kernel_fd,
+            c_int(initrd_fd),
+            pass_usize(cmdline.to_bytes_with_nul().len()),
+            cmdline,
+            pass_usize(flags.bits() as usize)
+        ))
+    }
+}
+
 #[inline]
 pub(crate) fn init_module(image: &[u8], param_values: &CStr) -> io::Result<()> {
     let (image, len) = slice(image);
@@ -13,11 +13,17 @@ use crate::backend::conv::{
 use crate::fd::BorrowedFd;
 use crate::io;
 use crate::pid::Pid;
+use crate::signal::Signal;
 use crate::thread::{ClockId, FutexFlags, FutexOperation, NanosleepRelativeResult, Timespec};
 use core::mem::MaybeUninit;
 #[cfg(target_pointer_width = "32")]
 use linux_raw_sys::general::timespec as __kernel_old_timespec;
-use linux_raw_sys::general::{__kernel_timespec, TIMER_ABSTIME};
+use linux_raw_sys::general::{
+    __kernel_timespec, __sifields, __sifields__bindgen_ty_3, siginfo__bindgen_ty_1__bindgen_ty_1,
+    siginfo_t, sigval, SI_QUEUE, TIMER_ABSTIME,
+};
+#[cfg(target_has_atomic = "64")]
+use {crate::thread::Rseq, core::mem::size_of};
 
 #[inline]
 pub(crate) fn clock_nanosleep_relative(
@@ -345,3 +351,55 @@ pub(crate) fn setresgid_thread(
         ret(syscall_readonly!(__NR_setresgid, rgid, egid, sgid))
     }
 }
+
+#[cfg(target_has_atomic = "64")]
+#[inline]
+pub(crate) unsafe fn rseq(rseq: &Rseq, flags: u32, sig: u32) -> io::Result<()> {
+    ret(syscall_readonly!(
+        __NR_rseq,
+        by_ref(rseq),
+        c_uint(size_of::<Rseq>() as u32),
+        c_uint(flags),
+        c_uint(sig)
+    ))
+}
+
+#[inline]
+pub(crate) fn tgkill(tid: Pid, sig: Signal) -> io::Result<()> {
+    let pid = crate::backend::pid::syscalls::getpid();
+    unsafe { ret(syscall_readonly!(__NR_tgkill, pid, tid, sig)) }
+}
+
+#[inline]
+pub(crate) fn tgsigqueueinfo(tid: Pid, sig: Signal, value: usize) -> io::Result<()> {
+    let pid = crate::backend::pid::syscalls::getpid();
+    let uid = crate::backend::ugid::syscalls::getuid();
+
+    // SAFETY: `siginfo_t` is a plain C struct, for which all-zeros is a
+    // valid value.
+    let mut info: siginfo_t = unsafe { core::mem::zeroed() };
+    info.__bindgen_anon_1.__bindgen_anon_1 = siginfo__bindgen_ty_1__bindgen_ty_1 {
+        si_signo: sig as c::c_int,
+        si_errno: 0,
+        si_code: SI_QUEUE,
+        _sifields: __sifields {
+            _rt: __sifields__bindgen_ty_3 {
+                _pid: pid.as_raw_nonzero().get(),
+                _uid: uid.as_raw(),
+                _sigval: sigval {
+                    sival_ptr: value as *mut c::c_void,
+                },
+            },
+        },
+    };
+
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_rt_tgsigqueueinfo,
+            pid,
+            tid,
+            sig,
+            by_ref(&info)
+        ))
+    }
+}
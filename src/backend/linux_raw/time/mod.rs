@@ -1,3 +1,4 @@
-#[cfg(any(feature = "time", target_arch = "x86"))]
+#[cfg(any(feature = "process", feature = "time", target_arch = "x86"))]
 pub(crate) mod syscalls;
+#[cfg(feature = "time")]
 pub(crate) mod types;
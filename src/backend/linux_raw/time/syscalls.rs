@@ -5,27 +5,30 @@
 //! See the `rustix::backend` module documentation for details.
 #![allow(unsafe_code, clippy::undocumented_unsafe_blocks)]
 
-use crate::backend::conv::{ret, ret_infallible};
-use crate::clockid::ClockId;
+use crate::backend::conv::{by_ref, c_uint, ret};
 use crate::io;
-use crate::timespec::Timespec;
 use core::mem::MaybeUninit;
+use linux_raw_sys::general::__kernel_old_itimerval;
 #[cfg(all(feature = "time", target_pointer_width = "32"))]
 use linux_raw_sys::general::itimerspec as __kernel_old_itimerspec;
-#[cfg(target_pointer_width = "32")]
+#[cfg(all(feature = "time", target_pointer_width = "32"))]
 use linux_raw_sys::general::timespec as __kernel_old_timespec;
 #[cfg(feature = "time")]
 use {
-    crate::backend::conv::{by_ref, ret_owned_fd},
+    crate::backend::conv::{ret_infallible, ret_owned_fd},
+    crate::clockid::ClockId,
     crate::fd::BorrowedFd,
     crate::fd::OwnedFd,
     crate::time::{Itimerspec, TimerfdClockId, TimerfdFlags, TimerfdTimerFlags},
+    crate::timespec::Timespec,
+    linux_raw_sys::general::__kernel_old_timeval,
 };
 
 // `clock_gettime` has special optimizations via the vDSO.
 #[cfg(feature = "time")]
 pub(crate) use crate::backend::vdso_wrappers::{clock_gettime, clock_gettime_dynamic};
 
+#[cfg(feature = "time")]
 #[inline]
 pub(crate) fn clock_getres(which_clock: ClockId) -> Timespec {
     #[cfg(target_pointer_width = "32")]
@@ -47,6 +50,7 @@ pub(crate) fn clock_getres(which_clock: ClockId) -> Timespec {
     }
 }
 
+#[cfg(feature = "time")]
 #[cfg(target_pointer_width = "32")]
 unsafe fn clock_getres_old(which_clock: ClockId, result: &mut MaybeUninit<Timespec>) {
     let mut old_result = MaybeUninit::<__kernel_old_timespec>::uninit();
@@ -255,3 +259,74 @@ unsafe fn timerfd_gettime_old(
     });
     Ok(())
 }
+
+#[cfg(feature = "time")]
+#[inline]
+pub(crate) fn setitimer(
+    which: crate::time::IntervalTimer,
+    new_value: &crate::time::Itimerval,
+) -> io::Result<crate::time::Itimerval> {
+    let new_value = __kernel_old_itimerval {
+        it_interval: duration_to_old_timeval(new_value.interval)?,
+        it_value: duration_to_old_timeval(new_value.value)?,
+    };
+    let old_value = setitimer_old(which as u32, &new_value)?;
+    Ok(old_itimerval_to_itimerval(old_value))
+}
+
+/// `setitimer` with the kernel's `struct itimerval`, which is also used to
+/// implement `alarm`.
+#[inline]
+pub(crate) fn setitimer_old(
+    which: u32,
+    new_value: &__kernel_old_itimerval,
+) -> io::Result<__kernel_old_itimerval> {
+    let mut old_value = MaybeUninit::<__kernel_old_itimerval>::uninit();
+    unsafe {
+        ret(syscall!(
+            __NR_setitimer,
+            c_uint(which),
+            by_ref(new_value),
+            &mut old_value
+        ))?;
+        Ok(old_value.assume_init())
+    }
+}
+
+#[cfg(feature = "time")]
+#[inline]
+pub(crate) fn getitimer(which: crate::time::IntervalTimer) -> io::Result<crate::time::Itimerval> {
+    let mut curr_value = MaybeUninit::<__kernel_old_itimerval>::uninit();
+    unsafe {
+        ret(syscall!(
+            __NR_getitimer,
+            c_uint(which as u32),
+            &mut curr_value
+        ))?;
+        Ok(old_itimerval_to_itimerval(curr_value.assume_init()))
+    }
+}
+
+#[cfg(feature = "time")]
+fn duration_to_old_timeval(duration: core::time::Duration) -> io::Result<__kernel_old_timeval> {
+    let (secs, micros) = crate::time::duration_to_timeval_parts(duration);
+    Ok(__kernel_old_timeval {
+        tv_sec: secs.try_into().map_err(|_| io::Errno::INVAL)?,
+        // This is less than 1_000_000, so it fits in a 32-bit `tv_usec`.
+        tv_usec: micros as _,
+    })
+}
+
+#[cfg(feature = "time")]
+fn old_itimerval_to_itimerval(value: __kernel_old_itimerval) -> crate::time::Itimerval {
+    crate::time::Itimerval {
+        interval: crate::time::timeval_parts_to_duration(
+            value.it_interval.tv_sec.into(),
+            value.it_interval.tv_usec.into(),
+        ),
+        value: crate::time::timeval_parts_to_duration(
+            value.it_value.tv_sec.into(),
+            value.it_value.tv_usec.into(),
+        ),
+    }
+}
@@ -125,7 +125,7 @@ pub(crate) fn sched_getcpu() -> usize {
     // to the result buffers, and no others.
     unsafe {
         let mut cpu = MaybeUninit::<u32>::uninit();
-        let callee = match transmute(GETCPU.load(Relaxed)) {
+        let callee = match transmute::<*mut Function, Option<GetcpuType>>(GETCPU.load(Relaxed)) {
             Some(callee) => callee,
             None => init_getcpu(),
         };
@@ -135,6 +135,31 @@ pub(crate) fn sched_getcpu() -> usize {
     }
 }
 
+#[cfg(feature = "process")]
+#[cfg(any(
+    target_arch = "x86_64",
+    target_arch = "x86",
+    target_arch = "riscv64",
+    target_arch = "powerpc64"
+))]
+#[inline]
+pub(crate) fn getcpu() -> (usize, usize) {
+    // SAFETY: `GETCPU` contains either null or the address of a function with
+    // an ABI like libc `getcpu`, and calling it has the side effect of writing
+    // to the result buffers, and no others.
+    unsafe {
+        let mut cpu = MaybeUninit::<u32>::uninit();
+        let mut node = MaybeUninit::<u32>::uninit();
+        let callee = match transmute::<*mut Function, Option<GetcpuType>>(GETCPU.load(Relaxed)) {
+            Some(callee) => callee,
+            None => init_getcpu(),
+        };
+        let r0 = callee(cpu.as_mut_ptr(), node.as_mut_ptr(), null_mut());
+        debug_assert_eq!(r0, 0);
+        (cpu.assume_init() as usize, node.assume_init() as usize)
+    }
+}
+
 #[cfg(target_arch = "x86")]
 pub(super) mod x86_via_vdso {
     use super::{transmute, ArgReg, Relaxed, RetReg, SyscallNumber, A0, A1, A2, A3, A4, A5, R0};
@@ -0,0 +1,423 @@
+//! Linux `bpf` syscall.
+//!
+//! These functions create and access eBPF maps and load eBPF programs. They
+//! cover enough of the `bpf` syscall for tools to load simple programs
+//! without depending on libbpf; programs are passed as raw instruction
+//! buffers, and attaching them is left to the relevant subsystem, such as
+//! [`setsockopt`] with `SO_ATTACH_BPF`.
+//!
+//! # References
+//!  - [Linux]
+//!
+//! [Linux]: https://man7.org/linux/man-pages/man2/bpf.2.html
+//! [`setsockopt`]: https://man7.org/linux/man-pages/man2/setsockopt.2.html
+
+#![allow(unsafe_code)]
+
+use crate::backend::bpf::syscalls;
+use crate::backend::c::{c_uint, c_void};
+use crate::fd::{AsFd, AsRawFd, OwnedFd};
+use crate::ffi::CStr;
+use crate::io;
+use bitflags::bitflags;
+use core::mem::size_of;
+
+const BPF_MAP_CREATE: c_uint = 0;
+const BPF_MAP_LOOKUP_ELEM: c_uint = 1;
+const BPF_MAP_UPDATE_ELEM: c_uint = 2;
+const BPF_MAP_DELETE_ELEM: c_uint = 3;
+const BPF_MAP_GET_NEXT_KEY: c_uint = 4;
+const BPF_PROG_LOAD: c_uint = 5;
+
+/// The size of a `struct bpf_insn`.
+const INSN_SIZE: usize = 8;
+
+/// `BPF_MAP_TYPE_*`—The type of an eBPF map.
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct MapType(u32);
+
+impl MapType {
+    /// `BPF_MAP_TYPE_HASH`
+    pub const HASH: Self = Self(1);
+
+    /// `BPF_MAP_TYPE_ARRAY`
+    pub const ARRAY: Self = Self(2);
+
+    /// `BPF_MAP_TYPE_PROG_ARRAY`
+    pub const PROG_ARRAY: Self = Self(3);
+
+    /// `BPF_MAP_TYPE_PERF_EVENT_ARRAY`
+    pub const PERF_EVENT_ARRAY: Self = Self(4);
+
+    /// `BPF_MAP_TYPE_PERCPU_HASH`
+    pub const PERCPU_HASH: Self = Self(5);
+
+    /// `BPF_MAP_TYPE_PERCPU_ARRAY`
+    pub const PERCPU_ARRAY: Self = Self(6);
+
+    /// `BPF_MAP_TYPE_STACK_TRACE`
+    pub const STACK_TRACE: Self = Self(7);
+
+    /// `BPF_MAP_TYPE_LRU_HASH`
+    pub const LRU_HASH: Self = Self(9);
+
+    /// `BPF_MAP_TYPE_LRU_PERCPU_HASH`
+    pub const LRU_PERCPU_HASH: Self = Self(10);
+
+    /// `BPF_MAP_TYPE_LPM_TRIE`
+    pub const LPM_TRIE: Self = Self(11);
+
+    /// `BPF_MAP_TYPE_QUEUE`
+    pub const QUEUE: Self = Self(22);
+
+    /// `BPF_MAP_TYPE_STACK`
+    pub const STACK: Self = Self(23);
+
+    /// `BPF_MAP_TYPE_RINGBUF`
+    pub const RINGBUF: Self = Self(27);
+
+    /// `BPF_MAP_TYPE_BLOOM_FILTER`
+    pub const BLOOM_FILTER: Self = Self(30);
+
+    /// Constructs a `MapType` from a raw `BPF_MAP_TYPE_*` value.
+    #[inline]
+    pub const fn from_raw(raw: u32) -> Self {
+        Self(raw)
+    }
+
+    /// Returns the raw `BPF_MAP_TYPE_*` value.
+    #[inline]
+    pub const fn as_raw(self) -> u32 {
+        self.0
+    }
+}
+
+/// `BPF_PROG_TYPE_*`—The type of an eBPF program.
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct ProgType(u32);
+
+impl ProgType {
+    /// `BPF_PROG_TYPE_SOCKET_FILTER`
+    pub const SOCKET_FILTER: Self = Self(1);
+
+    /// `BPF_PROG_TYPE_KPROBE`
+    pub const KPROBE: Self = Self(2);
+
+    /// `BPF_PROG_TYPE_SCHED_CLS`
+    pub const SCHED_CLS: Self = Self(3);
+
+    /// `BPF_PROG_TYPE_SCHED_ACT`
+    pub const SCHED_ACT: Self = Self(4);
+
+    /// `BPF_PROG_TYPE_TRACEPOINT`
+    pub const TRACEPOINT: Self = Self(5);
+
+    /// `BPF_PROG_TYPE_XDP`
+    pub const XDP: Self = Self(6);
+
+    /// `BPF_PROG_TYPE_PERF_EVENT`
+    pub const PERF_EVENT: Self = Self(7);
+
+    /// `BPF_PROG_TYPE_CGROUP_SKB`
+    pub const CGROUP_SKB: Self = Self(8);
+
+    /// `BPF_PROG_TYPE_CGROUP_SOCK`
+    pub const CGROUP_SOCK: Self = Self(9);
+
+    /// `BPF_PROG_TYPE_RAW_TRACEPOINT`
+    pub const RAW_TRACEPOINT: Self = Self(17);
+
+    /// `BPF_PROG_TYPE_TRACING`
+    pub const TRACING: Self = Self(26);
+
+    /// `BPF_PROG_TYPE_LSM`
+    pub const LSM: Self = Self(29);
+
+    /// Constructs a `ProgType` from a raw `BPF_PROG_TYPE_*` value.
+    #[inline]
+    pub const fn from_raw(raw: u32) -> Self {
+        Self(raw)
+    }
+
+    /// Returns the raw `BPF_PROG_TYPE_*` value.
+    #[inline]
+    pub const fn as_raw(self) -> u32 {
+        self.0
+    }
+}
+
+bitflags! {
+    /// `BPF_F_*` flags for use with [`map_create`].
+    #[repr(transparent)]
+    #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    pub struct MapFlags: u32 {
+        /// `BPF_F_NO_PREALLOC`
+        const NO_PREALLOC = 1 << 0;
+        /// `BPF_F_NO_COMMON_LRU`
+        const NO_COMMON_LRU = 1 << 1;
+        /// `BPF_F_RDONLY`
+        const RDONLY = 1 << 3;
+        /// `BPF_F_WRONLY`
+        const WRONLY = 1 << 4;
+        /// `BPF_F_RDONLY_PROG`
+        const RDONLY_PROG = 1 << 7;
+        /// `BPF_F_WRONLY_PROG`
+        const WRONLY_PROG = 1 << 8;
+        /// `BPF_F_MMAPABLE`
+        const MMAPABLE = 1 << 10;
+
+        /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+        const _ = !0;
+    }
+}
+
+bitflags! {
+    /// `BPF_*` flags for use with [`map_update_elem`].
+    ///
+    /// The empty set corresponds to `BPF_ANY`.
+    #[repr(transparent)]
+    #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    pub struct UpdateFlags: u64 {
+        /// `BPF_NOEXIST`—Only create a new element.
+        const NOEXIST = 1;
+        /// `BPF_EXIST`—Only update an existing element.
+        const EXIST = 2;
+        /// `BPF_F_LOCK`
+        const LOCK = 4;
+
+        /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+        const _ = !0;
+    }
+}
+
+/// The `BPF_MAP_CREATE` member of `union bpf_attr`.
+#[repr(C)]
+struct MapCreateAttr {
+    map_type: u32,
+    key_size: u32,
+    value_size: u32,
+    max_entries: u32,
+    map_flags: u32,
+}
+
+/// The `BPF_MAP_*_ELEM` member of `union bpf_attr`.
+#[repr(C)]
+struct MapElemAttr {
+    map_fd: u32,
+    _pad: u32,
+    key: u64,
+    value: u64,
+    flags: u64,
+}
+
+/// The `BPF_PROG_LOAD` member of `union bpf_attr`.
+#[repr(C)]
+struct ProgLoadAttr {
+    prog_type: u32,
+    insn_cnt: u32,
+    insns: u64,
+    license: u64,
+    log_level: u32,
+    log_size: u32,
+    log_buf: u64,
+}
+
+/// `bpf(BPF_MAP_CREATE, ...)`—Creates an eBPF map.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/bpf.2.html
+#[inline]
+#[doc(alias = "BPF_MAP_CREATE")]
+pub fn map_create(
+    map_type: MapType,
+    key_size: u32,
+    value_size: u32,
+    max_entries: u32,
+    flags: MapFlags,
+) -> io::Result<OwnedFd> {
+    let mut attr = MapCreateAttr {
+        map_type: map_type.0,
+        key_size,
+        value_size,
+        max_entries,
+        map_flags: flags.bits(),
+    };
+    unsafe { bpf_fd(BPF_MAP_CREATE, &mut attr) }
+}
+
+/// `bpf(BPF_MAP_LOOKUP_ELEM, ...)`—Looks up an element in an eBPF map.
+///
+/// # Safety
+///
+/// `key` must be at least as long as the map's key size, and `value` must be
+/// at least as long as the map's value size. For per-CPU maps, the value
+/// size is multiplied by the number of possible CPUs, rounded up to 8 bytes
+/// per CPU.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/bpf.2.html
+#[inline]
+#[doc(alias = "BPF_MAP_LOOKUP_ELEM")]
+pub unsafe fn map_lookup_elem<Fd: AsFd>(map: Fd, key: &[u8], value: &mut [u8]) -> io::Result<()> {
+    let mut attr = MapElemAttr {
+        map_fd: map.as_fd().as_raw_fd() as u32,
+        _pad: 0,
+        key: key.as_ptr() as usize as u64,
+        value: value.as_mut_ptr() as usize as u64,
+        flags: 0,
+    };
+    bpf(BPF_MAP_LOOKUP_ELEM, &mut attr)
+}
+
+/// `bpf(BPF_MAP_UPDATE_ELEM, ...)`—Creates or updates an element in an eBPF
+/// map.
+///
+/// # Safety
+///
+/// `key` must be at least as long as the map's key size, and `value` must be
+/// at least as long as the map's value size.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/bpf.2.html
+#[inline]
+#[doc(alias = "BPF_MAP_UPDATE_ELEM")]
+pub unsafe fn map_update_elem<Fd: AsFd>(
+    map: Fd,
+    key: &[u8],
+    value: &[u8],
+    flags: UpdateFlags,
+) -> io::Result<()> {
+    let mut attr = MapElemAttr {
+        map_fd: map.as_fd().as_raw_fd() as u32,
+        _pad: 0,
+        key: key.as_ptr() as usize as u64,
+        value: value.as_ptr() as usize as u64,
+        flags: flags.bits(),
+    };
+    bpf(BPF_MAP_UPDATE_ELEM, &mut attr)
+}
+
+/// `bpf(BPF_MAP_DELETE_ELEM, ...)`—Deletes an element from an eBPF map.
+///
+/// # Safety
+///
+/// `key` must be at least as long as the map's key size.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/bpf.2.html
+#[inline]
+#[doc(alias = "BPF_MAP_DELETE_ELEM")]
+pub unsafe fn map_delete_elem<Fd: AsFd>(map: Fd, key: &[u8]) -> io::Result<()> {
+    let mut attr = MapElemAttr {
+        map_fd: map.as_fd().as_raw_fd() as u32,
+        _pad: 0,
+        key: key.as_ptr() as usize as u64,
+        value: 0,
+        flags: 0,
+    };
+    bpf(BPF_MAP_DELETE_ELEM, &mut attr)
+}
+
+/// `bpf(BPF_MAP_GET_NEXT_KEY, ...)`—Finds the key following `key` in an eBPF
+/// map.
+///
+/// If `key` is `None`, this finds the first key. When `key` is the last key
+/// in the map, this fails with [`io::Errno::NOENT`].
+///
+/// # Safety
+///
+/// `key`, if present, and `next_key` must both be at least as long as the
+/// map's key size.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/bpf.2.html
+#[inline]
+#[doc(alias = "BPF_MAP_GET_NEXT_KEY")]
+pub unsafe fn map_get_next_key<Fd: AsFd>(
+    map: Fd,
+    key: Option<&[u8]>,
+    next_key: &mut [u8],
+) -> io::Result<()> {
+    let mut attr = MapElemAttr {
+        map_fd: map.as_fd().as_raw_fd() as u32,
+        _pad: 0,
+        key: key.map_or(0, |key| key.as_ptr() as usize as u64),
+        value: next_key.as_mut_ptr() as usize as u64,
+        flags: 0,
+    };
+    bpf(BPF_MAP_GET_NEXT_KEY, &mut attr)
+}
+
+/// `bpf(BPF_PROG_LOAD, ...)`—Verifies and loads an eBPF program.
+///
+/// `insns` holds the raw `struct bpf_insn` encoding of the program, so its
+/// length must be a multiple of 8. If `log` is `Some`, the verifier writes
+/// its log into it, which is especially useful when loading fails.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/bpf.2.html
+#[doc(alias = "BPF_PROG_LOAD")]
+pub fn prog_load(
+    prog_type: ProgType,
+    insns: &[u8],
+    license: &CStr,
+    log: Option<&mut [u8]>,
+) -> io::Result<OwnedFd> {
+    if insns.len() % INSN_SIZE != 0 {
+        return Err(io::Errno::INVAL);
+    }
+    let insn_cnt = u32::try_from(insns.len() / INSN_SIZE).map_err(|_| io::Errno::INVAL)?;
+
+    let (log_level, log_size, log_buf) = match log {
+        Some(log) => (
+            1,
+            u32::try_from(log.len()).unwrap_or(u32::MAX),
+            log.as_mut_ptr() as usize as u64,
+        ),
+        None => (0, 0, 0),
+    };
+
+    let mut attr = ProgLoadAttr {
+        prog_type: prog_type.0,
+        insn_cnt,
+        insns: insns.as_ptr() as usize as u64,
+        license: license.as_ptr() as usize as u64,
+        log_level,
+        log_size,
+        log_buf,
+    };
+    unsafe { bpf_fd(BPF_PROG_LOAD, &mut attr) }
+}
+
+#[inline]
+unsafe fn bpf<T>(cmd: c_uint, attr: &mut T) -> io::Result<()> {
+    syscalls::bpf(
+        cmd,
+        (attr as *mut T).cast::<c_void>(),
+        size_of::<T>() as c_uint,
+    )
+    .map(|_| ())
+}
+
+#[inline]
+unsafe fn bpf_fd<T>(cmd: c_uint, attr: &mut T) -> io::Result<OwnedFd> {
+    syscalls::bpf_fd(
+        cmd,
+        (attr as *mut T).cast::<c_void>(),
+        size_of::<T>() as c_uint,
+    )
+}
@@ -0,0 +1,433 @@
+//! Linux device-mapper functions.
+//!
+//! The functions in this module take a file descriptor opened from
+//! `/dev/mapper/control`, and identify the mapped device to act on by name.
+//! A device is created with [`ioctl_dm_dev_create`], given a table of targets
+//! with [`ioctl_dm_table_load`], and activated with [`ioctl_dm_dev_resume`].
+//!
+//! # References
+//!  - [Linux]
+//!
+//! [Linux]: https://docs.kernel.org/admin-guide/device-mapper/index.html
+
+#![allow(unsafe_code)]
+
+#[cfg(feature = "alloc")]
+use crate::alloc::vec;
+use crate::fd::AsFd;
+#[cfg(feature = "alloc")]
+use crate::ioctl::CompileTimeOpcode;
+use crate::{io, ioctl};
+use bitflags::bitflags;
+use core::mem::size_of;
+
+/// `DM_VERSION_MAJOR`—The version of the interface these functions use.
+const DM_VERSION_MAJOR: u32 = 4;
+
+/// `DM_NAME_LEN`
+const DM_NAME_LEN: usize = 128;
+
+/// `DM_UUID_LEN`
+const DM_UUID_LEN: usize = 129;
+
+/// `DM_MAX_TYPE_NAME`
+#[cfg(feature = "alloc")]
+const DM_MAX_TYPE_NAME: usize = 16;
+
+bitflags! {
+    /// `DM_*_FLAG` constants for use with the functions in this module.
+    #[repr(transparent)]
+    #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    pub struct DmFlags: u32 {
+        /// `DM_READONLY_FLAG`—The device or table is read-only.
+        const READONLY = 1 << 0;
+        /// `DM_SUSPEND_FLAG`—The device is suspended.
+        const SUSPEND = 1 << 1;
+        /// `DM_PERSISTENT_DEV_FLAG`—Use the device number given when
+        /// creating the device.
+        const PERSISTENT_DEV = 1 << 3;
+        /// `DM_ACTIVE_PRESENT_FLAG`—The device has an active table.
+        const ACTIVE_PRESENT = 1 << 5;
+        /// `DM_INACTIVE_PRESENT_FLAG`—The device has a loaded table that
+        /// isn't active yet.
+        const INACTIVE_PRESENT = 1 << 6;
+        /// `DM_SKIP_LOCKFS_FLAG`—Don't freeze the filesystem when
+        /// suspending.
+        const SKIP_LOCKFS = 1 << 10;
+        /// `DM_NOFLUSH_FLAG`—Don't flush outstanding I/O when suspending.
+        const NOFLUSH = 1 << 11;
+        /// `DM_UEVENT_GENERATED_FLAG`—A uevent was generated.
+        const UEVENT_GENERATED = 1 << 13;
+        /// `DM_SECURE_DATA_FLAG`—Wipe buffers that may hold key material.
+        const SECURE_DATA = 1 << 15;
+        /// `DM_DEFERRED_REMOVE`—Remove the device when it is last closed,
+        /// if it is open now.
+        const DEFERRED_REMOVE = 1 << 17;
+
+        /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+        const _ = !0;
+    }
+}
+
+/// Information about a mapped device, returned by most functions in this
+/// module.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct DmInfo {
+    /// The device number of the mapped device.
+    pub dev: u64,
+
+    /// The number of times the device is open.
+    pub open_count: i32,
+
+    /// The number of targets in the device's table.
+    pub target_count: u32,
+
+    /// The device's current event number.
+    pub event_nr: u32,
+
+    /// The device's status flags.
+    pub flags: DmFlags,
+}
+
+/// A target for [`ioctl_dm_table_load`], mapping a range of sectors of the
+/// device.
+#[cfg(feature = "alloc")]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct DmTarget<'a> {
+    /// The first sector of the range, in 512-byte sectors.
+    pub sector_start: u64,
+
+    /// The length of the range, in 512-byte sectors.
+    pub length: u64,
+
+    /// The target type, such as `"linear"`, `"zero"`, or `"verity"`.
+    pub target_type: &'a str,
+
+    /// The target-specific parameters.
+    pub params: &'a str,
+}
+
+/// `struct dm_ioctl`
+#[repr(C)]
+struct DmIoctl {
+    version: [u32; 3],
+    data_size: u32,
+    data_start: u32,
+    target_count: u32,
+    open_count: i32,
+    flags: u32,
+    event_nr: u32,
+    padding: u32,
+    dev: u64,
+    name: [u8; DM_NAME_LEN],
+    uuid: [u8; DM_UUID_LEN],
+    data: [u8; 7],
+}
+
+/// `struct dm_target_spec`
+#[cfg(feature = "alloc")]
+#[repr(C)]
+struct DmTargetSpec {
+    sector_start: u64,
+    length: u64,
+    status: i32,
+    next: u32,
+    target_type: [u8; DM_MAX_TYPE_NAME],
+}
+
+impl DmIoctl {
+    fn new(name: &str, flags: DmFlags) -> io::Result<Self> {
+        let mut dmi = Self {
+            version: [DM_VERSION_MAJOR, 0, 0],
+            data_size: size_of::<Self>() as u32,
+            data_start: size_of::<Self>() as u32,
+            target_count: 0,
+            open_count: 0,
+            flags: flags.bits(),
+            event_nr: 0,
+            padding: 0,
+            dev: 0,
+            name: [0; DM_NAME_LEN],
+            uuid: [0; DM_UUID_LEN],
+            data: [0; 7],
+        };
+        copy_name(&mut dmi.name, name)?;
+        Ok(dmi)
+    }
+
+    fn info(&self) -> DmInfo {
+        DmInfo {
+            dev: self.dev,
+            open_count: self.open_count,
+            target_count: self.target_count,
+            event_nr: self.event_nr,
+            flags: DmFlags::from_bits_retain(self.flags),
+        }
+    }
+}
+
+/// `_IOWR(0xfd, NUM, struct dm_ioctl)`—The encoding of the device-mapper
+/// opcodes.
+type DmOpcode<const NUM: u8> = ioctl::ReadWriteOpcode<0xfd, NUM, DmIoctl>;
+
+const VERSION: u8 = 0;
+const DEV_CREATE: u8 = 3;
+const DEV_REMOVE: u8 = 4;
+const DEV_SUSPEND: u8 = 6;
+const DEV_STATUS: u8 = 7;
+#[cfg(feature = "alloc")]
+const TABLE_LOAD: u8 = 9;
+
+/// Copies `src` into the NUL-terminated field `dst`, failing with `INVAL` if
+/// it doesn't fit or contains a NUL byte.
+fn copy_name(dst: &mut [u8], src: &str) -> io::Result<()> {
+    let src = src.as_bytes();
+    if src.len() >= dst.len() || src.contains(&0) {
+        return Err(io::Errno::INVAL);
+    }
+    dst[..src.len()].copy_from_slice(src);
+    Ok(())
+}
+
+/// Performs a device-mapper `ioctl` that takes and returns a bare
+/// `struct dm_ioctl`.
+///
+/// # Safety
+///
+/// `NUM` must be a device-mapper command that takes no data after the
+/// `struct dm_ioctl`.
+unsafe fn dm_ioctl<const NUM: u8, Fd: AsFd>(fd: Fd, dmi: &mut DmIoctl) -> io::Result<()> {
+    let ctl = ioctl::Updater::<DmOpcode<NUM>, DmIoctl>::new(dmi);
+    ioctl::ioctl(fd, ctl)
+}
+
+/// `ioctl(fd, DM_VERSION)`—Returns the version of the device-mapper
+/// interface, as major, minor, and patch numbers.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/admin-guide/device-mapper/index.html
+#[inline]
+#[doc(alias = "DM_VERSION")]
+pub fn ioctl_dm_version<Fd: AsFd>(fd: Fd) -> io::Result<(u32, u32, u32)> {
+    let mut dmi = DmIoctl::new("", DmFlags::empty())?;
+    // SAFETY: `DM_VERSION` takes no data.
+    unsafe { dm_ioctl::<VERSION, _>(fd, &mut dmi)? };
+    Ok((dmi.version[0], dmi.version[1], dmi.version[2]))
+}
+
+/// `ioctl(fd, DM_DEV_CREATE)`—Creates a mapped device, with no table.
+///
+/// `name` must be shorter than 128 bytes, and `uuid`, if given, shorter than
+/// 129 bytes.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/admin-guide/device-mapper/index.html
+#[inline]
+#[doc(alias = "DM_DEV_CREATE")]
+pub fn ioctl_dm_dev_create<Fd: AsFd>(
+    fd: Fd,
+    name: &str,
+    uuid: Option<&str>,
+    flags: DmFlags,
+) -> io::Result<DmInfo> {
+    let mut dmi = DmIoctl::new(name, flags)?;
+    if let Some(uuid) = uuid {
+        copy_name(&mut dmi.uuid, uuid)?;
+    }
+    // SAFETY: `DM_DEV_CREATE` takes no data.
+    unsafe { dm_ioctl::<DEV_CREATE, _>(fd, &mut dmi)? };
+    Ok(dmi.info())
+}
+
+/// `ioctl(fd, DM_DEV_REMOVE)`—Removes a mapped device.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/admin-guide/device-mapper/index.html
+#[inline]
+#[doc(alias = "DM_DEV_REMOVE")]
+pub fn ioctl_dm_dev_remove<Fd: AsFd>(fd: Fd, name: &str, flags: DmFlags) -> io::Result<DmInfo> {
+    let mut dmi = DmIoctl::new(name, flags)?;
+    // SAFETY: `DM_DEV_REMOVE` takes no data.
+    unsafe { dm_ioctl::<DEV_REMOVE, _>(fd, &mut dmi)? };
+    Ok(dmi.info())
+}
+
+/// `ioctl(fd, DM_DEV_SUSPEND)` with `DM_SUSPEND_FLAG`—Suspends a mapped
+/// device, holding back new I/O.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/admin-guide/device-mapper/index.html
+#[inline]
+#[doc(alias = "DM_DEV_SUSPEND")]
+pub fn ioctl_dm_dev_suspend<Fd: AsFd>(fd: Fd, name: &str, flags: DmFlags) -> io::Result<DmInfo> {
+    let mut dmi = DmIoctl::new(name, flags | DmFlags::SUSPEND)?;
+    // SAFETY: `DM_DEV_SUSPEND` takes no data.
+    unsafe { dm_ioctl::<DEV_SUSPEND, _>(fd, &mut dmi)? };
+    Ok(dmi.info())
+}
+
+/// `ioctl(fd, DM_DEV_SUSPEND)` without `DM_SUSPEND_FLAG`—Resumes a mapped
+/// device, making a table loaded with [`ioctl_dm_table_load`] active.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/admin-guide/device-mapper/index.html
+#[inline]
+#[doc(alias = "DM_DEV_SUSPEND")]
+pub fn ioctl_dm_dev_resume<Fd: AsFd>(fd: Fd, name: &str, flags: DmFlags) -> io::Result<DmInfo> {
+    let mut dmi = DmIoctl::new(name, flags - DmFlags::SUSPEND)?;
+    // SAFETY: `DM_DEV_SUSPEND` takes no data.
+    unsafe { dm_ioctl::<DEV_SUSPEND, _>(fd, &mut dmi)? };
+    Ok(dmi.info())
+}
+
+/// `ioctl(fd, DM_DEV_STATUS)`—Returns information about a mapped device.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/admin-guide/device-mapper/index.html
+#[inline]
+#[doc(alias = "DM_DEV_STATUS")]
+pub fn ioctl_dm_dev_status<Fd: AsFd>(fd: Fd, name: &str) -> io::Result<DmInfo> {
+    let mut dmi = DmIoctl::new(name, DmFlags::empty())?;
+    // SAFETY: `DM_DEV_STATUS` takes no data.
+    unsafe { dm_ioctl::<DEV_STATUS, _>(fd, &mut dmi)? };
+    Ok(dmi.info())
+}
+
+/// `ioctl(fd, DM_TABLE_LOAD)`—Loads a table of targets into a mapped
+/// device.
+///
+/// The table becomes active when the device is resumed with
+/// [`ioctl_dm_dev_resume`]. Target types must be shorter than 16 bytes, and
+/// neither target types nor parameters may contain NUL bytes.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/admin-guide/device-mapper/index.html
+#[cfg(feature = "alloc")]
+#[inline]
+#[doc(alias = "DM_TABLE_LOAD")]
+pub fn ioctl_dm_table_load<Fd: AsFd>(
+    fd: Fd,
+    name: &str,
+    targets: &[DmTarget<'_>],
+    flags: DmFlags,
+) -> io::Result<DmInfo> {
+    // Each target spec is followed by its NUL-terminated parameters, padded
+    // so that the next spec is 8-byte aligned.
+    let spec_len =
+        |target: &DmTarget<'_>| (size_of::<DmTargetSpec>() + target.params.len() + 1 + 7) & !7;
+    let size = size_of::<DmIoctl>() + targets.iter().map(spec_len).sum::<usize>();
+    let size = u32::try_from(size).map_err(|_| io::Errno::INVAL)?;
+
+    let mut dmi = DmIoctl::new(name, flags)?;
+    dmi.data_size = size;
+    dmi.target_count = targets.len() as u32;
+
+    // Use a `u64` buffer so that the structs in it are aligned.
+    let mut buf = vec![0_u64; size as usize / 8];
+    let base = buf.as_mut_ptr().cast::<u8>();
+    let mut offset = size_of::<DmIoctl>();
+    for target in targets {
+        if target.params.as_bytes().contains(&0) {
+            return Err(io::Errno::INVAL);
+        }
+        let mut spec = DmTargetSpec {
+            sector_start: target.sector_start,
+            length: target.length,
+            status: 0,
+            next: spec_len(target) as u32,
+            target_type: [0; DM_MAX_TYPE_NAME],
+        };
+        copy_name(&mut spec.target_type, target.target_type)?;
+
+        // SAFETY: `buf` is sized to hold the header and each spec with its
+        // parameters, and `offset` is 8-byte aligned.
+        unsafe {
+            base.add(offset).cast::<DmTargetSpec>().write(spec);
+            base.add(offset + size_of::<DmTargetSpec>())
+                .copy_from_nonoverlapping(target.params.as_ptr(), target.params.len());
+        }
+        offset += spec_len(target);
+    }
+
+    // SAFETY: `buf` is large enough and aligned for a `struct dm_ioctl`, and
+    // `DM_TABLE_LOAD` reads the target specs that follow it and writes back
+    // the `struct dm_ioctl`.
+    unsafe {
+        base.cast::<DmIoctl>().write(dmi);
+        ioctl::ioctl_with_opcode(fd.as_fd(), DmOpcode::<TABLE_LOAD>::OPCODE, base.cast())?;
+        Ok((*base.cast::<DmIoctl>()).info())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ioctl::{CompileTimeOpcode, RawOpcode};
+
+    #[cfg(not(any(
+        // These have no ioctl opcodes defined in linux_raw_sys.
+        target_arch = "sparc",
+        target_arch = "sparc64"
+    )))]
+    #[test]
+    fn dm_opcodes() {
+        use linux_raw_sys::ioctl as sys;
+
+        const_assert_eq!(
+            DmOpcode::<VERSION>::OPCODE.raw(),
+            sys::DM_VERSION as RawOpcode
+        );
+        const_assert_eq!(
+            DmOpcode::<DEV_CREATE>::OPCODE.raw(),
+            sys::DM_DEV_CREATE as RawOpcode
+        );
+        const_assert_eq!(
+            DmOpcode::<DEV_REMOVE>::OPCODE.raw(),
+            sys::DM_DEV_REMOVE as RawOpcode
+        );
+        const_assert_eq!(
+            DmOpcode::<DEV_SUSPEND>::OPCODE.raw(),
+            sys::DM_DEV_SUSPEND as RawOpcode
+        );
+        const_assert_eq!(
+            DmOpcode::<DEV_STATUS>::OPCODE.raw(),
+            sys::DM_DEV_STATUS as RawOpcode
+        );
+        #[cfg(feature = "alloc")]
+        const_assert_eq!(
+            DmOpcode::<TABLE_LOAD>::OPCODE.raw(),
+            sys::DM_TABLE_LOAD as RawOpcode
+        );
+    }
+
+    #[test]
+    fn dm_layouts() {
+        // struct dm_ioctl
+        assert_eq_size!(DmIoctl, [u64; 39]);
+        assert_eq_align!(DmIoctl, u64);
+        const_assert_eq!(memoffset::offset_of!(DmIoctl, dev), 40);
+        const_assert_eq!(memoffset::offset_of!(DmIoctl, name), 48);
+        const_assert_eq!(memoffset::offset_of!(DmIoctl, data), 305);
+
+        // struct dm_target_spec
+        #[cfg(feature = "alloc")]
+        {
+            assert_eq_size!(DmTargetSpec, [u64; 5]);
+            assert_eq_align!(DmTargetSpec, u64);
+        }
+    }
+}
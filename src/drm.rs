@@ -0,0 +1,163 @@
+//! Linux Direct Rendering Manager master management.
+//!
+//! The functions in this module take a file descriptor opened from a DRM
+//! device node such as `/dev/dri/card0`. They cover only DRM master
+//! management and authentication, which is what display servers need in
+//! order to hand off and share devices; mode setting and buffer management
+//! are left to other libraries.
+//!
+//! # References
+//!  - [Linux]
+//!
+//! [Linux]: https://docs.kernel.org/gpu/drm-uapi.html#primary-nodes-drm-master-and-authentication
+
+#![allow(unsafe_code)]
+
+use crate::backend::c;
+use crate::fd::AsFd;
+use crate::{io, ioctl};
+
+/// `drm_magic_t`—An authentication token for a DRM client.
+///
+/// A client gets its token with [`ioctl_get_magic`] and passes it to the
+/// DRM master, which authenticates it with [`ioctl_auth_magic`].
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Magic(c::c_uint);
+
+impl Magic {
+    /// Constructs a `Magic` from a raw `drm_magic_t` value.
+    #[inline]
+    pub const fn from_raw(raw: u32) -> Self {
+        Self(raw)
+    }
+
+    /// Returns the raw `drm_magic_t` value.
+    #[inline]
+    pub const fn as_raw(self) -> u32 {
+        self.0
+    }
+}
+
+/// `struct drm_auth`
+#[repr(C)]
+struct DrmAuth {
+    magic: c::c_uint,
+}
+
+/// `DRM_IOCTL_SET_MASTER`
+type SetMaster = ioctl::NoneOpcode<b'd', 0x1e, ()>;
+/// `DRM_IOCTL_DROP_MASTER`
+type DropMaster = ioctl::NoneOpcode<b'd', 0x1f, ()>;
+/// `DRM_IOCTL_GET_MAGIC`
+type GetMagic = ioctl::ReadOpcode<b'd', 0x02, DrmAuth>;
+/// `DRM_IOCTL_AUTH_MAGIC`
+type AuthMagic = ioctl::WriteOpcode<b'd', 0x11, DrmAuth>;
+
+/// `ioctl(fd, DRM_IOCTL_SET_MASTER)`—Becomes the DRM master for a device.
+///
+/// This requires `CAP_SYS_ADMIN`, or that the caller was the first to open
+/// the device or previously dropped master status.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/gpu/drm-uapi.html#primary-nodes-drm-master-and-authentication
+#[inline]
+#[doc(alias = "DRM_IOCTL_SET_MASTER")]
+pub fn ioctl_set_master<Fd: AsFd>(fd: Fd) -> io::Result<()> {
+    // SAFETY: `DRM_IOCTL_SET_MASTER` is a no-argument opcode.
+    unsafe {
+        let ctl = ioctl::NoArg::<SetMaster>::new();
+        ioctl::ioctl(fd, ctl)
+    }
+}
+
+/// `ioctl(fd, DRM_IOCTL_DROP_MASTER)`—Gives up DRM master status for a
+/// device.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/gpu/drm-uapi.html#primary-nodes-drm-master-and-authentication
+#[inline]
+#[doc(alias = "DRM_IOCTL_DROP_MASTER")]
+pub fn ioctl_drop_master<Fd: AsFd>(fd: Fd) -> io::Result<()> {
+    // SAFETY: `DRM_IOCTL_DROP_MASTER` is a no-argument opcode.
+    unsafe {
+        let ctl = ioctl::NoArg::<DropMaster>::new();
+        ioctl::ioctl(fd, ctl)
+    }
+}
+
+/// `ioctl(fd, DRM_IOCTL_GET_MAGIC)`—Returns the authentication token for
+/// this client.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/gpu/drm-uapi.html#primary-nodes-drm-master-and-authentication
+#[inline]
+#[doc(alias = "DRM_IOCTL_GET_MAGIC")]
+pub fn ioctl_get_magic<Fd: AsFd>(fd: Fd) -> io::Result<Magic> {
+    // SAFETY: `DRM_IOCTL_GET_MAGIC` is a getter opcode that gets a
+    // `struct drm_auth`.
+    unsafe {
+        let ctl = ioctl::Getter::<GetMagic, DrmAuth>::new();
+        ioctl::ioctl(fd, ctl).map(|auth| Magic(auth.magic))
+    }
+}
+
+/// `ioctl(fd, DRM_IOCTL_AUTH_MAGIC, &auth)`—Authenticates a client, given the
+/// token it got from [`ioctl_get_magic`].
+///
+/// This must be called on a file descriptor that is the DRM master.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/gpu/drm-uapi.html#primary-nodes-drm-master-and-authentication
+#[inline]
+#[doc(alias = "DRM_IOCTL_AUTH_MAGIC")]
+pub fn ioctl_auth_magic<Fd: AsFd>(fd: Fd, magic: Magic) -> io::Result<()> {
+    // SAFETY: `DRM_IOCTL_AUTH_MAGIC` is a pointer setter opcode that sets a
+    // `struct drm_auth`.
+    unsafe {
+        let ctl = ioctl::Setter::<AuthMagic, DrmAuth>::new(DrmAuth { magic: magic.0 });
+        ioctl::ioctl(fd, ctl)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ioctl::CompileTimeOpcode;
+
+    // `linux_raw_sys` doesn't have the DRM opcodes, so these are the values
+    // of the uapi macros with the generic `_IOC` encoding.
+    #[cfg(any(
+        target_arch = "aarch64",
+        target_arch = "arm",
+        target_arch = "riscv64",
+        target_arch = "x86",
+        target_arch = "x86_64",
+    ))]
+    #[test]
+    fn drm_opcodes() {
+        // _IO('d', 0x1e)
+        const_assert_eq!(SetMaster::OPCODE.raw(), 0x641e);
+        // _IO('d', 0x1f)
+        const_assert_eq!(DropMaster::OPCODE.raw(), 0x641f);
+        // _IOR('d', 0x02, struct drm_auth)
+        const_assert_eq!(GetMagic::OPCODE.raw(), 0x8004_6402);
+        // _IOW('d', 0x11, struct drm_auth)
+        const_assert_eq!(AuthMagic::OPCODE.raw(), 0x4004_6411);
+    }
+
+    #[test]
+    fn drm_layouts() {
+        assert_eq_size!(DrmAuth, u32);
+        assert_eq_align!(DrmAuth, u32);
+        assert_eq_size!(Magic, u32);
+    }
+}
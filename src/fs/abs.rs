@@ -3,11 +3,12 @@
 use crate::fd::OwnedFd;
 #[cfg(not(any(target_os = "espidf", target_os = "vita")))]
 use crate::fs::Access;
+#[cfg(bsd)]
+use crate::fs::FileFlags;
 #[cfg(not(any(
     solarish,
     target_os = "espidf",
     target_os = "haiku",
-    target_os = "netbsd",
     target_os = "nto",
     target_os = "redox",
     target_os = "vita",
@@ -229,6 +230,19 @@ pub fn mkdir<P: path::Arg>(path: P, mode: Mode) -> io::Result<()> {
     path.into_with_c_str(|path| backend::fs::syscalls::mkdir(path, mode))
 }
 
+/// `truncate(path, length)`—Sets the length of a file.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/truncate.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/truncate.2.html
+#[inline]
+pub fn truncate<P: path::Arg>(path: P, length: u64) -> io::Result<()> {
+    path.into_with_c_str(|path| backend::fs::syscalls::truncate(path, length))
+}
+
 /// `access(path, access)`—Tests permissions for a file or directory.
 ///
 /// # References
@@ -248,15 +262,19 @@ pub fn access<P: path::Arg>(path: P, access: Access) -> io::Result<()> {
 /// Compared to [`statvfs`], this function often provides more information,
 /// though it's less portable.
 ///
+/// NetBSD has no `statfs`, so there this calls `statvfs` and returns its
+/// `struct statvfs`, which holds the same information.
+///
 /// # References
 ///  - [Linux]
+///  - [NetBSD]
 ///
 /// [Linux]: https://man7.org/linux/man-pages/man2/statfs.2.html
+/// [NetBSD]: https://man.netbsd.org/statvfs.2
 #[cfg(not(any(
     solarish,
     target_os = "espidf",
     target_os = "haiku",
-    target_os = "netbsd",
     target_os = "nto",
     target_os = "redox",
     target_os = "vita",
@@ -277,9 +295,11 @@ pub fn statfs<P: path::Arg>(path: P) -> io::Result<StatFs> {
 /// # References
 ///  - [POSIX]
 ///  - [Linux]
+///  - [NetBSD]
 ///
 /// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/statvfs.html
 /// [Linux]: https://man7.org/linux/man-pages/man2/statvfs.2.html
+/// [NetBSD]: https://man.netbsd.org/statvfs.2
 #[cfg(not(any(target_os = "haiku", target_os = "redox", target_os = "wasi")))]
 #[inline]
 pub fn statvfs<P: path::Arg>(path: P) -> io::Result<StatVfs> {
@@ -288,6 +308,10 @@ pub fn statvfs<P: path::Arg>(path: P) -> io::Result<StatVfs> {
 
 /// `chown(path, owner, group)`—Sets open file or directory ownership.
 ///
+/// On Linux, changing the owner, or changing the group to one the caller
+/// isn't a member of, requires the `CAP_CHOWN` capability, and fails with
+/// [`io::Errno::PERM`] without it.
+///
 /// # References
 ///  - [POSIX]
 ///  - [Linux]
@@ -299,3 +323,48 @@ pub fn statvfs<P: path::Arg>(path: P) -> io::Result<StatVfs> {
 pub fn chown<P: path::Arg>(path: P, owner: Option<Uid>, group: Option<Gid>) -> io::Result<()> {
     path.into_with_c_str(|path| backend::fs::syscalls::chown(path, owner, group))
 }
+
+/// `chflags(path, flags)`—Sets BSD file flags.
+///
+/// This replaces all of the file's flags, so to set or clear individual
+/// flags, read the current flags from the `st_flags` field of [`stat`] first.
+///
+/// # References
+///  - [Apple]
+///  - [FreeBSD]
+///  - [NetBSD]
+///  - [OpenBSD]
+///  - [DragonFly BSD]
+///
+/// [Apple]: https://developer.apple.com/library/archive/documentation/System/Conceptual/ManPages_iPhoneOS/man2/chflags.2.html
+/// [FreeBSD]: https://man.freebsd.org/cgi/man.cgi?query=chflags&sektion=2
+/// [NetBSD]: https://man.netbsd.org/chflags.2
+/// [OpenBSD]: https://man.openbsd.org/chflags.2
+/// [DragonFly BSD]: https://man.dragonflybsd.org/?command=chflags&section=2
+#[cfg(bsd)]
+#[inline]
+pub fn chflags<P: path::Arg>(path: P, flags: FileFlags) -> io::Result<()> {
+    path.into_with_c_str(|path| backend::fs::syscalls::chflags(path, flags))
+}
+
+/// `lchflags(path, flags)`—Sets BSD file flags, without following symlinks.
+///
+/// On OpenBSD, use [`chflagsat`] with [`AtFlags::SYMLINK_NOFOLLOW`] instead.
+///
+/// # References
+///  - [Apple]
+///  - [FreeBSD]
+///  - [NetBSD]
+///  - [DragonFly BSD]
+///
+/// [`chflagsat`]: crate::fs::chflagsat
+/// [`AtFlags::SYMLINK_NOFOLLOW`]: crate::fs::AtFlags::SYMLINK_NOFOLLOW
+/// [Apple]: https://developer.apple.com/library/archive/documentation/System/Conceptual/ManPages_iPhoneOS/man2/chflags.2.html
+/// [FreeBSD]: https://man.freebsd.org/cgi/man.cgi?query=lchflags&sektion=2
+/// [NetBSD]: https://man.netbsd.org/lchflags.2
+/// [DragonFly BSD]: https://man.dragonflybsd.org/?command=lchflags&section=2
+#[cfg(any(apple, freebsdlike, target_os = "netbsd"))]
+#[inline]
+pub fn lchflags<P: path::Arg>(path: P, flags: FileFlags) -> io::Result<()> {
+    path.into_with_c_str(|path| backend::fs::syscalls::lchflags(path, flags))
+}
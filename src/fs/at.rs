@@ -13,15 +13,21 @@ use crate::fs::Access;
 use crate::fs::AtFlags;
 #[cfg(apple)]
 use crate::fs::CloneFlags;
+#[cfg(any(freebsdlike, target_os = "openbsd"))]
+use crate::fs::FileFlags;
 #[cfg(linux_kernel)]
 use crate::fs::RenameFlags;
 #[cfg(not(target_os = "espidf"))]
 use crate::fs::Stat;
+#[cfg(not(any(target_os = "espidf", target_os = "redox", target_os = "vita")))]
+use crate::fs::Timespec;
 #[cfg(not(any(apple, target_os = "espidf", target_os = "vita", target_os = "wasi")))]
 use crate::fs::{Dev, FileType};
 #[cfg(not(any(target_os = "espidf", target_os = "wasi")))]
 use crate::fs::{Gid, Uid};
 use crate::fs::{Mode, OFlags};
+#[cfg(all(linux_kernel, feature = "procfs"))]
+use crate::path::DecInt;
 use crate::{backend, io, path};
 use backend::fd::{AsFd, BorrowedFd};
 use core::mem::MaybeUninit;
@@ -43,6 +49,30 @@ pub const UTIME_NOW: Nsecs = backend::c::UTIME_NOW as Nsecs;
 #[cfg(not(any(target_os = "espidf", target_os = "redox", target_os = "vita")))]
 pub const UTIME_OMIT: Nsecs = backend::c::UTIME_OMIT as Nsecs;
 
+/// A [`Timespec`] holding [`UTIME_NOW`], which sets a timestamp to the
+/// current time when used with [`utimensat`] or [`futimens`].
+///
+/// [`utimensat`]: crate::fs::utimensat
+/// [`futimens`]: crate::fs::futimens
+#[cfg(not(any(target_os = "espidf", target_os = "redox", target_os = "vita")))]
+#[doc(alias = "UTIME_NOW")]
+pub const TIMESPEC_NOW: Timespec = Timespec {
+    tv_sec: 0,
+    tv_nsec: UTIME_NOW,
+};
+
+/// A [`Timespec`] holding [`UTIME_OMIT`], which leaves a timestamp unchanged
+/// when used with [`utimensat`] or [`futimens`].
+///
+/// [`utimensat`]: crate::fs::utimensat
+/// [`futimens`]: crate::fs::futimens
+#[cfg(not(any(target_os = "espidf", target_os = "redox", target_os = "vita")))]
+#[doc(alias = "UTIME_OMIT")]
+pub const TIMESPEC_OMIT: Timespec = Timespec {
+    tv_sec: 0,
+    tv_nsec: UTIME_OMIT,
+};
+
 /// `openat(dirfd, path, oflags, mode)`—Opens a file.
 ///
 /// POSIX guarantees that `openat` will use the lowest unused file descriptor,
@@ -190,6 +220,13 @@ pub fn mkdirat<P: path::Arg, Fd: AsFd>(dirfd: Fd, path: P, mode: Mode) -> io::Re
 /// `linkat(old_dirfd, old_path, new_dirfd, new_path, flags)`—Creates a hard
 /// link.
 ///
+/// On Linux, linking with [`AtFlags::EMPTY_PATH`] and an empty `old_path`
+/// requires the `CAP_DAC_READ_SEARCH` capability, and fails with
+/// [`io::Errno::NOENT`] without it. With the "procfs" feature enabled, this
+/// then retries by linking `/proc/self/fd/<old_dirfd>` with
+/// [`AtFlags::SYMLINK_FOLLOW`], which has the same effect and doesn't need
+/// the capability.
+///
 /// # References
 ///  - [POSIX]
 ///  - [Linux]
@@ -207,7 +244,7 @@ pub fn linkat<P: path::Arg, Q: path::Arg, PFd: AsFd, QFd: AsFd>(
 ) -> io::Result<()> {
     old_path.into_with_c_str(|old_path| {
         new_path.into_with_c_str(|new_path| {
-            backend::fs::syscalls::linkat(
+            _linkat(
                 old_dirfd.as_fd(),
                 old_path,
                 new_dirfd.as_fd(),
@@ -218,6 +255,82 @@ pub fn linkat<P: path::Arg, Q: path::Arg, PFd: AsFd, QFd: AsFd>(
     })
 }
 
+#[cfg(not(target_os = "espidf"))]
+#[cfg(not(all(linux_kernel, feature = "procfs")))]
+#[inline]
+fn _linkat(
+    old_dirfd: BorrowedFd<'_>,
+    old_path: &CStr,
+    new_dirfd: BorrowedFd<'_>,
+    new_path: &CStr,
+    flags: AtFlags,
+) -> io::Result<()> {
+    backend::fs::syscalls::linkat(old_dirfd, old_path, new_dirfd, new_path, flags)
+}
+
+#[cfg(all(linux_kernel, feature = "procfs"))]
+fn _linkat(
+    old_dirfd: BorrowedFd<'_>,
+    old_path: &CStr,
+    new_dirfd: BorrowedFd<'_>,
+    new_path: &CStr,
+    flags: AtFlags,
+) -> io::Result<()> {
+    match backend::fs::syscalls::linkat(old_dirfd, old_path, new_dirfd, new_path, flags) {
+        Err(io::Errno::NOENT)
+            if flags.contains(AtFlags::EMPTY_PATH) && old_path.to_bytes().is_empty() =>
+        {
+            // If procfs isn't available, report the original error.
+            let proc_self_fd = match crate::procfs::proc_self_fd() {
+                Ok(proc_self_fd) => proc_self_fd,
+                Err(_) => return Err(io::Errno::NOENT),
+            };
+            backend::fs::syscalls::linkat(
+                proc_self_fd,
+                DecInt::from_fd(old_dirfd).as_c_str(),
+                new_dirfd,
+                new_path,
+                (flags - AtFlags::EMPTY_PATH) | AtFlags::SYMLINK_FOLLOW,
+            )
+        }
+        otherwise => otherwise,
+    }
+}
+
+/// `linkat(fd, "", dirfd, path, AT_EMPTY_PATH)`—Gives a name to an open file.
+///
+/// This is meant for files opened with [`OFlags::TMPFILE`], which have no
+/// name until they're linked into a directory, so a file can be written
+/// completely before it appears at `path`. The file must not have been
+/// opened with [`OFlags::EXCL`]. See [`linkat`] for how this works without
+/// the `CAP_DAC_READ_SEARCH` capability.
+///
+/// Like any `linkat`, this fails with [`io::Errno::EXIST`] if `path` already
+/// exists, so it never replaces an existing file.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/open.2.html
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "O_TMPFILE")]
+pub fn link_tmpfile<P: path::Arg, Fd: AsFd, DirFd: AsFd>(
+    fd: Fd,
+    dirfd: DirFd,
+    path: P,
+) -> io::Result<()> {
+    path.into_with_c_str(|path| {
+        _linkat(
+            fd.as_fd(),
+            cstr!(""),
+            dirfd.as_fd(),
+            path,
+            AtFlags::EMPTY_PATH,
+        )
+    })
+}
+
 /// `unlinkat(fd, path, flags)`—Unlinks a file or remove a directory.
 ///
 /// With the [`REMOVEDIR`] flag, this removes a directory. This is in place of
@@ -267,6 +380,13 @@ pub fn renameat<P: path::Arg, Q: path::Arg, PFd: AsFd, QFd: AsFd>(
 /// `renameat2(old_dirfd, old_path, new_dirfd, new_path, flags)`—Renames a
 /// file or directory.
 ///
+/// With [`RenameFlags::EXCHANGE`], the two paths are swapped atomically,
+/// which allows replacing a file while keeping the old version available.
+/// With [`RenameFlags::NOREPLACE`], this fails with [`io::Errno::EXIST`]
+/// rather than replacing an existing file. Filesystems that don't support
+/// the requested flags fail with [`io::Errno::INVAL`], and kernels older than
+/// Linux 3.15 fail with [`io::Errno::NOSYS`].
+///
 /// # References
 ///  - [Linux]
 ///
@@ -447,9 +567,28 @@ pub fn mknodat<P: path::Arg, Fd: AsFd>(
     })
 }
 
+/// `mkfifoat(dirfd, path, mode)`—Creates a FIFO special file.
+///
+/// This is equivalent to [`mknodat`] with [`FileType::Fifo`] and a `dev` of
+/// 0.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/mkfifoat.html
+/// [Linux]: https://man7.org/linux/man-pages/man3/mkfifoat.3.html
+#[cfg(not(any(apple, target_os = "espidf", target_os = "vita", target_os = "wasi")))]
+#[inline]
+pub fn mkfifoat<P: path::Arg, Fd: AsFd>(dirfd: Fd, path: P, mode: Mode) -> io::Result<()> {
+    mknodat(dirfd, path, FileType::Fifo, mode, 0)
+}
+
 /// `fchownat(dirfd, path, owner, group, flags)`—Sets file or directory
 /// ownership.
 ///
+/// See [`fchown`][crate::fs::fchown] for the capability this needs on Linux.
+///
 /// # References
 ///  - [POSIX]
 ///  - [Linux]
@@ -470,3 +609,28 @@ pub fn chownat<P: path::Arg, Fd: AsFd>(
         backend::fs::syscalls::chownat(dirfd.as_fd(), path, owner, group, flags)
     })
 }
+
+/// `chflagsat(dirfd, path, flags, atflags)`—Sets BSD file flags.
+///
+/// See [`chflags`][crate::fs::chflags] for how `flags` is applied.
+///
+/// # References
+///  - [FreeBSD]
+///  - [OpenBSD]
+///  - [DragonFly BSD]
+///
+/// [FreeBSD]: https://man.freebsd.org/cgi/man.cgi?query=chflagsat&sektion=2
+/// [OpenBSD]: https://man.openbsd.org/chflagsat.2
+/// [DragonFly BSD]: https://man.dragonflybsd.org/?command=chflagsat&section=2
+#[cfg(any(freebsdlike, target_os = "openbsd"))]
+#[inline]
+pub fn chflagsat<P: path::Arg, Fd: AsFd>(
+    dirfd: Fd,
+    path: P,
+    flags: FileFlags,
+    atflags: AtFlags,
+) -> io::Result<()> {
+    path.into_with_c_str(|path| {
+        backend::fs::syscalls::chflagsat(dirfd.as_fd(), path, flags, atflags)
+    })
+}
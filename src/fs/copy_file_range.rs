@@ -4,6 +4,18 @@ use backend::fd::AsFd;
 /// `copy_file_range(fd_in, off_in, fd_out, off_out, len, 0)`—Copies data
 /// from one file to another.
 ///
+/// The copy happens within the kernel, without reading the data into
+/// userspace, and filesystems may implement it with a reflink or a
+/// server-side copy.
+///
+/// If `off_in` or `off_out` is `None`, the copy uses and advances the
+/// corresponding file's offset. Otherwise, it uses the given offset and
+/// advances that instead, leaving the file's offset unchanged.
+///
+/// This returns the number of bytes copied, which may be less than `len`, and
+/// is 0 at the end of `fd_in`. Before Linux 5.3, this fails with
+/// [`io::Errno::XDEV`] if the files are on different filesystems.
+///
 /// # References
 ///  - [Linux]
 ///
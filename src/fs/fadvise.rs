@@ -5,6 +5,16 @@ use backend::fs::types::Advice;
 /// `posix_fadvise(fd, offset, len, advice)`—Declares an expected access
 /// pattern for a file.
 ///
+/// A `len` of zero covers everything from `offset` to the end of the file.
+/// The advice is only a hint, and the kernel may ignore it.
+///
+/// To drop a range from the page cache while streaming through a large file,
+/// use [`Advice::DontNeed`] on the range after it has been read. Dirty pages
+/// aren't dropped, so after writing, flush the range first, for example with
+/// [`fdatasync`].
+///
+/// [`fdatasync`]: crate::fs::fdatasync
+///
 /// # References
 ///  - [POSIX]
 ///  - [Linux]
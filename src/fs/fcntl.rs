@@ -3,19 +3,15 @@
 //! API, rustix makes them all separate functions so that they can have
 //! dedicated static type signatures.
 
-#[cfg(not(any(
-    target_os = "emscripten",
-    target_os = "espidf",
-    target_os = "fuchsia",
-    target_os = "redox",
-    target_os = "vita",
-    target_os = "wasi"
-)))]
-use crate::fs::FlockOperation;
+#[cfg(fcntl_lock)]
+use crate::fs::{FlockOffsetType, FlockOperation, FlockType};
 use crate::{backend, io};
 use backend::fd::AsFd;
 use backend::fs::types::OFlags;
 
+#[cfg(fcntl_lock)]
+pub use crate::pid::Pid;
+
 // These `fcntl` functions live in the `io` module because they're not specific
 // to files, directories, or memfd objects. We re-export them here in the `fs`
 // module because the other the `fcntl` functions are here.
@@ -25,6 +21,10 @@ pub use crate::io::{fcntl_getfd, fcntl_setfd};
 
 /// `fcntl(fd, F_GETFL)`—Returns a file descriptor's access mode and status.
 ///
+/// The access mode is the part of the result within [`OFlags::RWMODE`]. The
+/// file descriptor flags, such as `FD_CLOEXEC`, are returned separately by
+/// [`fcntl_getfd`].
+///
 /// # References
 ///  - [POSIX]
 ///  - [Linux]
@@ -33,6 +33,7 @@ pub use crate::io::{fcntl_getfd, fcntl_setfd};
 /// [Linux]: https://man7.org/linux/man-pages/man2/fcntl.2.html
 #[inline]
 #[doc(alias = "F_GETFL")]
+#[doc(alias = "status_flags")]
 pub fn fcntl_getfl<Fd: AsFd>(fd: Fd) -> io::Result<OFlags> {
     backend::fs::syscalls::fcntl_getfl(fd.as_fd())
 }
@@ -69,10 +70,19 @@ use backend::fs::types::SealFlags;
 
 /// `fcntl(fd, F_ADD_SEALS)`
 ///
+/// Seals can only be added, never removed. To hand an immutable buffer to
+/// another process, create it with [`memfd_create`] and
+/// [`MemfdFlags::ALLOW_SEALING`], fill it, and then add
+/// `SealFlags::SEAL | SealFlags::SHRINK | SealFlags::GROW | SealFlags::WRITE`.
+/// Adding [`SealFlags::WRITE`] fails with [`io::Errno::BUSY`] while any
+/// writable shared mapping of the file exists.
+///
 /// # References
 ///  - [Linux]
 ///
 /// [Linux]: https://man7.org/linux/man-pages/man2/fcntl.2.html
+/// [`memfd_create`]: crate::fs::memfd_create
+/// [`MemfdFlags::ALLOW_SEALING`]: crate::fs::MemfdFlags::ALLOW_SEALING
 #[cfg(any(linux_kernel, target_os = "freebsd", target_os = "fuchsia"))]
 #[inline]
 #[doc(alias = "F_ADD_SEALS")]
@@ -82,9 +92,8 @@ pub fn fcntl_add_seals<Fd: AsFd>(fd: Fd, seals: SealFlags) -> io::Result<()> {
 
 /// `fcntl(fd, F_SETLK)`—Acquire or release an `fcntl`-style lock.
 ///
-/// This function doesn't currently have an offset or len; it currently always
-/// sets the `l_len` field to 0, which is a special case that means the entire
-/// file should be locked.
+/// This function always locks the entire file; to lock a range of a file,
+/// use [`fcntl_setlk`] or [`fcntl_setlkw`].
 ///
 /// Unlike `flock`-style locks, `fcntl`-style locks are process-associated,
 /// meaning that they don't guard against being acquired by two threads in the
@@ -96,17 +105,171 @@ pub fn fcntl_add_seals<Fd: AsFd>(fd: Fd, seals: SealFlags) -> io::Result<()> {
 ///
 /// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/fcntl.html
 /// [Linux]: https://man7.org/linux/man-pages/man2/fcntl.2.html
-#[cfg(not(any(
-    target_os = "emscripten",
-    target_os = "espidf",
-    target_os = "fuchsia",
-    target_os = "redox",
-    target_os = "vita",
-    target_os = "wasi"
-)))]
+#[cfg(fcntl_lock)]
 #[inline]
 #[doc(alias = "F_SETLK")]
 #[doc(alias = "F_SETLKW")]
 pub fn fcntl_lock<Fd: AsFd>(fd: Fd, operation: FlockOperation) -> io::Result<()> {
     backend::fs::syscalls::fcntl_lock(fd.as_fd(), operation)
 }
+
+/// `struct flock`—A byte-range record lock, for use with [`fcntl_setlk`],
+/// [`fcntl_getlk`], and related functions.
+#[cfg(fcntl_lock)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Flock {
+    /// `l_start`—The start of the range, relative to `offset_type`.
+    pub start: i64,
+
+    /// `l_len`—The length of the range. 0 means the range extends to the end
+    /// of the file, however far it grows.
+    pub length: i64,
+
+    /// `l_pid`—The process holding a conflicting lock, in the result of
+    /// [`fcntl_getlk`]. This is ignored when setting a lock, and is `None`
+    /// for open file description locks.
+    pub pid: Option<Pid>,
+
+    /// `l_type`
+    pub typ: FlockType,
+
+    /// `l_whence`
+    pub offset_type: FlockOffsetType,
+}
+
+#[cfg(fcntl_lock)]
+impl Flock {
+    /// Constructs a `Flock` for the `length` bytes starting at offset `start`
+    /// from the beginning of the file.
+    #[inline]
+    pub const fn new(typ: FlockType, start: i64, length: i64) -> Self {
+        Self {
+            start,
+            length,
+            pid: None,
+            typ,
+            offset_type: FlockOffsetType::Set,
+        }
+    }
+}
+
+/// `fcntl(fd, F_SETLK, lock)`—Acquire or release a record lock on a range of
+/// a file.
+///
+/// If a conflicting lock is held by another process, this fails with
+/// [`io::Errno::ACCESS`] or [`io::Errno::AGAIN`] instead of waiting.
+///
+/// Record locks are process-associated: they don't conflict with other locks
+/// held by the same process, and all of a process' locks on a file are
+/// released when it closes any file descriptor for the file.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/fcntl.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/fcntl.2.html
+#[cfg(fcntl_lock)]
+#[inline]
+#[doc(alias = "F_SETLK")]
+pub fn fcntl_setlk<Fd: AsFd>(fd: Fd, lock: &Flock) -> io::Result<()> {
+    backend::fs::syscalls::fcntl_setlk(fd.as_fd(), lock)
+}
+
+/// `fcntl(fd, F_SETLKW, lock)`—Acquire or release a record lock on a range
+/// of a file, waiting for conflicting locks to be released.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/fcntl.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/fcntl.2.html
+#[cfg(fcntl_lock)]
+#[inline]
+#[doc(alias = "F_SETLKW")]
+pub fn fcntl_setlkw<Fd: AsFd>(fd: Fd, lock: &Flock) -> io::Result<()> {
+    backend::fs::syscalls::fcntl_setlkw(fd.as_fd(), lock)
+}
+
+/// `fcntl(fd, F_GETLK, lock)`—Test whether a record lock could be acquired.
+///
+/// Returns `None` if `lock` could be acquired, or one of the locks that
+/// conflict with it otherwise.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/fcntl.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/fcntl.2.html
+#[cfg(fcntl_lock)]
+#[inline]
+#[doc(alias = "F_GETLK")]
+pub fn fcntl_getlk<Fd: AsFd>(fd: Fd, lock: &Flock) -> io::Result<Option<Flock>> {
+    let conflict = backend::fs::syscalls::fcntl_getlk(fd.as_fd(), lock)?;
+    Ok(match conflict.typ {
+        FlockType::Unlocked => None,
+        _ => Some(conflict),
+    })
+}
+
+/// `fcntl(fd, F_OFD_SETLK, lock)`—Acquire or release an open file
+/// description lock on a range of a file.
+///
+/// Open file description locks are like record locks, except that they're
+/// associated with the open file description rather than the process, so
+/// they conflict with locks acquired through other open file descriptions,
+/// even within the same process, and they're only released when the last
+/// file descriptor referring to the open file description is closed.
+///
+/// If a conflicting lock is held, this fails with [`io::Errno::AGAIN`]
+/// instead of waiting.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/fcntl.2.html
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "F_OFD_SETLK")]
+pub fn fcntl_ofd_setlk<Fd: AsFd>(fd: Fd, lock: &Flock) -> io::Result<()> {
+    backend::fs::syscalls::fcntl_ofd_setlk(fd.as_fd(), lock)
+}
+
+/// `fcntl(fd, F_OFD_SETLKW, lock)`—Acquire or release an open file
+/// description lock on a range of a file, waiting for conflicting locks to
+/// be released.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/fcntl.2.html
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "F_OFD_SETLKW")]
+pub fn fcntl_ofd_setlkw<Fd: AsFd>(fd: Fd, lock: &Flock) -> io::Result<()> {
+    backend::fs::syscalls::fcntl_ofd_setlkw(fd.as_fd(), lock)
+}
+
+/// `fcntl(fd, F_OFD_GETLK, lock)`—Test whether an open file description lock
+/// could be acquired.
+///
+/// Returns `None` if `lock` could be acquired, or one of the locks that
+/// conflict with it otherwise. Conflicting open file description locks have
+/// no owning process, so their `pid` is `None`.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/fcntl.2.html
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "F_OFD_GETLK")]
+pub fn fcntl_ofd_getlk<Fd: AsFd>(fd: Fd, lock: &Flock) -> io::Result<Option<Flock>> {
+    let conflict = backend::fs::syscalls::fcntl_ofd_getlk(fd.as_fd(), lock)?;
+    Ok(match conflict.typ {
+        FlockType::Unlocked => None,
+        _ => Some(conflict),
+    })
+}
@@ -8,7 +8,6 @@ use crate::fs::{OFlags, SeekFrom, Timespec};
 use crate::{backend, io};
 use backend::fd::{AsFd, BorrowedFd};
 #[cfg(not(any(
-    netbsdlike,
     solarish,
     target_os = "aix",
     target_os = "dragonfly",
@@ -18,6 +17,8 @@ use backend::fd::{AsFd, BorrowedFd};
     target_os = "vita",
 )))]
 use backend::fs::types::FallocateFlags;
+#[cfg(bsd)]
+use backend::fs::types::FileFlags;
 #[cfg(not(any(
     target_os = "espidf",
     target_os = "solaris",
@@ -32,7 +33,6 @@ use backend::fs::types::Stat;
     solarish,
     target_os = "espidf",
     target_os = "haiku",
-    target_os = "netbsd",
     target_os = "nto",
     target_os = "redox",
     target_os = "vita",
@@ -41,6 +41,8 @@ use backend::fs::types::Stat;
 use backend::fs::types::StatFs;
 #[cfg(not(any(target_os = "haiku", target_os = "redox", target_os = "wasi")))]
 use backend::fs::types::StatVfs;
+#[cfg(linux_kernel)]
+use backend::fs::types::SyncFileRangeFlags;
 
 /// Timestamps used by [`utimensat`] and [`futimens`].
 ///
@@ -58,6 +60,23 @@ pub struct Timestamps {
     pub last_modification: Timespec,
 }
 
+#[cfg(not(any(target_os = "espidf", target_os = "redox", target_os = "vita")))]
+impl Timestamps {
+    /// Timestamps which set both the last access and last modification
+    /// timestamps to the current time.
+    pub const NOW: Self = Self {
+        last_access: crate::fs::TIMESPEC_NOW,
+        last_modification: crate::fs::TIMESPEC_NOW,
+    };
+
+    /// Timestamps which leave both the last access and last modification
+    /// timestamps unchanged.
+    pub const OMIT: Self = Self {
+        last_access: crate::fs::TIMESPEC_OMIT,
+        last_modification: crate::fs::TIMESPEC_OMIT,
+    };
+}
+
 /// The filesystem magic number for procfs.
 ///
 /// See [the `fstatfs` manual page] for more information.
@@ -125,6 +144,10 @@ pub fn fchmod<Fd: AsFd>(fd: Fd, mode: Mode) -> io::Result<()> {
 
 /// `fchown(fd, owner, group)`—Sets open file or directory ownership.
 ///
+/// On Linux, changing the owner, or changing the group to one the caller
+/// isn't a member of, requires the `CAP_CHOWN` capability, and fails with
+/// [`io::Errno::PERM`] without it.
+///
 /// # References
 ///  - [POSIX]
 ///  - [Linux]
@@ -137,6 +160,30 @@ pub fn fchown<Fd: AsFd>(fd: Fd, owner: Option<Uid>, group: Option<Gid>) -> io::R
     backend::fs::syscalls::fchown(fd.as_fd(), owner, group)
 }
 
+/// `fchflags(fd, flags)`—Sets BSD file flags on an open file or directory.
+///
+/// This replaces all of the file's flags, so to set or clear individual
+/// flags, read the current flags from the `st_flags` field of [`fstat`]
+/// first.
+///
+/// # References
+///  - [Apple]
+///  - [FreeBSD]
+///  - [NetBSD]
+///  - [OpenBSD]
+///  - [DragonFly BSD]
+///
+/// [Apple]: https://developer.apple.com/library/archive/documentation/System/Conceptual/ManPages_iPhoneOS/man2/fchflags.2.html
+/// [FreeBSD]: https://man.freebsd.org/cgi/man.cgi?query=fchflags&sektion=2
+/// [NetBSD]: https://man.netbsd.org/fchflags.2
+/// [OpenBSD]: https://man.openbsd.org/fchflags.2
+/// [DragonFly BSD]: https://man.dragonflybsd.org/?command=fchflags&section=2
+#[cfg(bsd)]
+#[inline]
+pub fn fchflags<Fd: AsFd>(fd: Fd, flags: FileFlags) -> io::Result<()> {
+    backend::fs::syscalls::fchflags(fd.as_fd(), flags)
+}
+
 /// `fstat(fd)`—Queries metadata for an open file or directory.
 ///
 /// [`Mode::from_raw_mode`] and [`FileType::from_raw_mode`] may be used to
@@ -160,15 +207,19 @@ pub fn fstat<Fd: AsFd>(fd: Fd) -> io::Result<Stat> {
 /// Compared to [`fstatvfs`], this function often provides more information,
 /// though it's less portable.
 ///
+/// NetBSD has no `fstatfs`, so there this calls `fstatvfs` and returns its
+/// `struct statvfs`, which holds the same information.
+///
 /// # References
 ///  - [Linux]
+///  - [NetBSD]
 ///
 /// [Linux]: https://man7.org/linux/man-pages/man2/fstatfs.2.html
+/// [NetBSD]: https://man.netbsd.org/fstatvfs.2
 #[cfg(not(any(
     solarish,
     target_os = "espidf",
     target_os = "haiku",
-    target_os = "netbsd",
     target_os = "nto",
     target_os = "redox",
     target_os = "vita",
@@ -190,9 +241,11 @@ pub fn fstatfs<Fd: AsFd>(fd: Fd) -> io::Result<StatFs> {
 /// # References
 ///  - [POSIX]
 ///  - [Linux]
+///  - [NetBSD]
 ///
 /// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/fstatvfs.html
 /// [Linux]: https://man7.org/linux/man-pages/man2/fstatvfs.2.html
+/// [NetBSD]: https://man.netbsd.org/fstatvfs.2
 #[cfg(not(any(target_os = "haiku", target_os = "redox", target_os = "wasi")))]
 #[inline]
 pub fn fstatvfs<Fd: AsFd>(fd: Fd) -> io::Result<StatVfs> {
@@ -220,6 +273,9 @@ pub fn futimens<Fd: AsFd>(fd: Fd, times: &Timestamps) -> io::Result<()> {
 /// `posix_fallocate` and not the more general form, no `FallocateFlags` values
 /// are defined so it will always be empty.
 ///
+/// On NetBSD, this is `posix_fallocate`, which fails with
+/// [`io::Errno::OPNOTSUPP`] on filesystems that don't support it.
+///
 /// # References
 ///  - [POSIX]
 ///  - [Linux `fallocate`]
@@ -229,15 +285,15 @@ pub fn futimens<Fd: AsFd>(fd: Fd, times: &Timestamps) -> io::Result<()> {
 /// [Linux `fallocate`]: https://man7.org/linux/man-pages/man2/fallocate.2.html
 /// [Linux `posix_fallocate`]: https://man7.org/linux/man-pages/man3/posix_fallocate.3.html
 #[cfg(not(any(
-    netbsdlike,
     solarish,
     target_os = "aix",
     target_os = "dragonfly",
     target_os = "espidf",
     target_os = "nto",
+    target_os = "openbsd",
     target_os = "redox",
     target_os = "vita",
-)))] // not implemented in libc for netbsd yet
+)))]
 #[inline]
 #[doc(alias = "posix_fallocate")]
 pub fn fallocate<Fd: AsFd>(fd: Fd, mode: FallocateFlags, offset: u64, len: u64) -> io::Result<()> {
@@ -295,14 +351,20 @@ pub fn fsync<Fd: AsFd>(fd: Fd) -> io::Result<()> {
 /// `fdatasync(fd)`—Ensures that file data is written to the underlying
 /// storage device.
 ///
+/// On iOS and macOS, which have no `fdatasync`, this uses
+/// `fcntl(fd, F_FULLFSYNC)`, which also flushes the drive's cache, like
+/// `std::fs::File::sync_data` does there. On filesystems that don't support
+/// `F_FULLFSYNC`, it falls back to `fsync`.
+///
 /// # References
 ///  - [POSIX]
 ///  - [Linux]
+///  - [Apple]
 ///
 /// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/fdatasync.html
 /// [Linux]: https://man7.org/linux/man-pages/man2/fdatasync.2.html
+/// [Apple]: https://developer.apple.com/library/archive/documentation/System/Conceptual/ManPages_iPhoneOS/man2/fcntl.2.html
 #[cfg(not(any(
-    apple,
     target_os = "dragonfly",
     target_os = "espidf",
     target_os = "haiku",
@@ -314,6 +376,44 @@ pub fn fdatasync<Fd: AsFd>(fd: Fd) -> io::Result<()> {
     backend::fs::syscalls::fdatasync(fd.as_fd())
 }
 
+/// `sync_file_range(fd, offset, nbytes, flags)`—Starts or waits for
+/// writeback of a range of a file.
+///
+/// An `nbytes` of zero means everything from `offset` to the end of the
+/// file. This doesn't write out metadata or flush the disk's write cache,
+/// so it gives no durability guarantee by itself; use [`fdatasync`] for that.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/sync_file_range.2.html
+#[cfg(linux_kernel)]
+#[inline]
+pub fn sync_file_range<Fd: AsFd>(
+    fd: Fd,
+    offset: u64,
+    nbytes: u64,
+    flags: SyncFileRangeFlags,
+) -> io::Result<()> {
+    backend::fs::syscalls::sync_file_range(fd.as_fd(), offset, nbytes, flags)
+}
+
+/// `readahead(fd, offset, count)`—Starts reading a range of a file into the
+/// page cache.
+///
+/// This doesn't wait for the data to be read, so subsequent reads of the
+/// range may still block if they arrive first.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/readahead.2.html
+#[cfg(linux_kernel)]
+#[inline]
+pub fn readahead<Fd: AsFd>(fd: Fd, offset: u64, count: usize) -> io::Result<()> {
+    backend::fs::syscalls::readahead(fd.as_fd(), offset, count)
+}
+
 /// `ftruncate(fd, length)`—Sets the length of a file.
 ///
 /// # References
@@ -329,6 +429,12 @@ pub fn ftruncate<Fd: AsFd>(fd: Fd, length: u64) -> io::Result<()> {
 
 /// `flock(fd, operation)`—Acquire or release an advisory lock on an open file.
 ///
+/// The lock applies to the whole file, and belongs to the open file
+/// description, so it's shared by duplicated file descriptors, and released
+/// when the last of them is closed. With one of the `NonBlocking*`
+/// operations, this fails with [`io::Errno::WOULDBLOCK`] instead of waiting
+/// when a conflicting lock is held.
+///
 /// # References
 ///  - [Linux]
 ///
@@ -346,10 +452,15 @@ pub fn flock<Fd: AsFd>(fd: Fd, operation: FlockOperation) -> io::Result<()> {
 
 /// `syncfs(fd)`—Flush cached filesystem data.
 ///
+/// This flushes only the filesystem containing `fd`, unlike [`sync`], which
+/// flushes all of them. Since Linux 5.8, it fails with the first writeback
+/// error on that filesystem that hasn't already been reported to `fd`.
+///
 /// # References
 ///  - [Linux]
 ///
 /// [Linux]: https://man7.org/linux/man-pages/man2/syncfs.2.html
+/// [`sync`]: crate::fs::sync
 #[cfg(linux_kernel)]
 #[inline]
 pub fn syncfs<Fd: AsFd>(fd: Fd) -> io::Result<()> {
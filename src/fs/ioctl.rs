@@ -11,8 +11,16 @@ use {
 
 use bitflags::bitflags;
 
+#[cfg(linux_kernel)]
+use crate::fd::AsRawFd;
 #[cfg(all(linux_kernel, not(any(target_arch = "sparc", target_arch = "sparc64"))))]
-use crate::fd::{AsRawFd, BorrowedFd};
+use crate::fd::BorrowedFd;
+#[cfg(all(
+    linux_kernel,
+    feature = "alloc",
+    not(any(target_arch = "sparc", target_arch = "sparc64"))
+))]
+use alloc::{vec, vec::Vec};
 
 /// `ioctl(fd, BLKSSZGET)`—Returns the logical block size of a block device.
 ///
@@ -57,6 +65,444 @@ pub fn ioctl_ficlone<Fd: AsFd, SrcFd: AsFd>(fd: Fd, src_fd: SrcFd) -> io::Result
     unsafe { ioctl::ioctl(fd, Ficlone(src_fd.as_fd())) }
 }
 
+/// `ioctl(fd, FICLONERANGE, range)`—Share a range of data between open
+/// files.
+///
+/// This shares `src_length` bytes starting at `src_offset` in `src_fd` with
+/// `fd` at `dest_offset`. A `src_length` of zero shares everything from
+/// `src_offset` to the end of `src_fd`.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/ioctl_ficlonerange.2.html
+#[cfg(all(linux_kernel, not(any(target_arch = "sparc", target_arch = "sparc64"))))]
+#[inline]
+#[doc(alias = "FICLONERANGE")]
+pub fn ioctl_ficlonerange<Fd: AsFd, SrcFd: AsFd>(
+    fd: Fd,
+    src_fd: SrcFd,
+    src_offset: u64,
+    src_length: u64,
+    dest_offset: u64,
+) -> io::Result<()> {
+    use linux_raw_sys::general::file_clone_range;
+
+    let range = file_clone_range {
+        src_fd: src_fd.as_fd().as_raw_fd().into(),
+        src_offset,
+        src_length,
+        dest_offset,
+    };
+
+    // SAFETY: FICLONERANGE is a pointer setter opcode that reads a
+    // `file_clone_range`.
+    unsafe {
+        let ctl = ioctl::Setter::<
+            ioctl::BadOpcode<{ linux_raw_sys::ioctl::FICLONERANGE as ioctl::RawOpcode }>,
+            file_clone_range,
+        >::new(range);
+        ioctl::ioctl(fd, ctl)
+    }
+}
+
+/// A destination for [`ioctl_fideduperange`].
+#[cfg(all(
+    linux_kernel,
+    feature = "alloc",
+    not(any(target_arch = "sparc", target_arch = "sparc64"))
+))]
+#[derive(Debug, Copy, Clone)]
+pub struct DedupeDest<'a> {
+    /// The file to deduplicate against the source.
+    pub fd: BorrowedFd<'a>,
+
+    /// The offset in `fd` of the range to compare with the source range.
+    pub offset: u64,
+}
+
+/// The result of deduplicating one [`DedupeDest`] with
+/// [`ioctl_fideduperange`].
+#[cfg(all(
+    linux_kernel,
+    feature = "alloc",
+    not(any(target_arch = "sparc", target_arch = "sparc64"))
+))]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum DedupeResult {
+    /// `FILE_DEDUPE_RANGE_SAME`—The contents were identical, and
+    /// `bytes_deduped` bytes now share storage with the source.
+    Same {
+        /// The number of bytes that were deduplicated.
+        bytes_deduped: u64,
+    },
+
+    /// `FILE_DEDUPE_RANGE_DIFFERS`—The contents differed, and nothing was
+    /// deduplicated.
+    Differs,
+}
+
+/// `ioctl(fd, FIDEDUPERANGE, range)`—Share data between open files if it is
+/// identical.
+///
+/// This compares `src_length` bytes starting at `src_offset` in `fd` with the
+/// range starting at each destination's offset, and shares the storage of the
+/// ranges that are identical. The outer `Result` reports failures of the
+/// `ioctl` as a whole, and the returned `Vec` holds the result for each
+/// destination, in the order of `dests`.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/ioctl_fideduperange.2.html
+#[cfg(all(
+    linux_kernel,
+    feature = "alloc",
+    not(any(target_arch = "sparc", target_arch = "sparc64"))
+))]
+#[doc(alias = "FIDEDUPERANGE")]
+pub fn ioctl_fideduperange<Fd: AsFd>(
+    fd: Fd,
+    src_offset: u64,
+    src_length: u64,
+    dests: &[DedupeDest<'_>],
+) -> io::Result<Vec<io::Result<DedupeResult>>> {
+    use core::mem::size_of;
+    use linux_raw_sys::general::{
+        file_dedupe_range, file_dedupe_range_info, FILE_DEDUPE_RANGE_DIFFERS,
+        FILE_DEDUPE_RANGE_SAME,
+    };
+
+    let dest_count = u16::try_from(dests.len()).map_err(|_| io::Errno::INVAL)?;
+
+    // `file_dedupe_range` ends with a flexible array of
+    // `file_dedupe_range_info`, so allocate them together. Both sizes are
+    // multiples of 8, so use a `u64` buffer to get the alignment right.
+    let len = size_of::<file_dedupe_range>() + dests.len() * size_of::<file_dedupe_range_info>();
+    let mut buf = vec![0_u64; len / size_of::<u64>()];
+    let range = buf.as_mut_ptr().cast::<file_dedupe_range>();
+
+    // SAFETY: `buf` is large enough and suitably aligned for the header and
+    // `dests.len()` infos, and FIDEDUPERANGE is a read-write opcode that
+    // reads and updates a `file_dedupe_range` with that many infos.
+    unsafe {
+        let infos = range.add(1).cast::<file_dedupe_range_info>();
+        range.write(file_dedupe_range {
+            src_offset,
+            src_length,
+            dest_count,
+            reserved1: 0,
+            reserved2: 0,
+            info: linux_raw_sys::general::__IncompleteArrayField::new(),
+        });
+        for (i, dest) in dests.iter().enumerate() {
+            infos.add(i).write(file_dedupe_range_info {
+                dest_fd: dest.fd.as_raw_fd().into(),
+                dest_offset: dest.offset,
+                bytes_deduped: 0,
+                status: 0,
+                reserved: 0,
+            });
+        }
+
+        ioctl::ioctl(fd, Fideduperange(range))?;
+
+        Ok((0..dests.len())
+            .map(|i| {
+                let info = infos.add(i).read();
+                match info.status {
+                    status if status == FILE_DEDUPE_RANGE_SAME as i32 => Ok(DedupeResult::Same {
+                        bytes_deduped: info.bytes_deduped,
+                    }),
+                    status if status == FILE_DEDUPE_RANGE_DIFFERS as i32 => {
+                        Ok(DedupeResult::Differs)
+                    }
+                    status => Err(io::Errno::from_raw_os_error(-status)),
+                }
+            })
+            .collect())
+    }
+}
+
+/// Deduplicates a range of `fd` against ranges of any number of files.
+///
+/// This is a higher-level wrapper around [`ioctl_fideduperange`]. It splits
+/// `dests` into batches small enough for the kernel to accept in one
+/// `FIDEDUPERANGE` request, and it repeats requests when a filesystem
+/// deduplicates less than the whole range at once, until all of
+/// `src_length` bytes have been compared. A `src_length` of 0 means up to the
+/// end of `fd`.
+///
+/// The outer `Result` reports failures of a request as a whole, and the
+/// returned `Vec` holds the result for each destination, in the order of
+/// `dests`. If a leading part of a range was deduplicated before a later part
+/// was found to differ or failed, the result is [`DedupeResult::Same`] with a
+/// `bytes_deduped` less than the length of the range.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/ioctl_fideduperange.2.html
+#[cfg(all(
+    linux_kernel,
+    feature = "alloc",
+    not(any(target_arch = "sparc", target_arch = "sparc64"))
+))]
+pub fn dedupe<Fd: AsFd>(
+    fd: Fd,
+    src_offset: u64,
+    src_length: u64,
+    dests: &[DedupeDest<'_>],
+) -> io::Result<Vec<io::Result<DedupeResult>>> {
+    // The kernel rejects requests larger than a page, and a 4096-byte page
+    // holds the 24-byte header and 127 32-byte destination records.
+    const BATCH: usize = 127;
+
+    let fd = fd.as_fd();
+    let src_length = if src_length == 0 {
+        let size = crate::fs::fstat(fd)?.st_size as u64;
+        size.saturating_sub(src_offset)
+    } else {
+        src_length
+    };
+
+    if src_length == 0 {
+        return Ok(vec![
+            Ok(DedupeResult::Same { bytes_deduped: 0 });
+            dests.len()
+        ]);
+    }
+
+    let mut done = vec![0_u64; dests.len()];
+    let mut results: Vec<Option<io::Result<DedupeResult>>> = vec![None; dests.len()];
+
+    // Destinations usually advance in lockstep, but a filesystem may
+    // deduplicate different amounts for each, so each request covers
+    // pending destinations that have made the same amount of progress.
+    while let Some(progress) = (0..dests.len())
+        .filter(|&i| results[i].is_none())
+        .map(|i| done[i])
+        .min()
+    {
+        let batch: Vec<usize> = (0..dests.len())
+            .filter(|&i| results[i].is_none() && done[i] == progress)
+            .take(BATCH)
+            .collect();
+        let batch_dests = batch
+            .iter()
+            .map(|&i| {
+                Ok(DedupeDest {
+                    fd: dests[i].fd,
+                    offset: dests[i]
+                        .offset
+                        .checked_add(progress)
+                        .ok_or(io::Errno::OVERFLOW)?,
+                })
+            })
+            .collect::<io::Result<Vec<_>>>()?;
+
+        let batch_results = ioctl_fideduperange(
+            fd,
+            src_offset
+                .checked_add(progress)
+                .ok_or(io::Errno::OVERFLOW)?,
+            src_length - progress,
+            &batch_dests,
+        )?;
+
+        for (&i, result) in batch.iter().zip(batch_results) {
+            match result {
+                Ok(DedupeResult::Same { bytes_deduped }) if bytes_deduped != 0 => {
+                    done[i] += bytes_deduped;
+                    if done[i] >= src_length {
+                        results[i] = Some(Ok(DedupeResult::Same {
+                            bytes_deduped: done[i],
+                        }));
+                    }
+                }
+                result if done[i] == 0 => results[i] = Some(result),
+                _ => {
+                    results[i] = Some(Ok(DedupeResult::Same {
+                        bytes_deduped: done[i],
+                    }))
+                }
+            }
+        }
+    }
+
+    Ok(results.into_iter().map(Option::unwrap).collect())
+}
+
+#[cfg(all(
+    linux_kernel,
+    feature = "alloc",
+    not(any(target_arch = "sparc", target_arch = "sparc64"))
+))]
+bitflags! {
+    /// `FIEMAP_FLAG_*` constants for use with [`ioctl_fiemap`].
+    #[repr(transparent)]
+    #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    pub struct FiemapFlags: u32 {
+        /// `FIEMAP_FLAG_SYNC`—Sync the file before mapping it.
+        const SYNC = linux_raw_sys::ioctl::FIEMAP_FLAG_SYNC;
+        /// `FIEMAP_FLAG_XATTR`—Map the extended attribute tree instead.
+        const XATTR = linux_raw_sys::ioctl::FIEMAP_FLAG_XATTR;
+        /// `FIEMAP_FLAG_CACHE`—Request caching of the extents.
+        const CACHE = linux_raw_sys::ioctl::FIEMAP_FLAG_CACHE;
+
+        /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+        const _ = !0;
+    }
+}
+
+#[cfg(all(
+    linux_kernel,
+    feature = "alloc",
+    not(any(target_arch = "sparc", target_arch = "sparc64"))
+))]
+bitflags! {
+    /// `FIEMAP_EXTENT_*` constants for [`FileExtent`].
+    #[repr(transparent)]
+    #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    pub struct FiemapExtentFlags: u32 {
+        /// `FIEMAP_EXTENT_LAST`—This is the last extent in the file.
+        const LAST = linux_raw_sys::ioctl::FIEMAP_EXTENT_LAST;
+        /// `FIEMAP_EXTENT_UNKNOWN`—The location of the data is unknown.
+        const UNKNOWN = linux_raw_sys::ioctl::FIEMAP_EXTENT_UNKNOWN;
+        /// `FIEMAP_EXTENT_DELALLOC`—Delayed allocation; the location isn't
+        /// known yet.
+        const DELALLOC = linux_raw_sys::ioctl::FIEMAP_EXTENT_DELALLOC;
+        /// `FIEMAP_EXTENT_ENCODED`—The data is encoded, such as compressed.
+        const ENCODED = linux_raw_sys::ioctl::FIEMAP_EXTENT_ENCODED;
+        /// `FIEMAP_EXTENT_DATA_ENCRYPTED`—The data is encrypted.
+        const DATA_ENCRYPTED = linux_raw_sys::ioctl::FIEMAP_EXTENT_DATA_ENCRYPTED;
+        /// `FIEMAP_EXTENT_NOT_ALIGNED`—The extent offsets may not be block
+        /// aligned.
+        const NOT_ALIGNED = linux_raw_sys::ioctl::FIEMAP_EXTENT_NOT_ALIGNED;
+        /// `FIEMAP_EXTENT_DATA_INLINE`—The data is stored with the metadata.
+        const DATA_INLINE = linux_raw_sys::ioctl::FIEMAP_EXTENT_DATA_INLINE;
+        /// `FIEMAP_EXTENT_DATA_TAIL`—The data is packed with other files'
+        /// data.
+        const DATA_TAIL = linux_raw_sys::ioctl::FIEMAP_EXTENT_DATA_TAIL;
+        /// `FIEMAP_EXTENT_UNWRITTEN`—The space is allocated but not written,
+        /// so it reads as zeros.
+        const UNWRITTEN = linux_raw_sys::ioctl::FIEMAP_EXTENT_UNWRITTEN;
+        /// `FIEMAP_EXTENT_MERGED`—The filesystem doesn't track extents, and
+        /// this was merged from block mappings.
+        const MERGED = linux_raw_sys::ioctl::FIEMAP_EXTENT_MERGED;
+        /// `FIEMAP_EXTENT_SHARED`—The extent's storage may be shared with
+        /// other files.
+        const SHARED = linux_raw_sys::ioctl::FIEMAP_EXTENT_SHARED;
+
+        /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+        const _ = !0;
+    }
+}
+
+/// An extent of a file, as returned by [`ioctl_fiemap`].
+#[cfg(all(
+    linux_kernel,
+    feature = "alloc",
+    not(any(target_arch = "sparc", target_arch = "sparc64"))
+))]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[doc(alias = "fiemap_extent")]
+pub struct FileExtent {
+    /// The byte offset of the extent within the file.
+    pub logical: u64,
+
+    /// The byte offset of the extent on the device, or 0 if `flags` contains
+    /// `FiemapExtentFlags::UNKNOWN`.
+    pub physical: u64,
+
+    /// The length of the extent, in bytes.
+    pub length: u64,
+
+    /// Flags describing the extent.
+    pub flags: FiemapExtentFlags,
+}
+
+/// `ioctl(fd, FS_IOC_FIEMAP, fiemap)`—Returns the extents of a file.
+///
+/// This returns the extents that overlap the `length` bytes starting at
+/// `start`, making as many `ioctl` calls as needed. Pass `u64::MAX` as
+/// `length` to map to the end of the file. Holes in a sparse file are gaps
+/// between the returned extents.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://www.kernel.org/doc/html/latest/filesystems/fiemap.html
+#[cfg(all(
+    linux_kernel,
+    feature = "alloc",
+    not(any(target_arch = "sparc", target_arch = "sparc64"))
+))]
+#[doc(alias = "FS_IOC_FIEMAP")]
+pub fn ioctl_fiemap<Fd: AsFd>(
+    fd: Fd,
+    start: u64,
+    length: u64,
+    flags: FiemapFlags,
+) -> io::Result<Vec<FileExtent>> {
+    use core::mem::size_of;
+
+    // The number of extents to ask for in each call.
+    const BATCH: usize = 64;
+
+    let fd = fd.as_fd();
+    let end = start.saturating_add(length);
+    let mut extents = Vec::new();
+    let mut pos = start;
+
+    // `fiemap` ends with a flexible array of `fiemap_extent`, so allocate
+    // them together. Both sizes are multiples of 8, so use a `u64` buffer to
+    // get the alignment right.
+    let len = size_of::<RawFiemap>() + BATCH * size_of::<RawFiemapExtent>();
+    let mut buf = vec![0_u64; len / size_of::<u64>()];
+    let fiemap = buf.as_mut_ptr().cast::<RawFiemap>();
+
+    while pos < end {
+        // SAFETY: `buf` is large enough and suitably aligned for the header
+        // and `BATCH` extents, and FS_IOC_FIEMAP is a read-write opcode that
+        // reads a `fiemap` and fills in at most `fm_extent_count` extents.
+        let mapped = unsafe {
+            let raw_extents = fiemap.add(1).cast::<RawFiemapExtent>();
+            fiemap.write(RawFiemap {
+                fm_start: pos,
+                fm_length: end - pos,
+                fm_flags: flags.bits(),
+                fm_mapped_extents: 0,
+                fm_extent_count: BATCH as u32,
+                fm_reserved: 0,
+            });
+
+            ioctl::ioctl(fd, Fiemap(fiemap))?;
+
+            let mapped = (*fiemap).fm_mapped_extents as usize;
+            extents.extend((0..mapped.min(BATCH)).map(|i| {
+                let extent = raw_extents.add(i).read();
+                FileExtent {
+                    logical: extent.fe_logical,
+                    physical: extent.fe_physical,
+                    length: extent.fe_length,
+                    flags: FiemapExtentFlags::from_bits_retain(extent.fe_flags),
+                }
+            }));
+            mapped
+        };
+
+        match extents.last() {
+            Some(last) if mapped == BATCH && !last.flags.contains(FiemapExtentFlags::LAST) => {
+                pos = last.logical.saturating_add(last.length);
+            }
+            _ => break,
+        }
+    }
+
+    Ok(extents)
+}
+
 /// `ioctl(fd, EXT4_IOC_RESIZE_FS, blocks)`—Resize ext4 filesystem on fd.
 #[cfg(linux_kernel)]
 #[inline]
@@ -93,22 +539,75 @@ unsafe impl ioctl::Ioctl for Ficlone<'_> {
     }
 }
 
+#[cfg(all(
+    linux_kernel,
+    feature = "alloc",
+    not(any(target_arch = "sparc", target_arch = "sparc64"))
+))]
+struct Fideduperange(*mut linux_raw_sys::general::file_dedupe_range);
+
+#[cfg(all(
+    linux_kernel,
+    feature = "alloc",
+    not(any(target_arch = "sparc", target_arch = "sparc64"))
+))]
+unsafe impl ioctl::Ioctl for Fideduperange {
+    type Output = ();
+
+    const IS_MUTATING: bool = true;
+    const OPCODE: ioctl::Opcode =
+        ioctl::Opcode::old(linux_raw_sys::ioctl::FIDEDUPERANGE as ioctl::RawOpcode);
+
+    fn as_ptr(&mut self) -> *mut c::c_void {
+        self.0.cast()
+    }
+
+    unsafe fn output_from_ptr(
+        _: ioctl::IoctlOutput,
+        _: *mut c::c_void,
+    ) -> io::Result<Self::Output> {
+        Ok(())
+    }
+}
+
 #[cfg(linux_kernel)]
 bitflags! {
-    /// `FS_*` constants for use with [`ioctl_getflags`][crate::io::ioctl::ioctl_getflags].
+    /// `FS_*` constants for use with [`ioctl_getflags`] and
+    /// [`ioctl_setflags`].
+    ///
+    /// These are the inode attributes shown by `lsattr` and changed by
+    /// `chattr`. Not all filesystems support all flags, and setting
+    /// `IMMUTABLE` or `APPEND` requires the `CAP_LINUX_IMMUTABLE` capability.
+    #[repr(transparent)]
+    #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    #[doc(alias = "InodeFlags")]
     pub struct IFlags: c::c_uint {
         /// `FS_APPEND_FL`
         const APPEND = linux_raw_sys::general::FS_APPEND_FL;
+        /// `FS_CASEFOLD_FL`
+        const CASEFOLD = linux_raw_sys::general::FS_CASEFOLD_FL;
         /// `FS_COMPR_FL`
         const COMPRESSED = linux_raw_sys::general::FS_COMPR_FL;
+        /// `FS_DAX_FL`
+        const DAX = linux_raw_sys::general::FS_DAX_FL;
         /// `FS_DIRSYNC_FL`
         const DIRSYNC = linux_raw_sys::general::FS_DIRSYNC_FL;
+        /// `FS_ENCRYPT_FL`
+        const ENCRYPTED = linux_raw_sys::general::FS_ENCRYPT_FL;
+        /// `FS_EXTENT_FL`
+        const EXTENTS = linux_raw_sys::general::FS_EXTENT_FL;
         /// `FS_IMMUTABLE_FL`
         const IMMUTABLE = linux_raw_sys::general::FS_IMMUTABLE_FL;
+        /// `FS_INDEX_FL`
+        const INDEX = linux_raw_sys::general::FS_INDEX_FL;
+        /// `FS_INLINE_DATA_FL`
+        const INLINE_DATA = linux_raw_sys::general::FS_INLINE_DATA_FL;
         /// `FS_JOURNAL_DATA_FL`
         const JOURNALING = linux_raw_sys::general::FS_JOURNAL_DATA_FL;
         /// `FS_NOATIME_FL`
         const NOATIME = linux_raw_sys::general::FS_NOATIME_FL;
+        /// `FS_NOCOMP_FL`
+        const NOCOMP = linux_raw_sys::general::FS_NOCOMP_FL;
         /// `FS_NOCOW_FL`
         const NOCOW = linux_raw_sys::general::FS_NOCOW_FL;
         /// `FS_NODUMP_FL`
@@ -125,6 +624,11 @@ bitflags! {
         const TOPDIR = linux_raw_sys::general::FS_TOPDIR_FL;
         /// `FS_UNRM_FL`
         const UNRM = linux_raw_sys::general::FS_UNRM_FL;
+        /// `FS_VERITY_FL`
+        const VERITY = linux_raw_sys::general::FS_VERITY_FL;
+
+        /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+        const _ = !0;
     }
 }
 
@@ -163,3 +667,66 @@ pub fn ioctl_setflags<Fd: AsFd>(fd: Fd, flags: IFlags) -> io::Result<()> {
         ioctl::ioctl(fd, ctl)
     }
 }
+
+/// `struct fiemap`, without the trailing flexible array.
+#[cfg(all(
+    linux_kernel,
+    feature = "alloc",
+    not(any(target_arch = "sparc", target_arch = "sparc64"))
+))]
+#[repr(C)]
+struct RawFiemap {
+    fm_start: u64,
+    fm_length: u64,
+    fm_flags: u32,
+    fm_mapped_extents: u32,
+    fm_extent_count: u32,
+    fm_reserved: u32,
+}
+
+/// `struct fiemap_extent`.
+#[cfg(all(
+    linux_kernel,
+    feature = "alloc",
+    not(any(target_arch = "sparc", target_arch = "sparc64"))
+))]
+#[repr(C)]
+struct RawFiemapExtent {
+    fe_logical: u64,
+    fe_physical: u64,
+    fe_length: u64,
+    fe_reserved64: [u64; 2],
+    fe_flags: u32,
+    fe_reserved: [u32; 3],
+}
+
+#[cfg(all(
+    linux_kernel,
+    feature = "alloc",
+    not(any(target_arch = "sparc", target_arch = "sparc64"))
+))]
+struct Fiemap(*mut RawFiemap);
+
+#[cfg(all(
+    linux_kernel,
+    feature = "alloc",
+    not(any(target_arch = "sparc", target_arch = "sparc64"))
+))]
+unsafe impl ioctl::Ioctl for Fiemap {
+    type Output = ();
+
+    const IS_MUTATING: bool = true;
+    const OPCODE: ioctl::Opcode =
+        ioctl::Opcode::old(linux_raw_sys::ioctl::FS_IOC_FIEMAP as ioctl::RawOpcode);
+
+    fn as_ptr(&mut self) -> *mut c::c_void {
+        self.0.cast()
+    }
+
+    unsafe fn output_from_ptr(
+        _: ioctl::IoctlOutput,
+        _: *mut c::c_void,
+    ) -> io::Result<Self::Output> {
+        Ok(())
+    }
+}
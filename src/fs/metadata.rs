@@ -0,0 +1,66 @@
+//! Applying several pieces of file metadata at once.
+
+use crate::fd::AsFd;
+use crate::ffi::CStr;
+use crate::fs::{fchmod, fchown, fsetxattr, futimens, Gid, Mode, Timestamps, Uid, XattrFlags};
+use crate::io;
+
+/// Metadata to set with [`apply_metadata`].
+///
+/// Fields that are `None` or empty are left unchanged.
+#[derive(Debug, Clone, Default)]
+pub struct FileMetadata<'a> {
+    /// The new owner.
+    pub owner: Option<Uid>,
+
+    /// The new group.
+    pub group: Option<Gid>,
+
+    /// The new permission bits, including any setuid, setgid, and sticky
+    /// bits.
+    pub mode: Option<Mode>,
+
+    /// Extended attributes to set, as names and values.
+    pub xattrs: &'a [(&'a CStr, &'a [u8])],
+
+    /// The new access and modification times.
+    pub times: Option<Timestamps>,
+}
+
+/// Set the owner, mode, extended attributes, and timestamps of an open file.
+///
+/// These are set in an order in which no step undoes an earlier one, which
+/// matters when extracting archives:
+///
+///  - The owner and group are set first, because changing them clears the
+///    setuid and setgid bits and the `security.capability` extended
+///    attribute.
+///  - The mode is set next, so that setuid and setgid bits survive.
+///  - Extended attributes are set after the mode, so that ACLs stored in
+///    `system.posix_acl_access` aren't overwritten by the mode.
+///  - The timestamps are set last, so that nothing changes them afterwards.
+///
+/// If a step fails, the error is returned and the later steps aren't
+/// performed.
+#[doc(alias = "fchown")]
+#[doc(alias = "fchmod")]
+#[doc(alias = "fsetxattr")]
+#[doc(alias = "futimens")]
+pub fn apply_metadata<Fd: AsFd>(fd: Fd, metadata: &FileMetadata<'_>) -> io::Result<()> {
+    let fd = fd.as_fd();
+
+    if metadata.owner.is_some() || metadata.group.is_some() {
+        fchown(fd, metadata.owner, metadata.group)?;
+    }
+    if let Some(mode) = metadata.mode {
+        fchmod(fd, mode)?;
+    }
+    for (name, value) in metadata.xattrs {
+        fsetxattr(fd, *name, value, XattrFlags::empty())?;
+    }
+    if let Some(times) = &metadata.times {
+        futimens(fd, times)?;
+    }
+
+    Ok(())
+}
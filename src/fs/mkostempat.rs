@@ -0,0 +1,117 @@
+use crate::fd::{AsFd, OwnedFd};
+use crate::ffi::{CStr, CString};
+use crate::fs::{Mode, OFlags};
+#[cfg(all(linux_kernel, feature = "rand"))]
+use crate::rand::{getrandom, GetRandomFlags};
+use crate::{backend, io, path};
+
+/// The number of trailing `X` characters required in a template.
+const SUFFIX_LEN: usize = 6;
+
+/// The characters used to fill in a template.
+const LETTERS: &[u8; 62] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789";
+
+/// Random bytes at or above this are discarded, so that each letter is
+/// equally likely.
+const LIMIT: u8 = (256 / LETTERS.len() * LETTERS.len()) as u8;
+
+/// The number of names to try before giving up, matching glibc.
+const ATTEMPTS: usize = 62 * 62 * 62;
+
+/// `mkostempat(dirfd, template, oflags)`—Creates a uniquely-named file.
+///
+/// `template` must end with six `X` characters, which are replaced with
+/// random characters to form a name that doesn't yet exist in `dirfd`. The
+/// file is created with [`OFlags::RDWR`], [`OFlags::CREATE`],
+/// [`OFlags::EXCL`], and [`OFlags::CLOEXEC`], in addition to any flags in
+/// `oflags`, and with permissions [`Mode::RUSR`] | [`Mode::WUSR`].
+///
+/// On success, this returns the new file descriptor and the name that was
+/// created, relative to `dirfd`.
+///
+/// Names are generated with [`getrandom`] on Linux when the `rand` feature
+/// is enabled, and from `/dev/urandom` otherwise, rather than with libc, so
+/// this is available even where libc lacks `mkostemp`, or lacks a
+/// `dirfd`-relative form of it.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man3/mkostemp.3.html
+pub fn mkostempat<P: path::Arg, Fd: AsFd>(
+    dirfd: Fd,
+    template: P,
+    oflags: OFlags,
+) -> io::Result<(OwnedFd, CString)> {
+    let mut name = template.into_c_str()?.into_owned().into_bytes_with_nul();
+
+    // Check for the trailing `XXXXXX`, not counting the NUL terminator.
+    let len = name.len() - 1;
+    if len < SUFFIX_LEN || name[len - SUFFIX_LEN..len].iter().any(|b| *b != b'X') {
+        return Err(io::Errno::INVAL);
+    }
+
+    let dirfd = dirfd.as_fd();
+    let oflags = oflags | OFlags::RDWR | OFlags::CREATE | OFlags::EXCL | OFlags::CLOEXEC;
+    let mode = Mode::RUSR | Mode::WUSR;
+
+    for _ in 0..ATTEMPTS {
+        let mut filled = 0;
+        while filled < SUFFIX_LEN {
+            let mut random = [0_u8; 2 * SUFFIX_LEN];
+            fill_random(&mut random)?;
+            for r in random
+                .iter()
+                .filter(|r| **r < LIMIT)
+                .take(SUFFIX_LEN - filled)
+            {
+                name[len - SUFFIX_LEN + filled] = LETTERS[usize::from(*r) % LETTERS.len()];
+                filled += 1;
+            }
+        }
+
+        // The name was derived from a `CStr` and only alphanumeric
+        // characters were substituted, so it's still NUL-terminated.
+        let c_name = CStr::from_bytes_with_nul(&name).unwrap();
+        match backend::fs::syscalls::openat(dirfd, c_name, oflags, mode) {
+            Ok(fd) => return Ok((fd, CString::from_vec_with_nul(name).unwrap())),
+            Err(io::Errno::EXIST) => continue,
+            Err(err) => return Err(err),
+        }
+    }
+
+    Err(io::Errno::EXIST)
+}
+
+/// Fill `buf` with random bytes, retrying on short reads and `EINTR`.
+#[cfg(all(linux_kernel, feature = "rand"))]
+fn fill_random(mut buf: &mut [u8]) -> io::Result<()> {
+    while !buf.is_empty() {
+        match getrandom(buf, GetRandomFlags::empty()) {
+            Ok(n) => buf = &mut buf[n..],
+            Err(io::Errno::INTR) => continue,
+            Err(err) => return Err(err),
+        }
+    }
+    Ok(())
+}
+
+/// Fill `buf` with random bytes from `/dev/urandom`, retrying on short reads
+/// and `EINTR`.
+#[cfg(not(all(linux_kernel, feature = "rand")))]
+fn fill_random(mut buf: &mut [u8]) -> io::Result<()> {
+    let fd = backend::fs::syscalls::open(
+        cstr!("/dev/urandom"),
+        OFlags::RDONLY | OFlags::CLOEXEC,
+        Mode::empty(),
+    )?;
+    while !buf.is_empty() {
+        match crate::io::read(&fd, buf) {
+            Ok(0) => return Err(io::Errno::IO),
+            Ok(n) => buf = &mut buf[n..],
+            Err(io::Errno::INTR) => continue,
+            Err(err) => return Err(err),
+        }
+    }
+    Ok(())
+}
@@ -44,6 +44,18 @@ mod ioctl;
 mod makedev;
 #[cfg(any(linux_kernel, target_os = "freebsd"))]
 mod memfd_create;
+#[cfg(any(apple, linux_kernel))]
+mod metadata;
+#[cfg(all(
+    feature = "alloc",
+    not(any(
+        target_os = "espidf",
+        target_os = "redox",
+        target_os = "vita",
+        target_os = "wasi"
+    ))
+))]
+mod mkostempat;
 #[cfg(linux_kernel)]
 #[cfg(feature = "fs")]
 mod mount;
@@ -52,7 +64,7 @@ mod openat2;
 #[cfg(linux_kernel)]
 mod raw_dir;
 mod seek_from;
-#[cfg(target_os = "linux")]
+#[cfg(any(apple, freebsdlike, linux_kernel))]
 mod sendfile;
 #[cfg(linux_kernel)]
 mod statx;
@@ -63,7 +75,7 @@ mod statx;
     target_os = "wasi"
 )))]
 mod sync;
-#[cfg(any(apple, linux_kernel))]
+#[cfg(any(apple, linux_kernel, target_os = "freebsd"))]
 mod xattr;
 
 #[cfg(linux_kernel)]
@@ -112,6 +124,18 @@ pub use ioctl::*;
 pub use makedev::*;
 #[cfg(any(linux_kernel, target_os = "freebsd"))]
 pub use memfd_create::memfd_create;
+#[cfg(any(apple, linux_kernel))]
+pub use metadata::{apply_metadata, FileMetadata};
+#[cfg(all(
+    feature = "alloc",
+    not(any(
+        target_os = "espidf",
+        target_os = "redox",
+        target_os = "vita",
+        target_os = "wasi"
+    ))
+))]
+pub use mkostempat::mkostempat;
 #[cfg(linux_kernel)]
 #[cfg(feature = "fs")]
 pub use mount::*;
@@ -120,7 +144,7 @@ pub use openat2::openat2;
 #[cfg(linux_kernel)]
 pub use raw_dir::{RawDir, RawDirEntry};
 pub use seek_from::SeekFrom;
-#[cfg(target_os = "linux")]
+#[cfg(any(apple, freebsdlike, linux_kernel))]
 pub use sendfile::sendfile;
 #[cfg(linux_kernel)]
 pub use statx::statx;
@@ -131,7 +155,7 @@ pub use statx::statx;
     target_os = "wasi"
 )))]
 pub use sync::sync;
-#[cfg(any(apple, linux_kernel))]
+#[cfg(any(apple, linux_kernel, target_os = "freebsd"))]
 pub use xattr::*;
 
 /// Re-export types common to POSIX-ish platforms.
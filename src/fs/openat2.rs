@@ -5,10 +5,22 @@ use backend::fs::types::{Mode, OFlags, ResolveFlags};
 
 /// `openat2(dirfd, path, OpenHow { oflags, mode, resolve }, sizeof(OpenHow))`
 ///
+/// This is like [`openat`], with `resolve` restricting how `path` is
+/// resolved. For example, [`ResolveFlags::BENEATH`] fails with
+/// [`io::Errno::XDEV`] if `path` would escape `dirfd`, and
+/// [`ResolveFlags::IN_ROOT`] resolves `path` as if `dirfd` were the root
+/// directory.
+///
+/// This fails with [`io::Errno::NOSYS`] on Linux before 5.6. With
+/// [`ResolveFlags::BENEATH`] or [`ResolveFlags::IN_ROOT`], it may also fail
+/// with [`io::Errno::AGAIN`] if a concurrent rename or mount could have let
+/// `path` escape, in which case the caller should retry.
+///
 /// # References
 ///  - [Linux]
 ///
 /// [Linux]: https://man7.org/linux/man-pages/man2/openat2.2.html
+/// [`openat`]: crate::fs::openat
 #[inline]
 pub fn openat2<Fd: AsFd, P: path::Arg>(
     dirfd: Fd,
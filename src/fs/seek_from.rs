@@ -7,7 +7,8 @@
 /// It is used by the [`seek`] function.
 ///
 /// This is similar to [`std::io::SeekFrom`], however it adds platform-specific
-/// seek options.
+/// seek options. With the `std` feature, a `std::io::SeekFrom` can be
+/// converted into a `SeekFrom` with [`From`].
 ///
 /// [`seek`]: crate::fs::seek
 #[derive(Copy, PartialEq, Eq, Clone, Debug)]
@@ -33,21 +34,46 @@ pub enum SeekFrom {
     #[cfg_attr(staged_api, stable(feature = "rust1", since = "1.0.0"))]
     Current(#[cfg_attr(staged_api, stable(feature = "rust1", since = "1.0.0"))] i64),
 
-    /// Sets the offset to the current position plus the specified number of
-    /// bytes, plus the distance to the next byte which is not in a hole.
+    /// `SEEK_DATA`—Sets the offset to the first byte at or after the given
+    /// offset which is not in a hole.
     ///
-    /// If the offset is in a hole at the end of the file, the seek will fail
-    /// with [`Errno::NXIO`].
+    /// Unlike `Current`, the offset is measured from the start of the file,
+    /// not from the current position. If the offset is in a hole at the end
+    /// of the file, or past the end of the file, the seek will fail with
+    /// [`Errno::NXIO`].
     ///
     /// [`Errno::NXIO`]: crate::io::Errno::NXIO
     #[cfg(any(apple, freebsdlike, linux_kernel, solarish))]
+    #[doc(alias = "SEEK_DATA")]
     Data(i64),
 
-    /// Sets the offset to the current position plus the specified number of
-    /// bytes, plus the distance to the next byte which is in a hole.
+    /// `SEEK_HOLE`—Sets the offset to the first byte at or after the given
+    /// offset which is in a hole.
+    ///
+    /// Unlike `Current`, the offset is measured from the start of the file,
+    /// not from the current position. If there is no hole past the offset, it
+    /// will be set to the end of the file i.e. there is an implicit hole at
+    /// the end of any file. If the offset is past the end of the file, the
+    /// seek will fail with [`Errno::NXIO`].
+    ///
+    /// Alternating `Data` and `Hole` seeks visits each data region of a
+    /// sparse file in turn. Filesystems which don't track holes treat the
+    /// whole file as data.
     ///
-    /// If there is no hole past the offset, it will be set to the end of the
-    /// file i.e. there is an implicit hole at the end of any file.
+    /// [`Errno::NXIO`]: crate::io::Errno::NXIO
     #[cfg(any(apple, freebsdlike, linux_kernel, solarish))]
+    #[doc(alias = "SEEK_HOLE")]
     Hole(i64),
 }
+
+#[cfg(feature = "std")]
+impl From<std::io::SeekFrom> for SeekFrom {
+    #[inline]
+    fn from(pos: std::io::SeekFrom) -> Self {
+        match pos {
+            std::io::SeekFrom::Start(offset) => Self::Start(offset),
+            std::io::SeekFrom::End(offset) => Self::End(offset),
+            std::io::SeekFrom::Current(offset) => Self::Current(offset),
+        }
+    }
+}
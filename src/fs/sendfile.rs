@@ -1,13 +1,35 @@
 use crate::{backend, io};
 use backend::fd::AsFd;
 
-/// `sendfile(out_fd, in_fd, offset, count)`
+/// `sendfile(out_fd, in_fd, offset, count)`—Copies data from a file to
+/// another file or a socket.
+///
+/// The copy happens within the kernel, without reading the data into
+/// userspace. This returns the number of bytes copied, which may be less than
+/// `count`, and is 0 at the end of `in_fd`.
+///
+/// If `offset` is `None`, the copy starts at `in_fd`'s file offset and
+/// advances it. Otherwise, it starts at `*offset` and advances that instead,
+/// leaving the file offset unchanged.
+///
+/// On FreeBSD, DragonFly BSD, iOS, and macOS, `out_fd` must be a stream
+/// socket, and `in_fd` must be a regular file. The `sendfile`s there don't
+/// use the file offset, so with an `offset` of `None`, this reads it with
+/// `lseek` before the copy and sets it with `lseek` afterwards. Unlike on
+/// Linux, this isn't atomic, so if another thread or process uses the same
+/// open file description at the same time, the copy may start at a stale
+/// offset, and either update of the file offset may be lost.
 ///
 /// # References
 ///  - [Linux]
+///  - [Apple]
+///  - [FreeBSD]
+///  - [DragonFly BSD]
 ///
 /// [Linux]: https://man7.org/linux/man-pages/man2/sendfile.2.html
-#[cfg(linux_kernel)]
+/// [Apple]: https://developer.apple.com/library/archive/documentation/System/Conceptual/ManPages_iPhoneOS/man2/sendfile.2.html
+/// [FreeBSD]: https://man.freebsd.org/cgi/man.cgi?query=sendfile&sektion=2
+/// [DragonFly BSD]: https://man.dragonflybsd.org/?command=sendfile&section=2
 #[inline]
 pub fn sendfile<OutFd: AsFd, InFd: AsFd>(
     out_fd: OutFd,
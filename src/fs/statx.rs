@@ -17,6 +17,9 @@ use compat::statx as _statx;
 /// versions where the actual syscall fails with different error codes; rustix
 /// handles this and translates them into `NOSYS`.
 ///
+/// The `stx_attributes` and `stx_attributes_mask` fields of the result can be
+/// interpreted with [`StatxAttributes`].
+///
 /// # References
 ///  - [Linux]
 ///
@@ -29,11 +32,12 @@ use compat::statx as _statx;
 /// # use rustix::fd::BorrowedFd;
 /// /// Try to determine if the provided path is a mount root. Will return
 /// /// `Ok(None)` if the kernel is not new enough to support `statx` or
-/// /// [`libc::STATX_ATTR_MOUNT_ROOT`].
+/// /// [`StatxAttributes::MOUNT_ROOT`].
+/// ///
+/// /// [`StatxAttributes::MOUNT_ROOT`]: rustix::fs::StatxAttributes::MOUNT_ROOT
 /// fn is_mountpoint(root: BorrowedFd<'_>, path: &Path) -> io::Result<Option<bool>> {
-///     use rustix::fs::{AtFlags, StatxFlags};
+///     use rustix::fs::{AtFlags, StatxAttributes, StatxFlags};
 ///
-///     let mountroot_flag = libc::STATX_ATTR_MOUNT_ROOT as u64;
 ///     match rustix::fs::statx(
 ///         root,
 ///         path,
@@ -41,8 +45,10 @@ use compat::statx as _statx;
 ///         StatxFlags::empty(),
 ///     ) {
 ///         Ok(r) => {
-///             let present = (r.stx_attributes_mask & mountroot_flag) > 0;
-///             Ok(present.then(|| r.stx_attributes & mountroot_flag > 0))
+///             let mask = StatxAttributes::from_bits_retain(r.stx_attributes_mask);
+///             let attributes = StatxAttributes::from_bits_retain(r.stx_attributes);
+///             let present = mask.contains(StatxAttributes::MOUNT_ROOT);
+///             Ok(present.then(|| attributes.contains(StatxAttributes::MOUNT_ROOT)))
 ///         }
 ///         Err(e) if e == rustix::io::Errno::NOSYS => Ok(None),
 ///         Err(e) => Err(e.into()),
@@ -51,6 +57,7 @@ use compat::statx as _statx;
 /// ```
 ///
 /// [Linux]: https://man7.org/linux/man-pages/man2/statx.2.html
+/// [`StatxAttributes`]: crate::fs::StatxAttributes
 #[inline]
 pub fn statx<P: path::Arg, Fd: AsFd>(
     dirfd: Fd,
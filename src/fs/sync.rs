@@ -2,12 +2,17 @@ use crate::backend;
 
 /// `sync`—Flush cached filesystem data for all filesystems.
 ///
+/// This can't fail, and it doesn't report writeback errors. On Linux,
+/// [`syncfs`] flushes just the filesystem containing a given file descriptor,
+/// and does report errors.
+///
 /// # References
 ///  - [POSIX]
 ///  - [Linux]
 ///
 /// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/sync.html
 /// [Linux]: https://man7.org/linux/man-pages/man2/sync.2.html
+/// [`syncfs`]: https://docs.rs/rustix/*/x86_64-unknown-linux-gnu/rustix/fs/fn.syncfs.html
 #[inline]
 pub fn sync() {
     backend::fs::syscalls::sync();
@@ -1,4 +1,6 @@
 use crate::{backend, io, path};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
 use backend::c;
 use backend::fd::AsFd;
 use bitflags::bitflags;
@@ -23,10 +25,18 @@ bitflags! {
 /// `getxattr(path, name, value.as_ptr(), value.len())`—Get extended
 /// filesystem attributes.
 ///
+/// On FreeBSD, this uses `extattr_get_file`. Names beginning with `user.` or
+/// `system.` select the `EXTATTR_NAMESPACE_USER` or
+/// `EXTATTR_NAMESPACE_SYSTEM` namespace, and other names fail with
+/// [`io::Errno::OPNOTSUPP`]. The other `*xattr` functions treat names the
+/// same way.
+///
 /// # References
 ///  - [Linux]
+///  - [FreeBSD]
 ///
 /// [Linux]: https://man7.org/linux/man-pages/man2/getxattr.2.html
+/// [FreeBSD]: https://man.freebsd.org/cgi/man.cgi?query=extattr_get_file&sektion=2
 #[inline]
 pub fn getxattr<P: path::Arg, Name: path::Arg>(
     path: P,
@@ -38,6 +48,33 @@ pub fn getxattr<P: path::Arg, Name: path::Arg>(
     })
 }
 
+/// `getxattr(path, name, value.as_ptr(), value.len())`—Get extended
+/// filesystem attributes, into a `Vec`.
+///
+/// This is equivalent to [`getxattr`], except that it allocates a
+/// buffer large enough for the whole value. If `reuse` already has available
+/// capacity, reuse it if possible.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/getxattr.2.html
+#[cfg(feature = "alloc")]
+#[doc(alias = "getxattr")]
+pub fn getxattr_vec<P: path::Arg, Name: path::Arg, B: Into<Vec<u8>>>(
+    path: P,
+    name: Name,
+    reuse: B,
+) -> io::Result<Vec<u8>> {
+    path.into_with_c_str(|path| {
+        name.into_with_c_str(|name| {
+            with_vec(reuse.into(), |value| {
+                backend::fs::syscalls::getxattr(path, name, value)
+            })
+        })
+    })
+}
+
 /// `lgetxattr(path, name, value.as_ptr(), value.len())`—Get extended
 /// filesystem attributes, without following symlinks in the last path
 /// component.
@@ -57,6 +94,34 @@ pub fn lgetxattr<P: path::Arg, Name: path::Arg>(
     })
 }
 
+/// `lgetxattr(path, name, value.as_ptr(), value.len())`—Get extended
+/// filesystem attributes, without following symlinks in the last path
+/// component, into a `Vec`.
+///
+/// This is equivalent to [`lgetxattr`], except that it allocates a
+/// buffer large enough for the whole value. If `reuse` already has available
+/// capacity, reuse it if possible.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/lgetxattr.2.html
+#[cfg(feature = "alloc")]
+#[doc(alias = "lgetxattr")]
+pub fn lgetxattr_vec<P: path::Arg, Name: path::Arg, B: Into<Vec<u8>>>(
+    path: P,
+    name: Name,
+    reuse: B,
+) -> io::Result<Vec<u8>> {
+    path.into_with_c_str(|path| {
+        name.into_with_c_str(|name| {
+            with_vec(reuse.into(), |value| {
+                backend::fs::syscalls::lgetxattr(path, name, value)
+            })
+        })
+    })
+}
+
 /// `fgetxattr(fd, name, value.as_ptr(), value.len())`—Get extended
 /// filesystem attributes on an open file descriptor.
 ///
@@ -73,13 +138,45 @@ pub fn fgetxattr<Fd: AsFd, Name: path::Arg>(
     name.into_with_c_str(|name| backend::fs::syscalls::fgetxattr(fd.as_fd(), name, value))
 }
 
+/// `fgetxattr(fd, name, value.as_ptr(), value.len())`—Get extended
+/// filesystem attributes on an open file descriptor, into a `Vec`.
+///
+/// This is equivalent to [`fgetxattr`], except that it allocates a
+/// buffer large enough for the whole value. If `reuse` already has available
+/// capacity, reuse it if possible.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/fgetxattr.2.html
+#[cfg(feature = "alloc")]
+#[doc(alias = "fgetxattr")]
+pub fn fgetxattr_vec<Fd: AsFd, Name: path::Arg, B: Into<Vec<u8>>>(
+    fd: Fd,
+    name: Name,
+    reuse: B,
+) -> io::Result<Vec<u8>> {
+    let fd = fd.as_fd();
+    name.into_with_c_str(|name| {
+        with_vec(reuse.into(), |value| {
+            backend::fs::syscalls::fgetxattr(fd, name, value)
+        })
+    })
+}
+
 /// `setxattr(path, name, value.as_ptr(), value.len(), flags)`—Set extended
 /// filesystem attributes.
 ///
+/// On FreeBSD, `extattr_set_file` has no flags, so [`XattrFlags::CREATE`]
+/// and [`XattrFlags::REPLACE`] are emulated by checking whether the
+/// attribute exists first, which isn't atomic.
+///
 /// # References
 ///  - [Linux]
+///  - [FreeBSD]
 ///
 /// [Linux]: https://man7.org/linux/man-pages/man2/setxattr.2.html
+/// [FreeBSD]: https://man.freebsd.org/cgi/man.cgi?query=extattr_set_file&sektion=2
 #[inline]
 pub fn setxattr<P: path::Arg, Name: path::Arg>(
     path: P,
@@ -132,15 +229,44 @@ pub fn fsetxattr<Fd: AsFd, Name: path::Arg>(
 /// `listxattr(path, list.as_ptr(), list.len())`—List extended filesystem
 /// attributes.
 ///
+/// On FreeBSD, this lists the `user` namespace, and the `system` namespace if
+/// the process is privileged, and converts the names into the Linux format
+/// with `user.` and `system.` prefixes. When `list` is empty, the returned
+/// size there may be larger than the size of the list.
+///
 /// # References
 ///  - [Linux]
+///  - [FreeBSD]
 ///
 /// [Linux]: https://man7.org/linux/man-pages/man2/listxattr.2.html
+/// [FreeBSD]: https://man.freebsd.org/cgi/man.cgi?query=extattr_list_file&sektion=2
 #[inline]
 pub fn listxattr<P: path::Arg>(path: P, list: &mut [c::c_char]) -> io::Result<usize> {
     path.into_with_c_str(|path| backend::fs::syscalls::listxattr(path, list))
 }
 
+/// `listxattr(path, list.as_ptr(), list.len())`—List extended filesystem
+/// attributes, into a `Vec`.
+///
+/// This is equivalent to [`listxattr`], except that it allocates a
+/// buffer large enough for the whole list. The names in the list are each
+/// terminated by a NUL byte. If `reuse` already has available capacity,
+/// reuse it if possible.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/listxattr.2.html
+#[cfg(feature = "alloc")]
+#[doc(alias = "listxattr")]
+pub fn listxattr_vec<P: path::Arg, B: Into<Vec<u8>>>(path: P, reuse: B) -> io::Result<Vec<u8>> {
+    path.into_with_c_str(|path| {
+        with_vec(reuse.into(), |list| {
+            backend::fs::syscalls::listxattr(path, as_c_chars(list))
+        })
+    })
+}
+
 /// `llistxattr(path, list.as_ptr(), list.len())`—List extended filesystem
 /// attributes, without following symlinks in the last path component.
 ///
@@ -153,6 +279,29 @@ pub fn llistxattr<P: path::Arg>(path: P, list: &mut [c::c_char]) -> io::Result<u
     path.into_with_c_str(|path| backend::fs::syscalls::llistxattr(path, list))
 }
 
+/// `llistxattr(path, list.as_ptr(), list.len())`—List extended filesystem
+/// attributes, without following symlinks in the last path
+/// component, into a `Vec`.
+///
+/// This is equivalent to [`llistxattr`], except that it allocates a
+/// buffer large enough for the whole list. The names in the list are each
+/// terminated by a NUL byte. If `reuse` already has available capacity,
+/// reuse it if possible.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/llistxattr.2.html
+#[cfg(feature = "alloc")]
+#[doc(alias = "llistxattr")]
+pub fn llistxattr_vec<P: path::Arg, B: Into<Vec<u8>>>(path: P, reuse: B) -> io::Result<Vec<u8>> {
+    path.into_with_c_str(|path| {
+        with_vec(reuse.into(), |list| {
+            backend::fs::syscalls::llistxattr(path, as_c_chars(list))
+        })
+    })
+}
+
 /// `flistxattr(fd, list.as_ptr(), list.len())`—List extended filesystem
 /// attributes on an open file descriptor.
 ///
@@ -165,6 +314,27 @@ pub fn flistxattr<Fd: AsFd>(fd: Fd, list: &mut [c::c_char]) -> io::Result<usize>
     backend::fs::syscalls::flistxattr(fd.as_fd(), list)
 }
 
+/// `flistxattr(fd, list.as_ptr(), list.len())`—List extended filesystem
+/// attributes on an open file descriptor, into a `Vec`.
+///
+/// This is equivalent to [`flistxattr`], except that it allocates a
+/// buffer large enough for the whole list. The names in the list are each
+/// terminated by a NUL byte. If `reuse` already has available capacity,
+/// reuse it if possible.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/flistxattr.2.html
+#[cfg(feature = "alloc")]
+#[doc(alias = "flistxattr")]
+pub fn flistxattr_vec<Fd: AsFd, B: Into<Vec<u8>>>(fd: Fd, reuse: B) -> io::Result<Vec<u8>> {
+    let fd = fd.as_fd();
+    with_vec(reuse.into(), |list| {
+        backend::fs::syscalls::flistxattr(fd, as_c_chars(list))
+    })
+}
+
 /// `removexattr(path, name)`—Remove an extended filesystem attribute.
 ///
 /// # References
@@ -200,3 +370,41 @@ pub fn lremovexattr<P: path::Arg, Name: path::Arg>(path: P, name: Name) -> io::R
 pub fn fremovexattr<Fd: AsFd, Name: path::Arg>(fd: Fd, name: Name) -> io::Result<()> {
     name.into_with_c_str(|name| backend::fs::syscalls::fremovexattr(fd.as_fd(), name))
 }
+
+/// Calls `f` with a buffer large enough for the value it produces, retrying
+/// if the value grows between querying its size and reading it.
+#[cfg(feature = "alloc")]
+fn with_vec(
+    mut buffer: Vec<u8>,
+    mut f: impl FnMut(&mut [u8]) -> io::Result<usize>,
+) -> io::Result<Vec<u8>> {
+    buffer.clear();
+    buffer.resize(buffer.capacity(), 0);
+
+    loop {
+        if buffer.is_empty() {
+            // Passing an empty buffer queries the size of the value.
+            let len = f(&mut [])?;
+            if len == 0 {
+                return Ok(buffer);
+            }
+            buffer.resize(len, 0);
+        }
+
+        match f(&mut buffer) {
+            Ok(len) => {
+                buffer.truncate(len);
+                return Ok(buffer);
+            }
+            Err(io::Errno::RANGE) => buffer.clear(),
+            Err(err) => return Err(err),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[allow(unsafe_code)]
+fn as_c_chars(buf: &mut [u8]) -> &mut [c::c_char] {
+    // SAFETY: `c_char` is either `u8` or `i8`, which have the same layout.
+    unsafe { core::slice::from_raw_parts_mut(buf.as_mut_ptr().cast::<c::c_char>(), buf.len()) }
+}
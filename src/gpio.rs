@@ -0,0 +1,489 @@
+//! Linux GPIO character device functions, using the v2 uAPI.
+//!
+//! A GPIO chip is opened from a device node such as `/dev/gpiochip0`. Lines
+//! of the chip are requested with [`ioctl_get_line`], which returns a new
+//! file descriptor through which the lines' values are read and written,
+//! their configuration is changed, and, for lines with edge detection
+//! enabled, edge events are read with [`read_line_events`].
+//!
+//! # References
+//!  - [Linux]
+//!
+//! [Linux]: https://docs.kernel.org/userspace-api/gpio/chardev.html
+
+#![allow(unsafe_code)]
+
+use crate::fd::{AsFd, FromRawFd, OwnedFd};
+use crate::{io, ioctl};
+use bitflags::bitflags;
+use core::mem::{size_of, size_of_val};
+use core::slice;
+
+/// `GPIO_V2_LINES_MAX`
+const GPIO_V2_LINES_MAX: usize = 64;
+
+/// `GPIO_MAX_NAME_SIZE`
+const GPIO_MAX_NAME_SIZE: usize = 32;
+
+/// `GPIO_V2_LINE_NUM_ATTRS_MAX`
+const GPIO_V2_LINE_NUM_ATTRS_MAX: usize = 10;
+
+// `GPIO_V2_LINE_ATTR_ID_*`
+const GPIO_V2_LINE_ATTR_ID_FLAGS: u32 = 1;
+const GPIO_V2_LINE_ATTR_ID_OUTPUT_VALUES: u32 = 2;
+const GPIO_V2_LINE_ATTR_ID_DEBOUNCE: u32 = 3;
+
+bitflags! {
+    /// `GPIO_V2_LINE_FLAG_*` constants for use with [`LineConfig`].
+    #[repr(transparent)]
+    #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    pub struct LineFlags: u64 {
+        /// `GPIO_V2_LINE_FLAG_USED`—The line is in use.
+        const USED = 1 << 0;
+        /// `GPIO_V2_LINE_FLAG_ACTIVE_LOW`—The line's logical value is the
+        /// inverse of its physical level.
+        const ACTIVE_LOW = 1 << 1;
+        /// `GPIO_V2_LINE_FLAG_INPUT`
+        const INPUT = 1 << 2;
+        /// `GPIO_V2_LINE_FLAG_OUTPUT`
+        const OUTPUT = 1 << 3;
+        /// `GPIO_V2_LINE_FLAG_EDGE_RISING`—Report rising edges, for input
+        /// lines.
+        const EDGE_RISING = 1 << 4;
+        /// `GPIO_V2_LINE_FLAG_EDGE_FALLING`—Report falling edges, for input
+        /// lines.
+        const EDGE_FALLING = 1 << 5;
+        /// `GPIO_V2_LINE_FLAG_OPEN_DRAIN`
+        const OPEN_DRAIN = 1 << 6;
+        /// `GPIO_V2_LINE_FLAG_OPEN_SOURCE`
+        const OPEN_SOURCE = 1 << 7;
+        /// `GPIO_V2_LINE_FLAG_BIAS_PULL_UP`
+        const BIAS_PULL_UP = 1 << 8;
+        /// `GPIO_V2_LINE_FLAG_BIAS_PULL_DOWN`
+        const BIAS_PULL_DOWN = 1 << 9;
+        /// `GPIO_V2_LINE_FLAG_BIAS_DISABLED`
+        const BIAS_DISABLED = 1 << 10;
+        /// `GPIO_V2_LINE_FLAG_EVENT_CLOCK_REALTIME`—Timestamp events with
+        /// `CLOCK_REALTIME` instead of `CLOCK_MONOTONIC`.
+        const EVENT_CLOCK_REALTIME = 1 << 11;
+        /// `GPIO_V2_LINE_FLAG_EVENT_CLOCK_HTE`—Timestamp events with the
+        /// hardware timestamp engine.
+        const EVENT_CLOCK_HTE = 1 << 12;
+
+        /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+        const _ = !0;
+    }
+}
+
+/// `struct gpiochip_info`—A description of a GPIO chip, returned by
+/// [`ioctl_get_chipinfo`].
+#[repr(C)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct ChipInfo {
+    /// The name of the chip, NUL-terminated.
+    pub name: [u8; GPIO_MAX_NAME_SIZE],
+
+    /// A functional label for the chip, NUL-terminated.
+    pub label: [u8; GPIO_MAX_NAME_SIZE],
+
+    /// The number of lines the chip has.
+    pub lines: u32,
+}
+
+/// `struct gpio_v2_line_attribute`
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+struct LineAttribute {
+    id: u32,
+    padding: u32,
+    /// The `flags`, `values`, or `debounce_period_us` member of the union,
+    /// depending on `id`.
+    value: u64,
+}
+
+/// `struct gpio_v2_line_config_attribute`
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+struct LineConfigAttribute {
+    attr: LineAttribute,
+    mask: u64,
+}
+
+/// `struct gpio_v2_line_config`—The configuration of requested lines, for
+/// use with [`ioctl_get_line`] and [`ioctl_line_set_config`].
+///
+/// The flags given to [`LineConfig::new`] apply to all lines, except where
+/// overridden for some lines with [`LineConfig::add_flags`]. Lines are
+/// selected with a bitmask, in which bit `n` is the `n`th requested line.
+#[repr(C)]
+#[derive(Clone, Debug)]
+pub struct LineConfig {
+    flags: u64,
+    num_attrs: u32,
+    padding: [u32; 5],
+    attrs: [LineConfigAttribute; GPIO_V2_LINE_NUM_ATTRS_MAX],
+}
+
+impl LineConfig {
+    /// Constructs a `LineConfig` that applies `flags` to all lines.
+    #[inline]
+    pub const fn new(flags: LineFlags) -> Self {
+        Self {
+            flags: flags.bits(),
+            num_attrs: 0,
+            padding: [0; 5],
+            attrs: [LineConfigAttribute {
+                attr: LineAttribute {
+                    id: 0,
+                    padding: 0,
+                    value: 0,
+                },
+                mask: 0,
+            }; GPIO_V2_LINE_NUM_ATTRS_MAX],
+        }
+    }
+
+    fn add_attr(&mut self, id: u32, value: u64, mask: u64) -> io::Result<()> {
+        let attr = self
+            .attrs
+            .get_mut(self.num_attrs as usize)
+            .ok_or(io::Errno::INVAL)?;
+        *attr = LineConfigAttribute {
+            attr: LineAttribute {
+                id,
+                padding: 0,
+                value,
+            },
+            mask,
+        };
+        self.num_attrs += 1;
+        Ok(())
+    }
+
+    /// Overrides the flags for the lines in `mask`.
+    ///
+    /// A configuration has room for 10 overrides; this fails with `INVAL`
+    /// when they are used up.
+    #[inline]
+    pub fn add_flags(&mut self, flags: LineFlags, mask: u64) -> io::Result<()> {
+        self.add_attr(GPIO_V2_LINE_ATTR_ID_FLAGS, flags.bits(), mask)
+    }
+
+    /// Sets the initial values of the output lines in `mask`, where bit `n`
+    /// of `values` is the value of the `n`th line.
+    ///
+    /// A configuration has room for 10 overrides; this fails with `INVAL`
+    /// when they are used up.
+    #[inline]
+    pub fn add_output_values(&mut self, values: u64, mask: u64) -> io::Result<()> {
+        self.add_attr(GPIO_V2_LINE_ATTR_ID_OUTPUT_VALUES, values, mask)
+    }
+
+    /// Sets the debounce period of the input lines in `mask`, in
+    /// microseconds.
+    ///
+    /// A configuration has room for 10 overrides; this fails with `INVAL`
+    /// when they are used up.
+    #[inline]
+    pub fn add_debounce_period_us(&mut self, period_us: u32, mask: u64) -> io::Result<()> {
+        // `debounce_period_us` is a `__u32` member of a union whose other
+        // members are `__u64`s.
+        #[cfg(target_endian = "little")]
+        let value = u64::from(period_us);
+        #[cfg(target_endian = "big")]
+        let value = u64::from(period_us) << 32;
+        self.add_attr(GPIO_V2_LINE_ATTR_ID_DEBOUNCE, value, mask)
+    }
+}
+
+/// `struct gpio_v2_line_request`
+#[repr(C)]
+struct LineRequest {
+    offsets: [u32; GPIO_V2_LINES_MAX],
+    consumer: [u8; GPIO_MAX_NAME_SIZE],
+    config: LineConfig,
+    num_lines: u32,
+    event_buffer_size: u32,
+    padding: [u32; 5],
+    fd: i32,
+}
+
+/// `struct gpio_v2_line_values`
+#[repr(C)]
+struct LineValues {
+    bits: u64,
+    mask: u64,
+}
+
+/// `GPIO_V2_LINE_EVENT_*`—The kind of an edge event.
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct LineEventId(u32);
+
+impl LineEventId {
+    /// `GPIO_V2_LINE_EVENT_RISING_EDGE`
+    #[doc(alias = "GPIO_V2_LINE_EVENT_RISING_EDGE")]
+    pub const RISING_EDGE: Self = Self(1);
+
+    /// `GPIO_V2_LINE_EVENT_FALLING_EDGE`
+    #[doc(alias = "GPIO_V2_LINE_EVENT_FALLING_EDGE")]
+    pub const FALLING_EDGE: Self = Self(2);
+
+    /// Constructs a `LineEventId` from a raw `GPIO_V2_LINE_EVENT_*` value.
+    #[inline]
+    pub const fn from_raw(raw: u32) -> Self {
+        Self(raw)
+    }
+
+    /// Returns the raw `GPIO_V2_LINE_EVENT_*` value.
+    #[inline]
+    pub const fn as_raw(self) -> u32 {
+        self.0
+    }
+}
+
+/// `struct gpio_v2_line_event`—An edge event read with
+/// [`read_line_events`].
+#[repr(C)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct LineEvent {
+    /// The time of the event, in nanoseconds.
+    pub timestamp_ns: u64,
+
+    /// The kind of the event.
+    pub id: LineEventId,
+
+    /// The offset of the line on the chip.
+    pub offset: u32,
+
+    /// The sequence number of the event among all requested lines.
+    pub seqno: u32,
+
+    /// The sequence number of the event on this line.
+    pub line_seqno: u32,
+
+    padding: [u32; 6],
+}
+
+/// `GPIO_GET_CHIPINFO_IOCTL`
+type GetChipInfo = ioctl::ReadOpcode<0xb4, 0x01, ChipInfo>;
+/// `GPIO_V2_GET_LINE_IOCTL`
+type GetLine = ioctl::ReadWriteOpcode<0xb4, 0x07, LineRequest>;
+/// `GPIO_V2_LINE_SET_CONFIG_IOCTL`
+type LineSetConfig = ioctl::ReadWriteOpcode<0xb4, 0x0d, LineConfig>;
+/// `GPIO_V2_LINE_GET_VALUES_IOCTL`
+type LineGetValues = ioctl::ReadWriteOpcode<0xb4, 0x0e, LineValues>;
+/// `GPIO_V2_LINE_SET_VALUES_IOCTL`
+type LineSetValues = ioctl::ReadWriteOpcode<0xb4, 0x0f, LineValues>;
+
+/// `ioctl(fd, GPIO_GET_CHIPINFO_IOCTL)`—Returns a description of a GPIO
+/// chip.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/userspace-api/gpio/gpio-get-chipinfo-ioctl.html
+#[inline]
+#[doc(alias = "GPIO_GET_CHIPINFO_IOCTL")]
+pub fn ioctl_get_chipinfo<Fd: AsFd>(fd: Fd) -> io::Result<ChipInfo> {
+    // SAFETY: `GPIO_GET_CHIPINFO_IOCTL` is a getter opcode that gets a
+    // `struct gpiochip_info`.
+    unsafe {
+        let ctl = ioctl::Getter::<GetChipInfo, ChipInfo>::new();
+        ioctl::ioctl(fd, ctl)
+    }
+}
+
+/// `ioctl(fd, GPIO_V2_GET_LINE_IOCTL, request)`—Requests lines of a GPIO
+/// chip, and returns a file descriptor for them.
+///
+/// `offsets` are the offsets of the lines on the chip, of which there may be
+/// up to 64. `consumer` is a label for the user of the lines, which must be
+/// shorter than 32 bytes. `event_buffer_size` is the number of edge events
+/// the kernel buffers, or 0 for the default.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/userspace-api/gpio/gpio-v2-get-line-ioctl.html
+#[inline]
+#[doc(alias = "GPIO_V2_GET_LINE_IOCTL")]
+pub fn ioctl_get_line<Fd: AsFd>(
+    fd: Fd,
+    offsets: &[u32],
+    consumer: &str,
+    config: &LineConfig,
+    event_buffer_size: u32,
+) -> io::Result<OwnedFd> {
+    let consumer = consumer.as_bytes();
+    if offsets.is_empty()
+        || offsets.len() > GPIO_V2_LINES_MAX
+        || consumer.len() >= GPIO_MAX_NAME_SIZE
+        || consumer.contains(&0)
+    {
+        return Err(io::Errno::INVAL);
+    }
+
+    let mut request = LineRequest {
+        offsets: [0; GPIO_V2_LINES_MAX],
+        consumer: [0; GPIO_MAX_NAME_SIZE],
+        config: config.clone(),
+        num_lines: offsets.len() as u32,
+        event_buffer_size,
+        padding: [0; 5],
+        fd: -1,
+    };
+    request.offsets[..offsets.len()].copy_from_slice(offsets);
+    request.consumer[..consumer.len()].copy_from_slice(consumer);
+
+    // SAFETY: `GPIO_V2_GET_LINE_IOCTL` reads and writes a
+    // `struct gpio_v2_line_request`, and on success, `fd` is a new file
+    // descriptor that we own.
+    unsafe {
+        let ctl = ioctl::Updater::<GetLine, LineRequest>::new(&mut request);
+        ioctl::ioctl(fd, ctl)?;
+        Ok(OwnedFd::from_raw_fd(request.fd))
+    }
+}
+
+/// `ioctl(fd, GPIO_V2_LINE_SET_CONFIG_IOCTL, config)`—Changes the
+/// configuration of requested lines.
+///
+/// `fd` must be a file descriptor returned by [`ioctl_get_line`].
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/userspace-api/gpio/gpio-v2-line-set-config-ioctl.html
+#[inline]
+#[doc(alias = "GPIO_V2_LINE_SET_CONFIG_IOCTL")]
+pub fn ioctl_line_set_config<Fd: AsFd>(fd: Fd, config: &LineConfig) -> io::Result<()> {
+    let mut config = config.clone();
+    // SAFETY: `GPIO_V2_LINE_SET_CONFIG_IOCTL` reads and writes a
+    // `struct gpio_v2_line_config`.
+    unsafe {
+        let ctl = ioctl::Updater::<LineSetConfig, LineConfig>::new(&mut config);
+        ioctl::ioctl(fd, ctl)
+    }
+}
+
+/// `ioctl(fd, GPIO_V2_LINE_GET_VALUES_IOCTL, values)`—Reads the values of
+/// the requested lines in `mask`.
+///
+/// `fd` must be a file descriptor returned by [`ioctl_get_line`]. Bit `n` of
+/// the result is the value of the `n`th requested line.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/userspace-api/gpio/gpio-v2-line-get-values-ioctl.html
+#[inline]
+#[doc(alias = "GPIO_V2_LINE_GET_VALUES_IOCTL")]
+pub fn ioctl_line_get_values<Fd: AsFd>(fd: Fd, mask: u64) -> io::Result<u64> {
+    let mut values = LineValues { bits: 0, mask };
+    // SAFETY: `GPIO_V2_LINE_GET_VALUES_IOCTL` reads and writes a
+    // `struct gpio_v2_line_values`.
+    unsafe {
+        let ctl = ioctl::Updater::<LineGetValues, LineValues>::new(&mut values);
+        ioctl::ioctl(fd, ctl)?;
+    }
+    Ok(values.bits & mask)
+}
+
+/// `ioctl(fd, GPIO_V2_LINE_SET_VALUES_IOCTL, values)`—Sets the values of the
+/// requested output lines in `mask`.
+///
+/// `fd` must be a file descriptor returned by [`ioctl_get_line`]. Bit `n` of
+/// `bits` is the value of the `n`th requested line.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/userspace-api/gpio/gpio-v2-line-set-values-ioctl.html
+#[inline]
+#[doc(alias = "GPIO_V2_LINE_SET_VALUES_IOCTL")]
+pub fn ioctl_line_set_values<Fd: AsFd>(fd: Fd, bits: u64, mask: u64) -> io::Result<()> {
+    let mut values = LineValues { bits, mask };
+    // SAFETY: `GPIO_V2_LINE_SET_VALUES_IOCTL` reads and writes a
+    // `struct gpio_v2_line_values`.
+    unsafe {
+        let ctl = ioctl::Updater::<LineSetValues, LineValues>::new(&mut values);
+        ioctl::ioctl(fd, ctl)
+    }
+}
+
+/// `read(fd, events)`—Reads edge events from requested lines.
+///
+/// `fd` must be a file descriptor returned by [`ioctl_get_line`]. The kernel
+/// always returns whole events, so this returns the number of events read
+/// into `events`.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/userspace-api/gpio/gpio-v2-line-event-read.html
+#[inline]
+pub fn read_line_events<Fd: AsFd>(fd: Fd, events: &mut [LineEvent]) -> io::Result<usize> {
+    // SAFETY: `LineEvent` is a plain `repr(C)` struct with no implicit
+    // padding, for which any byte pattern is valid.
+    let buf =
+        unsafe { slice::from_raw_parts_mut(events.as_mut_ptr().cast::<u8>(), size_of_val(events)) };
+    let nread = io::read(fd, buf)?;
+    Ok(nread / size_of::<LineEvent>())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ioctl::{CompileTimeOpcode, RawOpcode};
+    use core::mem::{align_of, size_of};
+
+    #[cfg(not(any(
+        // These have no ioctl opcodes defined in linux_raw_sys.
+        target_arch = "sparc",
+        target_arch = "sparc64"
+    )))]
+    #[test]
+    fn gpio_opcodes() {
+        use linux_raw_sys::ioctl as sys;
+
+        const_assert_eq!(
+            GetChipInfo::OPCODE.raw(),
+            sys::GPIO_GET_CHIPINFO_IOCTL as RawOpcode
+        );
+        const_assert_eq!(
+            GetLine::OPCODE.raw(),
+            sys::GPIO_V2_GET_LINE_IOCTL as RawOpcode
+        );
+        const_assert_eq!(
+            LineSetConfig::OPCODE.raw(),
+            sys::GPIO_V2_LINE_SET_CONFIG_IOCTL as RawOpcode
+        );
+        const_assert_eq!(
+            LineGetValues::OPCODE.raw(),
+            sys::GPIO_V2_LINE_GET_VALUES_IOCTL as RawOpcode
+        );
+        const_assert_eq!(
+            LineSetValues::OPCODE.raw(),
+            sys::GPIO_V2_LINE_SET_VALUES_IOCTL as RawOpcode
+        );
+    }
+
+    #[test]
+    fn gpio_layouts() {
+        const_assert_eq!(size_of::<ChipInfo>(), 68);
+        const_assert_eq!(align_of::<ChipInfo>(), 4);
+
+        // These use `__aligned_u64` in the uapi headers, but `u64` here, so
+        // they have the same size, and the alignment of `u64`.
+        const_assert_eq!(size_of::<LineAttribute>(), 16);
+        const_assert_eq!(size_of::<LineConfigAttribute>(), 24);
+        const_assert_eq!(size_of::<LineConfig>(), 272);
+        const_assert_eq!(size_of::<LineRequest>(), 592);
+        const_assert_eq!(size_of::<LineValues>(), 16);
+        const_assert_eq!(size_of::<LineEvent>(), 48);
+        const_assert_eq!(align_of::<LineConfig>(), align_of::<u64>());
+        const_assert_eq!(align_of::<LineRequest>(), align_of::<u64>());
+        const_assert_eq!(align_of::<LineEvent>(), align_of::<u64>());
+    }
+}
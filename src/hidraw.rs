@@ -0,0 +1,318 @@
+//! Linux raw HID device (hidraw) functions.
+//!
+//! The functions in this module take a file descriptor opened from a hidraw
+//! device node such as `/dev/hidraw0`. Input reports are received with
+//! [`read`] and output reports are sent with [`write`]; in both cases the
+//! first byte is the report number if the device uses numbered reports.
+//!
+//! # References
+//!  - [Linux]
+//!
+//! [Linux]: https://docs.kernel.org/hid/hidraw.html
+//! [`read`]: crate::io::read
+//! [`write`]: crate::io::write
+
+#![allow(unsafe_code)]
+
+use crate::backend::c;
+use crate::fd::{AsFd, BorrowedFd};
+use crate::{io, ioctl};
+use core::fmt;
+
+/// The largest buffer size that can be encoded in an `ioctl` opcode on all
+/// architectures; some only have 13 bits for it.
+const MAX_IOCTL_SIZE: usize = 0x1fff;
+
+/// `HID_MAX_DESCRIPTOR_SIZE`
+const HID_MAX_DESCRIPTOR_SIZE: usize = 4096;
+
+/// `struct hidraw_devinfo`—The bus type, vendor, and product of a HID
+/// device, returned by [`ioctl_hidiocgrawinfo`].
+#[repr(C)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct DevInfo {
+    /// The bus type, one of the `BUS_*` values, such as `BUS_USB` (3) or
+    /// `BUS_BLUETOOTH` (5).
+    pub bustype: u32,
+
+    /// The vendor ID.
+    pub vendor: u16,
+
+    /// The product ID.
+    pub product: u16,
+}
+
+/// `struct hidraw_report_descriptor`—The report descriptor of a HID device,
+/// returned by [`ioctl_hidiocgrdesc`].
+#[repr(C)]
+#[derive(Clone)]
+pub struct ReportDescriptor {
+    size: u32,
+    value: [u8; HID_MAX_DESCRIPTOR_SIZE],
+}
+
+impl ReportDescriptor {
+    /// Returns the bytes of the report descriptor.
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.value[..self.size as usize]
+    }
+}
+
+impl fmt::Debug for ReportDescriptor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ReportDescriptor")
+            .field(&self.as_bytes())
+            .finish()
+    }
+}
+
+/// `HIDIOCGRDESCSIZE`
+type GRDescSize = ioctl::ReadOpcode<b'H', 0x01, c::c_int>;
+/// `HIDIOCGRDESC`
+type GRDesc = ioctl::ReadOpcode<b'H', 0x02, ReportDescriptor>;
+/// `HIDIOCGRAWINFO`
+type GRawInfo = ioctl::ReadOpcode<b'H', 0x03, DevInfo>;
+
+/// `ioctl(fd, HIDIOCGRDESCSIZE)`—Returns the size of the report descriptor
+/// of a HID device.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/hid/hidraw.html
+#[inline]
+#[doc(alias = "HIDIOCGRDESCSIZE")]
+pub fn ioctl_hidiocgrdescsize<Fd: AsFd>(fd: Fd) -> io::Result<usize> {
+    // SAFETY: `HIDIOCGRDESCSIZE` is a getter opcode that gets a `c_int`.
+    unsafe {
+        let ctl = ioctl::Getter::<GRDescSize, c::c_int>::new();
+        ioctl::ioctl(fd, ctl).map(|size| size as usize)
+    }
+}
+
+/// `ioctl(fd, HIDIOCGRDESC)`—Returns the report descriptor of a HID device.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/hid/hidraw.html
+#[inline]
+#[doc(alias = "HIDIOCGRDESC")]
+pub fn ioctl_hidiocgrdesc<Fd: AsFd>(fd: Fd) -> io::Result<ReportDescriptor> {
+    let fd = fd.as_fd();
+
+    // The kernel copies as many bytes as we ask for, up to the size of the
+    // descriptor, and rejects sizes that don't leave room in `value`.
+    let size = ioctl_hidiocgrdescsize(fd)?.min(HID_MAX_DESCRIPTOR_SIZE - 1);
+    let mut desc = ReportDescriptor {
+        size: size as u32,
+        value: [0; HID_MAX_DESCRIPTOR_SIZE],
+    };
+    // SAFETY: `HIDIOCGRDESC` reads the size from a
+    // `struct hidraw_report_descriptor` and writes at most that many bytes
+    // to its `value` field.
+    unsafe {
+        let ctl = ioctl::Updater::<GRDesc, _>::new(&mut desc);
+        ioctl::ioctl(fd, ctl)?;
+    }
+    Ok(desc)
+}
+
+/// `ioctl(fd, HIDIOCGRAWINFO)`—Returns the bus type, vendor, and product of
+/// a HID device.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/hid/hidraw.html
+#[inline]
+#[doc(alias = "HIDIOCGRAWINFO")]
+pub fn ioctl_hidiocgrawinfo<Fd: AsFd>(fd: Fd) -> io::Result<DevInfo> {
+    // SAFETY: `HIDIOCGRAWINFO` is a getter opcode that gets a
+    // `struct hidraw_devinfo`.
+    unsafe {
+        let ctl = ioctl::Getter::<GRawInfo, DevInfo>::new();
+        ioctl::ioctl(fd, ctl)
+    }
+}
+
+/// `HIDIOCGRAWNAME(len)`
+const fn hidiocgrawname(len: usize) -> ioctl::Opcode {
+    ioctl::Opcode::from_components(ioctl::Direction::Read, b'H', 0x04, len)
+}
+
+/// `HIDIOCGRAWPHYS(len)`
+const fn hidiocgrawphys(len: usize) -> ioctl::Opcode {
+    ioctl::Opcode::from_components(ioctl::Direction::Read, b'H', 0x05, len)
+}
+
+/// `HIDIOCSFEATURE(len)`
+const fn hidiocsfeature(len: usize) -> ioctl::Opcode {
+    ioctl::Opcode::from_components(ioctl::Direction::ReadWrite, b'H', 0x06, len)
+}
+
+/// `HIDIOCGFEATURE(len)`
+const fn hidiocgfeature(len: usize) -> ioctl::Opcode {
+    ioctl::Opcode::from_components(ioctl::Direction::ReadWrite, b'H', 0x07, len)
+}
+
+/// Performs a hidraw `ioctl` whose opcode encodes the length of `buf`.
+///
+/// # Safety
+///
+/// `opcode` must be a hidraw `ioctl` opcode that reads from or writes to at
+/// most `len` bytes of `buf`.
+unsafe fn hidraw_buf_ioctl(
+    fd: BorrowedFd<'_>,
+    opcode: fn(usize) -> ioctl::Opcode,
+    buf: *mut u8,
+    len: usize,
+) -> io::Result<usize> {
+    let opcode = opcode(len.min(MAX_IOCTL_SIZE));
+    ioctl::ioctl_with_opcode(fd, opcode, buf.cast()).map(|len| len as usize)
+}
+
+/// `ioctl(fd, HIDIOCGRAWNAME(len), buf)`—Reads the name of a HID device.
+///
+/// On success, returns the number of bytes written to `buf`, including the
+/// NUL terminator if it fit.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/hid/hidraw.html
+#[inline]
+#[doc(alias = "HIDIOCGRAWNAME")]
+pub fn ioctl_hidiocgrawname<Fd: AsFd>(fd: Fd, buf: &mut [u8]) -> io::Result<usize> {
+    // SAFETY: `HIDIOCGRAWNAME` writes at most the number of bytes encoded in
+    // the opcode into the buffer.
+    unsafe { hidraw_buf_ioctl(fd.as_fd(), hidiocgrawname, buf.as_mut_ptr(), buf.len()) }
+}
+
+/// `ioctl(fd, HIDIOCGRAWPHYS(len), buf)`—Reads the physical location of a
+/// HID device.
+///
+/// On success, returns the number of bytes written to `buf`, including the
+/// NUL terminator if it fit.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/hid/hidraw.html
+#[inline]
+#[doc(alias = "HIDIOCGRAWPHYS")]
+pub fn ioctl_hidiocgrawphys<Fd: AsFd>(fd: Fd, buf: &mut [u8]) -> io::Result<usize> {
+    // SAFETY: `HIDIOCGRAWPHYS` writes at most the number of bytes encoded in
+    // the opcode into the buffer.
+    unsafe { hidraw_buf_ioctl(fd.as_fd(), hidiocgrawphys, buf.as_mut_ptr(), buf.len()) }
+}
+
+/// `ioctl(fd, HIDIOCSFEATURE(len), report)`—Sends a feature report to a HID
+/// device.
+///
+/// The first byte of `report` is the report number, or 0 if the device
+/// doesn't use numbered reports.
+///
+/// On success, returns the number of bytes sent.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/hid/hidraw.html
+#[inline]
+#[doc(alias = "HIDIOCSFEATURE")]
+pub fn ioctl_hidiocsfeature<Fd: AsFd>(fd: Fd, report: &[u8]) -> io::Result<usize> {
+    if report.is_empty() || report.len() > MAX_IOCTL_SIZE {
+        return Err(io::Errno::INVAL);
+    }
+
+    // SAFETY: `HIDIOCSFEATURE` only reads the number of bytes encoded in the
+    // opcode from the buffer.
+    unsafe {
+        hidraw_buf_ioctl(
+            fd.as_fd(),
+            hidiocsfeature,
+            report.as_ptr() as *mut u8,
+            report.len(),
+        )
+    }
+}
+
+/// `ioctl(fd, HIDIOCGFEATURE(len), report)`—Receives a feature report from
+/// a HID device.
+///
+/// The first byte of `report` must be set to the report number to receive,
+/// or 0 if the device doesn't use numbered reports. The received report,
+/// starting with the report number, is written to `report`.
+///
+/// On success, returns the number of bytes received.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/hid/hidraw.html
+#[inline]
+#[doc(alias = "HIDIOCGFEATURE")]
+pub fn ioctl_hidiocgfeature<Fd: AsFd>(fd: Fd, report: &mut [u8]) -> io::Result<usize> {
+    if report.is_empty() {
+        return Err(io::Errno::INVAL);
+    }
+
+    // SAFETY: `HIDIOCGFEATURE` reads the report number from the buffer and
+    // writes at most the number of bytes encoded in the opcode into it.
+    unsafe {
+        hidraw_buf_ioctl(
+            fd.as_fd(),
+            hidiocgfeature,
+            report.as_mut_ptr(),
+            report.len(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ioctl::{CompileTimeOpcode, RawOpcode};
+    use core::mem::{align_of, size_of};
+
+    #[cfg(not(any(
+        // These have no ioctl opcodes defined in linux_raw_sys.
+        target_arch = "sparc",
+        target_arch = "sparc64"
+    )))]
+    #[test]
+    fn hidraw_opcodes() {
+        use linux_raw_sys::ioctl as sys;
+
+        const_assert_eq!(GRDescSize::OPCODE.raw(), sys::HIDIOCGRDESCSIZE as RawOpcode);
+        const_assert_eq!(GRDesc::OPCODE.raw(), sys::HIDIOCGRDESC as RawOpcode);
+        const_assert_eq!(GRawInfo::OPCODE.raw(), sys::HIDIOCGRAWINFO as RawOpcode);
+    }
+
+    // `linux_raw_sys` doesn't have the opcodes that take a length, so these
+    // are the values of the uapi macros with the generic `_IOC` encoding.
+    #[cfg(any(
+        target_arch = "aarch64",
+        target_arch = "arm",
+        target_arch = "riscv64",
+        target_arch = "x86",
+        target_arch = "x86_64",
+    ))]
+    #[test]
+    fn hidraw_opcodes_with_length() {
+        const_assert_eq!(hidiocgrawname(256).raw(), 0x8100_4804);
+        const_assert_eq!(hidiocgrawphys(64).raw(), 0x8040_4805);
+        const_assert_eq!(hidiocsfeature(16).raw(), 0xc010_4806);
+        const_assert_eq!(hidiocgfeature(16).raw(), 0xc010_4807);
+    }
+
+    #[test]
+    fn hidraw_layouts() {
+        const_assert_eq!(size_of::<DevInfo>(), 8);
+        const_assert_eq!(align_of::<DevInfo>(), 4);
+        const_assert_eq!(size_of::<ReportDescriptor>(), 4100);
+        const_assert_eq!(align_of::<ReportDescriptor>(), 4);
+    }
+}
@@ -0,0 +1,286 @@
+//! Linux I2C device functions.
+//!
+//! The functions in this module take a file descriptor opened from an I2C
+//! adapter device node such as `/dev/i2c-0`. Simple devices can be talked to
+//! by selecting a target address with [`ioctl_i2c_slave`] and then using
+//! [`read`] and [`write`]; combined transactions, such as a register write
+//! followed by a read with a repeated start, are performed with
+//! [`ioctl_i2c_rdwr`].
+//!
+//! # References
+//!  - [Linux]
+//!
+//! [Linux]: https://docs.kernel.org/i2c/dev-interface.html
+//! [`read`]: crate::io::read
+//! [`write`]: crate::io::write
+
+#![allow(unsafe_code)]
+
+use crate::backend::c;
+use crate::fd::AsFd;
+use crate::{io, ioctl};
+use bitflags::bitflags;
+use core::marker::PhantomData;
+
+/// `I2C_RDWR_IOCTL_MAX_MSGS`
+const I2C_RDWR_IOCTL_MAX_MSGS: usize = 42;
+
+bitflags! {
+    /// `I2C_FUNC_*` constants returned by [`ioctl_i2c_funcs`].
+    #[repr(transparent)]
+    #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    pub struct Functionality: u64 {
+        /// `I2C_FUNC_I2C`—The adapter supports plain I2C transfers with
+        /// [`ioctl_i2c_rdwr`].
+        const I2C = 0x0000_0001;
+        /// `I2C_FUNC_10BIT_ADDR`
+        const TEN_BIT_ADDR = 0x0000_0002;
+        /// `I2C_FUNC_PROTOCOL_MANGLING`
+        const PROTOCOL_MANGLING = 0x0000_0004;
+        /// `I2C_FUNC_SMBUS_PEC`
+        const SMBUS_PEC = 0x0000_0008;
+        /// `I2C_FUNC_NOSTART`
+        const NOSTART = 0x0000_0010;
+        /// `I2C_FUNC_SLAVE`
+        const SLAVE = 0x0000_0020;
+        /// `I2C_FUNC_SMBUS_BLOCK_PROC_CALL`
+        const SMBUS_BLOCK_PROC_CALL = 0x0000_8000;
+        /// `I2C_FUNC_SMBUS_QUICK`
+        const SMBUS_QUICK = 0x0001_0000;
+        /// `I2C_FUNC_SMBUS_READ_BYTE`
+        const SMBUS_READ_BYTE = 0x0002_0000;
+        /// `I2C_FUNC_SMBUS_WRITE_BYTE`
+        const SMBUS_WRITE_BYTE = 0x0004_0000;
+        /// `I2C_FUNC_SMBUS_READ_BYTE_DATA`
+        const SMBUS_READ_BYTE_DATA = 0x0008_0000;
+        /// `I2C_FUNC_SMBUS_WRITE_BYTE_DATA`
+        const SMBUS_WRITE_BYTE_DATA = 0x0010_0000;
+        /// `I2C_FUNC_SMBUS_READ_WORD_DATA`
+        const SMBUS_READ_WORD_DATA = 0x0020_0000;
+        /// `I2C_FUNC_SMBUS_WRITE_WORD_DATA`
+        const SMBUS_WRITE_WORD_DATA = 0x0040_0000;
+        /// `I2C_FUNC_SMBUS_PROC_CALL`
+        const SMBUS_PROC_CALL = 0x0080_0000;
+        /// `I2C_FUNC_SMBUS_READ_BLOCK_DATA`
+        const SMBUS_READ_BLOCK_DATA = 0x0100_0000;
+        /// `I2C_FUNC_SMBUS_WRITE_BLOCK_DATA`
+        const SMBUS_WRITE_BLOCK_DATA = 0x0200_0000;
+        /// `I2C_FUNC_SMBUS_READ_I2C_BLOCK`
+        const SMBUS_READ_I2C_BLOCK = 0x0400_0000;
+        /// `I2C_FUNC_SMBUS_WRITE_I2C_BLOCK`
+        const SMBUS_WRITE_I2C_BLOCK = 0x0800_0000;
+        /// `I2C_FUNC_SMBUS_HOST_NOTIFY`
+        const SMBUS_HOST_NOTIFY = 0x1000_0000;
+
+        /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+        const _ = !0;
+    }
+}
+
+bitflags! {
+    /// `I2C_M_*` constants for use with [`Message`].
+    #[repr(transparent)]
+    #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    pub struct MessageFlags: u16 {
+        /// `I2C_M_TEN`—The address is a 10-bit address.
+        const TEN = 0x0010;
+        /// `I2C_M_RECV_LEN`—The first byte received is the length of the
+        /// rest of the message.
+        const RECV_LEN = 0x0400;
+        /// `I2C_M_NO_RD_ACK`
+        const NO_RD_ACK = 0x0800;
+        /// `I2C_M_IGNORE_NAK`
+        const IGNORE_NAK = 0x1000;
+        /// `I2C_M_REV_DIR_ADDR`
+        const REV_DIR_ADDR = 0x2000;
+        /// `I2C_M_NOSTART`—Don't send a (repeated) start condition before
+        /// this message.
+        const NOSTART = 0x4000;
+        /// `I2C_M_STOP`—Send a stop condition after this message.
+        const STOP = 0x8000;
+
+        /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+        const _ = !0;
+    }
+}
+
+/// `I2C_M_RD`
+const I2C_M_RD: u16 = 0x0001;
+
+/// `struct i2c_msg`—One message of an I2C transaction, for use with
+/// [`ioctl_i2c_rdwr`].
+///
+/// Buffers longer than 65535 bytes are truncated.
+#[repr(C)]
+#[derive(Debug)]
+pub struct Message<'a> {
+    addr: u16,
+    flags: u16,
+    len: u16,
+    buf: *mut u8,
+    _buf: PhantomData<&'a mut [u8]>,
+}
+
+impl<'a> Message<'a> {
+    /// Constructs a message that writes `buf` to the device at `addr`.
+    #[inline]
+    pub fn write(addr: u16, buf: &'a [u8]) -> Self {
+        Self {
+            addr,
+            flags: 0,
+            len: buf.len().min(u16::MAX as usize) as u16,
+            // The kernel only reads from the buffers of write messages.
+            buf: buf.as_ptr() as *mut u8,
+            _buf: PhantomData,
+        }
+    }
+
+    /// Constructs a message that reads from the device at `addr` into
+    /// `buf`.
+    #[inline]
+    pub fn read(addr: u16, buf: &'a mut [u8]) -> Self {
+        Self {
+            addr,
+            flags: I2C_M_RD,
+            len: buf.len().min(u16::MAX as usize) as u16,
+            buf: buf.as_mut_ptr(),
+            _buf: PhantomData,
+        }
+    }
+
+    /// Adds flags to this message.
+    #[inline]
+    pub const fn with_flags(mut self, flags: MessageFlags) -> Self {
+        self.flags |= flags.bits() & !I2C_M_RD;
+        self
+    }
+}
+
+/// `struct i2c_rdwr_ioctl_data`
+#[repr(C)]
+struct RdwrIoctlData {
+    msgs: *mut Message<'static>,
+    nmsgs: u32,
+}
+
+/// `ioctl(fd, I2C_SLAVE, addr)`—Selects the address of the device that
+/// [`read`] and [`write`] talk to.
+///
+/// This fails with `BUSY` if a kernel driver is bound to the device at that
+/// address; see [`ioctl_i2c_slave_force`].
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/i2c/dev-interface.html
+/// [`read`]: crate::io::read
+/// [`write`]: crate::io::write
+#[inline]
+#[doc(alias = "I2C_SLAVE")]
+pub fn ioctl_i2c_slave<Fd: AsFd>(fd: Fd, addr: u16) -> io::Result<()> {
+    // SAFETY: `I2C_SLAVE` takes an integer argument.
+    unsafe {
+        let ctl = ioctl::IntegerSetter::<ioctl::BadOpcode<0x0703>>::new(addr as usize);
+        ioctl::ioctl(fd, ctl)
+    }
+}
+
+/// `ioctl(fd, I2C_SLAVE_FORCE, addr)`—Like [`ioctl_i2c_slave`], but
+/// succeeds even if a kernel driver is bound to the device.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/i2c/dev-interface.html
+#[inline]
+#[doc(alias = "I2C_SLAVE_FORCE")]
+pub fn ioctl_i2c_slave_force<Fd: AsFd>(fd: Fd, addr: u16) -> io::Result<()> {
+    // SAFETY: `I2C_SLAVE_FORCE` takes an integer argument.
+    unsafe {
+        let ctl = ioctl::IntegerSetter::<ioctl::BadOpcode<0x0706>>::new(addr as usize);
+        ioctl::ioctl(fd, ctl)
+    }
+}
+
+/// `ioctl(fd, I2C_TENBIT, enable)`—Sets whether the address selected with
+/// [`ioctl_i2c_slave`] is a 10-bit address.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/i2c/dev-interface.html
+#[inline]
+#[doc(alias = "I2C_TENBIT")]
+pub fn ioctl_i2c_tenbit<Fd: AsFd>(fd: Fd, enable: bool) -> io::Result<()> {
+    // SAFETY: `I2C_TENBIT` takes an integer argument.
+    unsafe {
+        let ctl = ioctl::IntegerSetter::<ioctl::BadOpcode<0x0704>>::new(usize::from(enable));
+        ioctl::ioctl(fd, ctl)
+    }
+}
+
+/// `ioctl(fd, I2C_FUNCS)`—Returns what the adapter supports.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/i2c/functionality.html
+#[inline]
+#[doc(alias = "I2C_FUNCS")]
+pub fn ioctl_i2c_funcs<Fd: AsFd>(fd: Fd) -> io::Result<Functionality> {
+    // SAFETY: `I2C_FUNCS` is a getter opcode that gets a `c_ulong`.
+    unsafe {
+        let ctl = ioctl::Getter::<ioctl::BadOpcode<0x0705>, c::c_ulong>::new();
+        ioctl::ioctl(fd, ctl).map(|funcs| Functionality::from_bits_retain(funcs as u64))
+    }
+}
+
+/// `ioctl(fd, I2C_RDWR, data)`—Performs a combined transaction of up to 42
+/// messages, with a repeated start condition between them.
+///
+/// On success, returns the number of messages transferred.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/i2c/dev-interface.html
+#[inline]
+#[doc(alias = "I2C_RDWR")]
+pub fn ioctl_i2c_rdwr<Fd: AsFd>(fd: Fd, msgs: &mut [Message<'_>]) -> io::Result<usize> {
+    if msgs.len() > I2C_RDWR_IOCTL_MAX_MSGS {
+        return Err(io::Errno::INVAL);
+    }
+
+    let data = RdwrIoctlData {
+        msgs: msgs.as_mut_ptr().cast(),
+        nmsgs: msgs.len() as u32,
+    };
+    // SAFETY: `I2C_RDWR` reads a `struct i2c_rdwr_ioctl_data`, and reads
+    // from and writes to the buffers of the messages it points to, which
+    // are borrowed for the duration of the call.
+    unsafe {
+        let ctl = ioctl::Setter::<ioctl::BadOpcode<0x0707>, RdwrIoctlData>::new(data);
+        ioctl::ioctl(fd, ctl)?;
+    }
+    Ok(msgs.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::mem::{align_of, size_of};
+
+    #[test]
+    fn i2c_layouts() {
+        // struct i2c_msg { __u16 addr, flags, len; __u8 *buf; }
+        #[cfg(target_pointer_width = "64")]
+        const_assert_eq!(size_of::<Message<'_>>(), 16);
+        #[cfg(target_pointer_width = "32")]
+        const_assert_eq!(size_of::<Message<'_>>(), 12);
+        const_assert_eq!(align_of::<Message<'_>>(), align_of::<usize>());
+
+        // struct i2c_rdwr_ioctl_data { struct i2c_msg *msgs; __u32 nmsgs; }
+        const_assert_eq!(size_of::<RdwrIoctlData>(), 2 * size_of::<usize>());
+        const_assert_eq!(align_of::<RdwrIoctlData>(), align_of::<usize>());
+    }
+}
@@ -0,0 +1,352 @@
+//! Linux input device (evdev) functions.
+//!
+//! The functions in this module take a file descriptor opened from an event
+//! device node such as `/dev/input/event0`. Virtual input devices can be
+//! created with the functions in [`uinput`].
+//!
+//! # References
+//!  - [Linux]
+//!
+//! [Linux]: https://docs.kernel.org/input/input.html#evdev
+
+#![allow(unsafe_code)]
+
+use crate::backend::c;
+use crate::fd::AsFd;
+use crate::{io, ioctl};
+use core::mem::{size_of, size_of_val, MaybeUninit};
+use core::slice;
+
+/// The largest buffer size that can be encoded in an `ioctl` opcode on all
+/// architectures; some only have 13 bits for it.
+const MAX_IOCTL_SIZE: usize = 0x1fff;
+
+pub mod uinput;
+
+/// `EV_*`—An input event type.
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct EventType(u16);
+
+impl EventType {
+    /// `EV_SYN`—Synchronization events.
+    #[doc(alias = "EV_SYN")]
+    pub const SYN: Self = Self(0x00);
+
+    /// `EV_KEY`—Key and button events.
+    #[doc(alias = "EV_KEY")]
+    pub const KEY: Self = Self(0x01);
+
+    /// `EV_REL`—Relative axis events.
+    #[doc(alias = "EV_REL")]
+    pub const REL: Self = Self(0x02);
+
+    /// `EV_ABS`—Absolute axis events.
+    #[doc(alias = "EV_ABS")]
+    pub const ABS: Self = Self(0x03);
+
+    /// `EV_MSC`—Miscellaneous events.
+    #[doc(alias = "EV_MSC")]
+    pub const MSC: Self = Self(0x04);
+
+    /// `EV_SW`—Switch events.
+    #[doc(alias = "EV_SW")]
+    pub const SW: Self = Self(0x05);
+
+    /// `EV_LED`—LED events.
+    #[doc(alias = "EV_LED")]
+    pub const LED: Self = Self(0x11);
+
+    /// `EV_SND`—Sound events.
+    #[doc(alias = "EV_SND")]
+    pub const SND: Self = Self(0x12);
+
+    /// `EV_REP`—Autorepeat events.
+    #[doc(alias = "EV_REP")]
+    pub const REP: Self = Self(0x14);
+
+    /// `EV_FF`—Force feedback events.
+    #[doc(alias = "EV_FF")]
+    pub const FF: Self = Self(0x15);
+
+    /// `EV_PWR`—Power events.
+    #[doc(alias = "EV_PWR")]
+    pub const PWR: Self = Self(0x16);
+
+    /// `EV_FF_STATUS`—Force feedback status events.
+    #[doc(alias = "EV_FF_STATUS")]
+    pub const FF_STATUS: Self = Self(0x17);
+
+    /// Constructs an `EventType` from a raw `EV_*` value.
+    #[inline]
+    pub const fn from_raw(raw: u16) -> Self {
+        Self(raw)
+    }
+
+    /// Returns the raw `EV_*` value.
+    #[inline]
+    pub const fn as_raw(self) -> u16 {
+        self.0
+    }
+}
+
+/// `struct input_absinfo`—The state and range of an absolute axis, returned
+/// by [`ioctl_eviocgabs`].
+#[repr(C)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct AbsInfo {
+    /// The current value of the axis.
+    pub value: i32,
+
+    /// The minimum value of the axis.
+    pub minimum: i32,
+
+    /// The maximum value of the axis.
+    pub maximum: i32,
+
+    /// The range within which changes are treated as noise.
+    pub fuzz: i32,
+
+    /// The range around the center that is reported as the center.
+    pub flat: i32,
+
+    /// The resolution of the axis, in units per millimeter, or per radian
+    /// for rotational axes.
+    pub resolution: i32,
+}
+
+/// `struct input_id`—The bus type, vendor, product, and version of an input
+/// device.
+#[repr(C)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct InputId {
+    /// The bus type, one of the `BUS_*` values, such as `BUS_USB` (3) or
+    /// `BUS_VIRTUAL` (6).
+    pub bustype: u16,
+
+    /// The vendor ID.
+    pub vendor: u16,
+
+    /// The product ID.
+    pub product: u16,
+
+    /// The version.
+    pub version: u16,
+}
+
+/// `struct input_event`—An event read from an input device with
+/// [`read_events`].
+#[repr(C)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct InputEvent {
+    /// The seconds part of the time the event happened.
+    pub sec: c::c_long,
+
+    /// The microseconds part of the time the event happened.
+    pub usec: c::c_long,
+
+    /// The event type, one of the `EV_*` values in [`EventType`].
+    pub type_: u16,
+
+    /// The event code, whose meaning depends on the event type.
+    pub code: u16,
+
+    /// The event value, whose meaning depends on the event type.
+    pub value: i32,
+}
+
+impl InputEvent {
+    /// Returns the event type.
+    #[inline]
+    pub const fn event_type(&self) -> EventType {
+        EventType(self.type_)
+    }
+}
+
+/// `EVIOCGNAME(len)`
+const fn eviocgname(len: usize) -> ioctl::Opcode {
+    ioctl::Opcode::from_components(ioctl::Direction::Read, b'E', 0x06, len)
+}
+
+/// `EVIOCGBIT(event_type, len)`
+const fn eviocgbit(event_type: EventType, len: usize) -> ioctl::Opcode {
+    ioctl::Opcode::from_components(ioctl::Direction::Read, b'E', 0x20 + event_type.0 as u8, len)
+}
+
+/// `EVIOCGABS(axis)`
+const fn eviocgabs(axis: u8) -> ioctl::Opcode {
+    ioctl::Opcode::read::<AbsInfo>(b'E', 0x40 + axis)
+}
+
+/// `EVIOCGRAB`
+type Grab = ioctl::WriteOpcode<b'E', 0x90, c::c_int>;
+
+/// `read(fd, events)`—Reads input events from an input device.
+///
+/// Event devices always return whole events, so this returns the number of
+/// events read into `events`.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/input/input.html#event-interface
+#[inline]
+pub fn read_events<Fd: AsFd>(fd: Fd, events: &mut [InputEvent]) -> io::Result<usize> {
+    // SAFETY: `InputEvent` is a plain `repr(C)` struct with no padding, for
+    // which any byte pattern is valid.
+    let buf =
+        unsafe { slice::from_raw_parts_mut(events.as_mut_ptr().cast::<u8>(), size_of_val(events)) };
+    let nread = io::read(fd, buf)?;
+    Ok(nread / size_of::<InputEvent>())
+}
+
+/// `ioctl(fd, EVIOCGNAME(len), buf)`—Reads the name of an input device.
+///
+/// On success, returns the number of bytes written to `buf`, including the
+/// NUL terminator if it fit.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/input/input-programming.html
+#[inline]
+#[doc(alias = "EVIOCGNAME")]
+pub fn ioctl_eviocgname<Fd: AsFd>(fd: Fd, buf: &mut [u8]) -> io::Result<usize> {
+    let opcode = eviocgname(buf.len().min(MAX_IOCTL_SIZE));
+    // SAFETY: `EVIOCGNAME` writes at most the number of bytes encoded in the
+    // opcode into the buffer.
+    unsafe {
+        ioctl::ioctl_with_opcode(fd.as_fd(), opcode, buf.as_mut_ptr().cast())
+            .map(|len| len as usize)
+    }
+}
+
+/// `ioctl(fd, EVIOCGBIT(event_type, len), buf)`—Reads the bitmask of event
+/// codes of a given type that an input device supports.
+///
+/// Passing [`EventType::SYN`] instead reads the bitmask of event types that
+/// the device supports. Bit `n` is stored in bit `n % 8` of byte `n / 8`.
+///
+/// On success, returns the number of bytes written to `buf`.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/input/input-programming.html
+#[inline]
+#[doc(alias = "EVIOCGBIT")]
+pub fn ioctl_eviocgbit<Fd: AsFd>(
+    fd: Fd,
+    event_type: EventType,
+    buf: &mut [u8],
+) -> io::Result<usize> {
+    // `EV_MAX` is 0x1f.
+    if event_type.0 > 0x1f {
+        return Err(io::Errno::INVAL);
+    }
+
+    let opcode = eviocgbit(event_type, buf.len().min(MAX_IOCTL_SIZE));
+    // SAFETY: `EVIOCGBIT` writes at most the number of bytes encoded in the
+    // opcode into the buffer.
+    unsafe {
+        ioctl::ioctl_with_opcode(fd.as_fd(), opcode, buf.as_mut_ptr().cast())
+            .map(|len| len as usize)
+    }
+}
+
+/// `ioctl(fd, EVIOCGABS(axis))`—Returns the state and range of an absolute
+/// axis.
+///
+/// `axis` is an `ABS_*` code, such as `ABS_X`, which is 0.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/input/input-programming.html
+#[inline]
+#[doc(alias = "EVIOCGABS")]
+pub fn ioctl_eviocgabs<Fd: AsFd>(fd: Fd, axis: u16) -> io::Result<AbsInfo> {
+    // `ABS_MAX` is 0x3f.
+    if axis > 0x3f {
+        return Err(io::Errno::INVAL);
+    }
+
+    let opcode = eviocgabs(axis as u8);
+    let mut info = MaybeUninit::<AbsInfo>::uninit();
+    // SAFETY: `EVIOCGABS` writes a `struct input_absinfo`.
+    unsafe {
+        ioctl::ioctl_with_opcode(fd.as_fd(), opcode, info.as_mut_ptr().cast())?;
+        Ok(info.assume_init())
+    }
+}
+
+/// `ioctl(fd, EVIOCGRAB, grab)`—Grabs or releases an input device, so that
+/// only this file descriptor receives its events.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/input/input-programming.html
+#[inline]
+#[doc(alias = "EVIOCGRAB")]
+pub fn ioctl_eviocgrab<Fd: AsFd>(fd: Fd, grab: bool) -> io::Result<()> {
+    // SAFETY: `EVIOCGRAB` takes an integer argument.
+    unsafe {
+        let ctl = ioctl::IntegerSetter::<Grab>::new(usize::from(grab));
+        ioctl::ioctl(fd, ctl)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ioctl::{CompileTimeOpcode, RawOpcode};
+
+    #[cfg(not(any(
+        // These have no ioctl opcodes defined in linux_raw_sys.
+        target_arch = "sparc",
+        target_arch = "sparc64"
+    )))]
+    #[test]
+    fn evdev_opcodes() {
+        use linux_raw_sys::ioctl as sys;
+
+        const_assert_eq!(Grab::OPCODE.raw(), sys::EVIOCGRAB as RawOpcode);
+    }
+
+    // `linux_raw_sys` doesn't have the opcodes that take arguments, so these
+    // are the values of the uapi macros with the generic `_IOC` encoding.
+    #[cfg(any(
+        target_arch = "aarch64",
+        target_arch = "arm",
+        target_arch = "riscv64",
+        target_arch = "x86",
+        target_arch = "x86_64",
+    ))]
+    #[test]
+    fn evdev_opcodes_with_arguments() {
+        // EVIOCGNAME(256)
+        const_assert_eq!(eviocgname(256).raw(), 0x8100_4506);
+        // EVIOCGBIT(EV_KEY, 96)
+        const_assert_eq!(eviocgbit(EventType::KEY, 96).raw(), 0x8060_4521);
+        // EVIOCGABS(ABS_Y)
+        const_assert_eq!(eviocgabs(1).raw(), 0x8018_4541);
+    }
+
+    #[test]
+    fn evdev_layouts() {
+        // struct input_absinfo { __s32 value, minimum, maximum, fuzz, flat,
+        // resolution; }
+        assert_eq_size!(AbsInfo, [i32; 6]);
+        assert_eq_align!(AbsInfo, i32);
+
+        // struct input_id { __u16 bustype, vendor, product, version; }
+        assert_eq_size!(InputId, [u16; 4]);
+        assert_eq_align!(InputId, u16);
+
+        // struct input_event { struct timeval time; __u16 type, code;
+        // __s32 value; }
+        assert_eq_size!(InputEvent, [c::c_long; 2 + 8 / size_of::<c::c_long>()]);
+        assert_eq_align!(InputEvent, c::c_long);
+    }
+}
@@ -0,0 +1,233 @@
+//! Linux uinput functions, for creating virtual input devices.
+//!
+//! The functions in this module take a file descriptor opened from
+//! `/dev/uinput`. A device is configured with the `ioctl_ui_set_*bit`
+//! functions and [`ioctl_ui_dev_setup`], created with
+//! [`ioctl_ui_dev_create`], and then fed events with [`write_events`].
+//!
+//! # References
+//!  - [Linux]
+//!
+//! [Linux]: https://docs.kernel.org/input/uinput.html
+
+use super::{EventType, InputEvent, InputId};
+use crate::backend::c;
+use crate::fd::AsFd;
+use crate::{io, ioctl};
+use core::mem::{size_of, size_of_val};
+use core::slice;
+
+/// `UINPUT_MAX_NAME_SIZE`
+const UINPUT_MAX_NAME_SIZE: usize = 80;
+
+/// `struct uinput_setup`
+#[repr(C)]
+struct UinputSetup {
+    id: InputId,
+    name: [u8; UINPUT_MAX_NAME_SIZE],
+    ff_effects_max: u32,
+}
+
+/// `UI_SET_EVBIT`
+type SetEvBit = ioctl::WriteOpcode<b'U', 100, c::c_int>;
+/// `UI_SET_KEYBIT`
+type SetKeyBit = ioctl::WriteOpcode<b'U', 101, c::c_int>;
+/// `UI_SET_RELBIT`
+type SetRelBit = ioctl::WriteOpcode<b'U', 102, c::c_int>;
+/// `UI_SET_ABSBIT`
+type SetAbsBit = ioctl::WriteOpcode<b'U', 103, c::c_int>;
+/// `UI_DEV_SETUP`
+type DevSetup = ioctl::WriteOpcode<b'U', 3, UinputSetup>;
+/// `UI_DEV_CREATE`
+type DevCreate = ioctl::NoneOpcode<b'U', 1, ()>;
+/// `UI_DEV_DESTROY`
+type DevDestroy = ioctl::NoneOpcode<b'U', 2, ()>;
+
+/// `ioctl(fd, UI_SET_EVBIT, event_type)`—Enables an event type for the
+/// device being set up.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/input/uinput.html
+#[inline]
+#[doc(alias = "UI_SET_EVBIT")]
+pub fn ioctl_ui_set_evbit<Fd: AsFd>(fd: Fd, event_type: EventType) -> io::Result<()> {
+    // SAFETY: `UI_SET_EVBIT` takes an integer argument.
+    unsafe {
+        let ctl = ioctl::IntegerSetter::<SetEvBit>::new(event_type.as_raw() as usize);
+        ioctl::ioctl(fd, ctl)
+    }
+}
+
+/// `ioctl(fd, UI_SET_KEYBIT, code)`—Enables a key or button code, such as
+/// `KEY_A` (30), for the device being set up.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/input/uinput.html
+#[inline]
+#[doc(alias = "UI_SET_KEYBIT")]
+pub fn ioctl_ui_set_keybit<Fd: AsFd>(fd: Fd, code: u16) -> io::Result<()> {
+    // SAFETY: `UI_SET_KEYBIT` takes an integer argument.
+    unsafe {
+        let ctl = ioctl::IntegerSetter::<SetKeyBit>::new(code as usize);
+        ioctl::ioctl(fd, ctl)
+    }
+}
+
+/// `ioctl(fd, UI_SET_RELBIT, code)`—Enables a relative axis code, such as
+/// `REL_X` (0), for the device being set up.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/input/uinput.html
+#[inline]
+#[doc(alias = "UI_SET_RELBIT")]
+pub fn ioctl_ui_set_relbit<Fd: AsFd>(fd: Fd, code: u16) -> io::Result<()> {
+    // SAFETY: `UI_SET_RELBIT` takes an integer argument.
+    unsafe {
+        let ctl = ioctl::IntegerSetter::<SetRelBit>::new(code as usize);
+        ioctl::ioctl(fd, ctl)
+    }
+}
+
+/// `ioctl(fd, UI_SET_ABSBIT, code)`—Enables an absolute axis code, such as
+/// `ABS_X` (0), for the device being set up.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/input/uinput.html
+#[inline]
+#[doc(alias = "UI_SET_ABSBIT")]
+pub fn ioctl_ui_set_absbit<Fd: AsFd>(fd: Fd, code: u16) -> io::Result<()> {
+    // SAFETY: `UI_SET_ABSBIT` takes an integer argument.
+    unsafe {
+        let ctl = ioctl::IntegerSetter::<SetAbsBit>::new(code as usize);
+        ioctl::ioctl(fd, ctl)
+    }
+}
+
+/// `ioctl(fd, UI_DEV_SETUP, setup)`—Sets the identity and name of the device
+/// being set up.
+///
+/// `name` must be shorter than 80 bytes and must not contain NUL bytes.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/input/uinput.html
+#[inline]
+#[doc(alias = "UI_DEV_SETUP")]
+pub fn ioctl_ui_dev_setup<Fd: AsFd>(
+    fd: Fd,
+    id: InputId,
+    name: &str,
+    ff_effects_max: u32,
+) -> io::Result<()> {
+    let name = name.as_bytes();
+    if name.len() >= UINPUT_MAX_NAME_SIZE || name.contains(&0) {
+        return Err(io::Errno::INVAL);
+    }
+
+    let mut setup = UinputSetup {
+        id,
+        name: [0; UINPUT_MAX_NAME_SIZE],
+        ff_effects_max,
+    };
+    setup.name[..name.len()].copy_from_slice(name);
+
+    // SAFETY: `UI_DEV_SETUP` is a pointer setter opcode that sets a
+    // `struct uinput_setup`.
+    unsafe {
+        let ctl = ioctl::Setter::<DevSetup, UinputSetup>::new(setup);
+        ioctl::ioctl(fd, ctl)
+    }
+}
+
+/// `ioctl(fd, UI_DEV_CREATE)`—Creates the device that has been set up.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/input/uinput.html
+#[inline]
+#[doc(alias = "UI_DEV_CREATE")]
+pub fn ioctl_ui_dev_create<Fd: AsFd>(fd: Fd) -> io::Result<()> {
+    // SAFETY: `UI_DEV_CREATE` is a no-argument opcode.
+    unsafe {
+        let ctl = ioctl::NoArg::<DevCreate>::new();
+        ioctl::ioctl(fd, ctl)
+    }
+}
+
+/// `ioctl(fd, UI_DEV_DESTROY)`—Destroys the device.
+///
+/// The device is also destroyed when `fd` is closed.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/input/uinput.html
+#[inline]
+#[doc(alias = "UI_DEV_DESTROY")]
+pub fn ioctl_ui_dev_destroy<Fd: AsFd>(fd: Fd) -> io::Result<()> {
+    // SAFETY: `UI_DEV_DESTROY` is a no-argument opcode.
+    unsafe {
+        let ctl = ioctl::NoArg::<DevDestroy>::new();
+        ioctl::ioctl(fd, ctl)
+    }
+}
+
+/// `write(fd, events)`—Emits input events from the device.
+///
+/// Events are delivered to readers when an `EV_SYN` event with code
+/// `SYN_REPORT` (0) is written. On success, returns the number of events
+/// written.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/input/uinput.html
+#[inline]
+pub fn write_events<Fd: AsFd>(fd: Fd, events: &[InputEvent]) -> io::Result<usize> {
+    // SAFETY: `InputEvent` is a plain `repr(C)` struct with no padding.
+    let buf = unsafe { slice::from_raw_parts(events.as_ptr().cast::<u8>(), size_of_val(events)) };
+    let nwritten = io::write(fd, buf)?;
+    Ok(nwritten / size_of::<InputEvent>())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ioctl::{CompileTimeOpcode, RawOpcode};
+
+    #[cfg(not(any(
+        // These have no ioctl opcodes defined in linux_raw_sys.
+        target_arch = "sparc",
+        target_arch = "sparc64"
+    )))]
+    #[test]
+    fn uinput_opcodes() {
+        use linux_raw_sys::ioctl as sys;
+
+        const_assert_eq!(SetEvBit::OPCODE.raw(), sys::UI_SET_EVBIT as RawOpcode);
+        const_assert_eq!(SetKeyBit::OPCODE.raw(), sys::UI_SET_KEYBIT as RawOpcode);
+        const_assert_eq!(SetRelBit::OPCODE.raw(), sys::UI_SET_RELBIT as RawOpcode);
+        const_assert_eq!(SetAbsBit::OPCODE.raw(), sys::UI_SET_ABSBIT as RawOpcode);
+        const_assert_eq!(DevSetup::OPCODE.raw(), sys::UI_DEV_SETUP as RawOpcode);
+        const_assert_eq!(DevCreate::OPCODE.raw(), sys::UI_DEV_CREATE as RawOpcode);
+        const_assert_eq!(DevDestroy::OPCODE.raw(), sys::UI_DEV_DESTROY as RawOpcode);
+    }
+
+    #[test]
+    fn uinput_layouts() {
+        // struct uinput_setup { struct input_id id; char name[80];
+        // __u32 ff_effects_max; }
+        assert_eq_size!(UinputSetup, [u32; 23]);
+        assert_eq_align!(UinputSetup, u32);
+    }
+}
@@ -21,10 +21,166 @@ impl Errno {
     pub fn kind(self) -> std::io::ErrorKind {
         std::io::Error::from(self).kind()
     }
+
+    /// Returns a static description of this error, such as
+    /// `"Operation not permitted"` for [`Errno::PERM`], or `None` if rustix
+    /// doesn't have one.
+    ///
+    /// The descriptions match those of glibc's `strerror`, but this doesn't
+    /// call `strerror`, allocate, or otherwise depend on libc, so it's usable
+    /// in `no_std` code and in signal handlers. Descriptions are currently
+    /// only available on Linux-kernel platforms.
+    #[inline]
+    pub fn kind_str(self) -> Option<&'static str> {
+        #[cfg(linux_kernel)]
+        {
+            Some(match self {
+                Self::ACCESS => "Permission denied",
+                Self::ADDRINUSE => "Address already in use",
+                Self::ADDRNOTAVAIL => "Cannot assign requested address",
+                Self::ADV => "Advertise error",
+                Self::AFNOSUPPORT => "Address family not supported by protocol",
+                Self::AGAIN => "Resource temporarily unavailable",
+                Self::ALREADY => "Operation already in progress",
+                Self::BADE => "Invalid exchange",
+                Self::BADF => "Bad file descriptor",
+                Self::BADFD => "File descriptor in bad state",
+                Self::BADMSG => "Bad message",
+                Self::BADR => "Invalid request descriptor",
+                Self::BADRQC => "Invalid request code",
+                Self::BADSLT => "Invalid slot",
+                Self::BFONT => "Bad font file format",
+                Self::BUSY => "Device or resource busy",
+                Self::CANCELED => "Operation canceled",
+                Self::CHILD => "No child processes",
+                Self::CHRNG => "Channel number out of range",
+                Self::COMM => "Communication error on send",
+                Self::CONNABORTED => "Software caused connection abort",
+                Self::CONNREFUSED => "Connection refused",
+                Self::CONNRESET => "Connection reset by peer",
+                Self::DEADLK => "Resource deadlock avoided",
+                Self::DESTADDRREQ => "Destination address required",
+                Self::DOM => "Numerical argument out of domain",
+                Self::DOTDOT => "RFS specific error",
+                Self::DQUOT => "Disk quota exceeded",
+                Self::EXIST => "File exists",
+                Self::FAULT => "Bad address",
+                Self::FBIG => "File too large",
+                Self::HOSTDOWN => "Host is down",
+                Self::HOSTUNREACH => "No route to host",
+                Self::HWPOISON => "Memory page has hardware error",
+                Self::IDRM => "Identifier removed",
+                Self::ILSEQ => "Invalid or incomplete multibyte or wide character",
+                Self::INPROGRESS => "Operation now in progress",
+                Self::INTR => "Interrupted system call",
+                Self::INVAL => "Invalid argument",
+                Self::IO => "Input/output error",
+                Self::ISCONN => "Transport endpoint is already connected",
+                Self::ISDIR => "Is a directory",
+                Self::ISNAM => "Is a named type file",
+                Self::KEYEXPIRED => "Key has expired",
+                Self::KEYREJECTED => "Key was rejected by service",
+                Self::KEYREVOKED => "Key has been revoked",
+                Self::L2HLT => "Level 2 halted",
+                Self::L2NSYNC => "Level 2 not synchronized",
+                Self::L3HLT => "Level 3 halted",
+                Self::L3RST => "Level 3 reset",
+                Self::LIBACC => "Can not access a needed shared library",
+                Self::LIBBAD => "Accessing a corrupted shared library",
+                Self::LIBEXEC => "Cannot exec a shared library directly",
+                Self::LIBMAX => "Attempting to link in too many shared libraries",
+                Self::LIBSCN => ".lib section in a.out corrupted",
+                Self::LNRNG => "Link number out of range",
+                Self::LOOP => "Too many levels of symbolic links",
+                Self::MEDIUMTYPE => "Wrong medium type",
+                Self::MFILE => "Too many open files",
+                Self::MLINK => "Too many links",
+                Self::MSGSIZE => "Message too long",
+                Self::MULTIHOP => "Multihop attempted",
+                Self::NAMETOOLONG => "File name too long",
+                Self::NAVAIL => "No XENIX semaphores available",
+                Self::NETDOWN => "Network is down",
+                Self::NETRESET => "Network dropped connection on reset",
+                Self::NETUNREACH => "Network is unreachable",
+                Self::NFILE => "Too many open files in system",
+                Self::NOANO => "No anode",
+                Self::NOBUFS => "No buffer space available",
+                Self::NOCSI => "No CSI structure available",
+                Self::NODATA => "No data available",
+                Self::NODEV => "No such device",
+                Self::NOENT => "No such file or directory",
+                Self::NOEXEC => "Exec format error",
+                Self::NOKEY => "Required key not available",
+                Self::NOLCK => "No locks available",
+                Self::NOLINK => "Link has been severed",
+                Self::NOMEDIUM => "No medium found",
+                Self::NOMEM => "Cannot allocate memory",
+                Self::NOMSG => "No message of desired type",
+                Self::NONET => "Machine is not on the network",
+                Self::NOPKG => "Package not installed",
+                Self::NOPROTOOPT => "Protocol not available",
+                Self::NOSPC => "No space left on device",
+                Self::NOSR => "Out of streams resources",
+                Self::NOSTR => "Device not a stream",
+                Self::NOSYS => "Function not implemented",
+                Self::NOTBLK => "Block device required",
+                Self::NOTCONN => "Transport endpoint is not connected",
+                Self::NOTDIR => "Not a directory",
+                Self::NOTEMPTY => "Directory not empty",
+                Self::NOTNAM => "Not a XENIX named type file",
+                Self::NOTRECOVERABLE => "State not recoverable",
+                Self::NOTSOCK => "Socket operation on non-socket",
+                Self::NOTTY => "Inappropriate ioctl for device",
+                Self::NOTUNIQ => "Name not unique on network",
+                Self::NXIO => "No such device or address",
+                Self::OPNOTSUPP => "Operation not supported",
+                Self::OVERFLOW => "Value too large for defined data type",
+                Self::OWNERDEAD => "Owner died",
+                Self::PERM => "Operation not permitted",
+                Self::PFNOSUPPORT => "Protocol family not supported",
+                Self::PIPE => "Broken pipe",
+                Self::PROTO => "Protocol error",
+                Self::PROTONOSUPPORT => "Protocol not supported",
+                Self::PROTOTYPE => "Protocol wrong type for socket",
+                Self::RANGE => "Numerical result out of range",
+                Self::REMCHG => "Remote address changed",
+                Self::REMOTE => "Object is remote",
+                Self::REMOTEIO => "Remote I/O error",
+                Self::RESTART => "Interrupted system call should be restarted",
+                Self::RFKILL => "Operation not possible due to RF-kill",
+                Self::ROFS => "Read-only file system",
+                Self::SHUTDOWN => "Cannot send after transport endpoint shutdown",
+                Self::SOCKTNOSUPPORT => "Socket type not supported",
+                Self::SPIPE => "Illegal seek",
+                Self::SRCH => "No such process",
+                Self::SRMNT => "Srmount error",
+                Self::STALE => "Stale file handle",
+                Self::STRPIPE => "Streams pipe error",
+                Self::TIME => "Timer expired",
+                Self::TIMEDOUT => "Connection timed out",
+                Self::TOOBIG => "Argument list too long",
+                Self::TOOMANYREFS => "Too many references: cannot splice",
+                Self::TXTBSY => "Text file busy",
+                Self::UCLEAN => "Structure needs cleaning",
+                Self::UNATCH => "Protocol driver not attached",
+                Self::USERS => "Too many users",
+                Self::XDEV => "Invalid cross-device link",
+                Self::XFULL => "Exchange full",
+                _ => return None,
+            })
+        }
+        #[cfg(not(linux_kernel))]
+        {
+            None
+        }
+    }
 }
 
 impl fmt::Display for Errno {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(message) = self.kind_str() {
+            return write!(fmt, "{} (os error {})", message, self.raw_os_error());
+        }
         #[cfg(feature = "std")]
         {
             std::io::Error::from(*self).fmt(fmt)
@@ -37,6 +37,7 @@ pub use backend::io::types::FdFlags;
 /// [glibc]: https://www.gnu.org/software/libc/manual/html_node/Control-Operations.html#index-fcntl-function
 #[inline]
 #[doc(alias = "F_GETFD")]
+#[doc(alias = "fd_flags")]
 pub fn fcntl_getfd<Fd: AsFd>(fd: Fd) -> io::Result<FdFlags> {
     backend::io::syscalls::fcntl_getfd(fd.as_fd())
 }
@@ -15,15 +15,54 @@ use backend::fd::AsFd;
 /// `ioctl(fd, FIOCLEX, NULL)`—Set the close-on-exec flag.
 ///
 /// This is similar to `fcntl(fd, F_SETFD, FD_CLOEXEC)`, except that it avoids
-/// clearing any other flags that might be set.
-#[cfg(apple)]
+/// clearing any other flags that might be set, and doesn't need a separate
+/// `F_GETFD` call to find them.
+///
+/// # References
+///  - [Linux]
+///  - [FreeBSD]
+///  - [NetBSD]
+///  - [OpenBSD]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/ioctl.2.html
+/// [FreeBSD]: https://man.freebsd.org/cgi/man.cgi?query=ioctl&sektion=2#GENERIC%09IOCTLS
+/// [NetBSD]: https://man.netbsd.org/ioctl.2#GENERIC%20IOCTLS
+/// [OpenBSD]: https://man.openbsd.org/ioctl.2#GENERIC_IOCTLS
+#[cfg(any(bsd, linux_kernel, solarish))]
 #[inline]
 #[doc(alias = "FIOCLEX")]
 #[doc(alias = "FD_CLOEXEC")]
 pub fn ioctl_fioclex<Fd: AsFd>(fd: Fd) -> io::Result<()> {
     // SAFETY: FIOCLEX is a no-argument setter opcode.
     unsafe {
-        let ctl = ioctl::NoArg::<ioctl::BadOpcode<{ c::FIOCLEX }>>::new();
+        let ctl = ioctl::NoArg::<ioctl::BadOpcode<{ c::FIOCLEX as _ }>>::new();
+        ioctl::ioctl(fd, ctl)
+    }
+}
+
+/// `ioctl(fd, FIONCLEX, NULL)`—Clear the close-on-exec flag.
+///
+/// This is similar to `fcntl(fd, F_SETFD, 0)`, except that it avoids clearing
+/// any other flags that might be set.
+///
+/// # References
+///  - [Linux]
+///  - [FreeBSD]
+///  - [NetBSD]
+///  - [OpenBSD]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/ioctl.2.html
+/// [FreeBSD]: https://man.freebsd.org/cgi/man.cgi?query=ioctl&sektion=2#GENERIC%09IOCTLS
+/// [NetBSD]: https://man.netbsd.org/ioctl.2#GENERIC%20IOCTLS
+/// [OpenBSD]: https://man.openbsd.org/ioctl.2#GENERIC_IOCTLS
+#[cfg(any(bsd, linux_kernel, solarish))]
+#[inline]
+#[doc(alias = "FIONCLEX")]
+#[doc(alias = "FD_CLOEXEC")]
+pub fn ioctl_fionclex<Fd: AsFd>(fd: Fd) -> io::Result<()> {
+    // SAFETY: FIONCLEX is a no-argument setter opcode.
+    unsafe {
+        let ctl = ioctl::NoArg::<ioctl::BadOpcode<{ c::FIONCLEX as _ }>>::new();
         ioctl::ioctl(fd, ctl)
     }
 }
@@ -48,6 +87,48 @@ pub fn ioctl_fionbio<Fd: AsFd>(fd: Fd, value: bool) -> io::Result<()> {
     }
 }
 
+/// `ioctl(fd, FIONBIO, &nonblocking)`—Enables or disables non-blocking mode
+/// for a socket, pipe, or other file descriptor.
+///
+/// This has the same effect as setting or clearing `O_NONBLOCK` with
+/// `fcntl(fd, F_GETFL)` and `fcntl(fd, F_SETFL, flags)`.
+///
+/// On Linux, `FIONBIO` is handled generically for all file descriptor types,
+/// and on Windows it's the only way to change the mode of a Winsock socket,
+/// so this just uses `FIONBIO`. On other platforms, `FIONBIO` is implemented
+/// by individual file types and isn't universally supported, so if it fails
+/// with `NOTTY`, `INVAL`, or `NOSYS`, this falls back to a `F_GETFL` and
+/// `F_SETFL` pair, which isn't atomic with respect to other threads changing
+/// the file status flags of the same open file description.
+///
+/// # References
+///  - [POSIX `fcntl`]
+///  - [Linux]
+///  - [Winsock]
+///
+/// [POSIX `fcntl`]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/fcntl.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/ioctl.2.html
+/// [Winsock]: https://docs.microsoft.com/en-us/windows/win32/api/winsock/nf-winsock-ioctlsocket
+#[inline]
+#[doc(alias = "O_NONBLOCK")]
+pub fn set_nonblocking<Fd: AsFd>(fd: Fd, nonblocking: bool) -> io::Result<()> {
+    #[cfg(any(linux_kernel, windows))]
+    {
+        ioctl_fionbio(fd, nonblocking)
+    }
+
+    #[cfg(not(any(linux_kernel, windows)))]
+    {
+        let fd = fd.as_fd();
+        match ioctl_fionbio(fd, nonblocking) {
+            Err(io::Errno::NOTTY | io::Errno::INVAL | io::Errno::NOSYS) => {
+                backend::io::syscalls::fcntl_set_nonblocking(fd, nonblocking)
+            }
+            otherwise => otherwise,
+        }
+    }
+}
+
 /// `ioctl(fd, FIONREAD)`—Returns the number of bytes ready to be read.
 ///
 /// The result of this function gets silently coerced into a C `int` by the OS,
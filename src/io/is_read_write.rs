@@ -9,7 +9,10 @@ use backend::fd::AsFd;
 /// readable and/or writable, respectively.
 ///
 /// Unlike [`is_file_read_write`], this correctly detects whether sockets
-/// have been shutdown, partially or completely.
+/// have been shutdown, partially or completely, by probing them with a
+/// non-blocking `recv` with `MSG_PEEK` and a zero-length `send`. On WASI,
+/// which has no non-blocking `recv` flag, this falls back to reporting the
+/// file's access mode, like `is_file_read_write`.
 ///
 /// [`is_file_read_write`]: crate::fs::is_file_read_write
 #[inline]
@@ -2,8 +2,15 @@
 //!
 //! If you're looking for [`SeekFrom`], that's in the [`fs`] module.
 //!
+//! If you're looking for [`splice`], [`tee`], or [`vmsplice`], those are in
+//! the [`pipe`] module.
+//!
 //! [`SeekFrom`]: https://docs.rs/rustix/*/rustix/fs/enum.SeekFrom.html
 //! [`fs`]: https://docs.rs/rustix/*/rustix/fs/index.html
+//! [`splice`]: https://docs.rs/rustix/*/x86_64-unknown-linux-gnu/rustix/pipe/fn.splice.html
+//! [`tee`]: https://docs.rs/rustix/*/x86_64-unknown-linux-gnu/rustix/pipe/fn.tee.html
+//! [`vmsplice`]: https://docs.rs/rustix/*/x86_64-unknown-linux-gnu/rustix/pipe/fn.vmsplice.html
+//! [`pipe`]: https://docs.rs/rustix/*/rustix/pipe/index.html
 
 mod close;
 #[cfg(not(windows))]
@@ -12,11 +19,16 @@ mod errno;
 #[cfg(not(windows))]
 mod fcntl;
 mod ioctl;
-#[cfg(not(any(windows, target_os = "redox")))]
+#[cfg(not(windows))]
 #[cfg(all(feature = "fs", feature = "net"))]
 mod is_read_write;
 #[cfg(not(windows))]
+mod offset_cursor;
+#[cfg(not(windows))]
 mod read_write;
+#[cfg(not(windows))]
+#[cfg(feature = "std")]
+mod std_adapters;
 
 pub use close::close;
 #[cfg(not(windows))]
@@ -25,8 +37,13 @@ pub use errno::{retry_on_intr, Errno, Result};
 #[cfg(not(windows))]
 pub use fcntl::*;
 pub use ioctl::*;
-#[cfg(not(any(windows, target_os = "redox")))]
+#[cfg(not(windows))]
 #[cfg(all(feature = "fs", feature = "net"))]
 pub use is_read_write::*;
 #[cfg(not(windows))]
+pub use offset_cursor::OffsetCursor;
+#[cfg(not(windows))]
 pub use read_write::*;
+#[cfg(not(windows))]
+#[cfg(feature = "std")]
+pub use std_adapters::{FdReader, FdWriter};
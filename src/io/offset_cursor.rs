@@ -0,0 +1,174 @@
+//! A file descriptor paired with an offset for positioned I/O.
+
+use crate::fd::AsFd;
+use crate::io::{self, pread, pread_exact, pwrite};
+#[cfg(not(any(
+    target_os = "espidf",
+    target_os = "haiku",
+    target_os = "nto",
+    target_os = "redox",
+    target_os = "solaris",
+    target_os = "vita"
+)))]
+use crate::io::{preadv, pwritev, IoSlice, IoSliceMut};
+
+/// A file descriptor together with an offset, for positioned I/O.
+///
+/// Reads and writes go through [`pread`] and [`pwrite`] and friends at the
+/// cursor's offset, and then advance the offset by the number of bytes
+/// transferred. The file descriptor's own file position is never used or
+/// changed, so several cursors can share one file descriptor.
+///
+/// With the `std` feature, this also implements [`std::io::Read`] and
+/// [`std::io::Write`].
+#[derive(Debug, Clone)]
+pub struct OffsetCursor<Fd> {
+    fd: Fd,
+    offset: u64,
+}
+
+impl<Fd: AsFd> OffsetCursor<Fd> {
+    /// Construct a new `OffsetCursor` positioned at `offset` in `fd`.
+    #[inline]
+    pub const fn new(fd: Fd, offset: u64) -> Self {
+        Self { fd, offset }
+    }
+
+    /// Return the current offset.
+    #[inline]
+    pub const fn offset(&self) -> u64 {
+        self.offset
+    }
+
+    /// Move the cursor to `offset`.
+    #[inline]
+    pub fn set_offset(&mut self, offset: u64) {
+        self.offset = offset;
+    }
+
+    /// Return a reference to the file descriptor.
+    #[inline]
+    pub const fn get_ref(&self) -> &Fd {
+        &self.fd
+    }
+
+    /// Consume the cursor and return the file descriptor.
+    #[inline]
+    pub fn into_inner(self) -> Fd {
+        self.fd
+    }
+
+    /// `pread(fd, buf, offset)`—Reads at the cursor and advances it.
+    #[inline]
+    pub fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = pread(&self.fd, buf, self.offset)?;
+        self.advance(n);
+        Ok(n)
+    }
+
+    /// `pread(fd, buf, offset)` in a loop—Fills `buf` from the cursor and
+    /// advances it.
+    ///
+    /// See [`pread_exact`] for how errors are handled. On error, the offset
+    /// is left unchanged.
+    #[inline]
+    pub fn read_exact(&mut self, buf: &mut [u8]) -> io::Result<()> {
+        pread_exact(&self.fd, buf, self.offset)?;
+        self.advance(buf.len());
+        Ok(())
+    }
+
+    /// `pwrite(fd, buf, offset)`—Writes at the cursor and advances it.
+    #[inline]
+    pub fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let n = pwrite(&self.fd, buf, self.offset)?;
+        self.advance(n);
+        Ok(n)
+    }
+
+    /// `preadv(fd, bufs, offset)`—Reads at the cursor into multiple buffers
+    /// and advances it.
+    #[cfg(not(any(
+        target_os = "espidf",
+        target_os = "haiku",
+        target_os = "nto",
+        target_os = "redox",
+        target_os = "solaris",
+        target_os = "vita"
+    )))]
+    #[inline]
+    pub fn read_vectored(&mut self, bufs: &mut [IoSliceMut<'_>]) -> io::Result<usize> {
+        let n = preadv(&self.fd, bufs, self.offset)?;
+        self.advance(n);
+        Ok(n)
+    }
+
+    /// `pwritev(fd, bufs, offset)`—Writes at the cursor from multiple buffers
+    /// and advances it.
+    #[cfg(not(any(
+        target_os = "espidf",
+        target_os = "haiku",
+        target_os = "nto",
+        target_os = "redox",
+        target_os = "solaris",
+        target_os = "vita"
+    )))]
+    #[inline]
+    pub fn write_vectored(&mut self, bufs: &[IoSlice<'_>]) -> io::Result<usize> {
+        let n = pwritev(&self.fd, bufs, self.offset)?;
+        self.advance(n);
+        Ok(n)
+    }
+
+    fn advance(&mut self, n: usize) {
+        // File offsets are at most `i64::MAX`, so this can't overflow.
+        self.offset += n as u64;
+    }
+}
+
+#[cfg(feature = "std")]
+impl<Fd: AsFd> std::io::Read for OffsetCursor<Fd> {
+    #[inline]
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        Ok(OffsetCursor::read(self, buf)?)
+    }
+
+    #[cfg(not(any(
+        target_os = "espidf",
+        target_os = "haiku",
+        target_os = "nto",
+        target_os = "redox",
+        target_os = "solaris",
+        target_os = "vita"
+    )))]
+    #[inline]
+    fn read_vectored(&mut self, bufs: &mut [IoSliceMut<'_>]) -> std::io::Result<usize> {
+        Ok(OffsetCursor::read_vectored(self, bufs)?)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<Fd: AsFd> std::io::Write for OffsetCursor<Fd> {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        Ok(OffsetCursor::write(self, buf)?)
+    }
+
+    #[cfg(not(any(
+        target_os = "espidf",
+        target_os = "haiku",
+        target_os = "nto",
+        target_os = "redox",
+        target_os = "solaris",
+        target_os = "vita"
+    )))]
+    #[inline]
+    fn write_vectored(&mut self, bufs: &[IoSlice<'_>]) -> std::io::Result<usize> {
+        Ok(OffsetCursor::write_vectored(self, bufs)?)
+    }
+
+    #[inline]
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
@@ -59,6 +59,34 @@ pub fn read_uninit<Fd: AsFd>(
     Ok(unsafe { split_init(buf, length?) })
 }
 
+/// `read(fd, buf)` in a loop—Reads from a stream until `buf` is full.
+///
+/// This retries short reads and reads interrupted by [`io::Errno::INTR`]. If
+/// the stream reaches end-of-file before `buf` is full, this fails with
+/// [`io::Errno::IO`], like `std`'s `ErrorKind::UnexpectedEof`, and the
+/// contents of `buf` are unspecified.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/read.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/read.2.html
+#[inline]
+pub fn read_exact<Fd: AsFd>(fd: Fd, buf: &mut [u8]) -> io::Result<()> {
+    let fd = fd.as_fd();
+    let mut filled = 0;
+    while filled < buf.len() {
+        match read(fd, &mut buf[filled..]) {
+            Ok(0) => return Err(io::Errno::IO),
+            Ok(n) => filled += n,
+            Err(io::Errno::INTR) => (),
+            Err(err) => return Err(err),
+        }
+    }
+    Ok(())
+}
+
 /// `write(fd, buf)`—Writes to a stream.
 ///
 /// # References
@@ -128,6 +156,36 @@ pub fn pread_uninit<Fd: AsFd>(
     Ok(unsafe { split_init(buf, length?) })
 }
 
+/// `pread(fd, buf, offset)` in a loop—Reads from a file at a given position
+/// until `buf` is full.
+///
+/// This retries short reads and reads interrupted by [`io::Errno::INTR`]. If
+/// the file ends before `buf` is full, this fails with [`io::Errno::IO`], like
+/// `std`'s `ErrorKind::UnexpectedEof`, and the contents of `buf` are
+/// unspecified.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/pread.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/pread.2.html
+#[inline]
+pub fn pread_exact<Fd: AsFd>(fd: Fd, buf: &mut [u8], offset: u64) -> io::Result<()> {
+    let fd = fd.as_fd();
+    let mut filled = 0;
+    while filled < buf.len() {
+        let pos = offset.checked_add(filled as u64).ok_or(io::Errno::INVAL)?;
+        match pread(fd, &mut buf[filled..], pos) {
+            Ok(0) => return Err(io::Errno::IO),
+            Ok(n) => filled += n,
+            Err(io::Errno::INTR) => (),
+            Err(err) => return Err(err),
+        }
+    }
+    Ok(())
+}
+
 /// `pwrite(fd, bufs)`—Writes to a file at a given position.
 ///
 /// Contrary to POSIX, on many popular platforms including Linux and FreeBSD,
@@ -209,6 +267,46 @@ pub fn writev<Fd: AsFd>(fd: Fd, bufs: &[IoSlice<'_>]) -> io::Result<usize> {
     backend::io::syscalls::writev(fd.as_fd(), bufs)
 }
 
+/// `writev(fd, bufs)`—Writes to a stream from buffers produced by an
+/// iterator.
+///
+/// This collects up to 64 buffers, or the platform's maximum number of
+/// buffers for a vectored write if that's lower, into a small array of
+/// [`IoSlice`]s on the stack and writes them with a single [`writev`].
+/// Buffers beyond that aren't consumed from the iterator. As with `writev`,
+/// fewer bytes than requested may be written.
+///
+/// This is convenient for writing out data held in several pieces, such as
+/// the two halves of a ring buffer, without building a slice of `IoSlice`s.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/writev.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/writev.2.html
+#[cfg(not(target_os = "espidf"))]
+#[inline]
+pub fn writev_from_iter<'a, Fd: AsFd, I: IntoIterator<Item = &'a [u8]>>(
+    fd: Fd,
+    bufs: I,
+) -> io::Result<usize> {
+    // Keep the array small, so that this is usable on small thread stacks.
+    const MAX_BUFS: usize = if backend::MAX_IOV < 64 {
+        backend::MAX_IOV
+    } else {
+        64
+    };
+
+    let mut slices = [IoSlice::new(&[]); MAX_BUFS];
+    let mut len = 0;
+    for (slice, buf) in slices.iter_mut().zip(bufs) {
+        *slice = IoSlice::new(buf);
+        len += 1;
+    }
+    writev(fd, &slices[..len])
+}
+
 /// `preadv(fd, bufs, offset)`—Reads from a file at a given position into
 /// multiple buffers.
 ///
@@ -310,3 +408,27 @@ pub fn pwritev2<Fd: AsFd>(
 ) -> io::Result<usize> {
     backend::io::syscalls::pwritev2(fd.as_fd(), bufs, offset, flags)
 }
+
+/// `pwritev2(fd, &[buf], 0, RWF_APPEND)`—Atomically appends data to a file.
+///
+/// This appends `buf` to the end of the file as a single atomic write, as if
+/// the file had been opened with `O_APPEND`, even if it wasn't. This lets
+/// several processes or threads sharing a file, such as loggers, append
+/// records without interleaving them. The current file offset is not used or
+/// updated.
+///
+/// Like [`write`], this may write fewer bytes than requested. On Linux
+/// versions before 4.16, which don't support `RWF_APPEND`, this fails with
+/// [`io::Errno::NOTSUP`].
+///
+/// # References
+///  - [Linux]
+///
+/// [`write`]: crate::io::write()
+/// [Linux]: https://man7.org/linux/man-pages/man2/pwritev2.2.html
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "RWF_APPEND")]
+pub fn append_write<Fd: AsFd>(fd: Fd, buf: &[u8]) -> io::Result<usize> {
+    pwritev2(fd, &[IoSlice::new(buf)], 0, ReadWriteFlags::APPEND)
+}
@@ -0,0 +1,116 @@
+//! Adapters implementing `std::io` traits for file descriptors.
+
+use crate::fd::AsFd;
+use crate::io::{read, write};
+#[cfg(not(target_os = "espidf"))]
+use crate::io::{readv, writev, IoSlice, IoSliceMut};
+
+/// An adapter implementing [`std::io::Read`] for a file descriptor.
+///
+/// This reads with rustix's [`read`], so it works with any [`AsFd`] type
+/// without converting it to a [`std::fs::File`]. With the `fs` feature, it
+/// also implements [`std::io::Seek`].
+#[derive(Debug, Clone)]
+pub struct FdReader<Fd> {
+    fd: Fd,
+}
+
+impl<Fd: AsFd> FdReader<Fd> {
+    /// Construct a new `FdReader` reading from `fd`.
+    #[inline]
+    pub const fn new(fd: Fd) -> Self {
+        Self { fd }
+    }
+
+    /// Return a reference to the file descriptor.
+    #[inline]
+    pub const fn get_ref(&self) -> &Fd {
+        &self.fd
+    }
+
+    /// Consume the adapter and return the file descriptor.
+    #[inline]
+    pub fn into_inner(self) -> Fd {
+        self.fd
+    }
+}
+
+impl<Fd: AsFd> std::io::Read for FdReader<Fd> {
+    #[inline]
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        Ok(read(&self.fd, buf)?)
+    }
+
+    #[cfg(not(target_os = "espidf"))]
+    #[inline]
+    fn read_vectored(&mut self, bufs: &mut [IoSliceMut<'_>]) -> std::io::Result<usize> {
+        Ok(readv(&self.fd, bufs)?)
+    }
+}
+
+#[cfg(feature = "fs")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "fs")))]
+impl<Fd: AsFd> std::io::Seek for FdReader<Fd> {
+    #[inline]
+    fn seek(&mut self, pos: std::io::SeekFrom) -> std::io::Result<u64> {
+        Ok(crate::fs::seek(&self.fd, pos.into())?)
+    }
+}
+
+/// An adapter implementing [`std::io::Write`] for a file descriptor.
+///
+/// This writes with rustix's [`write`][crate::io::write()], so it works with
+/// any [`AsFd`] type without converting it to a [`std::fs::File`]. It does no
+/// buffering of its own, so [`std::io::Write::flush`] does nothing. With the
+/// `fs` feature, it also implements [`std::io::Seek`].
+#[derive(Debug, Clone)]
+pub struct FdWriter<Fd> {
+    fd: Fd,
+}
+
+impl<Fd: AsFd> FdWriter<Fd> {
+    /// Construct a new `FdWriter` writing to `fd`.
+    #[inline]
+    pub const fn new(fd: Fd) -> Self {
+        Self { fd }
+    }
+
+    /// Return a reference to the file descriptor.
+    #[inline]
+    pub const fn get_ref(&self) -> &Fd {
+        &self.fd
+    }
+
+    /// Consume the adapter and return the file descriptor.
+    #[inline]
+    pub fn into_inner(self) -> Fd {
+        self.fd
+    }
+}
+
+impl<Fd: AsFd> std::io::Write for FdWriter<Fd> {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        Ok(write(&self.fd, buf)?)
+    }
+
+    #[cfg(not(target_os = "espidf"))]
+    #[inline]
+    fn write_vectored(&mut self, bufs: &[IoSlice<'_>]) -> std::io::Result<usize> {
+        Ok(writev(&self.fd, bufs)?)
+    }
+
+    #[inline]
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(feature = "fs")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "fs")))]
+impl<Fd: AsFd> std::io::Seek for FdWriter<Fd> {
+    #[inline]
+    fn seek(&mut self, pos: std::io::SeekFrom) -> std::io::Result<u64> {
+        Ok(crate::fs::seek(&self.fd, pos.into())?)
+    }
+}
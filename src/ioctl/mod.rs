@@ -117,6 +117,23 @@ unsafe fn _ioctl_readonly(
     crate::backend::io::syscalls::ioctl_readonly(fd, request, arg)
 }
 
+/// Perform an `ioctl` call that doesn't fit the [`Ioctl`] patterns, such as
+/// one whose opcode encodes the length of a caller-provided buffer, or whose
+/// argument is a variable-size buffer.
+///
+/// # Safety
+///
+/// `opcode` must be a valid opcode for `fd`, and `arg` must be the argument
+/// the kernel expects for it.
+#[cfg(ioctl_with_opcode)]
+pub(crate) unsafe fn ioctl_with_opcode(
+    fd: BorrowedFd<'_>,
+    opcode: Opcode,
+    arg: *mut c::c_void,
+) -> Result<IoctlOutput> {
+    _ioctl(fd, opcode.raw(), arg)
+}
+
 /// A trait defining the properties of an `ioctl` command.
 ///
 /// Objects implementing this trait can be passed to [`ioctl`] to make an
@@ -272,7 +289,7 @@ impl Opcode {
 
     /// Get the raw opcode.
     #[inline]
-    pub fn raw(self) -> RawOpcode {
+    pub const fn raw(self) -> RawOpcode {
         self.raw
     }
 }
@@ -153,6 +153,57 @@ unsafe impl<Opcode: CompileTimeOpcode, Input> Ioctl for Setter<Opcode, Input> {
     }
 }
 
+/// Implements the pattern for `ioctl`s where an integer argument is passed
+/// directly, rather than through a pointer.
+pub struct IntegerSetter<Opcode> {
+    /// The value to pass.
+    value: usize,
+
+    /// The opcode.
+    _opcode: PhantomData<Opcode>,
+}
+
+impl<Opcode: CompileTimeOpcode> fmt::Debug for IntegerSetter<Opcode> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("IntegerSetter")
+            .field(&Opcode::OPCODE)
+            .field(&self.value)
+            .finish()
+    }
+}
+
+impl<Opcode: CompileTimeOpcode> IntegerSetter<Opcode> {
+    /// Create a new integer setter-style `ioctl` object.
+    ///
+    /// # Safety
+    ///
+    /// - `Opcode` must provide a valid opcode.
+    /// - For this opcode, the kernel must expect an integer argument rather
+    ///   than a pointer.
+    #[inline]
+    pub unsafe fn new(value: usize) -> Self {
+        Self {
+            value,
+            _opcode: PhantomData,
+        }
+    }
+}
+
+unsafe impl<Opcode: CompileTimeOpcode> Ioctl for IntegerSetter<Opcode> {
+    type Output = ();
+
+    const IS_MUTATING: bool = false;
+    const OPCODE: self::Opcode = Opcode::OPCODE;
+
+    fn as_ptr(&mut self) -> *mut c::c_void {
+        self.value as *mut c::c_void
+    }
+
+    unsafe fn output_from_ptr(_: IoctlOutput, _: *mut c::c_void) -> Result<Self::Output> {
+        Ok(())
+    }
+}
+
 /// Implements an “updater” pattern for `ioctl`s.
 ///
 /// The ioctl takes a reference to a struct that it reads its input from,
@@ -0,0 +1,408 @@
+//! Linux kernel key management facility.
+//!
+//! These functions operate on the kernel's key retention service, which
+//! stores credentials, encryption keys, and other data in keys and keyrings
+//! managed by the kernel. This allows programs to use the kernel keyring
+//! without depending on libkeyutils.
+//!
+//! # References
+//!  - [Linux]
+//!
+//! [Linux]: https://man7.org/linux/man-pages/man7/keyrings.7.html
+
+#![allow(unsafe_code)]
+
+use crate::backend::c::{c_int, c_void};
+use crate::backend::keyctl::syscalls;
+use crate::ffi::CStr;
+use crate::io;
+use bitflags::bitflags;
+use core::ptr::null_mut;
+
+const KEYCTL_GET_KEYRING_ID: c_int = 0;
+const KEYCTL_JOIN_SESSION_KEYRING: c_int = 1;
+const KEYCTL_UPDATE: c_int = 2;
+const KEYCTL_REVOKE: c_int = 3;
+const KEYCTL_SETPERM: c_int = 5;
+const KEYCTL_CLEAR: c_int = 7;
+const KEYCTL_LINK: c_int = 8;
+const KEYCTL_UNLINK: c_int = 9;
+const KEYCTL_SEARCH: c_int = 10;
+const KEYCTL_READ: c_int = 11;
+const KEYCTL_SET_TIMEOUT: c_int = 15;
+const KEYCTL_INVALIDATE: c_int = 21;
+
+/// `key_serial_t`—The serial number of a key or keyring.
+///
+/// Besides the serial numbers of actual keys, this includes special values
+/// such as [`KeySerial::SESSION_KEYRING`], which refer to keyrings relative to
+/// the calling thread.
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct KeySerial(i32);
+
+impl KeySerial {
+    /// `KEY_SPEC_THREAD_KEYRING`—The calling thread's keyring.
+    #[doc(alias = "KEY_SPEC_THREAD_KEYRING")]
+    pub const THREAD_KEYRING: Self = Self(-1);
+
+    /// `KEY_SPEC_PROCESS_KEYRING`—The calling process' keyring.
+    #[doc(alias = "KEY_SPEC_PROCESS_KEYRING")]
+    pub const PROCESS_KEYRING: Self = Self(-2);
+
+    /// `KEY_SPEC_SESSION_KEYRING`—The calling process' session keyring.
+    #[doc(alias = "KEY_SPEC_SESSION_KEYRING")]
+    pub const SESSION_KEYRING: Self = Self(-3);
+
+    /// `KEY_SPEC_USER_KEYRING`—The calling process' UID-specific keyring.
+    #[doc(alias = "KEY_SPEC_USER_KEYRING")]
+    pub const USER_KEYRING: Self = Self(-4);
+
+    /// `KEY_SPEC_USER_SESSION_KEYRING`—The calling process' UID-session
+    /// keyring.
+    #[doc(alias = "KEY_SPEC_USER_SESSION_KEYRING")]
+    pub const USER_SESSION_KEYRING: Self = Self(-5);
+
+    /// `KEY_SPEC_GROUP_KEYRING`—The calling process' GID-specific keyring.
+    #[doc(alias = "KEY_SPEC_GROUP_KEYRING")]
+    pub const GROUP_KEYRING: Self = Self(-6);
+
+    /// `KEY_SPEC_REQKEY_AUTH_KEY`—The authorization key for a `request_key`
+    /// upcall.
+    #[doc(alias = "KEY_SPEC_REQKEY_AUTH_KEY")]
+    pub const REQKEY_AUTH_KEY: Self = Self(-7);
+
+    /// Converts a raw `key_serial_t` into a `KeySerial`.
+    #[inline]
+    pub const fn from_raw(raw: i32) -> Self {
+        Self(raw)
+    }
+
+    /// Converts a `KeySerial` into a raw `key_serial_t`.
+    #[inline]
+    pub const fn as_raw(self) -> i32 {
+        self.0
+    }
+}
+
+bitflags! {
+    /// `KEY_{POS,USR,GRP,OTH}_*` permission bits for use with
+    /// [`keyctl_setperm`].
+    #[repr(transparent)]
+    #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    pub struct KeyPerm: u32 {
+        /// `KEY_POS_VIEW`
+        const POS_VIEW = 0x0100_0000;
+        /// `KEY_POS_READ`
+        const POS_READ = 0x0200_0000;
+        /// `KEY_POS_WRITE`
+        const POS_WRITE = 0x0400_0000;
+        /// `KEY_POS_SEARCH`
+        const POS_SEARCH = 0x0800_0000;
+        /// `KEY_POS_LINK`
+        const POS_LINK = 0x1000_0000;
+        /// `KEY_POS_SETATTR`
+        const POS_SETATTR = 0x2000_0000;
+        /// `KEY_POS_ALL`
+        const POS_ALL = 0x3f00_0000;
+
+        /// `KEY_USR_VIEW`
+        const USR_VIEW = 0x0001_0000;
+        /// `KEY_USR_READ`
+        const USR_READ = 0x0002_0000;
+        /// `KEY_USR_WRITE`
+        const USR_WRITE = 0x0004_0000;
+        /// `KEY_USR_SEARCH`
+        const USR_SEARCH = 0x0008_0000;
+        /// `KEY_USR_LINK`
+        const USR_LINK = 0x0010_0000;
+        /// `KEY_USR_SETATTR`
+        const USR_SETATTR = 0x0020_0000;
+        /// `KEY_USR_ALL`
+        const USR_ALL = 0x003f_0000;
+
+        /// `KEY_GRP_VIEW`
+        const GRP_VIEW = 0x0000_0100;
+        /// `KEY_GRP_READ`
+        const GRP_READ = 0x0000_0200;
+        /// `KEY_GRP_WRITE`
+        const GRP_WRITE = 0x0000_0400;
+        /// `KEY_GRP_SEARCH`
+        const GRP_SEARCH = 0x0000_0800;
+        /// `KEY_GRP_LINK`
+        const GRP_LINK = 0x0000_1000;
+        /// `KEY_GRP_SETATTR`
+        const GRP_SETATTR = 0x0000_2000;
+        /// `KEY_GRP_ALL`
+        const GRP_ALL = 0x0000_3f00;
+
+        /// `KEY_OTH_VIEW`
+        const OTH_VIEW = 0x0000_0001;
+        /// `KEY_OTH_READ`
+        const OTH_READ = 0x0000_0002;
+        /// `KEY_OTH_WRITE`
+        const OTH_WRITE = 0x0000_0004;
+        /// `KEY_OTH_SEARCH`
+        const OTH_SEARCH = 0x0000_0008;
+        /// `KEY_OTH_LINK`
+        const OTH_LINK = 0x0000_0010;
+        /// `KEY_OTH_SETATTR`
+        const OTH_SETATTR = 0x0000_0020;
+        /// `KEY_OTH_ALL`
+        const OTH_ALL = 0x0000_003f;
+
+        /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+        const _ = !0;
+    }
+}
+
+/// `add_key(type_, description, payload, keyring)`—Creates or updates a key
+/// and links it into a keyring.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/add_key.2.html
+#[inline]
+pub fn add_key(
+    type_: &CStr,
+    description: &CStr,
+    payload: &[u8],
+    keyring: KeySerial,
+) -> io::Result<KeySerial> {
+    syscalls::add_key(type_, description, payload, keyring.0).map(KeySerial)
+}
+
+/// `request_key(type_, description, callout_info, dest_keyring)`—Requests
+/// a key, possibly invoking an upcall to construct it.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/request_key.2.html
+#[inline]
+pub fn request_key(
+    type_: &CStr,
+    description: &CStr,
+    callout_info: Option<&CStr>,
+    dest_keyring: Option<KeySerial>,
+) -> io::Result<KeySerial> {
+    let dest_keyring = dest_keyring.map_or(0, |key| key.0);
+    syscalls::request_key(type_, description, callout_info, dest_keyring).map(KeySerial)
+}
+
+/// `keyctl(KEYCTL_GET_KEYRING_ID, key, create)`—Resolves a special key ID
+/// to the serial number of an actual key.
+///
+/// If `create` is true, the keyring is created if it doesn't exist yet.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/keyctl.2.html
+#[inline]
+#[doc(alias = "KEYCTL_GET_KEYRING_ID")]
+pub fn keyctl_get_keyring_id(key: KeySerial, create: bool) -> io::Result<KeySerial> {
+    unsafe { keyctl_3args(KEYCTL_GET_KEYRING_ID, key, usize::from(create) as _) }
+        .map(|id| KeySerial(id as i32))
+}
+
+/// `keyctl(KEYCTL_JOIN_SESSION_KEYRING, name)`—Joins or creates a session
+/// keyring.
+///
+/// If `name` is `None`, a new anonymous session keyring is created.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/keyctl.2.html
+#[inline]
+#[doc(alias = "KEYCTL_JOIN_SESSION_KEYRING")]
+pub fn keyctl_join_session_keyring(name: Option<&CStr>) -> io::Result<KeySerial> {
+    let name = name.map_or(null_mut(), |name| name.as_ptr() as *mut c_void);
+    unsafe {
+        syscalls::keyctl(
+            KEYCTL_JOIN_SESSION_KEYRING,
+            name,
+            null_mut(),
+            null_mut(),
+            null_mut(),
+        )
+    }
+    .map(|id| KeySerial(id as i32))
+}
+
+/// `keyctl(KEYCTL_UPDATE, key, payload, len)`—Updates a key's payload.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/keyctl.2.html
+#[inline]
+#[doc(alias = "KEYCTL_UPDATE")]
+pub fn keyctl_update(key: KeySerial, payload: &[u8]) -> io::Result<()> {
+    unsafe {
+        syscalls::keyctl(
+            KEYCTL_UPDATE,
+            key.0 as usize as *mut c_void,
+            payload.as_ptr() as *mut c_void,
+            payload.len() as *mut c_void,
+            null_mut(),
+        )
+    }
+    .map(|_| ())
+}
+
+/// `keyctl(KEYCTL_REVOKE, key)`—Revokes a key.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/keyctl.2.html
+#[inline]
+#[doc(alias = "KEYCTL_REVOKE")]
+pub fn keyctl_revoke(key: KeySerial) -> io::Result<()> {
+    unsafe { keyctl_3args(KEYCTL_REVOKE, key, null_mut()) }.map(|_| ())
+}
+
+/// `keyctl(KEYCTL_INVALIDATE, key)`—Invalidates a key, scheduling it for
+/// immediate garbage collection.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/keyctl.2.html
+#[inline]
+#[doc(alias = "KEYCTL_INVALIDATE")]
+pub fn keyctl_invalidate(key: KeySerial) -> io::Result<()> {
+    unsafe { keyctl_3args(KEYCTL_INVALIDATE, key, null_mut()) }.map(|_| ())
+}
+
+/// `keyctl(KEYCTL_SETPERM, key, perm)`—Sets the permissions of a key.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/keyctl.2.html
+#[inline]
+#[doc(alias = "KEYCTL_SETPERM")]
+pub fn keyctl_setperm(key: KeySerial, perm: KeyPerm) -> io::Result<()> {
+    unsafe { keyctl_3args(KEYCTL_SETPERM, key, perm.bits() as usize as *mut c_void) }.map(|_| ())
+}
+
+/// `keyctl(KEYCTL_SET_TIMEOUT, key, seconds)`—Sets an expiration timer on a
+/// key.
+///
+/// A timeout of zero clears any existing timer.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/keyctl.2.html
+#[inline]
+#[doc(alias = "KEYCTL_SET_TIMEOUT")]
+pub fn keyctl_set_timeout(key: KeySerial, seconds: u32) -> io::Result<()> {
+    unsafe { keyctl_3args(KEYCTL_SET_TIMEOUT, key, seconds as usize as *mut c_void) }.map(|_| ())
+}
+
+/// `keyctl(KEYCTL_CLEAR, keyring)`—Unlinks all keys from a keyring.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/keyctl.2.html
+#[inline]
+#[doc(alias = "KEYCTL_CLEAR")]
+pub fn keyctl_clear(keyring: KeySerial) -> io::Result<()> {
+    unsafe { keyctl_3args(KEYCTL_CLEAR, keyring, null_mut()) }.map(|_| ())
+}
+
+/// `keyctl(KEYCTL_LINK, key, keyring)`—Links a key into a keyring.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/keyctl.2.html
+#[inline]
+#[doc(alias = "KEYCTL_LINK")]
+pub fn keyctl_link(key: KeySerial, keyring: KeySerial) -> io::Result<()> {
+    unsafe { keyctl_3args(KEYCTL_LINK, key, keyring.0 as usize as *mut c_void) }.map(|_| ())
+}
+
+/// `keyctl(KEYCTL_UNLINK, key, keyring)`—Unlinks a key from a keyring.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/keyctl.2.html
+#[inline]
+#[doc(alias = "KEYCTL_UNLINK")]
+pub fn keyctl_unlink(key: KeySerial, keyring: KeySerial) -> io::Result<()> {
+    unsafe { keyctl_3args(KEYCTL_UNLINK, key, keyring.0 as usize as *mut c_void) }.map(|_| ())
+}
+
+/// `keyctl(KEYCTL_SEARCH, keyring, type_, description, dest_keyring)`—Searches
+/// a keyring tree for a key.
+///
+/// If `dest_keyring` is `Some`, the found key is also linked into it.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/keyctl.2.html
+#[inline]
+#[doc(alias = "KEYCTL_SEARCH")]
+pub fn keyctl_search(
+    keyring: KeySerial,
+    type_: &CStr,
+    description: &CStr,
+    dest_keyring: Option<KeySerial>,
+) -> io::Result<KeySerial> {
+    let dest_keyring = dest_keyring.map_or(0, |key| key.0);
+    unsafe {
+        syscalls::keyctl(
+            KEYCTL_SEARCH,
+            keyring.0 as usize as *mut c_void,
+            type_.as_ptr() as *mut c_void,
+            description.as_ptr() as *mut c_void,
+            dest_keyring as usize as *mut c_void,
+        )
+    }
+    .map(|id| KeySerial(id as i32))
+}
+
+/// `keyctl(KEYCTL_READ, key, buf, buflen)`—Reads the payload of a key.
+///
+/// This returns the full size of the payload, which may be larger than
+/// `buf`, in which case only the first `buf.len()` bytes were written and
+/// the caller may retry with a bigger buffer. For keyrings, the payload is an
+/// array of `i32` key serial numbers.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/keyctl.2.html
+#[inline]
+#[doc(alias = "KEYCTL_READ")]
+pub fn keyctl_read(key: KeySerial, buf: &mut [u8]) -> io::Result<usize> {
+    unsafe {
+        syscalls::keyctl(
+            KEYCTL_READ,
+            key.0 as usize as *mut c_void,
+            buf.as_mut_ptr().cast(),
+            buf.len() as *mut c_void,
+            null_mut(),
+        )
+    }
+}
+
+#[inline]
+unsafe fn keyctl_3args(option: c_int, key: KeySerial, arg3: *mut c_void) -> io::Result<usize> {
+    syscalls::keyctl(
+        option,
+        key.0 as usize as *mut c_void,
+        arg3,
+        null_mut(),
+        null_mut(),
+    )
+}
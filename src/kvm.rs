@@ -0,0 +1,503 @@
+//! Linux Kernel-based Virtual Machine (KVM) functions.
+//!
+//! KVM is used through three kinds of file descriptors: the system file
+//! descriptor opened from `/dev/kvm`, virtual machine file descriptors
+//! created with [`ioctl_kvm_create_vm`], and virtual CPU file descriptors
+//! created with [`ioctl_kvm_create_vcpu`]. Each virtual CPU shares a
+//! [`Run`] structure with the kernel, which is mapped with [`mmap_run`].
+//!
+//! # References
+//!  - [Linux]
+//!
+//! [Linux]: https://docs.kernel.org/virt/kvm/api.html
+
+#![allow(unsafe_code)]
+
+use crate::backend::c;
+use crate::fd::{AsFd, FromRawFd, OwnedFd};
+use crate::{io, ioctl};
+use bitflags::bitflags;
+
+/// `KVMIO`
+const KVMIO: u8 = 0xae;
+
+/// `KVM_API_VERSION`—The only API version there has ever been, returned by
+/// [`ioctl_kvm_get_api_version`].
+pub const API_VERSION: i32 = 12;
+
+bitflags! {
+    /// `KVM_MEM_*` constants for use with [`UserspaceMemoryRegion`].
+    #[repr(transparent)]
+    #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    pub struct MemoryRegionFlags: u32 {
+        /// `KVM_MEM_LOG_DIRTY_PAGES`
+        const LOG_DIRTY_PAGES = 0x1;
+        /// `KVM_MEM_READONLY`
+        const READONLY = 0x2;
+
+        /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+        const _ = !0;
+    }
+}
+
+/// `struct kvm_userspace_memory_region`—A slot of guest physical memory
+/// backed by memory of this process, for use with
+/// [`ioctl_kvm_set_user_memory_region`].
+#[repr(C)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct UserspaceMemoryRegion {
+    /// The slot number.
+    pub slot: u32,
+
+    /// Flags for the slot.
+    pub flags: MemoryRegionFlags,
+
+    /// The guest physical address of the start of the slot.
+    pub guest_phys_addr: u64,
+
+    /// The size of the slot, in bytes, or 0 to delete the slot.
+    pub memory_size: u64,
+
+    /// The address in this process of the memory backing the slot.
+    pub userspace_addr: u64,
+}
+
+/// `KVM_EXIT_*`—The reason [`ioctl_kvm_run`] returned.
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct ExitReason(u32);
+
+impl ExitReason {
+    /// `KVM_EXIT_UNKNOWN`
+    #[doc(alias = "KVM_EXIT_UNKNOWN")]
+    pub const UNKNOWN: Self = Self(0);
+
+    /// `KVM_EXIT_EXCEPTION`
+    #[doc(alias = "KVM_EXIT_EXCEPTION")]
+    pub const EXCEPTION: Self = Self(1);
+
+    /// `KVM_EXIT_IO`—The guest accessed an I/O port; see [`Run::io`].
+    #[doc(alias = "KVM_EXIT_IO")]
+    pub const IO: Self = Self(2);
+
+    /// `KVM_EXIT_HYPERCALL`
+    #[doc(alias = "KVM_EXIT_HYPERCALL")]
+    pub const HYPERCALL: Self = Self(3);
+
+    /// `KVM_EXIT_DEBUG`
+    #[doc(alias = "KVM_EXIT_DEBUG")]
+    pub const DEBUG: Self = Self(4);
+
+    /// `KVM_EXIT_HLT`—The guest executed a halt instruction.
+    #[doc(alias = "KVM_EXIT_HLT")]
+    pub const HLT: Self = Self(5);
+
+    /// `KVM_EXIT_MMIO`—The guest accessed memory that isn't backed by a
+    /// memory slot; see [`Run::mmio_mut`].
+    #[doc(alias = "KVM_EXIT_MMIO")]
+    pub const MMIO: Self = Self(6);
+
+    /// `KVM_EXIT_IRQ_WINDOW_OPEN`
+    #[doc(alias = "KVM_EXIT_IRQ_WINDOW_OPEN")]
+    pub const IRQ_WINDOW_OPEN: Self = Self(7);
+
+    /// `KVM_EXIT_SHUTDOWN`—The guest triple faulted or otherwise shut
+    /// down.
+    #[doc(alias = "KVM_EXIT_SHUTDOWN")]
+    pub const SHUTDOWN: Self = Self(8);
+
+    /// `KVM_EXIT_FAIL_ENTRY`
+    #[doc(alias = "KVM_EXIT_FAIL_ENTRY")]
+    pub const FAIL_ENTRY: Self = Self(9);
+
+    /// `KVM_EXIT_INTR`—A signal is pending.
+    #[doc(alias = "KVM_EXIT_INTR")]
+    pub const INTR: Self = Self(10);
+
+    /// `KVM_EXIT_INTERNAL_ERROR`
+    #[doc(alias = "KVM_EXIT_INTERNAL_ERROR")]
+    pub const INTERNAL_ERROR: Self = Self(17);
+
+    /// `KVM_EXIT_SYSTEM_EVENT`
+    #[doc(alias = "KVM_EXIT_SYSTEM_EVENT")]
+    pub const SYSTEM_EVENT: Self = Self(24);
+
+    /// Constructs an `ExitReason` from a raw `KVM_EXIT_*` value.
+    #[inline]
+    pub const fn from_raw(raw: u32) -> Self {
+        Self(raw)
+    }
+
+    /// Returns the raw `KVM_EXIT_*` value.
+    #[inline]
+    pub const fn as_raw(self) -> u32 {
+        self.0
+    }
+}
+
+/// The `io` member of `struct kvm_run`, describing an I/O port access.
+#[repr(C)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct ExitIo {
+    /// `KVM_EXIT_IO_IN` (0) or `KVM_EXIT_IO_OUT` (1).
+    pub direction: u8,
+
+    /// The size of each access, in bytes.
+    pub size: u8,
+
+    /// The port number.
+    pub port: u16,
+
+    /// The number of accesses.
+    pub count: u32,
+
+    /// The offset of the data, from the start of the mapping returned by
+    /// [`mmap_run`].
+    pub data_offset: u64,
+}
+
+/// The `mmio` member of `struct kvm_run`, describing a memory-mapped I/O
+/// access.
+#[repr(C)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct ExitMmio {
+    /// The guest physical address that was accessed.
+    pub phys_addr: u64,
+
+    /// The data written by the guest, or to be read by the guest when the
+    /// virtual CPU is next run.
+    pub data: [u8; 8],
+
+    /// The number of bytes of `data` that are used.
+    pub len: u32,
+
+    /// Whether the access is a write.
+    pub is_write: u8,
+}
+
+/// `struct kvm_run`—The state shared between the kernel and the
+/// user-space part of a virtual CPU, mapped with [`mmap_run`].
+///
+/// This only describes the architecture-independent start of the
+/// structure.
+#[repr(C)]
+pub struct Run {
+    /// Requests an exit with [`ExitReason::IRQ_WINDOW_OPEN`] when the guest
+    /// can accept an interrupt.
+    pub request_interrupt_window: u8,
+
+    /// Makes [`ioctl_kvm_run`] return immediately with `INTR`.
+    pub immediate_exit: u8,
+
+    padding1: [u8; 6],
+
+    exit_reason: u32,
+
+    /// Whether an interrupt can be injected now.
+    pub ready_for_interrupt_injection: u8,
+
+    /// The value of the guest's interrupt flag.
+    pub if_flag: u8,
+
+    /// Architecture-specific `KVM_RUN_*` flags.
+    pub flags: u16,
+
+    /// The value of the guest's CR8 register, on x86.
+    pub cr8: u64,
+
+    /// The value of the guest's APIC base MSR, on x86.
+    pub apic_base: u64,
+
+    exit: [u64; 32],
+}
+
+impl Run {
+    /// Returns why [`ioctl_kvm_run`] last returned.
+    #[inline]
+    pub fn exit_reason(&self) -> ExitReason {
+        ExitReason(self.exit_reason)
+    }
+
+    /// Returns the I/O port access that caused the exit, if the exit reason
+    /// is [`ExitReason::IO`].
+    #[inline]
+    pub fn io(&self) -> Option<&ExitIo> {
+        if self.exit_reason() != ExitReason::IO {
+            return None;
+        }
+        // SAFETY: The `io` member of the union is active for `KVM_EXIT_IO`.
+        Some(unsafe { &*self.exit.as_ptr().cast::<ExitIo>() })
+    }
+
+    /// Returns the memory-mapped I/O access that caused the exit, if the
+    /// exit reason is [`ExitReason::MMIO`].
+    ///
+    /// For reads, the value to be read by the guest should be written to
+    /// [`ExitMmio::data`] before running the virtual CPU again.
+    #[inline]
+    pub fn mmio_mut(&mut self) -> Option<&mut ExitMmio> {
+        if self.exit_reason() != ExitReason::MMIO {
+            return None;
+        }
+        // SAFETY: The `mmio` member of the union is active for
+        // `KVM_EXIT_MMIO`.
+        Some(unsafe { &mut *self.exit.as_mut_ptr().cast::<ExitMmio>() })
+    }
+}
+
+/// A KVM `_IO` `ioctl` that takes an integer argument and returns an
+/// integer.
+///
+/// This is like [`ioctl::IntegerSetter`], except that it returns the
+/// `ioctl`'s result rather than discarding it.
+struct IntegerIoctl<const NUM: u8>(usize);
+
+unsafe impl<const NUM: u8> ioctl::Ioctl for IntegerIoctl<NUM> {
+    type Output = c::c_int;
+
+    const IS_MUTATING: bool = false;
+    const OPCODE: ioctl::Opcode = ioctl::Opcode::none::<()>(KVMIO, NUM);
+
+    fn as_ptr(&mut self) -> *mut c::c_void {
+        self.0 as *mut c::c_void
+    }
+
+    unsafe fn output_from_ptr(
+        ret: ioctl::IoctlOutput,
+        _arg: *mut c::c_void,
+    ) -> io::Result<Self::Output> {
+        Ok(ret)
+    }
+}
+
+const GET_API_VERSION: u8 = 0x00;
+const CREATE_VM: u8 = 0x01;
+const CHECK_EXTENSION: u8 = 0x03;
+const GET_VCPU_MMAP_SIZE: u8 = 0x04;
+const CREATE_VCPU: u8 = 0x41;
+
+/// `KVM_SET_USER_MEMORY_REGION`
+type KvmSetUserMemoryRegion = ioctl::WriteOpcode<KVMIO, 0x46, UserspaceMemoryRegion>;
+/// `KVM_RUN`
+type KvmRun = ioctl::NoneOpcode<KVMIO, 0x80, ()>;
+
+/// `ioctl(kvm, KVM_GET_API_VERSION)`—Returns the KVM API version, which is
+/// always [`API_VERSION`].
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/virt/kvm/api.html#kvm-get-api-version
+#[inline]
+#[doc(alias = "KVM_GET_API_VERSION")]
+pub fn ioctl_kvm_get_api_version<Fd: AsFd>(kvm: Fd) -> io::Result<i32> {
+    // SAFETY: `KVM_GET_API_VERSION` doesn't take an argument.
+    unsafe { ioctl::ioctl(kvm, IntegerIoctl::<GET_API_VERSION>(0)) }
+}
+
+/// `ioctl(kvm, KVM_CHECK_EXTENSION, cap)`—Returns whether a capability is
+/// supported, or for some capabilities, a value describing it.
+///
+/// `cap` is a `KVM_CAP_*` value. `fd` may be the system file descriptor or
+/// a virtual machine file descriptor.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/virt/kvm/api.html#kvm-check-extension
+#[inline]
+#[doc(alias = "KVM_CHECK_EXTENSION")]
+pub fn ioctl_kvm_check_extension<Fd: AsFd>(fd: Fd, cap: u32) -> io::Result<i32> {
+    // SAFETY: `KVM_CHECK_EXTENSION` takes an integer argument.
+    unsafe { ioctl::ioctl(fd, IntegerIoctl::<CHECK_EXTENSION>(cap as usize)) }
+}
+
+/// `ioctl(kvm, KVM_GET_VCPU_MMAP_SIZE)`—Returns the size of the mapping to
+/// pass to [`mmap_run`].
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/virt/kvm/api.html#kvm-get-vcpu-mmap-size
+#[inline]
+#[doc(alias = "KVM_GET_VCPU_MMAP_SIZE")]
+pub fn ioctl_kvm_get_vcpu_mmap_size<Fd: AsFd>(kvm: Fd) -> io::Result<usize> {
+    // SAFETY: `KVM_GET_VCPU_MMAP_SIZE` doesn't take an argument.
+    unsafe { ioctl::ioctl(kvm, IntegerIoctl::<GET_VCPU_MMAP_SIZE>(0)).map(|size| size as usize) }
+}
+
+/// `ioctl(kvm, KVM_CREATE_VM, machine_type)`—Creates a virtual machine.
+///
+/// `machine_type` is 0 for the default machine type on most architectures.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/virt/kvm/api.html#kvm-create-vm
+#[inline]
+#[doc(alias = "KVM_CREATE_VM")]
+pub fn ioctl_kvm_create_vm<Fd: AsFd>(kvm: Fd, machine_type: u32) -> io::Result<OwnedFd> {
+    // SAFETY: `KVM_CREATE_VM` takes an integer argument and returns a new
+    // file descriptor.
+    unsafe {
+        let fd = ioctl::ioctl(kvm, IntegerIoctl::<CREATE_VM>(machine_type as usize))?;
+        Ok(OwnedFd::from_raw_fd(fd))
+    }
+}
+
+/// `ioctl(vm, KVM_CREATE_VCPU, id)`—Creates a virtual CPU in a virtual
+/// machine.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/virt/kvm/api.html#kvm-create-vcpu
+#[inline]
+#[doc(alias = "KVM_CREATE_VCPU")]
+pub fn ioctl_kvm_create_vcpu<Fd: AsFd>(vm: Fd, id: u32) -> io::Result<OwnedFd> {
+    // SAFETY: `KVM_CREATE_VCPU` takes an integer argument and returns a new
+    // file descriptor.
+    unsafe {
+        let fd = ioctl::ioctl(vm, IntegerIoctl::<CREATE_VCPU>(id as usize))?;
+        Ok(OwnedFd::from_raw_fd(fd))
+    }
+}
+
+/// `ioctl(vm, KVM_SET_USER_MEMORY_REGION, region)`—Creates, modifies, or
+/// deletes a slot of guest physical memory.
+///
+/// # Safety
+///
+/// The memory at `region.userspace_addr` must be valid for
+/// `region.memory_size` bytes for as long as the slot exists, and the guest
+/// may read and write it at any time while the virtual machine runs.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/virt/kvm/api.html#kvm-set-user-memory-region
+#[inline]
+#[doc(alias = "KVM_SET_USER_MEMORY_REGION")]
+pub unsafe fn ioctl_kvm_set_user_memory_region<Fd: AsFd>(
+    vm: Fd,
+    region: &UserspaceMemoryRegion,
+) -> io::Result<()> {
+    let ctl = ioctl::Setter::<KvmSetUserMemoryRegion, UserspaceMemoryRegion>::new(*region);
+    ioctl::ioctl(vm, ctl)
+}
+
+/// `ioctl(vcpu, KVM_RUN)`—Runs a virtual CPU until it exits.
+///
+/// The reason for the exit is reported in the virtual CPU's [`Run`]
+/// structure. If a signal is pending, this fails with `INTR`.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/virt/kvm/api.html#kvm-run
+#[inline]
+#[doc(alias = "KVM_RUN")]
+pub fn ioctl_kvm_run<Fd: AsFd>(vcpu: Fd) -> io::Result<()> {
+    // SAFETY: `KVM_RUN` doesn't take an argument.
+    unsafe {
+        let ctl = ioctl::NoArg::<KvmRun>::new();
+        ioctl::ioctl(vcpu, ctl)
+    }
+}
+
+/// `mmap(NULL, size, PROT_READ | PROT_WRITE, MAP_SHARED, vcpu, 0)`—Maps
+/// the [`Run`] structure of a virtual CPU into memory.
+///
+/// `size` must be the value returned by [`ioctl_kvm_get_vcpu_mmap_size`].
+///
+/// # Safety
+///
+/// The mapping is shared with the kernel, which writes to it while
+/// [`ioctl_kvm_run`] runs. It must be unmapped with [`munmap`] using `size`
+/// as the length.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/virt/kvm/api.html#kvm-run
+/// [`munmap`]: crate::mm::munmap
+#[cfg(feature = "mm")]
+#[inline]
+pub unsafe fn mmap_run<Fd: AsFd>(vcpu: Fd, size: usize) -> io::Result<*mut Run> {
+    use crate::mm::{mmap, MapFlags, ProtFlags};
+
+    if size < core::mem::size_of::<Run>() {
+        return Err(io::Errno::INVAL);
+    }
+    mmap(
+        core::ptr::null_mut(),
+        size,
+        ProtFlags::READ | ProtFlags::WRITE,
+        MapFlags::SHARED,
+        vcpu,
+        0,
+    )
+    .map(|ptr| ptr.cast())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ioctl::{CompileTimeOpcode, Ioctl, RawOpcode};
+    use core::mem::{align_of, size_of};
+
+    // `linux_raw_sys` only has the KVM opcodes on architectures that
+    // support KVM.
+    #[cfg(any(
+        target_arch = "aarch64",
+        target_arch = "mips",
+        target_arch = "mips64",
+        target_arch = "s390x",
+        target_arch = "x86",
+        target_arch = "x86_64",
+    ))]
+    #[test]
+    fn kvm_opcodes() {
+        use linux_raw_sys::ioctl as sys;
+
+        const_assert_eq!(
+            <IntegerIoctl<GET_API_VERSION> as Ioctl>::OPCODE.raw(),
+            sys::KVM_GET_API_VERSION as RawOpcode
+        );
+        const_assert_eq!(
+            <IntegerIoctl<CREATE_VM> as Ioctl>::OPCODE.raw(),
+            sys::KVM_CREATE_VM as RawOpcode
+        );
+        const_assert_eq!(
+            <IntegerIoctl<CHECK_EXTENSION> as Ioctl>::OPCODE.raw(),
+            sys::KVM_CHECK_EXTENSION as RawOpcode
+        );
+        const_assert_eq!(
+            <IntegerIoctl<GET_VCPU_MMAP_SIZE> as Ioctl>::OPCODE.raw(),
+            sys::KVM_GET_VCPU_MMAP_SIZE as RawOpcode
+        );
+        const_assert_eq!(
+            <IntegerIoctl<CREATE_VCPU> as Ioctl>::OPCODE.raw(),
+            sys::KVM_CREATE_VCPU as RawOpcode
+        );
+        const_assert_eq!(
+            KvmSetUserMemoryRegion::OPCODE.raw(),
+            sys::KVM_SET_USER_MEMORY_REGION as RawOpcode
+        );
+        const_assert_eq!(KvmRun::OPCODE.raw(), sys::KVM_RUN as RawOpcode);
+    }
+
+    #[test]
+    fn kvm_layouts() {
+        const_assert_eq!(size_of::<UserspaceMemoryRegion>(), 32);
+        const_assert_eq!(align_of::<UserspaceMemoryRegion>(), align_of::<u64>());
+        const_assert_eq!(size_of::<ExitIo>(), 16);
+        const_assert_eq!(size_of::<ExitMmio>(), 24);
+
+        // The architecture-independent start of `struct kvm_run`, up to its
+        // `exit_reason`-dependent union.
+        const_assert_eq!(memoffset::offset_of!(Run, exit_reason), 8);
+        const_assert_eq!(memoffset::offset_of!(Run, exit), 32);
+        const_assert_eq!(size_of::<Run>(), 288);
+    }
+}
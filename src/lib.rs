@@ -179,6 +179,18 @@ mod backend;
 ///
 /// Users can use this to avoid needing to import anything else to use the same
 /// versions of these types and traits.
+///
+/// `OwnedFd` is the standard library's type, and rustix deliberately doesn't
+/// wrap it in a type that tracks where file descriptors are created or
+/// closed, because that would change the type returned by every function
+/// that opens a file descriptor. To look for leaks, list the process' open
+/// file descriptors instead, for example with
+/// [`procfs::proc_self_open_fds`] on Linux, and inspect them with
+/// [`fs::fcntl_getfl`] and [`io::fcntl_getfd`].
+///
+/// [`procfs::proc_self_open_fds`]: crate::procfs::proc_self_open_fds
+/// [`fs::fcntl_getfl`]: crate::fs::fcntl_getfl
+/// [`io::fcntl_getfd`]: crate::io::fcntl_getfd
 pub mod fd {
     use super::backend;
 
@@ -192,6 +204,22 @@ pub mod fd {
 }
 
 // The public API modules.
+#[cfg(linux_kernel)]
+#[cfg(feature = "aio")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "aio")))]
+pub mod aio;
+#[cfg(linux_kernel)]
+#[cfg(feature = "bpf")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "bpf")))]
+pub mod bpf;
+#[cfg(linux_kernel)]
+#[cfg(feature = "device_mapper")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "device_mapper")))]
+pub mod device_mapper;
+#[cfg(linux_kernel)]
+#[cfg(feature = "drm")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "drm")))]
+pub mod drm;
 #[cfg(feature = "event")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "event")))]
 pub mod event;
@@ -201,12 +229,40 @@ pub mod ffi;
 #[cfg(feature = "fs")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "fs")))]
 pub mod fs;
+#[cfg(linux_kernel)]
+#[cfg(feature = "gpio")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "gpio")))]
+pub mod gpio;
+#[cfg(linux_kernel)]
+#[cfg(feature = "hidraw")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "hidraw")))]
+pub mod hidraw;
+#[cfg(linux_kernel)]
+#[cfg(feature = "i2c")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "i2c")))]
+pub mod i2c;
+#[cfg(linux_kernel)]
+#[cfg(feature = "input")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "input")))]
+pub mod input;
 pub mod io;
 #[cfg(linux_kernel)]
 #[cfg(feature = "io_uring")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "io_uring")))]
 pub mod io_uring;
 pub mod ioctl;
+#[cfg(linux_kernel)]
+#[cfg(feature = "keyctl")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "keyctl")))]
+pub mod keyctl;
+#[cfg(linux_kernel)]
+#[cfg(feature = "kvm")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "kvm")))]
+pub mod kvm;
+#[cfg(linux_kernel)]
+#[cfg(feature = "loop_device")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "loop_device")))]
+pub mod loop_device;
 #[cfg(not(any(windows, target_os = "espidf", target_os = "vita", target_os = "wasi")))]
 #[cfg(feature = "mm")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "mm")))]
@@ -215,6 +271,10 @@ pub mod mm;
 #[cfg(feature = "mount")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "mount")))]
 pub mod mount;
+#[cfg(linux_kernel)]
+#[cfg(feature = "nbd")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "nbd")))]
+pub mod nbd;
 #[cfg(not(any(target_os = "redox", target_os = "wasi")))]
 #[cfg(feature = "net")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "net")))]
@@ -230,6 +290,10 @@ pub mod param;
     doc(cfg(any(feature = "fs", feature = "mount", feature = "net")))
 )]
 pub mod path;
+#[cfg(linux_kernel)]
+#[cfg(feature = "perf_event")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "perf_event")))]
+pub mod perf_event;
 #[cfg(feature = "pipe")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "pipe")))]
 #[cfg(not(any(windows, target_os = "wasi")))]
@@ -251,6 +315,10 @@ pub mod pty;
 #[cfg(feature = "rand")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "rand")))]
 pub mod rand;
+#[cfg(not(windows))]
+#[cfg(any(feature = "fs", feature = "net"))]
+#[cfg_attr(doc_cfg, doc(cfg(any(feature = "fs", feature = "net"))))]
+pub mod raw;
 #[cfg(not(any(
     windows,
     target_os = "android",
@@ -261,6 +329,14 @@ pub mod rand;
 #[cfg(feature = "shm")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "shm")))]
 pub mod shm;
+#[cfg(linux_kernel)]
+#[cfg(feature = "sound")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "sound")))]
+pub mod sound;
+#[cfg(linux_kernel)]
+#[cfg(feature = "spi")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "spi")))]
+pub mod spi;
 #[cfg(not(windows))]
 #[cfg(feature = "stdio")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "stdio")))]
@@ -281,6 +357,18 @@ pub mod thread;
 #[cfg(feature = "time")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "time")))]
 pub mod time;
+#[cfg(linux_kernel)]
+#[cfg(feature = "usbdevfs")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "usbdevfs")))]
+pub mod usbdevfs;
+#[cfg(linux_kernel)]
+#[cfg(feature = "vhost")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "vhost")))]
+pub mod vhost;
+#[cfg(linux_kernel)]
+#[cfg(feature = "video")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "video")))]
+pub mod video;
 
 // "runtime" is also a public API module, but it's only for libc-like users.
 #[cfg(not(windows))]
@@ -305,6 +393,7 @@ pub(crate) mod mount;
     not(feature = "use-libc-auxv"),
     not(feature = "use-explicitly-provided-auxv"),
     any(
+        feature = "mm",
         feature = "param",
         feature = "process",
         feature = "runtime",
@@ -323,6 +412,7 @@ pub(crate) mod fs;
     not(feature = "use-libc-auxv"),
     not(feature = "use-explicitly-provided-auxv"),
     any(
+        feature = "mm",
         feature = "param",
         feature = "process",
         feature = "runtime",
@@ -338,20 +428,34 @@ pub(crate) mod path;
 mod clockid;
 #[cfg(not(any(windows, target_os = "wasi")))]
 #[cfg(any(
+    feature = "fs",
     feature = "procfs",
     feature = "process",
     feature = "runtime",
     feature = "termios",
     feature = "thread",
     all(bsd, feature = "event"),
-    all(linux_kernel, feature = "net")
+    all(linux_kernel, feature = "net"),
+    all(linux_kernel, feature = "perf_event"),
+    // The private `fs` module declared above for procfs also needs `Pid`.
+    all(
+        linux_raw,
+        not(feature = "use-libc-auxv"),
+        not(feature = "use-explicitly-provided-auxv"),
+        any(feature = "mm", feature = "param", feature = "time", target_arch = "x86")
+    )
 ))]
 mod pid;
 #[cfg(any(feature = "process", feature = "thread"))]
 #[cfg(linux_kernel)]
 mod prctl;
 #[cfg(not(any(windows, target_os = "espidf", target_os = "wasi")))]
-#[cfg(any(feature = "process", feature = "runtime", all(bsd, feature = "event")))]
+#[cfg(any(
+    feature = "process",
+    feature = "runtime",
+    all(linux_kernel, feature = "thread"),
+    all(bsd, feature = "event")
+))]
 mod signal;
 #[cfg(not(windows))]
 #[cfg(any(
@@ -360,11 +464,13 @@ mod signal;
     feature = "runtime",
     feature = "thread",
     feature = "time",
+    all(linux_kernel, feature = "aio"),
     all(
         linux_raw,
         not(feature = "use-libc-auxv"),
         not(feature = "use-explicitly-provided-auxv"),
         any(
+            feature = "mm",
             feature = "param",
             feature = "process",
             feature = "runtime",
@@ -384,6 +490,7 @@ mod timespec;
         not(feature = "use-libc-auxv"),
         not(feature = "use-explicitly-provided-auxv"),
         any(
+            feature = "mm",
             feature = "param",
             feature = "runtime",
             feature = "time",
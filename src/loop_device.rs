@@ -0,0 +1,275 @@
+//! Linux loop device functions.
+//!
+//! Loop devices make a regular file available as a block device. A free
+//! device is found with [`ioctl_loop_ctl_get_free`] on `/dev/loop-control`,
+//! and the functions that take a loop device file descriptor then attach it
+//! to a backing file, configure it, and detach it again.
+//!
+//! # References
+//!  - [Linux]
+//!
+//! [Linux]: https://man7.org/linux/man-pages/man4/loop.4.html
+
+#![allow(unsafe_code)]
+
+use crate::fd::{AsFd, AsRawFd, BorrowedFd};
+use crate::{io, ioctl};
+use bitflags::bitflags;
+use core::ptr::null_mut;
+use linux_raw_sys::loop_device::{
+    loop_config, loop_info64, LOOP_CLR_FD, LOOP_CONFIGURE, LOOP_CTL_GET_FREE, LOOP_GET_STATUS64,
+    LOOP_SET_FD, LOOP_SET_STATUS64, LO_NAME_SIZE,
+};
+
+bitflags! {
+    /// `LO_FLAGS_*` constants for use with [`LoopInfo`].
+    #[repr(transparent)]
+    #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    pub struct LoopFlags: u32 {
+        /// `LO_FLAGS_READ_ONLY`—The device is read-only.
+        const READ_ONLY = 1;
+        /// `LO_FLAGS_AUTOCLEAR`—Detach the backing file when the device is
+        /// last closed.
+        const AUTOCLEAR = 4;
+        /// `LO_FLAGS_PARTSCAN`—Scan the device for partitions.
+        const PARTSCAN = 8;
+        /// `LO_FLAGS_DIRECT_IO`—Access the backing file with direct I/O.
+        const DIRECT_IO = 16;
+
+        /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+        const _ = !0;
+    }
+}
+
+/// `struct loop_info64`—The status of a loop device, for use with
+/// [`ioctl_loop_get_status64`], [`ioctl_loop_set_status64`], and
+/// [`LoopConfig`].
+///
+/// The device, inode, and number fields are filled in by
+/// [`ioctl_loop_get_status64`], and are ignored when setting the status.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct LoopInfo {
+    /// The device number of the filesystem containing the backing file.
+    pub device: u64,
+
+    /// The inode number of the backing file.
+    pub inode: u64,
+
+    /// The device number of the backing file, if it is a device.
+    pub rdevice: u64,
+
+    /// The offset into the backing file at which the device starts.
+    pub offset: u64,
+
+    /// The maximum size of the device in bytes, or 0 for the rest of the
+    /// backing file.
+    pub size_limit: u64,
+
+    /// The number of the loop device.
+    pub number: u32,
+
+    /// Flags for the device.
+    pub flags: LoopFlags,
+
+    /// A name for the backing file, which is only informational. Names
+    /// longer than 63 bytes are truncated.
+    pub file_name: [u8; LO_NAME_SIZE as usize],
+}
+
+impl LoopInfo {
+    /// Constructs a `LoopInfo` with all fields zeroed.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            device: 0,
+            inode: 0,
+            rdevice: 0,
+            offset: 0,
+            size_limit: 0,
+            number: 0,
+            flags: LoopFlags::empty(),
+            file_name: [0; LO_NAME_SIZE as usize],
+        }
+    }
+
+    fn from_raw(raw: &loop_info64) -> Self {
+        Self {
+            device: raw.lo_device,
+            inode: raw.lo_inode,
+            rdevice: raw.lo_rdevice,
+            offset: raw.lo_offset,
+            size_limit: raw.lo_sizelimit,
+            number: raw.lo_number,
+            flags: LoopFlags::from_bits_retain(raw.lo_flags),
+            file_name: raw.lo_file_name,
+        }
+    }
+
+    fn to_raw(&self) -> loop_info64 {
+        let mut file_name = self.file_name;
+        file_name[file_name.len() - 1] = 0;
+        loop_info64 {
+            lo_device: self.device,
+            lo_inode: self.inode,
+            lo_rdevice: self.rdevice,
+            lo_offset: self.offset,
+            lo_sizelimit: self.size_limit,
+            lo_number: self.number,
+            lo_encrypt_type: 0,
+            lo_encrypt_key_size: 0,
+            lo_flags: self.flags.bits(),
+            lo_file_name: file_name,
+            lo_crypt_name: [0; LO_NAME_SIZE as usize],
+            lo_encrypt_key: [0; 32],
+            lo_init: [0; 2],
+        }
+    }
+}
+
+impl Default for LoopInfo {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// `struct loop_config`—The configuration of a loop device, for use with
+/// [`ioctl_loop_configure`].
+#[derive(Clone, Debug)]
+pub struct LoopConfig<'a> {
+    /// The backing file.
+    pub fd: BorrowedFd<'a>,
+
+    /// The logical block size of the device, or 0 for the default.
+    pub block_size: u32,
+
+    /// The status of the device.
+    pub info: LoopInfo,
+}
+
+/// `ioctl(fd, LOOP_CTL_GET_FREE)`—Finds a free loop device, creating one if
+/// needed, and returns its number.
+///
+/// `fd` must be a file descriptor for `/dev/loop-control`. The returned
+/// number `n` corresponds to the device `/dev/loop{n}`.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man4/loop.4.html
+#[inline]
+#[doc(alias = "LOOP_CTL_GET_FREE")]
+pub fn ioctl_loop_ctl_get_free<Fd: AsFd>(fd: Fd) -> io::Result<u32> {
+    // SAFETY: `LOOP_CTL_GET_FREE` is a no-argument opcode that returns the
+    // device number.
+    unsafe {
+        ioctl::ioctl_with_opcode(
+            fd.as_fd(),
+            ioctl::Opcode::old(LOOP_CTL_GET_FREE as _),
+            null_mut(),
+        )
+        .map(|n| n as u32)
+    }
+}
+
+/// `ioctl(fd, LOOP_SET_FD, backing)`—Attaches a backing file to a loop
+/// device.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man4/loop.4.html
+#[inline]
+#[doc(alias = "LOOP_SET_FD")]
+pub fn ioctl_loop_set_fd<Fd: AsFd, Backing: AsFd>(fd: Fd, backing: Backing) -> io::Result<()> {
+    let backing = backing.as_fd().as_raw_fd();
+    // SAFETY: `LOOP_SET_FD` takes an integer argument.
+    unsafe {
+        let ctl =
+            ioctl::IntegerSetter::<ioctl::BadOpcode<{ LOOP_SET_FD as _ }>>::new(backing as usize);
+        ioctl::ioctl(fd, ctl)
+    }
+}
+
+/// `ioctl(fd, LOOP_CONFIGURE, config)`—Attaches a backing file to a loop
+/// device and configures it in one step.
+///
+/// This requires Linux 5.8 or later.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man4/loop.4.html
+#[inline]
+#[doc(alias = "LOOP_CONFIGURE")]
+pub fn ioctl_loop_configure<Fd: AsFd>(fd: Fd, config: &LoopConfig<'_>) -> io::Result<()> {
+    let config = loop_config {
+        fd: config.fd.as_raw_fd() as u32,
+        block_size: config.block_size,
+        info: config.info.to_raw(),
+        __reserved: [0; 8],
+    };
+    // SAFETY: `LOOP_CONFIGURE` is a pointer setter opcode that sets a
+    // `struct loop_config`.
+    unsafe {
+        let ctl =
+            ioctl::Setter::<ioctl::BadOpcode<{ LOOP_CONFIGURE as _ }>, loop_config>::new(config);
+        ioctl::ioctl(fd, ctl)
+    }
+}
+
+/// `ioctl(fd, LOOP_GET_STATUS64)`—Returns the status of a loop device.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man4/loop.4.html
+#[inline]
+#[doc(alias = "LOOP_GET_STATUS64")]
+pub fn ioctl_loop_get_status64<Fd: AsFd>(fd: Fd) -> io::Result<LoopInfo> {
+    // SAFETY: `LOOP_GET_STATUS64` is a getter opcode that gets a
+    // `struct loop_info64`.
+    unsafe {
+        let ctl = ioctl::Getter::<ioctl::BadOpcode<{ LOOP_GET_STATUS64 as _ }>, loop_info64>::new();
+        ioctl::ioctl(fd, ctl).map(|info| LoopInfo::from_raw(&info))
+    }
+}
+
+/// `ioctl(fd, LOOP_SET_STATUS64, info)`—Sets the status of a loop device.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man4/loop.4.html
+#[inline]
+#[doc(alias = "LOOP_SET_STATUS64")]
+pub fn ioctl_loop_set_status64<Fd: AsFd>(fd: Fd, info: &LoopInfo) -> io::Result<()> {
+    // SAFETY: `LOOP_SET_STATUS64` is a pointer setter opcode that sets a
+    // `struct loop_info64`.
+    unsafe {
+        let ctl = ioctl::Setter::<ioctl::BadOpcode<{ LOOP_SET_STATUS64 as _ }>, loop_info64>::new(
+            info.to_raw(),
+        );
+        ioctl::ioctl(fd, ctl)
+    }
+}
+
+/// `ioctl(fd, LOOP_CLR_FD)`—Detaches the backing file from a loop device.
+///
+/// If the device is still open elsewhere, this fails with `BUSY`, unless
+/// [`LoopFlags::AUTOCLEAR`] is set, in which case the backing file is
+/// detached when the device is last closed.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man4/loop.4.html
+#[inline]
+#[doc(alias = "LOOP_CLR_FD")]
+pub fn ioctl_loop_clr_fd<Fd: AsFd>(fd: Fd) -> io::Result<()> {
+    // SAFETY: `LOOP_CLR_FD` is a no-argument opcode.
+    unsafe {
+        let ctl = ioctl::NoArg::<ioctl::BadOpcode<{ LOOP_CLR_FD as _ }>>::new();
+        ioctl::ioctl(fd, ctl)
+    }
+}
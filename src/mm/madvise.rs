@@ -6,6 +6,8 @@
 //! mutate the memory or have other side effects.
 #![allow(unsafe_code)]
 
+#[cfg(linux_kernel)]
+use crate::fd::AsFd;
 use crate::{backend, io};
 use core::ffi::c_void;
 
@@ -47,3 +49,55 @@ pub use backend::mm::types::Advice;
 pub unsafe fn madvise(addr: *mut c_void, len: usize, advice: Advice) -> io::Result<()> {
     backend::mm::syscalls::madvise(addr, len, advice)
 }
+
+/// `struct iovec`—A range of addresses in another process's address space,
+/// for use with [`process_madvise`].
+///
+/// Unlike [`IoSlice`], this doesn't borrow any memory in the calling process.
+///
+/// [`IoSlice`]: crate::io::IoSlice
+#[cfg(linux_kernel)]
+#[repr(C)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct RemoteIoVec {
+    /// The start of the range.
+    pub base: *mut c_void,
+
+    /// The length of the range, in bytes.
+    pub len: usize,
+}
+
+#[cfg(linux_kernel)]
+impl RemoteIoVec {
+    /// Constructs a new `RemoteIoVec` for `len` bytes starting at `base`.
+    #[inline]
+    pub const fn new(base: *mut c_void, len: usize) -> Self {
+        Self { base, len }
+    }
+}
+
+/// `process_madvise(pidfd, ranges, advice, 0)`—Declares an expected access
+/// pattern for memory in another process.
+///
+/// `pidfd` is a process file descriptor, such as one returned by
+/// `pidfd_open`, and `ranges` are address ranges in that process. The kernel
+/// only accepts advice that doesn't change the contents of the memory, such as
+/// [`Advice::LinuxCold`], [`Advice::LinuxPageOut`], and [`Advice::WillNeed`],
+/// and requires `CAP_SYS_NICE` and `PTRACE_MODE_READ` access to the process.
+///
+/// On success, returns the number of bytes advised, which may be less than
+/// the total length of `ranges` if an error occurs partway through.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/process_madvise.2.html
+#[cfg(linux_kernel)]
+#[inline]
+pub fn process_madvise<Fd: AsFd>(
+    pidfd: Fd,
+    ranges: &[RemoteIoVec],
+    advice: Advice,
+) -> io::Result<usize> {
+    backend::mm::syscalls::process_madvise(pidfd.as_fd(), ranges, advice)
+}
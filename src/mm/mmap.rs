@@ -126,6 +126,44 @@ pub unsafe fn mmap_anonymous(
     backend::mm::syscalls::mmap_anonymous(ptr, len, prot, flags)
 }
 
+/// `mmap(NULL, len, PROT_READ | PROT_WRITE, MAP_SHARED | MAP_ANONYMOUS, -1,
+/// 0)`—Create a readable and writable anonymous memory mapping which is
+/// shared with child processes.
+///
+/// Unlike private mappings, writes to this mapping by the process or any of
+/// its children created with `fork` after this call are visible to all of
+/// them, which makes it a simple way to set up shared memory for fork-based
+/// IPC. The memory is zero-initialized.
+///
+/// To share memory with processes which aren't children, create a file
+/// descriptor with [`memfd_create`], size it with [`ftruncate`], pass it to
+/// the other process, and map it with [`mmap`] using [`MapFlags::SHARED`].
+///
+/// The mapping must be removed with [`munmap`].
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/mmap.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/mmap.2.html
+/// [`memfd_create`]: https://docs.rs/rustix/*/rustix/fs/fn.memfd_create.html
+/// [`ftruncate`]: https://docs.rs/rustix/*/rustix/fs/fn.ftruncate.html
+#[inline]
+#[doc(alias = "mmap")]
+pub fn shared_anonymous_map(len: usize) -> io::Result<*mut c_void> {
+    // SAFETY: Passing a null pointer without `MAP_FIXED` lets the OS pick an
+    // address which doesn't overlap any existing mapping.
+    unsafe {
+        backend::mm::syscalls::mmap_anonymous(
+            core::ptr::null_mut(),
+            len,
+            ProtFlags::READ | ProtFlags::WRITE,
+            MapFlags::SHARED,
+        )
+    }
+}
+
 /// `munmap(ptr, len)`—Remove a memory mapping.
 ///
 /// # Safety
@@ -343,6 +381,44 @@ pub unsafe fn munlock(ptr: *mut c_void, len: usize) -> io::Result<()> {
     backend::mm::syscalls::munlock(ptr, len)
 }
 
+/// `mincore(ptr, len, vec)`—Determine whether pages are resident in memory.
+///
+/// For each page in the range starting at `ptr`, which must be page-aligned,
+/// and extending for `len` bytes, sets the least significant bit of the
+/// corresponding byte in `vec` if the page is resident in RAM, and clears it
+/// otherwise. The other bits of each byte are reserved, so they should be
+/// masked off rather than compared against.
+///
+/// To check whether reading from a file would block on I/O without mapping it,
+/// [`preadv2`] with [`ReadWriteFlags::NOWAIT`] can be used instead; it fails
+/// with [`io::Errno::AGAIN`] if the data isn't in the page cache.
+///
+/// `vec` must have at least one element for each page in the range, which is
+/// `len` divided by the page size, rounded up; if it's too short, this fails
+/// with [`io::Errno::INVAL`].
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/mincore.2.html
+/// [`preadv2`]: crate::io::preadv2
+/// [`ReadWriteFlags::NOWAIT`]: crate::io::ReadWriteFlags::NOWAIT
+#[cfg(linux_kernel)]
+#[inline]
+#[allow(clippy::not_unsafe_ptr_arg_deref)] // `mincore` doesn't dereference `ptr`
+pub fn mincore(ptr: *mut c_void, len: usize, vec: &mut [u8]) -> io::Result<()> {
+    let page_size = backend::param::auxv::page_size();
+    let pages = len / page_size + usize::from(len % page_size != 0);
+    if vec.len() < pages {
+        return Err(io::Errno::INVAL);
+    }
+
+    // SAFETY: `mincore` doesn't access the memory in the range, it just
+    // queries the page tables, and we checked that `vec` is long enough to
+    // hold one byte per page.
+    unsafe { backend::mm::syscalls::mincore(ptr, len, vec.as_mut_ptr()) }
+}
+
 /// Locks all pages mapped into the address space of the calling process.
 ///
 /// This includes the pages of the code, data, and stack segment, as well as
@@ -5,11 +5,20 @@ mod madvise;
 mod mmap;
 mod msync;
 #[cfg(linux_kernel)]
+mod pkey;
+#[cfg(linux_kernel)]
 mod userfaultfd;
 
 #[cfg(not(target_os = "redox"))]
 pub use madvise::{madvise, Advice};
+#[cfg(linux_kernel)]
+pub use madvise::{process_madvise, RemoteIoVec};
 pub use mmap::*;
 pub use msync::{msync, MsyncFlags};
 #[cfg(linux_kernel)]
+pub use pkey::{pkey_alloc, pkey_free, pkey_mprotect, PkeyRights};
+#[cfg(linux_kernel)]
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub use pkey::{pkey_get, pkey_set};
+#[cfg(linux_kernel)]
 pub use userfaultfd::{userfaultfd, UserfaultfdFlags};
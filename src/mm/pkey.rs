@@ -0,0 +1,177 @@
+//! Linux memory protection keys.
+//!
+//! Memory protection keys let a thread change the access rights of whole
+//! groups of pages, tagged with [`pkey_mprotect`], without making system
+//! calls or flushing TLBs. On x86, the rights are held in the per-thread
+//! `PKRU` register, which [`pkey_get`] and [`pkey_set`] read and write
+//! directly.
+//!
+//! # References
+//!  - [Linux]
+//!
+//! [Linux]: https://man7.org/linux/man-pages/man7/pkeys.7.html
+#![allow(unsafe_code)]
+
+use crate::mm::MprotectFlags;
+use crate::{backend, io};
+use core::ffi::c_void;
+
+pub use backend::mm::types::PkeyRights;
+
+/// `pkey_alloc(0, rights)`—Allocates a protection key.
+///
+/// `rights` is the initial access rights of the key for the calling thread.
+/// This fails with [`io::Errno::NOSPC`] if all the keys are in use, and with
+/// [`io::Errno::INVAL`] or [`io::Errno::NOSYS`] if the CPU or kernel doesn't
+/// support protection keys.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/pkey_alloc.2.html
+#[inline]
+pub fn pkey_alloc(rights: PkeyRights) -> io::Result<u32> {
+    backend::mm::syscalls::pkey_alloc(rights)
+}
+
+/// `pkey_free(pkey)`—Frees a protection key.
+///
+/// Pages tagged with the key keep it, so it shouldn't be freed while any are
+/// still mapped.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/pkey_free.2.html
+#[inline]
+pub fn pkey_free(pkey: u32) -> io::Result<()> {
+    backend::mm::syscalls::pkey_free(pkey)
+}
+
+/// `pkey_mprotect(ptr, len, flags, pkey)`—Change the protection flags of a
+/// region of memory, and tag it with a protection key.
+///
+/// # Safety
+///
+/// Raw pointers and lots of special semantics.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/pkey_mprotect.2.html
+#[inline]
+pub unsafe fn pkey_mprotect(
+    ptr: *mut c_void,
+    len: usize,
+    flags: MprotectFlags,
+    pkey: u32,
+) -> io::Result<()> {
+    backend::mm::syscalls::pkey_mprotect(ptr, len, flags, pkey)
+}
+
+/// The number of protection keys that the `PKRU` register holds rights for.
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+const NUM_PKEYS: u32 = 16;
+
+/// `pkey_get(pkey)`—Returns the calling thread's access rights for a
+/// protection key.
+///
+/// This reads the `PKRU` register with `RDPKRU`. If the CPU doesn't support
+/// protection keys, or the OS hasn't enabled them, this fails with
+/// [`io::Errno::NOSYS`].
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man3/pkey_get.3.html
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[inline]
+pub fn pkey_get(pkey: u32) -> io::Result<PkeyRights> {
+    if pkey >= NUM_PKEYS {
+        return Err(io::Errno::INVAL);
+    }
+    if !ospke() {
+        return Err(io::Errno::NOSYS);
+    }
+
+    // SAFETY: `RDPKRU` only reads the `PKRU` register, and we checked that
+    // it's enabled.
+    let pkru = unsafe { rdpkru() };
+    Ok(PkeyRights::from_bits_retain((pkru >> (2 * pkey)) & 0b11))
+}
+
+/// `pkey_set(pkey, rights)`—Sets the calling thread's access rights for a
+/// protection key.
+///
+/// This writes the `PKRU` register with `WRPKRU`. If the CPU doesn't support
+/// protection keys, or the OS hasn't enabled them, this fails with
+/// [`io::Errno::NOSYS`].
+///
+/// # Safety
+///
+/// Disabling access to memory that other code in the thread is using, such
+/// as its stack, can violate invariants of that code.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man3/pkey_set.3.html
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[inline]
+pub unsafe fn pkey_set(pkey: u32, rights: PkeyRights) -> io::Result<()> {
+    let known = PkeyRights::DISABLE_ACCESS | PkeyRights::DISABLE_WRITE;
+    if pkey >= NUM_PKEYS || !known.contains(rights) {
+        return Err(io::Errno::INVAL);
+    }
+    if !ospke() {
+        return Err(io::Errno::NOSYS);
+    }
+
+    let shift = 2 * pkey;
+    let pkru = (rdpkru() & !(0b11 << shift)) | (rights.bits() << shift);
+    wrpkru(pkru);
+    Ok(())
+}
+
+/// Tests whether the OS has enabled protection keys, which is what makes
+/// `RDPKRU` and `WRPKRU` valid instructions, with the `OSPKE` bit of `CPUID`
+/// leaf 7.
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[allow(unused_unsafe)] // Newer Rust versions declare the `CPUID` functions safe.
+fn ospke() -> bool {
+    #[cfg(target_arch = "x86")]
+    use core::arch::x86::{__cpuid_count, __get_cpuid_max};
+    #[cfg(target_arch = "x86_64")]
+    use core::arch::x86_64::{__cpuid_count, __get_cpuid_max};
+
+    // SAFETY: All the x86 CPUs that Rust targets have `CPUID`.
+    unsafe { __get_cpuid_max(0).0 >= 7 && __cpuid_count(7, 0).ecx & (1 << 4) != 0 }
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[inline]
+unsafe fn rdpkru() -> u32 {
+    let pkru: u32;
+    core::arch::asm!(
+        "rdpkru",
+        in("ecx") 0_u32,
+        out("eax") pkru,
+        out("edx") _,
+        options(nomem, nostack, preserves_flags)
+    );
+    pkru
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[inline]
+unsafe fn wrpkru(pkru: u32) {
+    // `WRPKRU` changes which memory is accessible, so don't let the compiler
+    // move memory accesses across it.
+    core::arch::asm!(
+        "wrpkru",
+        in("eax") pkru,
+        in("ecx") 0_u32,
+        in("edx") 0_u32,
+        options(nostack, preserves_flags)
+    );
+}
@@ -1,7 +1,8 @@
 //! `fsopen` and related functions in Linux's `mount` API.
 
 use crate::backend::mount::types::{
-    FsMountFlags, FsOpenFlags, FsPickFlags, MountAttrFlags, MoveMountFlags, OpenTreeFlags,
+    FsMountFlags, FsOpenFlags, FsPickFlags, MountAttrFlags, MountPropagationFlags,
+    MountSetattrFlags, MoveMountFlags, OpenTreeFlags,
 };
 use crate::fd::{BorrowedFd, OwnedFd};
 use crate::{backend, io, path};
@@ -93,6 +94,44 @@ pub fn fspick<Path: path::Arg>(
     path.into_with_c_str(|path| backend::mount::syscalls::fspick(dfd, path, flags))
 }
 
+/// `struct mount_attr` for use with [`mount_setattr`].
+#[derive(Debug, Copy, Clone)]
+#[doc(alias = "mount_attr")]
+pub struct MountAttr<'a> {
+    /// Attributes to set.
+    pub attr_set: MountAttrFlags,
+
+    /// Attributes to clear.
+    pub attr_clr: MountAttrFlags,
+
+    /// The propagation type to change to, or empty to leave it unchanged.
+    pub propagation: MountPropagationFlags,
+
+    /// A user namespace, for use with `MOUNT_ATTR_IDMAP` to create an
+    /// idmapped mount.
+    pub userns_fd: Option<BorrowedFd<'a>>,
+}
+
+/// `mount_setattr(dfd, path, flags, attr, sizeof(struct mount_attr))`
+///
+/// This changes the properties of a mount, including detached mounts created
+/// with [`open_tree`] and `OPEN_TREE_CLONE`. With `AT_RECURSIVE`, it applies
+/// to the whole mount tree.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/mount_setattr.2.html
+#[inline]
+pub fn mount_setattr<Path: path::Arg>(
+    dfd: BorrowedFd<'_>,
+    path: Path,
+    flags: MountSetattrFlags,
+    attr: &MountAttr<'_>,
+) -> io::Result<()> {
+    path.into_with_c_str(|path| backend::mount::syscalls::mount_setattr(dfd, path, flags, attr))
+}
+
 /// `fsconfig(fs_fd, FSCONFIG_SET_FLAG, key, NULL, 0)`
 ///
 /// # References
@@ -3,10 +3,15 @@
 use crate::backend::mount::types::{
     InternalMountFlags, MountFlags, MountFlagsArg, MountPropagationFlags, UnmountFlags,
 };
+use crate::ffi::CStr;
 use crate::{backend, io, path};
 
 /// `mount(source, target, filesystemtype, mountflags, data)`
 ///
+/// This requires the `CAP_SYS_ADMIN` capability in the user namespace that
+/// owns the caller's mount namespace, and fails with [`io::Errno::PERM`]
+/// without it.
+///
 /// # References
 ///  - [Linux]
 ///
@@ -36,6 +41,57 @@ pub fn mount<Source: path::Arg, Target: path::Arg, Fs: path::Arg, Data: path::Ar
     })
 }
 
+/// `mount2(source, target, filesystemtype, mountflags, data)`
+///
+/// This is the same as [`mount`], except that the source, file system type,
+/// and data may be omitted, and the data is passed as a `CStr` rather than a
+/// `path::Arg`. For example, pseudo-filesystems such as `proc` and
+/// `tmpfs` can be mounted with no source, and many filesystems need no data.
+///
+/// This requires the `CAP_SYS_ADMIN` capability in the user namespace that
+/// owns the caller's mount namespace, and fails with [`io::Errno::PERM`]
+/// without it.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/mount.2.html
+#[inline]
+#[doc(alias = "mount")]
+pub fn mount2<Source: path::Arg, Target: path::Arg, Fs: path::Arg>(
+    source: Option<Source>,
+    target: Target,
+    file_system_type: Option<Fs>,
+    flags: MountFlags,
+    data: Option<&CStr>,
+) -> io::Result<()> {
+    option_into_with_c_str(source, |source| {
+        target.into_with_c_str(|target| {
+            option_into_with_c_str(file_system_type, |file_system_type| {
+                backend::mount::syscalls::mount(
+                    source,
+                    target,
+                    file_system_type,
+                    MountFlagsArg(flags.bits()),
+                    data,
+                )
+            })
+        })
+    })
+}
+
+/// Like `path::Arg::into_with_c_str`, but for an optional argument, which is
+/// passed as a null pointer if absent.
+fn option_into_with_c_str<Arg: path::Arg, T, F>(arg: Option<Arg>, f: F) -> io::Result<T>
+where
+    F: FnOnce(Option<&CStr>) -> io::Result<T>,
+{
+    match arg {
+        Some(arg) => arg.into_with_c_str(|arg| f(Some(arg))),
+        None => f(None),
+    }
+}
+
 /// `mount(NULL, target, NULL, MS_REMOUNT | mountflags, data)`
 ///
 /// # References
@@ -0,0 +1,256 @@
+//! Linux network block device functions.
+//!
+//! The functions in this module take a file descriptor opened from a network
+//! block device node such as `/dev/nbd0`. A user-space server configures the
+//! device, hands it one end of a connected socket with [`ioctl_nbd_set_sock`],
+//! and then calls [`ioctl_nbd_do_it`], which serves requests until the device
+//! is disconnected.
+//!
+//! # References
+//!  - [Linux]
+//!
+//! [Linux]: https://docs.kernel.org/admin-guide/blockdev/nbd.html
+
+#![allow(unsafe_code)]
+
+use crate::fd::{AsFd, AsRawFd};
+use crate::{io, ioctl};
+use bitflags::bitflags;
+
+bitflags! {
+    /// `NBD_FLAG_*` constants for use with [`ioctl_nbd_set_flags`].
+    #[repr(transparent)]
+    #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    pub struct NbdFlags: u32 {
+        /// `NBD_FLAG_HAS_FLAGS`—The other flags are valid.
+        const HAS_FLAGS = 1 << 0;
+        /// `NBD_FLAG_READ_ONLY`—The device is read-only.
+        const READ_ONLY = 1 << 1;
+        /// `NBD_FLAG_SEND_FLUSH`—The server supports flush requests.
+        const SEND_FLUSH = 1 << 2;
+        /// `NBD_FLAG_SEND_FUA`—The server supports forced unit access.
+        const SEND_FUA = 1 << 3;
+        /// `NBD_FLAG_ROTATIONAL`—The device is backed by rotational media.
+        const ROTATIONAL = 1 << 4;
+        /// `NBD_FLAG_SEND_TRIM`—The server supports trim requests.
+        const SEND_TRIM = 1 << 5;
+        /// `NBD_FLAG_SEND_WRITE_ZEROES`—The server supports write-zeroes
+        /// requests.
+        const SEND_WRITE_ZEROES = 1 << 6;
+        /// `NBD_FLAG_CAN_MULTI_CONN`—The server supports multiple
+        /// connections.
+        const CAN_MULTI_CONN = 1 << 8;
+
+        /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+        const _ = !0;
+    }
+}
+
+/// `_IO(0xab, NUM)`—The encoding of the nbd opcodes.
+type NbdOpcode<const NUM: u8> = ioctl::NoneOpcode<0xab, NUM, ()>;
+
+const SET_SOCK: u8 = 0;
+const SET_BLKSIZE: u8 = 1;
+const SET_SIZE: u8 = 2;
+const DO_IT: u8 = 3;
+const CLEAR_SOCK: u8 = 4;
+const SET_SIZE_BLOCKS: u8 = 7;
+const DISCONNECT: u8 = 8;
+const SET_TIMEOUT: u8 = 9;
+const SET_FLAGS: u8 = 10;
+
+/// Performs an nbd `ioctl` that takes an integer argument.
+///
+/// # Safety
+///
+/// `NUM` must be an nbd command that takes an integer argument, or none.
+unsafe fn nbd_ioctl<const NUM: u8, Fd: AsFd>(fd: Fd, value: usize) -> io::Result<()> {
+    let ctl = ioctl::IntegerSetter::<NbdOpcode<NUM>>::new(value);
+    ioctl::ioctl(fd, ctl)
+}
+
+/// `ioctl(fd, NBD_SET_SOCK, sock)`—Attaches a connected socket to the
+/// device, over which the kernel sends requests to the server.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/admin-guide/blockdev/nbd.html
+#[inline]
+#[doc(alias = "NBD_SET_SOCK")]
+pub fn ioctl_nbd_set_sock<Fd: AsFd, Sock: AsFd>(fd: Fd, sock: Sock) -> io::Result<()> {
+    let sock = sock.as_fd().as_raw_fd();
+    // SAFETY: `NBD_SET_SOCK` takes an integer argument.
+    unsafe { nbd_ioctl::<SET_SOCK, _>(fd, sock as usize) }
+}
+
+/// `ioctl(fd, NBD_SET_BLKSIZE, size)`—Sets the block size of the device, in
+/// bytes.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/admin-guide/blockdev/nbd.html
+#[inline]
+#[doc(alias = "NBD_SET_BLKSIZE")]
+pub fn ioctl_nbd_set_blksize<Fd: AsFd>(fd: Fd, size: u32) -> io::Result<()> {
+    // SAFETY: `NBD_SET_BLKSIZE` takes an integer argument.
+    unsafe { nbd_ioctl::<SET_BLKSIZE, _>(fd, size as usize) }
+}
+
+/// `ioctl(fd, NBD_SET_SIZE, size)`—Sets the size of the device, in bytes.
+///
+/// On 32-bit platforms, sizes that don't fit in a `usize` fail with
+/// `OVERFLOW`; use [`ioctl_nbd_set_size_blocks`] instead.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/admin-guide/blockdev/nbd.html
+#[inline]
+#[doc(alias = "NBD_SET_SIZE")]
+pub fn ioctl_nbd_set_size<Fd: AsFd>(fd: Fd, size: u64) -> io::Result<()> {
+    let size = usize::try_from(size).map_err(|_| io::Errno::OVERFLOW)?;
+    // SAFETY: `NBD_SET_SIZE` takes an integer argument.
+    unsafe { nbd_ioctl::<SET_SIZE, _>(fd, size) }
+}
+
+/// `ioctl(fd, NBD_SET_SIZE_BLOCKS, blocks)`—Sets the size of the device, in
+/// blocks of the size set with [`ioctl_nbd_set_blksize`].
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/admin-guide/blockdev/nbd.html
+#[inline]
+#[doc(alias = "NBD_SET_SIZE_BLOCKS")]
+pub fn ioctl_nbd_set_size_blocks<Fd: AsFd>(fd: Fd, blocks: u64) -> io::Result<()> {
+    let blocks = usize::try_from(blocks).map_err(|_| io::Errno::OVERFLOW)?;
+    // SAFETY: `NBD_SET_SIZE_BLOCKS` takes an integer argument.
+    unsafe { nbd_ioctl::<SET_SIZE_BLOCKS, _>(fd, blocks) }
+}
+
+/// `ioctl(fd, NBD_SET_TIMEOUT, secs)`—Sets the time, in seconds, after which
+/// a request that the server hasn't answered fails.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/admin-guide/blockdev/nbd.html
+#[inline]
+#[doc(alias = "NBD_SET_TIMEOUT")]
+pub fn ioctl_nbd_set_timeout<Fd: AsFd>(fd: Fd, secs: u32) -> io::Result<()> {
+    // SAFETY: `NBD_SET_TIMEOUT` takes an integer argument.
+    unsafe { nbd_ioctl::<SET_TIMEOUT, _>(fd, secs as usize) }
+}
+
+/// `ioctl(fd, NBD_SET_FLAGS, flags)`—Sets the transmission flags the server
+/// advertised.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/admin-guide/blockdev/nbd.html
+#[inline]
+#[doc(alias = "NBD_SET_FLAGS")]
+pub fn ioctl_nbd_set_flags<Fd: AsFd>(fd: Fd, flags: NbdFlags) -> io::Result<()> {
+    // SAFETY: `NBD_SET_FLAGS` takes an integer argument.
+    unsafe { nbd_ioctl::<SET_FLAGS, _>(fd, flags.bits() as usize) }
+}
+
+/// `ioctl(fd, NBD_DO_IT)`—Starts the device, and serves its requests over
+/// the socket until it is disconnected.
+///
+/// This blocks until the device is disconnected, for example with
+/// [`ioctl_nbd_disconnect`] from another thread or process.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/admin-guide/blockdev/nbd.html
+#[inline]
+#[doc(alias = "NBD_DO_IT")]
+pub fn ioctl_nbd_do_it<Fd: AsFd>(fd: Fd) -> io::Result<()> {
+    // SAFETY: `NBD_DO_IT` takes no argument.
+    unsafe { nbd_ioctl::<DO_IT, _>(fd, 0) }
+}
+
+/// `ioctl(fd, NBD_CLEAR_SOCK)`—Detaches the sockets from the device.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/admin-guide/blockdev/nbd.html
+#[inline]
+#[doc(alias = "NBD_CLEAR_SOCK")]
+pub fn ioctl_nbd_clear_sock<Fd: AsFd>(fd: Fd) -> io::Result<()> {
+    // SAFETY: `NBD_CLEAR_SOCK` takes no argument.
+    unsafe { nbd_ioctl::<CLEAR_SOCK, _>(fd, 0) }
+}
+
+/// `ioctl(fd, NBD_DISCONNECT)`—Asks the server to disconnect, which makes
+/// [`ioctl_nbd_do_it`] return.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/admin-guide/blockdev/nbd.html
+#[inline]
+#[doc(alias = "NBD_DISCONNECT")]
+pub fn ioctl_nbd_disconnect<Fd: AsFd>(fd: Fd) -> io::Result<()> {
+    // SAFETY: `NBD_DISCONNECT` takes no argument.
+    unsafe { nbd_ioctl::<DISCONNECT, _>(fd, 0) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ioctl::{CompileTimeOpcode, RawOpcode};
+
+    #[cfg(not(any(
+        // These have no ioctl opcodes defined in linux_raw_sys.
+        target_arch = "sparc",
+        target_arch = "sparc64"
+    )))]
+    #[test]
+    fn nbd_opcodes() {
+        use linux_raw_sys::ioctl as sys;
+
+        const_assert_eq!(
+            NbdOpcode::<SET_SOCK>::OPCODE.raw(),
+            sys::NBD_SET_SOCK as RawOpcode
+        );
+        const_assert_eq!(
+            NbdOpcode::<SET_BLKSIZE>::OPCODE.raw(),
+            sys::NBD_SET_BLKSIZE as RawOpcode
+        );
+        const_assert_eq!(
+            NbdOpcode::<SET_SIZE>::OPCODE.raw(),
+            sys::NBD_SET_SIZE as RawOpcode
+        );
+        const_assert_eq!(
+            NbdOpcode::<DO_IT>::OPCODE.raw(),
+            sys::NBD_DO_IT as RawOpcode
+        );
+        const_assert_eq!(
+            NbdOpcode::<CLEAR_SOCK>::OPCODE.raw(),
+            sys::NBD_CLEAR_SOCK as RawOpcode
+        );
+        const_assert_eq!(
+            NbdOpcode::<SET_SIZE_BLOCKS>::OPCODE.raw(),
+            sys::NBD_SET_SIZE_BLOCKS as RawOpcode
+        );
+        const_assert_eq!(
+            NbdOpcode::<DISCONNECT>::OPCODE.raw(),
+            sys::NBD_DISCONNECT as RawOpcode
+        );
+        const_assert_eq!(
+            NbdOpcode::<SET_TIMEOUT>::OPCODE.raw(),
+            sys::NBD_SET_TIMEOUT as RawOpcode
+        );
+        const_assert_eq!(
+            NbdOpcode::<SET_FLAGS>::OPCODE.raw(),
+            sys::NBD_SET_FLAGS as RawOpcode
+        );
+    }
+}
@@ -19,6 +19,8 @@ mod wsa;
 #[cfg(linux_kernel)]
 pub mod netdevice;
 pub mod sockopt;
+#[cfg(linux_kernel)]
+pub mod tun;
 
 pub use crate::maybe_polyfill::net::{
     IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6,
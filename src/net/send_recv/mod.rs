@@ -9,6 +9,9 @@ use crate::net::xdp::SocketAddrXdp;
 use crate::net::SocketAddrUnix;
 use crate::net::{SocketAddr, SocketAddrAny, SocketAddrV4, SocketAddrV6};
 use crate::{backend, io};
+#[cfg(feature = "alloc")]
+#[cfg(not(any(target_os = "espidf", target_os = "vita")))]
+use alloc::vec::Vec;
 use backend::fd::{AsFd, BorrowedFd};
 use core::mem::MaybeUninit;
 
@@ -81,6 +84,75 @@ pub fn recv_uninit<Fd: AsFd>(
     Ok(unsafe { split_init(buf, length?) })
 }
 
+/// `recv(fd, buf, flags)`—Reads one datagram from a socket, appending it to
+/// a `Vec`.
+///
+/// This first probes the size of the next datagram, reserves that much
+/// spare capacity in `buf`, and then receives the datagram into it, so that
+/// the datagram is never truncated. It returns the number of bytes appended.
+///
+/// On Linux, the size is probed with `recv(fd, NULL, 0, flags | MSG_PEEK |
+/// MSG_TRUNC)`, which reports the exact size of the next datagram. On other
+/// platforms, it's probed with `ioctl(fd, FIONREAD)`, which may report more
+/// bytes than the next datagram holds, in which case `buf` is left with
+/// extra spare capacity.
+///
+/// Any flags in `flags`, such as [`RecvFlags::DONTWAIT`], apply to both the
+/// probe and the receive.
+///
+/// The probe and the receive are separate system calls, so if another thread
+/// or process receives from the same socket in between, the datagram that's
+/// received may not be the one that was probed. If it's larger, it's
+/// truncated to the reserved capacity, as with [`recv`].
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///  - [Winsock]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/recv.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/recv.2.html
+/// [Winsock]: https://docs.microsoft.com/en-us/windows/win32/api/winsock2/nf-winsock2-recv
+#[cfg(feature = "alloc")]
+#[cfg(not(any(target_os = "espidf", target_os = "vita")))]
+pub fn recv_to_vec<Fd: AsFd>(fd: Fd, buf: &mut Vec<u8>, flags: RecvFlags) -> io::Result<usize> {
+    let fd = fd.as_fd();
+    let probe = flags | RecvFlags::PEEK | RecvFlags::TRUNC;
+
+    // SAFETY: A zero-length buffer is never written to.
+    #[cfg(linux_kernel)]
+    let size = unsafe { backend::net::syscalls::recv(fd, buf.as_mut_ptr(), 0, probe)? };
+
+    #[cfg(not(linux_kernel))]
+    let size = {
+        // `FIONREAD` reports 0 until a datagram has arrived, so wait for one
+        // first, or fail if `flags` says not to wait. Some platforms ignore
+        // `MSG_TRUNC`, and Winsock fails with `WSAEMSGSIZE` when the peeked
+        // datagram doesn't fit.
+        //
+        // SAFETY: A zero-length buffer is never written to.
+        match unsafe { backend::net::syscalls::recv(fd, buf.as_mut_ptr(), 0, probe) } {
+            Ok(_) | Err(io::Errno::MSGSIZE) => (),
+            Err(err) => return Err(err),
+        }
+        io::ioctl_fionread(fd)?
+            .try_into()
+            .map_err(|_| io::Errno::NOMEM)?
+    };
+
+    buf.reserve(size);
+    let spare = buf.spare_capacity_mut();
+
+    // SAFETY: `spare` is valid for writes of `spare.len()` bytes.
+    let nread = unsafe {
+        backend::net::syscalls::recv(fd, spare.as_mut_ptr().cast::<u8>(), spare.len(), flags)?
+    };
+
+    // SAFETY: `recv` initialized `nread` bytes of the spare capacity.
+    unsafe { buf.set_len(buf.len() + nread) };
+    Ok(nread)
+}
+
 /// `send(fd, buf, flags)`—Writes data to a socket.
 ///
 /// # References
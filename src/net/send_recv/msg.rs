@@ -6,13 +6,13 @@ use crate::backend::{self, c};
 use crate::fd::{AsFd, BorrowedFd, OwnedFd};
 use crate::io::{self, IoSlice, IoSliceMut};
 #[cfg(linux_kernel)]
-use crate::net::UCred;
+use crate::net::{Ipv4Addr, Ipv6Addr, UCred};
 
 use core::iter::FusedIterator;
 use core::marker::PhantomData;
 use core::mem::{align_of, size_of, size_of_val, take};
 #[cfg(linux_kernel)]
-use core::ptr::addr_of;
+use core::ptr::{addr_of, addr_of_mut};
 use core::{ptr, slice};
 
 use super::{RecvFlags, SendFlags, SocketAddrAny, SocketAddrV4, SocketAddrV6};
@@ -45,6 +45,15 @@ use super::{RecvFlags, SendFlags, SocketAddrAny, SocketAddrV4, SocketAddrV6};
 /// let mut space = [0; rustix::cmsg_space!(ScmRights(2), ScmCredentials(1))];
 /// # }
 /// ```
+///
+/// Allocate a buffer for the destination address of an IPv6 datagram:
+/// ```
+/// # #[cfg(linux_kernel)]
+/// # {
+/// # use rustix::cmsg_space;
+/// let mut space = [0; rustix::cmsg_space!(Ipv6PktInfo(1))];
+/// # }
+/// ```
 #[macro_export]
 macro_rules! cmsg_space {
     // Base Rules
@@ -58,6 +67,12 @@ macro_rules! cmsg_space {
             $len * ::core::mem::size_of::<$crate::net::UCred>(),
         )
     };
+    (IpPktInfo($len:expr)) => {
+        $crate::net::__cmsg_space($len * $crate::net::__IN_PKTINFO_SIZE)
+    };
+    (Ipv6PktInfo($len:expr)) => {
+        $crate::net::__cmsg_space($len * $crate::net::__IN6_PKTINFO_SIZE)
+    };
 
     // Combo Rules
     ($firstid:ident($firstex:expr), $($restid:ident($restex:expr)),*) => {{
@@ -86,6 +101,12 @@ macro_rules! cmsg_aligned_space {
             $len * ::core::mem::size_of::<$crate::net::UCred>(),
         )
     };
+    (IpPktInfo($len:expr)) => {
+        $crate::net::__cmsg_aligned_space($len * $crate::net::__IN_PKTINFO_SIZE)
+    };
+    (Ipv6PktInfo($len:expr)) => {
+        $crate::net::__cmsg_aligned_space($len * $crate::net::__IN6_PKTINFO_SIZE)
+    };
 
     // Combo Rules
     ($firstid:ident($firstex:expr), $($restid:ident($restex:expr)),*) => {{
@@ -118,6 +139,52 @@ pub const fn __cmsg_aligned_space(len: usize) -> usize {
     unsafe { c::CMSG_SPACE(converted_len) as usize }
 }
 
+#[doc(hidden)]
+#[cfg(linux_kernel)]
+pub const __IN_PKTINFO_SIZE: usize = size_of::<c::in_pktinfo>();
+
+#[doc(hidden)]
+#[cfg(linux_kernel)]
+pub const __IN6_PKTINFO_SIZE: usize = size_of::<c::in6_pktinfo>();
+
+/// `struct in_pktinfo`—The addresses and interface of an IPv4 datagram, for
+/// use with [`RecvAncillaryMessage::IpPktInfo`] and
+/// [`SendAncillaryMessage::IpPktInfo`].
+#[cfg(linux_kernel)]
+#[doc(alias = "in_pktinfo")]
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct Ipv4PktInfo {
+    /// `ipi_ifindex`—The index of the interface the datagram was received
+    /// on, or is to be sent on, or 0 for any.
+    pub ifindex: u32,
+
+    /// `ipi_spec_dst`—The local address of the received datagram, or the
+    /// source address to send the datagram from.
+    pub spec_dst: Ipv4Addr,
+
+    /// `ipi_addr`—The destination address in the header of the received
+    /// datagram. This is ignored when sending.
+    pub addr: Ipv4Addr,
+}
+
+/// `struct in6_pktinfo`—The address and interface of an IPv6 datagram, for
+/// use with [`RecvAncillaryMessage::Ipv6PktInfo`] and
+/// [`SendAncillaryMessage::Ipv6PktInfo`].
+#[cfg(linux_kernel)]
+#[doc(alias = "in6_pktinfo")]
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct Ipv6PktInfo {
+    /// `ipi6_addr`—The destination address of the received datagram, or the
+    /// source address to send the datagram from.
+    pub addr: Ipv6Addr,
+
+    /// `ipi6_ifindex`—The index of the interface the datagram was received
+    /// on, or is to be sent on, or 0 for any.
+    pub ifindex: u32,
+}
+
 /// Ancillary message for [`sendmsg`], [`sendmsg_v4`], [`sendmsg_v6`],
 /// [`sendmsg_unix`], and [`sendmsg_any`].
 #[non_exhaustive]
@@ -129,6 +196,14 @@ pub enum SendAncillaryMessage<'slice, 'fd> {
     #[cfg(linux_kernel)]
     #[doc(alias = "SCM_CREDENTIAL")]
     ScmCredentials(UCred),
+    /// Send an IPv4 datagram from a specific source address or interface.
+    #[cfg(linux_kernel)]
+    #[doc(alias = "IP_PKTINFO")]
+    IpPktInfo(Ipv4PktInfo),
+    /// Send an IPv6 datagram from a specific source address or interface.
+    #[cfg(linux_kernel)]
+    #[doc(alias = "IPV6_PKTINFO")]
+    Ipv6PktInfo(Ipv6PktInfo),
 }
 
 impl SendAncillaryMessage<'_, '_> {
@@ -140,6 +215,10 @@ impl SendAncillaryMessage<'_, '_> {
             Self::ScmRights(slice) => cmsg_space!(ScmRights(slice.len())),
             #[cfg(linux_kernel)]
             Self::ScmCredentials(_) => cmsg_space!(ScmCredentials(1)),
+            #[cfg(linux_kernel)]
+            Self::IpPktInfo(_) => cmsg_space!(IpPktInfo(1)),
+            #[cfg(linux_kernel)]
+            Self::Ipv6PktInfo(_) => cmsg_space!(Ipv6PktInfo(1)),
         }
     }
 }
@@ -154,6 +233,20 @@ pub enum RecvAncillaryMessage<'a> {
     #[cfg(linux_kernel)]
     #[doc(alias = "SCM_CREDENTIALS")]
     ScmCredentials(UCred),
+    /// The addresses and interface of a received IPv4 datagram, enabled with
+    /// [`set_ip_pktinfo`].
+    ///
+    /// [`set_ip_pktinfo`]: crate::net::sockopt::set_ip_pktinfo
+    #[cfg(linux_kernel)]
+    #[doc(alias = "IP_PKTINFO")]
+    IpPktInfo(Ipv4PktInfo),
+    /// The destination address and interface of a received IPv6 datagram,
+    /// enabled with [`set_ipv6_recvpktinfo`].
+    ///
+    /// [`set_ipv6_recvpktinfo`]: crate::net::sockopt::set_ipv6_recvpktinfo
+    #[cfg(linux_kernel)]
+    #[doc(alias = "IPV6_PKTINFO")]
+    Ipv6PktInfo(Ipv6PktInfo),
 }
 
 /// Buffer for sending ancillary messages with [`sendmsg`], [`sendmsg_v4`],
@@ -278,6 +371,39 @@ impl<'buf, 'slice, 'fd> SendAncillaryBuffer<'buf, 'slice, 'fd> {
                 };
                 self.push_ancillary(ucred_bytes, c::SOL_SOCKET as _, c::SCM_CREDENTIALS as _)
             }
+            #[cfg(linux_kernel)]
+            SendAncillaryMessage::IpPktInfo(info) => {
+                let info = c::in_pktinfo {
+                    ipi_ifindex: info.ifindex as _,
+                    ipi_spec_dst: c::in_addr {
+                        s_addr: u32::from(info.spec_dst).to_be(),
+                    },
+                    ipi_addr: c::in_addr {
+                        s_addr: u32::from(info.addr).to_be(),
+                    },
+                };
+                let info_bytes = unsafe {
+                    slice::from_raw_parts(addr_of!(info).cast::<u8>(), size_of_val(&info))
+                };
+                self.push_ancillary(info_bytes, c::IPPROTO_IP as _, c::IP_PKTINFO as _)
+            }
+            #[cfg(linux_kernel)]
+            SendAncillaryMessage::Ipv6PktInfo(info) => {
+                // SAFETY: `in6_pktinfo` is plain data, and `in6_addr` is the
+                // address in network byte order.
+                let info = unsafe {
+                    let mut raw = core::mem::zeroed::<c::in6_pktinfo>();
+                    raw.ipi6_ifindex = info.ifindex as _;
+                    addr_of_mut!(raw.ipi6_addr)
+                        .cast::<[u8; 16]>()
+                        .write(info.addr.octets());
+                    raw
+                };
+                let info_bytes = unsafe {
+                    slice::from_raw_parts(addr_of!(info).cast::<u8>(), size_of_val(&info))
+                };
+                self.push_ancillary(info_bytes, c::IPPROTO_IPV6 as _, c::IPV6_PKTINFO as _)
+            }
         }
     }
 
@@ -532,6 +658,32 @@ impl<'buf> AncillaryDrain<'buf> {
                         None
                     }
                 }
+                #[cfg(linux_kernel)]
+                (c::IPPROTO_IP, c::IP_PKTINFO) => {
+                    if payload_len >= size_of::<c::in_pktinfo>() {
+                        let info = payload.as_ptr().cast::<c::in_pktinfo>().read_unaligned();
+                        Some(RecvAncillaryMessage::IpPktInfo(Ipv4PktInfo {
+                            ifindex: info.ipi_ifindex as u32,
+                            spec_dst: Ipv4Addr::from(u32::from_be(info.ipi_spec_dst.s_addr)),
+                            addr: Ipv4Addr::from(u32::from_be(info.ipi_addr.s_addr)),
+                        }))
+                    } else {
+                        None
+                    }
+                }
+                #[cfg(linux_kernel)]
+                (c::IPPROTO_IPV6, c::IPV6_PKTINFO) => {
+                    if payload_len >= size_of::<c::in6_pktinfo>() {
+                        let info = payload.as_ptr().cast::<c::in6_pktinfo>().read_unaligned();
+                        let addr = addr_of!(info.ipi6_addr).cast::<[u8; 16]>().read();
+                        Some(RecvAncillaryMessage::Ipv6PktInfo(Ipv6PktInfo {
+                            addr: Ipv6Addr::from(addr),
+                            ifindex: info.ipi6_ifindex as u32,
+                        }))
+                    } else {
+                        None
+                    }
+                }
                 _ => None,
             }
         }
@@ -808,6 +960,30 @@ pub fn recvmsg(
     backend::net::syscalls::recvmsg(socket.as_fd(), iov, control, flags)
 }
 
+/// `recvmsg(msghdr)`—Receives data from a socket into multiple buffers,
+/// without ancillary data.
+///
+/// This is like [`readv`], but takes [`RecvFlags`], such as
+/// [`RecvFlags::PEEK`] and [`RecvFlags::WAITALL`]. It's equivalent to
+/// [`recvmsg`] with an empty ancillary buffer.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/recvmsg.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/recvmsg.2.html
+/// [`readv`]: crate::io::readv
+#[inline]
+#[doc(alias = "recvmsg")]
+pub fn recvv(
+    socket: impl AsFd,
+    iov: &mut [IoSliceMut<'_>],
+    flags: RecvFlags,
+) -> io::Result<RecvMsgReturn> {
+    recvmsg(socket, iov, &mut RecvAncillaryBuffer::default(), flags)
+}
+
 /// The result of a successful [`recvmsg`] call.
 pub struct RecvMsgReturn {
     /// The number of bytes received.
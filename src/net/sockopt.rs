@@ -169,9 +169,9 @@ use crate::net::AddressFamily;
 use crate::net::Protocol;
 #[cfg(any(linux_kernel, target_os = "fuchsia"))]
 use crate::net::SocketAddrV4;
-#[cfg(linux_kernel)]
-use crate::net::SocketAddrV6;
 use crate::net::{Ipv4Addr, Ipv6Addr, SocketType};
+#[cfg(linux_kernel)]
+use crate::net::{SocketAddr, SocketAddrV6};
 use crate::{backend, io};
 #[cfg(feature = "alloc")]
 #[cfg(any(
@@ -1019,6 +1019,66 @@ pub fn get_ipv6_recvtclass<Fd: AsFd>(fd: Fd) -> io::Result<bool> {
     backend::net::sockopt::get_ipv6_recvtclass(fd.as_fd())
 }
 
+/// `setsockopt(fd, IPPROTO_IP, IP_PKTINFO, value)`
+///
+/// When enabled, each datagram received with [`recvmsg`] comes with a
+/// [`RecvAncillaryMessage::IpPktInfo`] reporting the address it was sent to
+/// and the interface it arrived on.
+///
+/// See the [module-level documentation] for more.
+///
+/// [module-level documentation]: self#references-for-get_ip_-and-set_ip_-functions
+/// [`recvmsg`]: crate::net::recvmsg
+/// [`RecvAncillaryMessage::IpPktInfo`]: crate::net::RecvAncillaryMessage::IpPktInfo
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "IP_PKTINFO")]
+pub fn set_ip_pktinfo<Fd: AsFd>(fd: Fd, value: bool) -> io::Result<()> {
+    backend::net::sockopt::set_ip_pktinfo(fd.as_fd(), value)
+}
+
+/// `getsockopt(fd, IPPROTO_IP, IP_PKTINFO)`
+///
+/// See the [module-level documentation] for more.
+///
+/// [module-level documentation]: self#references-for-get_ip_-and-set_ip_-functions
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "IP_PKTINFO")]
+pub fn get_ip_pktinfo<Fd: AsFd>(fd: Fd) -> io::Result<bool> {
+    backend::net::sockopt::get_ip_pktinfo(fd.as_fd())
+}
+
+/// `setsockopt(fd, IPPROTO_IPV6, IPV6_RECVPKTINFO, value)`
+///
+/// When enabled, each datagram received with [`recvmsg`] comes with a
+/// [`RecvAncillaryMessage::Ipv6PktInfo`] reporting the address it was sent
+/// to and the interface it arrived on.
+///
+/// See the [module-level documentation] for more.
+///
+/// [module-level documentation]: self#references-for-get_ipv6_-and-set_ipv6_-functions
+/// [`recvmsg`]: crate::net::recvmsg
+/// [`RecvAncillaryMessage::Ipv6PktInfo`]: crate::net::RecvAncillaryMessage::Ipv6PktInfo
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "IPV6_RECVPKTINFO")]
+pub fn set_ipv6_recvpktinfo<Fd: AsFd>(fd: Fd, value: bool) -> io::Result<()> {
+    backend::net::sockopt::set_ipv6_recvpktinfo(fd.as_fd(), value)
+}
+
+/// `getsockopt(fd, IPPROTO_IPV6, IPV6_RECVPKTINFO)`
+///
+/// See the [module-level documentation] for more.
+///
+/// [module-level documentation]: self#references-for-get_ipv6_-and-set_ipv6_-functions
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "IPV6_RECVPKTINFO")]
+pub fn get_ipv6_recvpktinfo<Fd: AsFd>(fd: Fd) -> io::Result<bool> {
+    backend::net::sockopt::get_ipv6_recvpktinfo(fd.as_fd())
+}
+
 /// `setsockopt(fd, IPPROTO_IP, IP_FREEBIND, value)`
 ///
 /// See the [module-level documentation] for more.
@@ -1097,6 +1157,38 @@ pub fn get_ipv6_original_dst<Fd: AsFd>(fd: Fd) -> io::Result<SocketAddrV6> {
     backend::net::sockopt::get_ipv6_original_dst(fd.as_fd())
 }
 
+/// `getsockopt(fd, IPPROTO_IP, SO_ORIGINAL_DST)` or
+/// `getsockopt(fd, IPPROTO_IPV6, IP6T_SO_ORIGINAL_DST)`—Returns the
+/// destination address of a connection before it was redirected by
+/// netfilter, such as by an iptables `REDIRECT` rule.
+///
+/// This uses [`get_ipv6_original_dst`] for IPv6 sockets, falling back to
+/// [`get_ip_original_dst`] for IPv4 connections accepted on dual-stack IPv6
+/// sockets or when IPv6 connection tracking isn't available, and
+/// [`get_ip_original_dst`] for IPv4 sockets. It fails with
+/// [`io::Errno::NOENT`] if the connection has no netfilter connection
+/// tracking entry.
+///
+/// See the [module-level documentation] for more.
+///
+/// [module-level documentation]: self#references-for-get_ipv6_-and-set_ipv6_-functions
+#[cfg(linux_kernel)]
+#[doc(alias = "SO_ORIGINAL_DST")]
+#[doc(alias = "IP6T_SO_ORIGINAL_DST")]
+pub fn get_original_dst<Fd: AsFd>(fd: Fd) -> io::Result<SocketAddr> {
+    let fd = fd.as_fd();
+    if get_socket_domain(fd)? == AddressFamily::INET6 {
+        match get_ipv6_original_dst(fd) {
+            // IPv4 connections on dual-stack sockets are tracked as IPv4, and
+            // kernels without the IPv6 conntrack module don't support
+            // `IP6T_SO_ORIGINAL_DST` at all.
+            Err(io::Errno::NOENT | io::Errno::NOPROTOOPT) => (),
+            result => return result.map(SocketAddr::V6),
+        }
+    }
+    get_ip_original_dst(fd).map(SocketAddr::V4)
+}
+
 /// `setsockopt(fd, IPPROTO_IPV6, IPV6_TCLASS, value)`
 ///
 /// See the [module-level documentation] for more.
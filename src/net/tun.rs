@@ -0,0 +1,206 @@
+//! Linux tun/tap device configuration.
+//!
+//! The functions in this module take a file descriptor opened from
+//! `/dev/net/tun`, and configure the virtual network interface attached to
+//! it.
+//!
+//! # References
+//!  - [Linux]
+//!
+//! [Linux]: https://www.kernel.org/doc/html/latest/networking/tuntap.html
+
+#![allow(unsafe_code)]
+
+#[cfg(feature = "alloc")]
+use crate::alloc::string::String;
+use crate::backend::c;
+use crate::fd::AsFd;
+use crate::{io, ioctl};
+use bitflags::bitflags;
+use linux_raw_sys::net::{ifreq, ifreq__bindgen_ty_1, ifreq__bindgen_ty_2, IFNAMSIZ};
+
+pub use crate::ugid::{Gid, Uid};
+
+bitflags! {
+    /// `IFF_*` constants for use with [`ioctl_tunsetiff`].
+    #[repr(transparent)]
+    #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    pub struct TunFlags: u16 {
+        /// `IFF_TUN`—A layer 3 device, without Ethernet headers.
+        const TUN = 0x0001;
+        /// `IFF_TAP`—A layer 2 device, with Ethernet headers.
+        const TAP = 0x0002;
+        /// `IFF_NAPI`
+        const NAPI = 0x0010;
+        /// `IFF_NAPI_FRAGS`
+        const NAPI_FRAGS = 0x0020;
+        /// `IFF_MULTI_QUEUE`—Allow multiple file descriptors to be attached
+        /// to the device, each as its own queue.
+        const MULTI_QUEUE = 0x0100;
+        /// `IFF_NO_PI`—Don't prepend packet information to packets.
+        const NO_PI = 0x1000;
+        /// `IFF_VNET_HDR`—Prepend a `struct virtio_net_hdr` to packets.
+        const VNET_HDR = 0x4000;
+        /// `IFF_TUN_EXCL`—Fail if the device already exists.
+        const TUN_EXCL = 0x8000;
+
+        /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+        const _ = !0;
+    }
+}
+
+/// `TUNSETIFF`
+type SetIff = ioctl::WriteOpcode<b'T', 202, c::c_int>;
+/// `TUNGETIFF`
+type GetIff = ioctl::ReadOpcode<b'T', 210, c::c_uint>;
+/// `TUNSETPERSIST`
+type SetPersist = ioctl::WriteOpcode<b'T', 203, c::c_int>;
+/// `TUNSETOWNER`
+type SetOwner = ioctl::WriteOpcode<b'T', 204, c::c_int>;
+/// `TUNSETGROUP`
+type SetGroup = ioctl::WriteOpcode<b'T', 206, c::c_int>;
+
+/// `ioctl(fd, TUNSETIFF, ifreq)`—Attaches `fd` to a tun/tap device,
+/// creating it if needed.
+///
+/// `name` may be empty, or contain a `%d` pattern such as `"tun%d"`, in which
+/// case the kernel picks a name; use [`ioctl_tungetiff`] to find out which.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://www.kernel.org/doc/html/latest/networking/tuntap.html
+#[inline]
+#[doc(alias = "TUNSETIFF")]
+pub fn ioctl_tunsetiff<Fd: AsFd>(fd: Fd, name: &str, flags: TunFlags) -> io::Result<()> {
+    let name = name.as_bytes();
+    if name.len() >= IFNAMSIZ as usize || name.contains(&0) {
+        return Err(io::Errno::INVAL);
+    }
+
+    let mut ifreq = ifreq {
+        ifr_ifrn: ifreq__bindgen_ty_1 { ifrn_name: [0; 16] },
+        ifr_ifru: ifreq__bindgen_ty_2 {
+            ifru_flags: flags.bits() as c::c_short,
+        },
+    };
+    // SAFETY: `ifrn_name` is the only member of its union.
+    for (dst, src) in unsafe { ifreq.ifr_ifrn.ifrn_name.iter_mut() }.zip(name) {
+        *dst = *src as _;
+    }
+
+    // SAFETY: `TUNSETIFF` reads and writes a `struct ifreq`.
+    unsafe {
+        let ctl = ioctl::Updater::<SetIff, ifreq>::new(&mut ifreq);
+        ioctl::ioctl(fd, ctl)
+    }
+}
+
+/// `ioctl(fd, TUNGETIFF, ifreq)`—Returns the name and flags of the tun/tap
+/// device `fd` is attached to.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://www.kernel.org/doc/html/latest/networking/tuntap.html
+#[cfg(feature = "alloc")]
+#[inline]
+#[doc(alias = "TUNGETIFF")]
+pub fn ioctl_tungetiff<Fd: AsFd>(fd: Fd) -> io::Result<(String, TunFlags)> {
+    let mut ifreq = ifreq {
+        ifr_ifrn: ifreq__bindgen_ty_1 { ifrn_name: [0; 16] },
+        ifr_ifru: ifreq__bindgen_ty_2 { ifru_flags: 0 },
+    };
+
+    // SAFETY: `TUNGETIFF` writes a `struct ifreq`.
+    unsafe {
+        let ctl = ioctl::Updater::<GetIff, ifreq>::new(&mut ifreq);
+        ioctl::ioctl(fd, ctl)?;
+    }
+
+    // SAFETY: The kernel filled in the name and flags members.
+    let (name, flags) = unsafe { (ifreq.ifr_ifrn.ifrn_name, ifreq.ifr_ifru.ifru_flags) };
+    let len = name.iter().position(|c| *c == 0).unwrap_or(name.len());
+    let name = name[..len].iter().map(|c| *c as u8).collect();
+    let name = String::from_utf8(name).map_err(|_| io::Errno::ILSEQ)?;
+    Ok((name, TunFlags::from_bits_retain(flags as u16)))
+}
+
+/// `ioctl(fd, TUNSETPERSIST, persist)`—Sets whether the device persists
+/// after `fd` is closed.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://www.kernel.org/doc/html/latest/networking/tuntap.html
+#[inline]
+#[doc(alias = "TUNSETPERSIST")]
+pub fn ioctl_tunsetpersist<Fd: AsFd>(fd: Fd, persist: bool) -> io::Result<()> {
+    // SAFETY: `TUNSETPERSIST` takes an integer argument.
+    unsafe {
+        let ctl = ioctl::IntegerSetter::<SetPersist>::new(usize::from(persist));
+        ioctl::ioctl(fd, ctl)
+    }
+}
+
+/// `ioctl(fd, TUNSETOWNER, uid)`—Sets the user allowed to attach to a
+/// persistent device.
+///
+/// `None` allows any user with the relevant capabilities.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://www.kernel.org/doc/html/latest/networking/tuntap.html
+#[inline]
+#[doc(alias = "TUNSETOWNER")]
+pub fn ioctl_tunsetowner<Fd: AsFd>(fd: Fd, owner: Option<Uid>) -> io::Result<()> {
+    let owner = owner.map_or(-1_i32 as c::c_uint, Uid::as_raw);
+    // SAFETY: `TUNSETOWNER` takes an integer argument.
+    unsafe {
+        let ctl = ioctl::IntegerSetter::<SetOwner>::new(owner as usize);
+        ioctl::ioctl(fd, ctl)
+    }
+}
+
+/// `ioctl(fd, TUNSETGROUP, gid)`—Sets the group allowed to attach to a
+/// persistent device.
+///
+/// `None` allows any group with the relevant capabilities.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://www.kernel.org/doc/html/latest/networking/tuntap.html
+#[inline]
+#[doc(alias = "TUNSETGROUP")]
+pub fn ioctl_tunsetgroup<Fd: AsFd>(fd: Fd, group: Option<Gid>) -> io::Result<()> {
+    let group = group.map_or(-1_i32 as c::c_uint, Gid::as_raw);
+    // SAFETY: `TUNSETGROUP` takes an integer argument.
+    unsafe {
+        let ctl = ioctl::IntegerSetter::<SetGroup>::new(group as usize);
+        ioctl::ioctl(fd, ctl)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ioctl::{CompileTimeOpcode, RawOpcode};
+
+    #[cfg(not(any(
+        // These have no ioctl opcodes defined in linux_raw_sys.
+        target_arch = "sparc",
+        target_arch = "sparc64"
+    )))]
+    #[test]
+    fn tun_opcodes() {
+        use linux_raw_sys::ioctl as sys;
+
+        const_assert_eq!(SetIff::OPCODE.raw(), sys::TUNSETIFF as RawOpcode);
+        const_assert_eq!(GetIff::OPCODE.raw(), sys::TUNGETIFF as RawOpcode);
+        const_assert_eq!(SetPersist::OPCODE.raw(), sys::TUNSETPERSIST as RawOpcode);
+        const_assert_eq!(SetOwner::OPCODE.raw(), sys::TUNSETOWNER as RawOpcode);
+        const_assert_eq!(SetGroup::OPCODE.raw(), sys::TUNSETGROUP as RawOpcode);
+    }
+}
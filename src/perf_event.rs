@@ -0,0 +1,606 @@
+//! Linux performance monitoring.
+//!
+//! [`perf_event_open`] creates a file descriptor for a performance counter
+//! or sampling event. Counters can be read with [`read`], and for sampling
+//! events, the ring buffer can be mapped with [`mmap`].
+//!
+//! # References
+//!  - [Linux]
+//!
+//! [Linux]: https://man7.org/linux/man-pages/man2/perf_event_open.2.html
+//! [`read`]: crate::io::read
+//! [`mmap`]: https://docs.rs/rustix/*/rustix/mm/fn.mmap.html
+
+#![allow(unsafe_code)]
+
+use crate::backend::perf_event::syscalls;
+use crate::fd::{AsFd, BorrowedFd, OwnedFd};
+use crate::{io, ioctl};
+use bitflags::bitflags;
+use core::mem::size_of;
+
+pub use crate::pid::Pid;
+
+/// `PERF_TYPE_*`—The type of a performance event.
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct EventType(u32);
+
+impl EventType {
+    /// `PERF_TYPE_HARDWARE`
+    pub const HARDWARE: Self = Self(0);
+
+    /// `PERF_TYPE_SOFTWARE`
+    pub const SOFTWARE: Self = Self(1);
+
+    /// `PERF_TYPE_TRACEPOINT`
+    pub const TRACEPOINT: Self = Self(2);
+
+    /// `PERF_TYPE_HW_CACHE`
+    pub const HW_CACHE: Self = Self(3);
+
+    /// `PERF_TYPE_RAW`
+    pub const RAW: Self = Self(4);
+
+    /// `PERF_TYPE_BREAKPOINT`
+    pub const BREAKPOINT: Self = Self(5);
+
+    /// Constructs an `EventType` from a raw value, such as a dynamic PMU
+    /// type read from `/sys/bus/event_source/devices/*/type`.
+    #[inline]
+    pub const fn from_raw(raw: u32) -> Self {
+        Self(raw)
+    }
+
+    /// Returns the raw `PERF_TYPE_*` value.
+    #[inline]
+    pub const fn as_raw(self) -> u32 {
+        self.0
+    }
+}
+
+/// `PERF_COUNT_HW_*`—Generalized hardware events, for use with
+/// [`EventType::HARDWARE`].
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct HardwareEvent(u64);
+
+impl HardwareEvent {
+    /// `PERF_COUNT_HW_CPU_CYCLES`
+    pub const CPU_CYCLES: Self = Self(0);
+
+    /// `PERF_COUNT_HW_INSTRUCTIONS`
+    pub const INSTRUCTIONS: Self = Self(1);
+
+    /// `PERF_COUNT_HW_CACHE_REFERENCES`
+    pub const CACHE_REFERENCES: Self = Self(2);
+
+    /// `PERF_COUNT_HW_CACHE_MISSES`
+    pub const CACHE_MISSES: Self = Self(3);
+
+    /// `PERF_COUNT_HW_BRANCH_INSTRUCTIONS`
+    pub const BRANCH_INSTRUCTIONS: Self = Self(4);
+
+    /// `PERF_COUNT_HW_BRANCH_MISSES`
+    pub const BRANCH_MISSES: Self = Self(5);
+
+    /// `PERF_COUNT_HW_BUS_CYCLES`
+    pub const BUS_CYCLES: Self = Self(6);
+
+    /// `PERF_COUNT_HW_STALLED_CYCLES_FRONTEND`
+    pub const STALLED_CYCLES_FRONTEND: Self = Self(7);
+
+    /// `PERF_COUNT_HW_STALLED_CYCLES_BACKEND`
+    pub const STALLED_CYCLES_BACKEND: Self = Self(8);
+
+    /// `PERF_COUNT_HW_REF_CPU_CYCLES`
+    pub const REF_CPU_CYCLES: Self = Self(9);
+}
+
+/// `PERF_COUNT_SW_*`—Software events, for use with
+/// [`EventType::SOFTWARE`].
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct SoftwareEvent(u64);
+
+impl SoftwareEvent {
+    /// `PERF_COUNT_SW_CPU_CLOCK`
+    pub const CPU_CLOCK: Self = Self(0);
+
+    /// `PERF_COUNT_SW_TASK_CLOCK`
+    pub const TASK_CLOCK: Self = Self(1);
+
+    /// `PERF_COUNT_SW_PAGE_FAULTS`
+    pub const PAGE_FAULTS: Self = Self(2);
+
+    /// `PERF_COUNT_SW_CONTEXT_SWITCHES`
+    pub const CONTEXT_SWITCHES: Self = Self(3);
+
+    /// `PERF_COUNT_SW_CPU_MIGRATIONS`
+    pub const CPU_MIGRATIONS: Self = Self(4);
+
+    /// `PERF_COUNT_SW_PAGE_FAULTS_MIN`
+    pub const PAGE_FAULTS_MIN: Self = Self(5);
+
+    /// `PERF_COUNT_SW_PAGE_FAULTS_MAJ`
+    pub const PAGE_FAULTS_MAJ: Self = Self(6);
+
+    /// `PERF_COUNT_SW_ALIGNMENT_FAULTS`
+    pub const ALIGNMENT_FAULTS: Self = Self(7);
+
+    /// `PERF_COUNT_SW_EMULATION_FAULTS`
+    pub const EMULATION_FAULTS: Self = Self(8);
+
+    /// `PERF_COUNT_SW_DUMMY`
+    pub const DUMMY: Self = Self(9);
+
+    /// `PERF_COUNT_SW_BPF_OUTPUT`
+    pub const BPF_OUTPUT: Self = Self(10);
+}
+
+bitflags! {
+    /// `PERF_SAMPLE_*` constants for use with
+    /// [`PerfEventAttr::sample_type`].
+    #[repr(transparent)]
+    #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    pub struct SampleType: u64 {
+        /// `PERF_SAMPLE_IP`
+        const IP = 1 << 0;
+        /// `PERF_SAMPLE_TID`
+        const TID = 1 << 1;
+        /// `PERF_SAMPLE_TIME`
+        const TIME = 1 << 2;
+        /// `PERF_SAMPLE_ADDR`
+        const ADDR = 1 << 3;
+        /// `PERF_SAMPLE_READ`
+        const READ = 1 << 4;
+        /// `PERF_SAMPLE_CALLCHAIN`
+        const CALLCHAIN = 1 << 5;
+        /// `PERF_SAMPLE_ID`
+        const ID = 1 << 6;
+        /// `PERF_SAMPLE_CPU`
+        const CPU = 1 << 7;
+        /// `PERF_SAMPLE_PERIOD`
+        const PERIOD = 1 << 8;
+        /// `PERF_SAMPLE_STREAM_ID`
+        const STREAM_ID = 1 << 9;
+        /// `PERF_SAMPLE_RAW`
+        const RAW = 1 << 10;
+        /// `PERF_SAMPLE_BRANCH_STACK`
+        const BRANCH_STACK = 1 << 11;
+        /// `PERF_SAMPLE_REGS_USER`
+        const REGS_USER = 1 << 12;
+        /// `PERF_SAMPLE_STACK_USER`
+        const STACK_USER = 1 << 13;
+        /// `PERF_SAMPLE_WEIGHT`
+        const WEIGHT = 1 << 14;
+        /// `PERF_SAMPLE_DATA_SRC`
+        const DATA_SRC = 1 << 15;
+        /// `PERF_SAMPLE_IDENTIFIER`
+        const IDENTIFIER = 1 << 16;
+        /// `PERF_SAMPLE_TRANSACTION`
+        const TRANSACTION = 1 << 17;
+        /// `PERF_SAMPLE_REGS_INTR`
+        const REGS_INTR = 1 << 18;
+        /// `PERF_SAMPLE_PHYS_ADDR`
+        const PHYS_ADDR = 1 << 19;
+
+        /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+        const _ = !0;
+    }
+}
+
+bitflags! {
+    /// `PERF_FORMAT_*` constants for use with
+    /// [`PerfEventAttr::read_format`].
+    #[repr(transparent)]
+    #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    pub struct ReadFormat: u64 {
+        /// `PERF_FORMAT_TOTAL_TIME_ENABLED`
+        const TOTAL_TIME_ENABLED = 1 << 0;
+        /// `PERF_FORMAT_TOTAL_TIME_RUNNING`
+        const TOTAL_TIME_RUNNING = 1 << 1;
+        /// `PERF_FORMAT_ID`
+        const ID = 1 << 2;
+        /// `PERF_FORMAT_GROUP`
+        const GROUP = 1 << 3;
+        /// `PERF_FORMAT_LOST`
+        const LOST = 1 << 4;
+
+        /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+        const _ = !0;
+    }
+}
+
+bitflags! {
+    /// Single-bit fields of `struct perf_event_attr`, for use with
+    /// [`PerfEventAttr::flags`].
+    #[repr(transparent)]
+    #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    pub struct AttrFlags: u64 {
+        /// `disabled`—Start the event disabled.
+        const DISABLED = 1 << 0;
+        /// `inherit`—Count events of child tasks too.
+        const INHERIT = 1 << 1;
+        /// `pinned`—Always keep the event on the PMU.
+        const PINNED = 1 << 2;
+        /// `exclusive`—Be the only group on the PMU.
+        const EXCLUSIVE = 1 << 3;
+        /// `exclude_user`—Don't count user-space events.
+        const EXCLUDE_USER = 1 << 4;
+        /// `exclude_kernel`—Don't count kernel events.
+        const EXCLUDE_KERNEL = 1 << 5;
+        /// `exclude_hv`—Don't count hypervisor events.
+        const EXCLUDE_HV = 1 << 6;
+        /// `exclude_idle`—Don't count when idle.
+        const EXCLUDE_IDLE = 1 << 7;
+        /// `mmap`—Include `mmap` records.
+        const MMAP = 1 << 8;
+        /// `comm`—Include `comm` records.
+        const COMM = 1 << 9;
+        /// `inherit_stat`—Save per-task counts on context switch.
+        const INHERIT_STAT = 1 << 11;
+        /// `enable_on_exec`—Enable the event on `exec`.
+        const ENABLE_ON_EXEC = 1 << 12;
+        /// `task`—Include fork and exit records.
+        const TASK = 1 << 13;
+        /// `watermark`—Wake up on a byte watermark rather than a number of
+        /// events.
+        const WATERMARK = 1 << 14;
+        /// `mmap_data`—Include `mmap` records for non-executable mappings.
+        const MMAP_DATA = 1 << 17;
+        /// `sample_id_all`—Include sample IDs in all records.
+        const SAMPLE_ID_ALL = 1 << 18;
+        /// `exclude_host`—Don't count in the host.
+        const EXCLUDE_HOST = 1 << 19;
+        /// `exclude_guest`—Don't count in guests.
+        const EXCLUDE_GUEST = 1 << 20;
+        /// `exclude_callchain_kernel`—Exclude kernel callchains.
+        const EXCLUDE_CALLCHAIN_KERNEL = 1 << 21;
+        /// `exclude_callchain_user`—Exclude user callchains.
+        const EXCLUDE_CALLCHAIN_USER = 1 << 22;
+        /// `mmap2`—Include extended `mmap` records.
+        const MMAP2 = 1 << 23;
+        /// `comm_exec`—Flag `comm` records caused by `exec`.
+        const COMM_EXEC = 1 << 24;
+        /// `context_switch`—Include context switch records.
+        const CONTEXT_SWITCH = 1 << 26;
+        /// `write_backward`—Write the ring buffer from end to beginning.
+        const WRITE_BACKWARD = 1 << 27;
+
+        /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+        const _ = !0;
+    }
+}
+
+bitflags! {
+    /// `PERF_FLAG_*` constants for use with [`perf_event_open`].
+    #[repr(transparent)]
+    #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    pub struct PerfEventOpenFlags: u32 {
+        /// `PERF_FLAG_FD_NO_GROUP`
+        const FD_NO_GROUP = 1 << 0;
+        /// `PERF_FLAG_FD_OUTPUT`
+        const FD_OUTPUT = 1 << 1;
+        /// `PERF_FLAG_PID_CGROUP`
+        const PID_CGROUP = 1 << 2;
+        /// `PERF_FLAG_FD_CLOEXEC`
+        const FD_CLOEXEC = 1 << 3;
+
+        /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+        const _ = !0;
+    }
+}
+
+/// The `freq` bit of `struct perf_event_attr`.
+const ATTR_FREQ: u64 = 1 << 10;
+
+/// The `precise_ip` field of `struct perf_event_attr`.
+const ATTR_PRECISE_IP_SHIFT: u32 = 15;
+const ATTR_PRECISE_IP_MASK: u64 = 0b11 << ATTR_PRECISE_IP_SHIFT;
+
+/// `struct perf_event_attr`—Describes an event for [`perf_event_open`].
+///
+/// This is a builder; start with [`PerfEventAttr::new`],
+/// [`PerfEventAttr::hardware`], or [`PerfEventAttr::software`] and chain
+/// calls to configure the event.
+///
+/// # Examples
+///
+/// ```
+/// use rustix::perf_event::{AttrFlags, HardwareEvent, PerfEventAttr};
+///
+/// let attr = PerfEventAttr::hardware(HardwareEvent::INSTRUCTIONS)
+///     .flags(AttrFlags::DISABLED | AttrFlags::EXCLUDE_KERNEL | AttrFlags::EXCLUDE_HV);
+/// ```
+#[repr(C)]
+#[derive(Clone, Debug)]
+pub struct PerfEventAttr {
+    type_: u32,
+    size: u32,
+    config: u64,
+    sample_period_or_freq: u64,
+    sample_type: u64,
+    read_format: u64,
+    flags: u64,
+    wakeup_events_or_watermark: u32,
+    bp_type: u32,
+    config1: u64,
+    config2: u64,
+    branch_sample_type: u64,
+    sample_regs_user: u64,
+    sample_stack_user: u32,
+    clockid: i32,
+    sample_regs_intr: u64,
+    aux_watermark: u32,
+    sample_max_stack: u16,
+    reserved_2: u16,
+}
+
+impl PerfEventAttr {
+    /// Creates an attribute for an event of type `type_` with the
+    /// type-specific `config` value.
+    #[inline]
+    pub const fn new(type_: EventType, config: u64) -> Self {
+        Self {
+            type_: type_.0,
+            size: size_of::<Self>() as u32,
+            config,
+            sample_period_or_freq: 0,
+            sample_type: 0,
+            read_format: 0,
+            flags: 0,
+            wakeup_events_or_watermark: 0,
+            bp_type: 0,
+            config1: 0,
+            config2: 0,
+            branch_sample_type: 0,
+            sample_regs_user: 0,
+            sample_stack_user: 0,
+            clockid: 0,
+            sample_regs_intr: 0,
+            aux_watermark: 0,
+            sample_max_stack: 0,
+            reserved_2: 0,
+        }
+    }
+
+    /// Creates an attribute for a generalized hardware event.
+    #[inline]
+    pub const fn hardware(event: HardwareEvent) -> Self {
+        Self::new(EventType::HARDWARE, event.0)
+    }
+
+    /// Creates an attribute for a software event.
+    #[inline]
+    pub const fn software(event: SoftwareEvent) -> Self {
+        Self::new(EventType::SOFTWARE, event.0)
+    }
+
+    /// Sets `config1`, an extension of `config` used by some event types.
+    #[inline]
+    pub const fn config1(mut self, config1: u64) -> Self {
+        self.config1 = config1;
+        self
+    }
+
+    /// Sets `config2`, an extension of `config1` used by some event types.
+    #[inline]
+    pub const fn config2(mut self, config2: u64) -> Self {
+        self.config2 = config2;
+        self
+    }
+
+    /// Sets `sample_period`, the number of events between samples.
+    #[inline]
+    pub const fn sample_period(mut self, period: u64) -> Self {
+        self.sample_period_or_freq = period;
+        self.flags &= !ATTR_FREQ;
+        self
+    }
+
+    /// Sets `sample_freq` and the `freq` bit, to sample at a frequency in Hz
+    /// rather than after a number of events.
+    #[inline]
+    pub const fn sample_freq(mut self, freq: u64) -> Self {
+        self.sample_period_or_freq = freq;
+        self.flags |= ATTR_FREQ;
+        self
+    }
+
+    /// Sets `sample_type`, which selects the fields included in samples.
+    #[inline]
+    pub const fn sample_type(mut self, sample_type: SampleType) -> Self {
+        self.sample_type = sample_type.bits();
+        self
+    }
+
+    /// Sets `read_format`, which selects the fields returned by reading the
+    /// event's file descriptor.
+    #[inline]
+    pub const fn read_format(mut self, read_format: ReadFormat) -> Self {
+        self.read_format = read_format.bits();
+        self
+    }
+
+    /// Sets the single-bit fields, such as `disabled` and the `exclude_*`
+    /// bits, replacing any previously set.
+    #[inline]
+    pub const fn flags(mut self, flags: AttrFlags) -> Self {
+        let keep = ATTR_FREQ | ATTR_PRECISE_IP_MASK;
+        self.flags = (self.flags & keep) | (flags.bits() & !keep);
+        self
+    }
+
+    /// Sets `precise_ip`, the requested skid constraint, from 0 to 3.
+    #[inline]
+    pub const fn precise_ip(mut self, precise_ip: u8) -> Self {
+        self.flags = (self.flags & !ATTR_PRECISE_IP_MASK)
+            | ((precise_ip as u64) << ATTR_PRECISE_IP_SHIFT) & ATTR_PRECISE_IP_MASK;
+        self
+    }
+
+    /// Sets `wakeup_events`, the number of samples between wakeups.
+    #[inline]
+    pub const fn wakeup_events(mut self, events: u32) -> Self {
+        self.wakeup_events_or_watermark = events;
+        self.flags &= !AttrFlags::WATERMARK.bits();
+        self
+    }
+
+    /// Sets `wakeup_watermark` and the `watermark` bit, to wake up after a
+    /// number of bytes rather than samples.
+    #[inline]
+    pub const fn wakeup_watermark(mut self, bytes: u32) -> Self {
+        self.wakeup_events_or_watermark = bytes;
+        self.flags |= AttrFlags::WATERMARK.bits();
+        self
+    }
+}
+
+/// `perf_event_open(attr, pid, cpu, group_fd, flags)`—Opens a performance
+/// event.
+///
+/// If `pid` is `None`, the event measures the calling thread. If `cpu` is
+/// `None`, the event measures on any CPU. To measure all processes on one
+/// CPU, use [`perf_event_open_cpu`].
+///
+/// If `group_fd` is `Some`, the event joins the group led by that event.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/perf_event_open.2.html
+#[inline]
+pub fn perf_event_open(
+    attr: &PerfEventAttr,
+    pid: Option<Pid>,
+    cpu: Option<u32>,
+    group_fd: Option<BorrowedFd<'_>>,
+    flags: PerfEventOpenFlags,
+) -> io::Result<OwnedFd> {
+    let cpu = match cpu {
+        Some(cpu) => i32::try_from(cpu).map_err(|_| io::Errno::INVAL)?,
+        None => -1,
+    };
+    syscalls::perf_event_open(attr, Pid::as_raw(pid), cpu, group_fd, flags)
+}
+
+/// `perf_event_open(attr, -1, cpu, group_fd, flags)`—Opens a performance
+/// event measuring all processes on a CPU.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/perf_event_open.2.html
+#[inline]
+pub fn perf_event_open_cpu(
+    attr: &PerfEventAttr,
+    cpu: u32,
+    group_fd: Option<BorrowedFd<'_>>,
+    flags: PerfEventOpenFlags,
+) -> io::Result<OwnedFd> {
+    let cpu = i32::try_from(cpu).map_err(|_| io::Errno::INVAL)?;
+    syscalls::perf_event_open(attr, -1, cpu, group_fd, flags)
+}
+
+/// `PERF_IOC_FLAG_GROUP`—Apply an `ioctl` to all events in a group.
+const PERF_IOC_FLAG_GROUP: usize = 1;
+
+/// `ioctl(fd, PERF_EVENT_IOC_ENABLE, flags)`—Enables an event.
+///
+/// If `group` is true, all events in the group led by `fd` are enabled.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/perf_event_open.2.html
+#[inline]
+#[doc(alias = "PERF_EVENT_IOC_ENABLE")]
+pub fn ioctl_enable<Fd: AsFd>(fd: Fd, group: bool) -> io::Result<()> {
+    // SAFETY: `PERF_EVENT_IOC_ENABLE` takes an integer flags argument.
+    unsafe {
+        let ctl = ioctl::IntegerSetter::<ioctl::NoneOpcode<b'$', 0, ()>>::new(group_flag(group));
+        ioctl::ioctl(fd, ctl)
+    }
+}
+
+/// `ioctl(fd, PERF_EVENT_IOC_DISABLE, flags)`—Disables an event.
+///
+/// If `group` is true, all events in the group led by `fd` are disabled.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/perf_event_open.2.html
+#[inline]
+#[doc(alias = "PERF_EVENT_IOC_DISABLE")]
+pub fn ioctl_disable<Fd: AsFd>(fd: Fd, group: bool) -> io::Result<()> {
+    // SAFETY: `PERF_EVENT_IOC_DISABLE` takes an integer flags argument.
+    unsafe {
+        let ctl = ioctl::IntegerSetter::<ioctl::NoneOpcode<b'$', 1, ()>>::new(group_flag(group));
+        ioctl::ioctl(fd, ctl)
+    }
+}
+
+/// `ioctl(fd, PERF_EVENT_IOC_RESET, flags)`—Resets an event's count to
+/// zero.
+///
+/// If `group` is true, all events in the group led by `fd` are reset.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/perf_event_open.2.html
+#[inline]
+#[doc(alias = "PERF_EVENT_IOC_RESET")]
+pub fn ioctl_reset<Fd: AsFd>(fd: Fd, group: bool) -> io::Result<()> {
+    // SAFETY: `PERF_EVENT_IOC_RESET` takes an integer flags argument.
+    unsafe {
+        let ctl = ioctl::IntegerSetter::<ioctl::NoneOpcode<b'$', 3, ()>>::new(group_flag(group));
+        ioctl::ioctl(fd, ctl)
+    }
+}
+
+/// `ioctl(fd, PERF_EVENT_IOC_PERIOD, &period)`—Updates an event's sampling
+/// period.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/perf_event_open.2.html
+#[inline]
+#[doc(alias = "PERF_EVENT_IOC_PERIOD")]
+pub fn ioctl_period<Fd: AsFd>(fd: Fd, period: u64) -> io::Result<()> {
+    // SAFETY: `PERF_EVENT_IOC_PERIOD` reads a `u64` through a pointer.
+    unsafe {
+        let ctl = ioctl::Setter::<ioctl::WriteOpcode<b'$', 4, u64>, u64>::new(period);
+        ioctl::ioctl(fd, ctl)
+    }
+}
+
+/// `ioctl(fd, PERF_EVENT_IOC_ID, &id)`—Returns an event's unique ID.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/perf_event_open.2.html
+#[inline]
+#[doc(alias = "PERF_EVENT_IOC_ID")]
+pub fn ioctl_id<Fd: AsFd>(fd: Fd) -> io::Result<u64> {
+    // SAFETY: `PERF_EVENT_IOC_ID` writes a `u64`. Its opcode is declared
+    // with a pointer type, so its size field is the size of a pointer.
+    unsafe {
+        let ctl = ioctl::Getter::<ioctl::ReadOpcode<b'$', 7, *mut u64>, u64>::new();
+        ioctl::ioctl(fd, ctl)
+    }
+}
+
+#[inline]
+fn group_flag(group: bool) -> usize {
+    if group {
+        PERF_IOC_FLAG_GROUP
+    } else {
+        0
+    }
+}
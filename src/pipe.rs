@@ -209,6 +209,14 @@ pub fn fcntl_getpipe_size<Fd: AsFd>(fd: Fd) -> io::Result<usize> {
 
 /// `fnctl(fd, F_SETPIPE_SZ)`—Set the buffer capacity of a pipe.
 ///
+/// The kernel rounds `size` up to a power-of-two number of pages; use
+/// [`fcntl_getpipe_size`] to query the resulting capacity. Unprivileged
+/// processes can't grow a pipe beyond the limit in
+/// `/proc/sys/fs/pipe-max-size`, and this fails with [`io::Errno::PERM`] if
+/// `size` exceeds it, or doesn't fit in a C `int`. It fails with
+/// [`io::Errno::BUSY`] if `size` is smaller than the data currently in the
+/// pipe.
+///
 /// # References
 ///  - [Linux]
 ///
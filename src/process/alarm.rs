@@ -0,0 +1,26 @@
+//! The `alarm` function.
+
+use crate::backend;
+
+/// `alarm(seconds)`—Schedules a `SIGALRM` signal to be delivered to the
+/// process.
+///
+/// After `seconds` seconds, `SIGALRM` is delivered to the process, which
+/// terminates it unless the signal is handled or blocked. A `seconds` of `0`
+/// cancels any pending alarm. This returns the number of seconds remaining
+/// on the previously scheduled alarm, or `0` if there was none.
+///
+/// This shares the `ITIMER_REAL` timer with `setitimer`, so using both in
+/// the same process interferes. Together with `rustix::event::pause`, this
+/// implements a simple sleep-until-timeout pattern.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/alarm.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/alarm.2.html
+#[inline]
+pub fn alarm(seconds: u32) -> u32 {
+    backend::process::syscalls::alarm(seconds)
+}
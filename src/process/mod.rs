@@ -1,5 +1,7 @@
 //! Process-associated operations.
 
+#[cfg(not(any(target_os = "espidf", target_os = "vita", target_os = "wasi")))]
+mod alarm;
 #[cfg(not(target_os = "wasi"))]
 mod chdir;
 #[cfg(not(any(target_os = "fuchsia", target_os = "wasi")))]
@@ -40,6 +42,8 @@ mod umask;
 #[cfg(not(any(target_os = "espidf", target_os = "vita", target_os = "wasi")))]
 mod wait;
 
+#[cfg(not(any(target_os = "espidf", target_os = "vita", target_os = "wasi")))]
+pub use alarm::alarm;
 #[cfg(not(target_os = "wasi"))]
 pub use chdir::*;
 #[cfg(not(any(target_os = "fuchsia", target_os = "wasi")))]
@@ -4,6 +4,9 @@ use crate::{backend, io};
 
 /// `nice(inc)`—Adjust the scheduling priority of the current process.
 ///
+/// On Linux, a negative `inc` requires the `CAP_SYS_NICE` capability, or a
+/// sufficient `RLIMIT_NICE`, and fails with [`io::Errno::PERM`] without it.
+///
 /// # References
 ///  - [POSIX]
 ///  - [Linux]
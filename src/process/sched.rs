@@ -123,3 +123,20 @@ pub fn sched_getaffinity(pid: Option<Pid>) -> io::Result<CpuSet> {
 pub fn sched_getcpu() -> usize {
     backend::process::syscalls::sched_getcpu()
 }
+
+/// `getcpu()`—Get the CPU and NUMA node that the current thread is currently
+/// on.
+///
+/// Returns a `(cpu, node)` pair. Like [`sched_getcpu`], the result may be
+/// out of date as soon as it's returned, unless the thread's affinity is
+/// limited to a single CPU.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/getcpu.2.html
+#[cfg(linux_kernel)]
+#[inline]
+pub fn getcpu() -> (usize, usize) {
+    backend::process::syscalls::getcpu()
+}
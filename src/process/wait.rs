@@ -8,6 +8,9 @@ use crate::fd::BorrowedFd;
 #[cfg(linux_raw)]
 use crate::backend::process::wait::SiginfoExt;
 
+#[cfg(any(linux_kernel, bsd))]
+use crate::timespec::Timespec;
+
 bitflags! {
     /// Options for modifying the behavior of [`wait`]/[`waitpid`].
     #[repr(transparent)]
@@ -125,6 +128,64 @@ impl WaitStatus {
             None
         }
     }
+
+    /// Returns whether the process was terminated by a signal and did create a
+    /// core file.
+    #[inline]
+    #[doc(alias = "WCOREDUMP")]
+    pub fn dumped(self) -> bool {
+        self.signaled() && backend::process::wait::WCOREDUMP(self.0 as _)
+    }
+
+    /// Returns the `PTRACE_EVENT_*` value of the event that stopped the
+    /// process, if it is a ptrace tracee in an event stop.
+    ///
+    /// Event stops are enabled with `PTRACE_O_TRACE*` options, and are
+    /// reported with a stopping signal of `SIGTRAP`, except for
+    /// `PTRACE_EVENT_STOP` (128), which is reported for group stops and
+    /// `PTRACE_INTERRUPT` of tracees attached with `PTRACE_SEIZE`.
+    ///
+    /// # References
+    ///  - [Linux]
+    ///
+    /// [Linux]: https://man7.org/linux/man-pages/man2/ptrace.2.html
+    #[cfg(linux_kernel)]
+    #[inline]
+    pub fn ptrace_event(self) -> Option<u32> {
+        if self.stopped() && (self.0 >> 16) != 0 {
+            Some(self.0 >> 16)
+        } else {
+            None
+        }
+    }
+
+    /// Returns whether the process is a ptrace tracee attached with
+    /// `PTRACE_SEIZE` which is in a group stop, which is to say it was
+    /// stopped by a job control signal rather than by the tracer.
+    ///
+    /// # References
+    ///  - [Linux]
+    ///
+    /// [Linux]: https://man7.org/linux/man-pages/man2/ptrace.2.html
+    #[cfg(linux_kernel)]
+    #[inline]
+    pub fn group_stopped(self) -> bool {
+        // `PTRACE_EVENT_STOP` is used for group stops with the stopping
+        // signal set to the job control signal, and for `PTRACE_INTERRUPT`
+        // and other stops with `SIGTRAP`.
+        const PTRACE_EVENT_STOP: u32 = 128;
+
+        self.ptrace_event() == Some(PTRACE_EVENT_STOP)
+            && matches!(
+                self.stopping_signal().map(|sig| sig as _),
+                Some(
+                    backend::c::SIGSTOP
+                        | backend::c::SIGTSTP
+                        | backend::c::SIGTTIN
+                        | backend::c::SIGTTOU
+                )
+            )
+    }
 }
 
 /// The status of a process after calling [`waitid`].
@@ -353,6 +414,83 @@ pub fn wait(waitopts: WaitOptions) -> io::Result<Option<(Pid, WaitStatus)>> {
     backend::process::syscalls::wait(waitopts)
 }
 
+/// `struct rusage`—Resource usage of a child process, as returned by
+/// [`wait4`].
+///
+/// Some of these fields are not maintained on all platforms, and are always
+/// zero there; for example, Linux doesn't maintain `ixrss`, `idrss`, `isrss`,
+/// `nswap`, `msgsnd`, `msgrcv`, or `nsignals`.
+#[cfg(any(linux_kernel, bsd))]
+#[derive(Debug, Clone, Copy)]
+#[doc(alias = "rusage")]
+pub struct Rusage {
+    /// `ru_utime`—User CPU time used.
+    pub utime: Timespec,
+    /// `ru_stime`—System CPU time used.
+    pub stime: Timespec,
+    /// `ru_maxrss`—Maximum resident set size, in kilobytes on Linux.
+    pub maxrss: i64,
+    /// `ru_ixrss`—Integral shared memory size.
+    pub ixrss: i64,
+    /// `ru_idrss`—Integral unshared data size.
+    pub idrss: i64,
+    /// `ru_isrss`—Integral unshared stack size.
+    pub isrss: i64,
+    /// `ru_minflt`—Page reclaims (soft page faults).
+    pub minflt: i64,
+    /// `ru_majflt`—Page faults (hard page faults).
+    pub majflt: i64,
+    /// `ru_nswap`—Swaps.
+    pub nswap: i64,
+    /// `ru_inblock`—Block input operations.
+    pub inblock: i64,
+    /// `ru_oublock`—Block output operations.
+    pub oublock: i64,
+    /// `ru_msgsnd`—IPC messages sent.
+    pub msgsnd: i64,
+    /// `ru_msgrcv`—IPC messages received.
+    pub msgrcv: i64,
+    /// `ru_nsignals`—Signals received.
+    pub nsignals: i64,
+    /// `ru_nvcsw`—Voluntary context switches.
+    pub nvcsw: i64,
+    /// `ru_nivcsw`—Involuntary context switches.
+    pub nivcsw: i64,
+}
+
+/// `wait4(pid, waitopts, &mut rusage)`—Wait for a child process to change
+/// state, and return its resource usage.
+///
+/// If the pid is `None`, the call will wait for any child process, like
+/// [`wait`]. Note that this differs from [`waitpid`], where `None` means any
+/// child in the caller's process group.
+///
+/// Otherwise, the call will wait for the child process with the given pid.
+///
+/// On success, returns the pid of the child process whose state changed, its
+/// status, and its resource usage, which includes the resource usage of any
+/// of its descendants that it waited for.
+///
+/// If `NOHANG` was specified in the options, and the selected child process
+/// didn't change state, returns `None`.
+///
+/// # References
+///  - [Linux]
+///  - [FreeBSD]
+///  - [Apple]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/wait4.2.html
+/// [FreeBSD]: https://man.freebsd.org/cgi/man.cgi?query=wait4&sektion=2
+/// [Apple]: https://developer.apple.com/library/archive/documentation/System/Conceptual/ManPages_iPhoneOS/man2/wait4.2.html
+#[cfg(any(linux_kernel, bsd))]
+#[inline]
+pub fn wait4(
+    pid: Option<Pid>,
+    waitopts: WaitOptions,
+) -> io::Result<Option<(Pid, WaitStatus, Rusage)>> {
+    backend::process::syscalls::wait4(pid, waitopts)
+}
+
 /// `waitid(_, _, _, opts)`—Wait for the specified child process to change
 /// state.
 #[cfg(not(any(target_os = "openbsd", target_os = "redox", target_os = "wasi")))]
@@ -18,14 +18,21 @@
 //! namespace. So with the checking here, they may fail, but they won't be able
 //! to succeed with bogus results.
 
+#[cfg(feature = "alloc")]
+use crate::fd::RawFd;
 use crate::fd::{AsFd, BorrowedFd, OwnedFd};
 use crate::ffi::CStr;
+#[cfg(feature = "alloc")]
+use crate::ffi::CString;
 use crate::fs::{
     fstat, fstatfs, major, openat, renameat, seek, FileType, FsWord, Mode, OFlags, RawDir,
     SeekFrom, Stat, CWD, PROC_SUPER_MAGIC,
 };
 use crate::io;
 use crate::path::DecInt;
+pub use crate::pid::Pid;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
 #[cfg(feature = "rustc-dep-of-std")]
 use core::lazy::OnceCell;
 use core::mem::MaybeUninit;
@@ -41,6 +48,7 @@ enum Kind {
     Proc,
     Pid,
     Fd,
+    FdInfo,
     File,
     Symlink,
 }
@@ -67,14 +75,14 @@ fn check_proc_entry_with_stat(
 
     match kind {
         Kind::Proc => check_proc_root(entry, &entry_stat)?,
-        Kind::Pid | Kind::Fd => check_proc_subdir(entry, &entry_stat, proc_stat)?,
+        Kind::Pid | Kind::Fd | Kind::FdInfo => check_proc_subdir(entry, &entry_stat, proc_stat)?,
         Kind::File => check_proc_file(&entry_stat, proc_stat)?,
         Kind::Symlink => check_proc_symlink(&entry_stat, proc_stat)?,
     }
 
-    // "/proc" directories are typically mounted r-xr-xr-x.
-    // "/proc/self/fd" is r-x------. Allow them to have fewer permissions, but
-    // not more.
+    // "/proc" directories are typically mounted r-xr-xr-x, including
+    // "/proc/self/fdinfo". "/proc/self/fd" is r-x------. Allow them to have
+    // fewer permissions, but not more.
     match kind {
         Kind::Symlink => {
             // On Linux, symlinks don't have their own permissions.
@@ -88,10 +96,10 @@ fn check_proc_entry_with_stat(
     }
 
     match kind {
-        Kind::Fd => {
-            // Check that the "/proc/self/fd" directory doesn't have any
-            // extraneous links into it (which might include unexpected
-            // subdirectories).
+        Kind::Fd | Kind::FdInfo => {
+            // Check that the "/proc/self/fd" and "/proc/self/fdinfo"
+            // directories don't have any extraneous links into them (which
+            // might include unexpected subdirectories).
             if entry_stat.st_nlink != 2 {
                 return Err(io::Errno::NOTSUP);
             }
@@ -359,7 +367,7 @@ fn proc_self_fdinfo() -> io::Result<(BorrowedFd<'static>, &'static Stat)> {
             // Open "/proc/self/fdinfo".
             let proc_self_fdinfo = proc_opendirat(proc_self, cstr!("fdinfo"))?;
             let proc_self_fdinfo_stat =
-                check_proc_entry(Kind::Fd, proc_self_fdinfo.as_fd(), Some(proc_stat))
+                check_proc_entry(Kind::FdInfo, proc_self_fdinfo.as_fd(), Some(proc_stat))
                     .map_err(|_err| io::Errno::NOTSUP)?;
 
             Ok((proc_self_fdinfo, proc_self_fdinfo_stat))
@@ -393,6 +401,141 @@ fn _proc_self_fdinfo(fd: BorrowedFd<'_>) -> io::Result<OwnedFd> {
     )
 }
 
+/// An entry in the snapshot returned by [`proc_self_open_fds`].
+#[cfg(feature = "alloc")]
+#[cfg_attr(doc_cfg, doc(cfg(all(feature = "procfs", feature = "alloc"))))]
+#[derive(Clone, Debug)]
+pub struct OpenFd {
+    /// The file descriptor number.
+    pub fd: RawFd,
+
+    /// The file status flags and access mode, as reported by the `flags:`
+    /// line of `/proc/self/fdinfo/<fd>`, which includes `OFlags::CLOEXEC` if
+    /// the descriptor is close-on-exec.
+    pub flags: OFlags,
+
+    /// The target of the `/proc/self/fd/<fd>` symlink. For files this is a
+    /// path; for other kinds of descriptors it's a string such as
+    /// `pipe:[1234]` or `anon_inode:[eventfd]`.
+    pub target: CString,
+}
+
+/// Returns a snapshot of the process' open file descriptors.
+///
+/// This walks `/proc/self/fd`, and for each descriptor reads its symlink
+/// target and reads its flags from `/proc/self/fdinfo`. It's intended for
+/// diagnostics, and for finding descriptors that should be closed before an
+/// `exec`.
+///
+/// The result is inherently racy: other threads may open or close
+/// descriptors while it runs, and descriptors which are closed during the
+/// walk are omitted. The descriptor used to read the directory itself is not
+/// included.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man5/proc.5.html
+#[cfg(feature = "alloc")]
+#[cfg_attr(doc_cfg, doc(cfg(all(feature = "procfs", feature = "alloc"))))]
+#[doc(alias = "list_open_fds")]
+pub fn proc_self_open_fds() -> io::Result<Vec<OpenFd>> {
+    let proc_self_fd = proc_self_fd()?;
+
+    // Open a new handle to the directory, rather than iterating with the
+    // shared static one, so that we don't disturb its position.
+    let dir = openat(
+        proc_self_fd,
+        cstr!("."),
+        OFlags::RDONLY | OFlags::DIRECTORY | OFlags::CLOEXEC,
+        Mode::empty(),
+    )?;
+    let dir_fd = crate::fd::AsRawFd::as_raw_fd(&dir);
+
+    let mut fds = Vec::new();
+    let mut buf = [MaybeUninit::uninit(); 2048];
+    let mut iter = RawDir::new(&dir, &mut buf);
+    while let Some(entry) = iter.next() {
+        let entry = entry?;
+        let fd = match entry
+            .file_name()
+            .to_str()
+            .ok()
+            .and_then(|name| name.parse::<RawFd>().ok())
+        {
+            Some(fd) => fd,
+            // Skip "." and "..".
+            None => continue,
+        };
+        if fd != dir_fd {
+            fds.push(fd);
+        }
+    }
+    drop(dir);
+
+    let mut open_fds = Vec::with_capacity(fds.len());
+    for fd in fds {
+        let fd_str = DecInt::new(fd);
+        let target = match crate::fs::readlinkat(proc_self_fd, fd_str.as_c_str(), Vec::new()) {
+            Ok(target) => target,
+            // The descriptor was closed after we listed it.
+            Err(io::Errno::NOENT) => continue,
+            Err(err) => return Err(err),
+        };
+        let flags = match open_fd_flags(fd) {
+            Ok(flags) => flags,
+            // The descriptor was closed after we listed it.
+            Err(io::Errno::NOENT) => continue,
+            Err(err) => return Err(err),
+        };
+        open_fds.push(OpenFd { fd, flags, target });
+    }
+
+    Ok(open_fds)
+}
+
+/// Read the status flags and close-on-exec flag of `fd` from the `flags:`
+/// line of `/proc/self/fdinfo/<fd>`.
+///
+/// This doesn't use `fcntl`, because `fd` may have been closed, and its
+/// number reused, since we listed it.
+#[cfg(feature = "alloc")]
+fn open_fd_flags(fd: RawFd) -> io::Result<OFlags> {
+    let (proc_self_fdinfo, proc_self_fdinfo_stat) = proc_self_fdinfo()?;
+    let fd_str = DecInt::new(fd);
+    let fdinfo = match open_and_check_file(
+        proc_self_fdinfo,
+        proc_self_fdinfo_stat,
+        fd_str.as_c_str(),
+        Kind::File,
+    ) {
+        Ok(fdinfo) => fdinfo,
+        // `open_and_check_file` reports all failures to open as `NOTSUP`, so
+        // check whether the descriptor was closed after we listed it.
+        Err(err) => {
+            return match crate::fs::statat(
+                proc_self_fdinfo,
+                fd_str.as_c_str(),
+                crate::fs::AtFlags::SYMLINK_NOFOLLOW,
+            ) {
+                Err(io::Errno::NOENT) => Err(io::Errno::NOENT),
+                _ => Err(err),
+            }
+        }
+    };
+
+    // The `flags:` line follows the `pos:` line, so it's in the first read.
+    let mut buf = [0_u8; 256];
+    let n = io::read(&fdinfo, &mut buf)?;
+    buf[..n]
+        .split(|b| *b == b'\n')
+        .find_map(|line| line.strip_prefix(b"flags:"))
+        .and_then(|flags| core::str::from_utf8(flags).ok())
+        .and_then(|flags| u32::from_str_radix(flags.trim(), 8).ok())
+        .map(OFlags::from_bits_retain)
+        .ok_or(io::Errno::IO)
+}
+
 /// Returns a handle to a Linux `/proc/self/pagemap` file.
 ///
 /// This ensures that `/proc/self/pagemap` is `procfs`, that nothing is
@@ -440,6 +583,155 @@ pub fn proc_self_status() -> io::Result<OwnedFd> {
     proc_self_file(cstr!("status"))
 }
 
+/// Returns a handle to a Linux `/proc/<pid>` directory.
+///
+/// This ensures that `/proc/<pid>` is `procfs`, that nothing is mounted on
+/// top of it, and that it looks normal. `pid` is interpreted in the pid
+/// namespace of the procfs mount.
+///
+/// The returned handle stays associated with the process it was opened for,
+/// even if `pid` is later reused, so use it with [`proc_pid_cmdline`],
+/// [`proc_pid_environ`], [`proc_pid_fd`], and the other `proc_pid_*`
+/// functions to inspect the process without racing against pid reuse. Once
+/// the process exits, those functions fail.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man5/proc.5.html
+#[cfg_attr(doc_cfg, doc(cfg(feature = "procfs")))]
+pub fn proc_pid(pid: Pid) -> io::Result<OwnedFd> {
+    let (proc, proc_stat) = proc()?;
+
+    // Open "/proc/<pid>". Don't use `proc_opendirat`, so that we report
+    // `NOENT` if there is no such process.
+    let pid_str = DecInt::new(pid.as_raw_nonzero().get());
+    let proc_pid = openat(
+        proc,
+        pid_str.as_c_str(),
+        OFlags::NOFOLLOW | OFlags::DIRECTORY | OFlags::CLOEXEC | OFlags::NOCTTY,
+        Mode::empty(),
+    )?;
+    check_proc_entry(Kind::Pid, proc_pid.as_fd(), Some(proc_stat))
+        .map_err(|_err| io::Errno::NOTSUP)?;
+
+    Ok(proc_pid)
+}
+
+/// Returns a handle to a Linux `/proc/<pid>/cmdline` file.
+///
+/// `proc_pid` is a handle returned by [`proc_pid`]. The contents are the
+/// process' arguments, each terminated by a NUL byte.
+///
+/// This ensures that `/proc/<pid>/cmdline` is `procfs`, that nothing is
+/// mounted on top of it, and that it looks normal.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man5/proc.5.html
+#[inline]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "procfs")))]
+pub fn proc_pid_cmdline<Fd: AsFd>(proc_pid: Fd) -> io::Result<OwnedFd> {
+    proc_pid_file(proc_pid.as_fd(), cstr!("cmdline"))
+}
+
+/// Returns a handle to a Linux `/proc/<pid>/environ` file.
+///
+/// `proc_pid` is a handle returned by [`proc_pid`]. The contents are the
+/// process' initial environment, with each `NAME=value` entry terminated by a
+/// NUL byte. Reading it requires `PTRACE_MODE_READ` access to the process.
+///
+/// This ensures that `/proc/<pid>/environ` is `procfs`, that nothing is
+/// mounted on top of it, and that it looks normal.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man5/proc.5.html
+#[inline]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "procfs")))]
+pub fn proc_pid_environ<Fd: AsFd>(proc_pid: Fd) -> io::Result<OwnedFd> {
+    proc_pid_file(proc_pid.as_fd(), cstr!("environ"))
+}
+
+/// Returns a handle to a Linux `/proc/<pid>/fd` directory.
+///
+/// `proc_pid` is a handle returned by [`proc_pid`]. Opening it requires
+/// `PTRACE_MODE_READ` access to the process.
+///
+/// This ensures that `/proc/<pid>/fd` is `procfs`, that nothing is mounted on
+/// top of it, and that it looks normal.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man5/proc.5.html
+#[cfg_attr(doc_cfg, doc(cfg(feature = "procfs")))]
+pub fn proc_pid_fd<Fd: AsFd>(proc_pid: Fd) -> io::Result<OwnedFd> {
+    let (_, proc_stat) = proc()?;
+    let proc_pid = proc_pid.as_fd();
+    check_proc_entry(Kind::Pid, proc_pid, Some(proc_stat)).map_err(|_err| io::Errno::NOTSUP)?;
+
+    let proc_pid_fd = proc_opendirat(proc_pid, cstr!("fd"))?;
+    check_proc_entry(Kind::Fd, proc_pid_fd.as_fd(), Some(proc_stat))
+        .map_err(|_err| io::Errno::NOTSUP)?;
+
+    Ok(proc_pid_fd)
+}
+
+/// Returns the target of a Linux `/proc/<pid>/cwd` symlink, which is the
+/// process' current working directory.
+///
+/// `proc_pid` is a handle returned by [`proc_pid`]. Reading it requires
+/// `PTRACE_MODE_READ` access to the process.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man5/proc.5.html
+#[cfg(feature = "alloc")]
+#[inline]
+#[cfg_attr(doc_cfg, doc(cfg(all(feature = "procfs", feature = "alloc"))))]
+pub fn proc_pid_cwd<Fd: AsFd>(proc_pid: Fd) -> io::Result<CString> {
+    proc_pid_symlink(proc_pid.as_fd(), cstr!("cwd"))
+}
+
+/// Returns the target of a Linux `/proc/<pid>/exe` symlink, which is the
+/// path of the process' executable.
+///
+/// `proc_pid` is a handle returned by [`proc_pid`]. Reading it requires
+/// `PTRACE_MODE_READ` access to the process.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man5/proc.5.html
+#[cfg(feature = "alloc")]
+#[inline]
+#[cfg_attr(doc_cfg, doc(cfg(all(feature = "procfs", feature = "alloc"))))]
+pub fn proc_pid_exe<Fd: AsFd>(proc_pid: Fd) -> io::Result<CString> {
+    proc_pid_symlink(proc_pid.as_fd(), cstr!("exe"))
+}
+
+/// Open a file under a `/proc/<pid>` directory.
+fn proc_pid_file(proc_pid: BorrowedFd<'_>, name: &CStr) -> io::Result<OwnedFd> {
+    let (_, proc_stat) = proc()?;
+    let proc_pid_stat =
+        check_proc_entry(Kind::Pid, proc_pid, Some(proc_stat)).map_err(|_err| io::Errno::NOTSUP)?;
+    open_and_check_file(proc_pid, &proc_pid_stat, name, Kind::File)
+}
+
+/// Read a symlink under a `/proc/<pid>` directory.
+#[cfg(feature = "alloc")]
+fn proc_pid_symlink(proc_pid: BorrowedFd<'_>, name: &CStr) -> io::Result<CString> {
+    let (_, proc_stat) = proc()?;
+    let proc_pid_stat =
+        check_proc_entry(Kind::Pid, proc_pid, Some(proc_stat)).map_err(|_err| io::Errno::NOTSUP)?;
+    let symlink = open_and_check_file(proc_pid, &proc_pid_stat, name, Kind::Symlink)?;
+    crate::fs::readlinkat(&symlink, cstr!(""), Vec::new())
+}
+
 /// Open a file under `/proc/self`.
 fn proc_self_file(name: &CStr) -> io::Result<OwnedFd> {
     let (proc_self, proc_self_stat) = proc_self()?;
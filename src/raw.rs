@@ -0,0 +1,23 @@
+//! Raw OS types, for code that mixes rustix with C FFI.
+//!
+//! These are the underlying structs that the current backend exchanges with
+//! the OS, under their C names. With the libc backend they're the libc
+//! crate's types, so they can be passed to C functions as-is. With the
+//! linux_raw backend they're the Linux kernel's types, which match the C
+//! library's types on most 64-bit platforms, but not on 32-bit platforms,
+//! where C's `struct stat` and `struct timespec` may differ from the
+//! kernel's.
+//!
+//! Where rustix's own types are aliases of these types, such as
+//! `rustix::fs::Stat` on 64-bit Linux, values convert between the two without
+//! any conversion at all. Where they aren't, the layouts differ, and values
+//! must be converted field by field.
+//!
+//! On platforms with large-file-support variants of the `stat` functions,
+//! `stat` is `struct stat64`, since that's what rustix's backends use.
+//!
+//! The items in this module follow the backend, so unlike the rest of
+//! rustix's API they may change when the backend changes.
+
+pub use crate::backend::raw::*;
+pub use crate::fd::RawFd;
@@ -0,0 +1,657 @@
+//! Linux ALSA PCM functions.
+//!
+//! The functions in this module take a file descriptor opened from an ALSA
+//! PCM device node such as `/dev/snd/pcmC0D0p`. They cover the handshake
+//! that sets up a stream: choosing hardware parameters with [`HwParams`],
+//! software parameters with [`SwParams`], and then preparing and starting
+//! the stream. With [`Access::RW_INTERLEAVED`], audio data can then be
+//! transferred with [`read`] and [`write`].
+//!
+//! # References
+//!  - [Linux]
+//!
+//! [Linux]: https://www.kernel.org/doc/html/latest/sound/designs/index.html
+//! [`read`]: crate::io::read
+//! [`write`]: crate::io::write
+
+#![allow(unsafe_code)]
+
+use crate::backend::c;
+use crate::fd::AsFd;
+use crate::{io, ioctl};
+
+/// `SNDRV_PCM_ACCESS_*`—How audio data is transferred.
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Access(u32);
+
+impl Access {
+    /// `SNDRV_PCM_ACCESS_MMAP_INTERLEAVED`
+    #[doc(alias = "SNDRV_PCM_ACCESS_MMAP_INTERLEAVED")]
+    pub const MMAP_INTERLEAVED: Self = Self(0);
+
+    /// `SNDRV_PCM_ACCESS_MMAP_NONINTERLEAVED`
+    #[doc(alias = "SNDRV_PCM_ACCESS_MMAP_NONINTERLEAVED")]
+    pub const MMAP_NONINTERLEAVED: Self = Self(1);
+
+    /// `SNDRV_PCM_ACCESS_RW_INTERLEAVED`—Interleaved frames are transferred
+    /// with `read` and `write`.
+    #[doc(alias = "SNDRV_PCM_ACCESS_RW_INTERLEAVED")]
+    pub const RW_INTERLEAVED: Self = Self(3);
+
+    /// `SNDRV_PCM_ACCESS_RW_NONINTERLEAVED`
+    #[doc(alias = "SNDRV_PCM_ACCESS_RW_NONINTERLEAVED")]
+    pub const RW_NONINTERLEAVED: Self = Self(4);
+
+    /// Constructs an `Access` from a raw `SNDRV_PCM_ACCESS_*` value.
+    #[inline]
+    pub const fn from_raw(raw: u32) -> Self {
+        Self(raw)
+    }
+
+    /// Returns the raw `SNDRV_PCM_ACCESS_*` value.
+    #[inline]
+    pub const fn as_raw(self) -> u32 {
+        self.0
+    }
+}
+
+/// `SNDRV_PCM_FORMAT_*`—A sample format.
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Format(u32);
+
+impl Format {
+    /// `SNDRV_PCM_FORMAT_S8`
+    #[doc(alias = "SNDRV_PCM_FORMAT_S8")]
+    pub const S8: Self = Self(0);
+
+    /// `SNDRV_PCM_FORMAT_U8`
+    #[doc(alias = "SNDRV_PCM_FORMAT_U8")]
+    pub const U8: Self = Self(1);
+
+    /// `SNDRV_PCM_FORMAT_S16_LE`
+    #[doc(alias = "SNDRV_PCM_FORMAT_S16_LE")]
+    pub const S16_LE: Self = Self(2);
+
+    /// `SNDRV_PCM_FORMAT_S16_BE`
+    #[doc(alias = "SNDRV_PCM_FORMAT_S16_BE")]
+    pub const S16_BE: Self = Self(3);
+
+    /// `SNDRV_PCM_FORMAT_S24_LE`—Signed 24-bit samples in the low bits of
+    /// 32-bit little-endian words.
+    #[doc(alias = "SNDRV_PCM_FORMAT_S24_LE")]
+    pub const S24_LE: Self = Self(6);
+
+    /// `SNDRV_PCM_FORMAT_S32_LE`
+    #[doc(alias = "SNDRV_PCM_FORMAT_S32_LE")]
+    pub const S32_LE: Self = Self(10);
+
+    /// `SNDRV_PCM_FORMAT_S32_BE`
+    #[doc(alias = "SNDRV_PCM_FORMAT_S32_BE")]
+    pub const S32_BE: Self = Self(11);
+
+    /// `SNDRV_PCM_FORMAT_FLOAT_LE`
+    #[doc(alias = "SNDRV_PCM_FORMAT_FLOAT_LE")]
+    pub const FLOAT_LE: Self = Self(14);
+
+    /// `SNDRV_PCM_FORMAT_FLOAT_BE`
+    #[doc(alias = "SNDRV_PCM_FORMAT_FLOAT_BE")]
+    pub const FLOAT_BE: Self = Self(15);
+
+    /// `SNDRV_PCM_FORMAT_S24_3LE`—Signed 24-bit samples in 3 bytes.
+    #[doc(alias = "SNDRV_PCM_FORMAT_S24_3LE")]
+    pub const S24_3LE: Self = Self(32);
+
+    /// Constructs a `Format` from a raw `SNDRV_PCM_FORMAT_*` value.
+    #[inline]
+    pub const fn from_raw(raw: u32) -> Self {
+        Self(raw)
+    }
+
+    /// Returns the raw `SNDRV_PCM_FORMAT_*` value.
+    #[inline]
+    pub const fn as_raw(self) -> u32 {
+        self.0
+    }
+}
+
+// `SNDRV_PCM_HW_PARAM_*` indices of masks.
+const HW_PARAM_ACCESS: usize = 0;
+const HW_PARAM_FORMAT: usize = 1;
+
+// `SNDRV_PCM_HW_PARAM_*` indices of intervals.
+const HW_PARAM_FIRST_INTERVAL: usize = 8;
+const HW_PARAM_CHANNELS: usize = 10;
+const HW_PARAM_RATE: usize = 11;
+const HW_PARAM_PERIOD_SIZE: usize = 13;
+const HW_PARAM_PERIODS: usize = 15;
+const HW_PARAM_BUFFER_SIZE: usize = 17;
+
+/// The bits of the bit-fields in `struct snd_interval`, which C compilers
+/// allocate from the most significant end on big-endian targets.
+#[cfg(target_endian = "little")]
+mod interval_bits {
+    pub(super) const OPENMIN: u32 = 1 << 0;
+    pub(super) const OPENMAX: u32 = 1 << 1;
+    pub(super) const INTEGER: u32 = 1 << 2;
+    pub(super) const EMPTY: u32 = 1 << 3;
+}
+#[cfg(target_endian = "big")]
+mod interval_bits {
+    pub(super) const OPENMIN: u32 = 1 << 31;
+    pub(super) const OPENMAX: u32 = 1 << 30;
+    pub(super) const INTEGER: u32 = 1 << 29;
+    pub(super) const EMPTY: u32 = 1 << 28;
+}
+
+/// `struct snd_mask`
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+struct Mask {
+    bits: [u32; 8],
+}
+
+/// `struct snd_interval`
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+struct Interval {
+    min: u32,
+    max: u32,
+    bits: u32,
+}
+
+/// `struct snd_pcm_hw_params`—Hardware parameters of a stream, for use with
+/// [`ioctl_pcm_hw_refine`] and [`ioctl_pcm_hw_params`].
+///
+/// Each parameter is a set of allowed values. [`HwParams::any`] allows all
+/// values, the setters narrow a parameter down to one value, and the kernel
+/// narrows the rest down to what the hardware supports.
+#[repr(C)]
+#[derive(Clone, Debug)]
+pub struct HwParams {
+    flags: u32,
+    masks: [Mask; 3],
+    mres: [Mask; 5],
+    intervals: [Interval; 12],
+    ires: [Interval; 9],
+    rmask: u32,
+    cmask: u32,
+    info: u32,
+    msbits: u32,
+    rate_num: u32,
+    rate_den: u32,
+    fifo_size: c::c_ulong,
+    reserved: [u8; 64],
+}
+
+impl HwParams {
+    /// Constructs a `HwParams` that allows any value for every parameter,
+    /// like `snd_pcm_hw_params_any`.
+    pub fn any() -> Self {
+        let mask = Mask { bits: [!0; 8] };
+        let interval = Interval {
+            min: 0,
+            max: !0,
+            bits: 0,
+        };
+        Self {
+            flags: 0,
+            masks: [mask; 3],
+            mres: [Mask { bits: [0; 8] }; 5],
+            intervals: [interval; 12],
+            ires: [Interval {
+                min: 0,
+                max: 0,
+                bits: 0,
+            }; 9],
+            rmask: !0,
+            cmask: 0,
+            info: !0,
+            msbits: 0,
+            rate_num: 0,
+            rate_den: 0,
+            fifo_size: 0,
+            reserved: [0; 64],
+        }
+    }
+
+    fn set_mask(&mut self, param: usize, value: u32) {
+        let mask = &mut self.masks[param];
+        mask.bits = [0; 8];
+        mask.bits[(value / 32) as usize % 8] = 1 << (value % 32);
+        self.rmask |= 1 << param;
+    }
+
+    fn get_mask(&self, param: usize) -> Option<u32> {
+        let bits = &self.masks[param].bits;
+        let mut found = None;
+        for (i, word) in bits.iter().enumerate() {
+            if *word == 0 {
+                continue;
+            }
+            if found.is_some() || word.count_ones() != 1 {
+                return None;
+            }
+            found = Some(i as u32 * 32 + word.trailing_zeros());
+        }
+        found
+    }
+
+    fn set_interval(&mut self, param: usize, value: u32) {
+        self.intervals[param - HW_PARAM_FIRST_INTERVAL] = Interval {
+            min: value,
+            max: value,
+            bits: interval_bits::INTEGER,
+        };
+        self.rmask |= 1 << param;
+    }
+
+    fn get_interval(&self, param: usize) -> Option<u32> {
+        let interval = &self.intervals[param - HW_PARAM_FIRST_INTERVAL];
+        let open_or_empty = interval_bits::OPENMIN | interval_bits::OPENMAX | interval_bits::EMPTY;
+        if interval.min == interval.max && interval.bits & open_or_empty == 0 {
+            Some(interval.min)
+        } else {
+            None
+        }
+    }
+
+    /// Sets the access type.
+    #[inline]
+    pub fn set_access(&mut self, access: Access) {
+        self.set_mask(HW_PARAM_ACCESS, access.0);
+    }
+
+    /// Returns the access type, if it has been narrowed down to one.
+    #[inline]
+    pub fn access(&self) -> Option<Access> {
+        self.get_mask(HW_PARAM_ACCESS).map(Access)
+    }
+
+    /// Sets the sample format.
+    #[inline]
+    pub fn set_format(&mut self, format: Format) {
+        self.set_mask(HW_PARAM_FORMAT, format.0);
+    }
+
+    /// Returns the sample format, if it has been narrowed down to one.
+    #[inline]
+    pub fn format(&self) -> Option<Format> {
+        self.get_mask(HW_PARAM_FORMAT).map(Format)
+    }
+
+    /// Sets the number of channels.
+    #[inline]
+    pub fn set_channels(&mut self, channels: u32) {
+        self.set_interval(HW_PARAM_CHANNELS, channels);
+    }
+
+    /// Returns the number of channels, if it has been narrowed down to one.
+    #[inline]
+    pub fn channels(&self) -> Option<u32> {
+        self.get_interval(HW_PARAM_CHANNELS)
+    }
+
+    /// Sets the sample rate, in hertz.
+    #[inline]
+    pub fn set_rate(&mut self, rate: u32) {
+        self.set_interval(HW_PARAM_RATE, rate);
+    }
+
+    /// Returns the sample rate, if it has been narrowed down to one.
+    #[inline]
+    pub fn rate(&self) -> Option<u32> {
+        self.get_interval(HW_PARAM_RATE)
+    }
+
+    /// Sets the size of a period, in frames.
+    #[inline]
+    pub fn set_period_size(&mut self, frames: u32) {
+        self.set_interval(HW_PARAM_PERIOD_SIZE, frames);
+    }
+
+    /// Returns the size of a period, if it has been narrowed down to one.
+    #[inline]
+    pub fn period_size(&self) -> Option<u32> {
+        self.get_interval(HW_PARAM_PERIOD_SIZE)
+    }
+
+    /// Sets the number of periods in the buffer.
+    #[inline]
+    pub fn set_periods(&mut self, periods: u32) {
+        self.set_interval(HW_PARAM_PERIODS, periods);
+    }
+
+    /// Returns the number of periods, if it has been narrowed down to one.
+    #[inline]
+    pub fn periods(&self) -> Option<u32> {
+        self.get_interval(HW_PARAM_PERIODS)
+    }
+
+    /// Sets the size of the buffer, in frames.
+    #[inline]
+    pub fn set_buffer_size(&mut self, frames: u32) {
+        self.set_interval(HW_PARAM_BUFFER_SIZE, frames);
+    }
+
+    /// Returns the size of the buffer, if it has been narrowed down to one.
+    #[inline]
+    pub fn buffer_size(&self) -> Option<u32> {
+        self.get_interval(HW_PARAM_BUFFER_SIZE)
+    }
+}
+
+/// `struct snd_pcm_sw_params`—Software parameters of a stream, for use with
+/// [`ioctl_pcm_sw_params`].
+///
+/// Frame counts are `snd_pcm_uframes_t` values.
+#[repr(C)]
+#[derive(Clone, Debug)]
+pub struct SwParams {
+    /// The timestamp mode, a `SNDRV_PCM_TSTAMP_*` value.
+    pub tstamp_mode: i32,
+
+    /// Unused.
+    pub period_step: u32,
+
+    /// Unused.
+    pub sleep_min: u32,
+
+    /// The minimum number of available frames before a wakeup.
+    pub avail_min: c::c_ulong,
+
+    /// Obsolete.
+    pub xfer_align: c::c_ulong,
+
+    /// The number of queued frames at which a playback stream starts
+    /// automatically.
+    pub start_threshold: c::c_ulong,
+
+    /// The number of available frames at which the stream stops
+    /// automatically, because of an underrun or overrun.
+    pub stop_threshold: c::c_ulong,
+
+    /// The distance from the noise at which to fill silence.
+    pub silence_threshold: c::c_ulong,
+
+    /// The number of frames of silence to fill.
+    pub silence_size: c::c_ulong,
+
+    /// The point at which frame positions wrap around, set by the kernel.
+    pub boundary: c::c_ulong,
+
+    /// The protocol version.
+    pub proto: u32,
+
+    /// The timestamp type, a `SNDRV_PCM_TSTAMP_TYPE_*` value.
+    pub tstamp_type: u32,
+
+    reserved: [u8; 56],
+}
+
+impl SwParams {
+    /// Constructs a `SwParams` with all fields zeroed.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            tstamp_mode: 0,
+            period_step: 0,
+            sleep_min: 0,
+            avail_min: 0,
+            xfer_align: 0,
+            start_threshold: 0,
+            stop_threshold: 0,
+            silence_threshold: 0,
+            silence_size: 0,
+            boundary: 0,
+            proto: 0,
+            tstamp_type: 0,
+            reserved: [0; 56],
+        }
+    }
+}
+
+impl Default for SwParams {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// `SNDRV_PCM_IOCTL_PVERSION`
+type PcmPVersion = ioctl::ReadOpcode<b'A', 0x00, c::c_int>;
+/// `SNDRV_PCM_IOCTL_HW_REFINE`
+type PcmHwRefine = ioctl::ReadWriteOpcode<b'A', 0x10, HwParams>;
+/// `SNDRV_PCM_IOCTL_HW_PARAMS`
+type PcmHwParams = ioctl::ReadWriteOpcode<b'A', 0x11, HwParams>;
+/// `SNDRV_PCM_IOCTL_HW_FREE`
+type PcmHwFree = ioctl::NoneOpcode<b'A', 0x12, ()>;
+/// `SNDRV_PCM_IOCTL_SW_PARAMS`
+type PcmSwParams = ioctl::ReadWriteOpcode<b'A', 0x13, SwParams>;
+/// `SNDRV_PCM_IOCTL_PREPARE`
+type PcmPrepare = ioctl::NoneOpcode<b'A', 0x40, ()>;
+/// `SNDRV_PCM_IOCTL_START`
+type PcmStart = ioctl::NoneOpcode<b'A', 0x42, ()>;
+/// `SNDRV_PCM_IOCTL_DROP`
+type PcmDrop = ioctl::NoneOpcode<b'A', 0x43, ()>;
+/// `SNDRV_PCM_IOCTL_DRAIN`
+type PcmDrain = ioctl::NoneOpcode<b'A', 0x44, ()>;
+
+/// `ioctl(fd, SNDRV_PCM_IOCTL_PVERSION)`—Returns the version of the PCM
+/// protocol.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://www.kernel.org/doc/html/latest/sound/designs/index.html
+#[inline]
+#[doc(alias = "SNDRV_PCM_IOCTL_PVERSION")]
+pub fn ioctl_pcm_pversion<Fd: AsFd>(fd: Fd) -> io::Result<u32> {
+    // SAFETY: `SNDRV_PCM_IOCTL_PVERSION` is a getter opcode that gets a
+    // `c_int`.
+    unsafe {
+        let ctl = ioctl::Getter::<PcmPVersion, c::c_int>::new();
+        ioctl::ioctl(fd, ctl).map(|version| version as u32)
+    }
+}
+
+/// `ioctl(fd, SNDRV_PCM_IOCTL_HW_REFINE, params)`—Narrows down hardware
+/// parameters to what the device supports, without applying them.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://www.kernel.org/doc/html/latest/sound/designs/index.html
+#[inline]
+#[doc(alias = "SNDRV_PCM_IOCTL_HW_REFINE")]
+pub fn ioctl_pcm_hw_refine<Fd: AsFd>(fd: Fd, params: &mut HwParams) -> io::Result<()> {
+    // SAFETY: `SNDRV_PCM_IOCTL_HW_REFINE` reads and writes a
+    // `struct snd_pcm_hw_params`.
+    unsafe {
+        let ctl = ioctl::Updater::<PcmHwRefine, HwParams>::new(params);
+        ioctl::ioctl(fd, ctl)
+    }
+}
+
+/// `ioctl(fd, SNDRV_PCM_IOCTL_HW_PARAMS, params)`—Chooses and applies
+/// hardware parameters.
+///
+/// On success, each parameter in `params` is narrowed down to the value the
+/// kernel chose.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://www.kernel.org/doc/html/latest/sound/designs/index.html
+#[inline]
+#[doc(alias = "SNDRV_PCM_IOCTL_HW_PARAMS")]
+pub fn ioctl_pcm_hw_params<Fd: AsFd>(fd: Fd, params: &mut HwParams) -> io::Result<()> {
+    // SAFETY: `SNDRV_PCM_IOCTL_HW_PARAMS` reads and writes a
+    // `struct snd_pcm_hw_params`.
+    unsafe {
+        let ctl = ioctl::Updater::<PcmHwParams, HwParams>::new(params);
+        ioctl::ioctl(fd, ctl)
+    }
+}
+
+/// `ioctl(fd, SNDRV_PCM_IOCTL_HW_FREE)`—Releases the resources allocated
+/// by [`ioctl_pcm_hw_params`].
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://www.kernel.org/doc/html/latest/sound/designs/index.html
+#[inline]
+#[doc(alias = "SNDRV_PCM_IOCTL_HW_FREE")]
+pub fn ioctl_pcm_hw_free<Fd: AsFd>(fd: Fd) -> io::Result<()> {
+    // SAFETY: `SNDRV_PCM_IOCTL_HW_FREE` is a no-argument opcode.
+    unsafe {
+        let ctl = ioctl::NoArg::<PcmHwFree>::new();
+        ioctl::ioctl(fd, ctl)
+    }
+}
+
+/// `ioctl(fd, SNDRV_PCM_IOCTL_SW_PARAMS, params)`—Applies software
+/// parameters.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://www.kernel.org/doc/html/latest/sound/designs/index.html
+#[inline]
+#[doc(alias = "SNDRV_PCM_IOCTL_SW_PARAMS")]
+pub fn ioctl_pcm_sw_params<Fd: AsFd>(fd: Fd, params: &mut SwParams) -> io::Result<()> {
+    // SAFETY: `SNDRV_PCM_IOCTL_SW_PARAMS` reads and writes a
+    // `struct snd_pcm_sw_params`.
+    unsafe {
+        let ctl = ioctl::Updater::<PcmSwParams, SwParams>::new(params);
+        ioctl::ioctl(fd, ctl)
+    }
+}
+
+/// `ioctl(fd, SNDRV_PCM_IOCTL_PREPARE)`—Prepares a stream to be started.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://www.kernel.org/doc/html/latest/sound/designs/index.html
+#[inline]
+#[doc(alias = "SNDRV_PCM_IOCTL_PREPARE")]
+pub fn ioctl_pcm_prepare<Fd: AsFd>(fd: Fd) -> io::Result<()> {
+    // SAFETY: `SNDRV_PCM_IOCTL_PREPARE` is a no-argument opcode.
+    unsafe {
+        let ctl = ioctl::NoArg::<PcmPrepare>::new();
+        ioctl::ioctl(fd, ctl)
+    }
+}
+
+/// `ioctl(fd, SNDRV_PCM_IOCTL_START)`—Starts a stream.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://www.kernel.org/doc/html/latest/sound/designs/index.html
+#[inline]
+#[doc(alias = "SNDRV_PCM_IOCTL_START")]
+pub fn ioctl_pcm_start<Fd: AsFd>(fd: Fd) -> io::Result<()> {
+    // SAFETY: `SNDRV_PCM_IOCTL_START` is a no-argument opcode.
+    unsafe {
+        let ctl = ioctl::NoArg::<PcmStart>::new();
+        ioctl::ioctl(fd, ctl)
+    }
+}
+
+/// `ioctl(fd, SNDRV_PCM_IOCTL_DROP)`—Stops a stream immediately, dropping
+/// pending frames.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://www.kernel.org/doc/html/latest/sound/designs/index.html
+#[inline]
+#[doc(alias = "SNDRV_PCM_IOCTL_DROP")]
+pub fn ioctl_pcm_drop<Fd: AsFd>(fd: Fd) -> io::Result<()> {
+    // SAFETY: `SNDRV_PCM_IOCTL_DROP` is a no-argument opcode.
+    unsafe {
+        let ctl = ioctl::NoArg::<PcmDrop>::new();
+        ioctl::ioctl(fd, ctl)
+    }
+}
+
+/// `ioctl(fd, SNDRV_PCM_IOCTL_DRAIN)`—Stops a stream after pending frames
+/// have been played.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://www.kernel.org/doc/html/latest/sound/designs/index.html
+#[inline]
+#[doc(alias = "SNDRV_PCM_IOCTL_DRAIN")]
+pub fn ioctl_pcm_drain<Fd: AsFd>(fd: Fd) -> io::Result<()> {
+    // SAFETY: `SNDRV_PCM_IOCTL_DRAIN` is a no-argument opcode.
+    unsafe {
+        let ctl = ioctl::NoArg::<PcmDrain>::new();
+        ioctl::ioctl(fd, ctl)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ioctl::CompileTimeOpcode;
+    use core::mem::{align_of, size_of};
+
+    // `linux_raw_sys` doesn't have the ALSA opcodes, so these are the values
+    // of the uapi macros with the generic `_IOC` encoding.
+    #[cfg(any(
+        target_arch = "aarch64",
+        target_arch = "arm",
+        target_arch = "riscv64",
+        target_arch = "x86",
+        target_arch = "x86_64",
+    ))]
+    #[test]
+    fn pcm_opcodes() {
+        const_assert_eq!(PcmPVersion::OPCODE.raw(), 0x8004_4100);
+        const_assert_eq!(PcmHwFree::OPCODE.raw(), 0x4112);
+        const_assert_eq!(PcmPrepare::OPCODE.raw(), 0x4140);
+        const_assert_eq!(PcmStart::OPCODE.raw(), 0x4142);
+        const_assert_eq!(PcmDrop::OPCODE.raw(), 0x4143);
+        const_assert_eq!(PcmDrain::OPCODE.raw(), 0x4144);
+
+        #[cfg(target_pointer_width = "64")]
+        {
+            const_assert_eq!(PcmHwRefine::OPCODE.raw(), 0xc260_4110);
+            const_assert_eq!(PcmHwParams::OPCODE.raw(), 0xc260_4111);
+            const_assert_eq!(PcmSwParams::OPCODE.raw(), 0xc088_4113);
+        }
+        #[cfg(target_pointer_width = "32")]
+        {
+            const_assert_eq!(PcmHwRefine::OPCODE.raw(), 0xc25c_4110);
+            const_assert_eq!(PcmHwParams::OPCODE.raw(), 0xc25c_4111);
+            const_assert_eq!(PcmSwParams::OPCODE.raw(), 0xc068_4113);
+        }
+    }
+
+    #[test]
+    fn pcm_layouts() {
+        const_assert_eq!(size_of::<Mask>(), 32);
+        const_assert_eq!(align_of::<Mask>(), 4);
+        const_assert_eq!(size_of::<Interval>(), 12);
+        const_assert_eq!(align_of::<Interval>(), 4);
+
+        #[cfg(target_pointer_width = "64")]
+        {
+            const_assert_eq!(size_of::<HwParams>(), 608);
+            const_assert_eq!(size_of::<SwParams>(), 136);
+        }
+        #[cfg(target_pointer_width = "32")]
+        {
+            const_assert_eq!(size_of::<HwParams>(), 604);
+            const_assert_eq!(size_of::<SwParams>(), 104);
+        }
+        const_assert_eq!(align_of::<HwParams>(), align_of::<c::c_ulong>());
+        const_assert_eq!(align_of::<SwParams>(), align_of::<c::c_ulong>());
+    }
+}
@@ -0,0 +1,354 @@
+//! Linux SPI device (spidev) functions.
+//!
+//! The functions in this module take a file descriptor opened from a spidev
+//! device node such as `/dev/spidev0.0`. Half-duplex transfers can use
+//! [`read`] and [`write`]; full-duplex and multi-part transactions are
+//! performed with [`ioctl_spi_message`].
+//!
+//! # References
+//!  - [Linux]
+//!
+//! [Linux]: https://docs.kernel.org/spi/spidev.html
+//! [`read`]: crate::io::read
+//! [`write`]: crate::io::write
+
+#![allow(unsafe_code)]
+
+use crate::fd::AsFd;
+use crate::{io, ioctl};
+use bitflags::bitflags;
+use core::marker::PhantomData;
+use core::mem::{size_of, size_of_val};
+
+/// The largest buffer size that can be encoded in an `ioctl` opcode on all
+/// architectures; some only have 13 bits for it.
+const MAX_IOCTL_SIZE: usize = 0x1fff;
+
+bitflags! {
+    /// `SPI_*` mode constants for use with [`ioctl_spi_wr_mode32`].
+    #[repr(transparent)]
+    #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    pub struct Mode: u32 {
+        /// `SPI_CPHA`—Sample on the trailing clock edge.
+        const CPHA = 0x0000_0001;
+        /// `SPI_CPOL`—The clock idles high.
+        const CPOL = 0x0000_0002;
+        /// `SPI_MODE_0`
+        const MODE_0 = 0;
+        /// `SPI_MODE_1`
+        const MODE_1 = Self::CPHA.bits();
+        /// `SPI_MODE_2`
+        const MODE_2 = Self::CPOL.bits();
+        /// `SPI_MODE_3`
+        const MODE_3 = Self::CPOL.bits() | Self::CPHA.bits();
+        /// `SPI_CS_HIGH`—The chip select is active high.
+        const CS_HIGH = 0x0000_0004;
+        /// `SPI_LSB_FIRST`
+        const LSB_FIRST = 0x0000_0008;
+        /// `SPI_3WIRE`—Data in and out share a single wire.
+        const THREE_WIRE = 0x0000_0010;
+        /// `SPI_LOOP`—Loop back data out to data in.
+        const LOOP = 0x0000_0020;
+        /// `SPI_NO_CS`—Don't use a chip select.
+        const NO_CS = 0x0000_0040;
+        /// `SPI_READY`
+        const READY = 0x0000_0080;
+        /// `SPI_TX_DUAL`
+        const TX_DUAL = 0x0000_0100;
+        /// `SPI_TX_QUAD`
+        const TX_QUAD = 0x0000_0200;
+        /// `SPI_RX_DUAL`
+        const RX_DUAL = 0x0000_0400;
+        /// `SPI_RX_QUAD`
+        const RX_QUAD = 0x0000_0800;
+        /// `SPI_CS_WORD`
+        const CS_WORD = 0x0000_1000;
+        /// `SPI_TX_OCTAL`
+        const TX_OCTAL = 0x0000_2000;
+        /// `SPI_RX_OCTAL`
+        const RX_OCTAL = 0x0000_4000;
+        /// `SPI_3WIRE_HIZ`
+        const THREE_WIRE_HIZ = 0x0000_8000;
+
+        /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+        const _ = !0;
+    }
+}
+
+/// `struct spi_ioc_transfer`—One transfer of an SPI transaction, for use
+/// with [`ioctl_spi_message`].
+///
+/// Unless overridden, a transfer uses the device's current speed and word
+/// size. Buffers longer than `u32::MAX` bytes are truncated.
+#[repr(C)]
+#[derive(Debug)]
+pub struct Transfer<'a> {
+    tx_buf: u64,
+    rx_buf: u64,
+    len: u32,
+    speed_hz: u32,
+    delay_usecs: u16,
+    bits_per_word: u8,
+    cs_change: u8,
+    tx_nbits: u8,
+    rx_nbits: u8,
+    word_delay_usecs: u8,
+    pad: u8,
+    _bufs: PhantomData<(&'a [u8], &'a mut [u8])>,
+}
+
+impl<'a> Transfer<'a> {
+    fn new(tx_buf: *const u8, rx_buf: *mut u8, len: usize) -> Self {
+        Self {
+            tx_buf: tx_buf as usize as u64,
+            rx_buf: rx_buf as usize as u64,
+            len: len.min(u32::MAX as usize) as u32,
+            speed_hz: 0,
+            delay_usecs: 0,
+            bits_per_word: 0,
+            cs_change: 0,
+            tx_nbits: 0,
+            rx_nbits: 0,
+            word_delay_usecs: 0,
+            pad: 0,
+            _bufs: PhantomData,
+        }
+    }
+
+    /// Constructs a transfer that writes `tx` and ignores the data read.
+    #[inline]
+    pub fn write(tx: &'a [u8]) -> Self {
+        Self::new(tx.as_ptr(), core::ptr::null_mut(), tx.len())
+    }
+
+    /// Constructs a transfer that writes zeros and reads into `rx`.
+    #[inline]
+    pub fn read(rx: &'a mut [u8]) -> Self {
+        Self::new(core::ptr::null(), rx.as_mut_ptr(), rx.len())
+    }
+
+    /// Constructs a full-duplex transfer that writes `tx` while reading into
+    /// `rx`.
+    ///
+    /// The length of the transfer is the length of the shorter buffer.
+    #[inline]
+    pub fn full_duplex(tx: &'a [u8], rx: &'a mut [u8]) -> Self {
+        let len = tx.len().min(rx.len());
+        Self::new(tx.as_ptr(), rx.as_mut_ptr(), len)
+    }
+
+    /// Sets the clock speed of this transfer, in Hz.
+    #[inline]
+    pub const fn with_speed_hz(mut self, speed_hz: u32) -> Self {
+        self.speed_hz = speed_hz;
+        self
+    }
+
+    /// Sets the word size of this transfer, in bits.
+    #[inline]
+    pub const fn with_bits_per_word(mut self, bits_per_word: u8) -> Self {
+        self.bits_per_word = bits_per_word;
+        self
+    }
+
+    /// Sets the delay after the last bit of this transfer, in microseconds.
+    #[inline]
+    pub const fn with_delay_usecs(mut self, delay_usecs: u16) -> Self {
+        self.delay_usecs = delay_usecs;
+        self
+    }
+
+    /// Sets whether to deselect the device after this transfer, before the
+    /// next one starts.
+    #[inline]
+    pub const fn with_cs_change(mut self, cs_change: bool) -> Self {
+        self.cs_change = cs_change as u8;
+        self
+    }
+}
+
+/// `SPI_IOC_RD_MODE32`
+type RdMode32 = ioctl::ReadOpcode<b'k', 5, u32>;
+/// `SPI_IOC_WR_MODE32`
+type WrMode32 = ioctl::WriteOpcode<b'k', 5, u32>;
+/// `SPI_IOC_RD_BITS_PER_WORD`
+type RdBitsPerWord = ioctl::ReadOpcode<b'k', 3, u8>;
+/// `SPI_IOC_WR_BITS_PER_WORD`
+type WrBitsPerWord = ioctl::WriteOpcode<b'k', 3, u8>;
+/// `SPI_IOC_RD_MAX_SPEED_HZ`
+type RdMaxSpeedHz = ioctl::ReadOpcode<b'k', 4, u32>;
+/// `SPI_IOC_WR_MAX_SPEED_HZ`
+type WrMaxSpeedHz = ioctl::WriteOpcode<b'k', 4, u32>;
+
+/// `SPI_IOC_MESSAGE(n)`
+const fn spi_ioc_message(n: usize) -> ioctl::Opcode {
+    ioctl::Opcode::from_components(
+        ioctl::Direction::Write,
+        b'k',
+        0,
+        n * size_of::<Transfer<'_>>(),
+    )
+}
+
+/// `ioctl(fd, SPI_IOC_MESSAGE(transfers.len()), transfers)`—Performs a
+/// transaction made of several transfers, keeping the device selected
+/// between them unless a transfer sets [`Transfer::with_cs_change`].
+///
+/// On success, returns the total number of bytes transferred.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/spi/spidev.html
+#[inline]
+#[doc(alias = "SPI_IOC_MESSAGE")]
+pub fn ioctl_spi_message<Fd: AsFd>(fd: Fd, transfers: &mut [Transfer<'_>]) -> io::Result<usize> {
+    let size = size_of_val(transfers);
+    if size > MAX_IOCTL_SIZE {
+        return Err(io::Errno::INVAL);
+    }
+
+    let opcode = spi_ioc_message(transfers.len());
+    // SAFETY: `SPI_IOC_MESSAGE` reads the array of transfers encoded in the
+    // opcode, and reads from and writes to their buffers, which are borrowed
+    // for the duration of the call.
+    unsafe {
+        ioctl::ioctl_with_opcode(fd.as_fd(), opcode, transfers.as_mut_ptr().cast())
+            .map(|len| len as usize)
+    }
+}
+
+/// `ioctl(fd, SPI_IOC_RD_MODE32)`—Returns the SPI mode of the device.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/spi/spidev.html
+#[inline]
+#[doc(alias = "SPI_IOC_RD_MODE32")]
+pub fn ioctl_spi_rd_mode32<Fd: AsFd>(fd: Fd) -> io::Result<Mode> {
+    // SAFETY: `SPI_IOC_RD_MODE32` is a getter opcode that gets a `u32`.
+    unsafe {
+        let ctl = ioctl::Getter::<RdMode32, u32>::new();
+        ioctl::ioctl(fd, ctl).map(Mode::from_bits_retain)
+    }
+}
+
+/// `ioctl(fd, SPI_IOC_WR_MODE32, mode)`—Sets the SPI mode of the device.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/spi/spidev.html
+#[inline]
+#[doc(alias = "SPI_IOC_WR_MODE32")]
+pub fn ioctl_spi_wr_mode32<Fd: AsFd>(fd: Fd, mode: Mode) -> io::Result<()> {
+    // SAFETY: `SPI_IOC_WR_MODE32` is a setter opcode that sets a `u32`.
+    unsafe {
+        let ctl = ioctl::Setter::<WrMode32, u32>::new(mode.bits());
+        ioctl::ioctl(fd, ctl)
+    }
+}
+
+/// `ioctl(fd, SPI_IOC_RD_BITS_PER_WORD)`—Returns the default word size of
+/// the device, in bits.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/spi/spidev.html
+#[inline]
+#[doc(alias = "SPI_IOC_RD_BITS_PER_WORD")]
+pub fn ioctl_spi_rd_bits_per_word<Fd: AsFd>(fd: Fd) -> io::Result<u8> {
+    // SAFETY: `SPI_IOC_RD_BITS_PER_WORD` is a getter opcode that gets a `u8`.
+    unsafe {
+        let ctl = ioctl::Getter::<RdBitsPerWord, u8>::new();
+        ioctl::ioctl(fd, ctl)
+    }
+}
+
+/// `ioctl(fd, SPI_IOC_WR_BITS_PER_WORD, bits)`—Sets the default word size
+/// of the device, in bits.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/spi/spidev.html
+#[inline]
+#[doc(alias = "SPI_IOC_WR_BITS_PER_WORD")]
+pub fn ioctl_spi_wr_bits_per_word<Fd: AsFd>(fd: Fd, bits: u8) -> io::Result<()> {
+    // SAFETY: `SPI_IOC_WR_BITS_PER_WORD` is a setter opcode that sets a `u8`.
+    unsafe {
+        let ctl = ioctl::Setter::<WrBitsPerWord, u8>::new(bits);
+        ioctl::ioctl(fd, ctl)
+    }
+}
+
+/// `ioctl(fd, SPI_IOC_RD_MAX_SPEED_HZ)`—Returns the default clock speed of
+/// the device, in Hz.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/spi/spidev.html
+#[inline]
+#[doc(alias = "SPI_IOC_RD_MAX_SPEED_HZ")]
+pub fn ioctl_spi_rd_max_speed_hz<Fd: AsFd>(fd: Fd) -> io::Result<u32> {
+    // SAFETY: `SPI_IOC_RD_MAX_SPEED_HZ` is a getter opcode that gets a `u32`.
+    unsafe {
+        let ctl = ioctl::Getter::<RdMaxSpeedHz, u32>::new();
+        ioctl::ioctl(fd, ctl)
+    }
+}
+
+/// `ioctl(fd, SPI_IOC_WR_MAX_SPEED_HZ, speed_hz)`—Sets the default clock
+/// speed of the device, in Hz.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/spi/spidev.html
+#[inline]
+#[doc(alias = "SPI_IOC_WR_MAX_SPEED_HZ")]
+pub fn ioctl_spi_wr_max_speed_hz<Fd: AsFd>(fd: Fd, speed_hz: u32) -> io::Result<()> {
+    // SAFETY: `SPI_IOC_WR_MAX_SPEED_HZ` is a setter opcode that sets a `u32`.
+    unsafe {
+        let ctl = ioctl::Setter::<WrMaxSpeedHz, u32>::new(speed_hz);
+        ioctl::ioctl(fd, ctl)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ioctl::CompileTimeOpcode;
+    use core::mem::align_of;
+
+    // `linux_raw_sys` doesn't have the spidev opcodes, so these are the
+    // values of the uapi macros with the generic `_IOC` encoding.
+    #[cfg(any(
+        target_arch = "aarch64",
+        target_arch = "arm",
+        target_arch = "riscv64",
+        target_arch = "x86",
+        target_arch = "x86_64",
+    ))]
+    #[test]
+    fn spi_opcodes() {
+        const_assert_eq!(spi_ioc_message(1).raw(), 0x4020_6b00);
+        const_assert_eq!(spi_ioc_message(2).raw(), 0x4040_6b00);
+        const_assert_eq!(RdMode32::OPCODE.raw(), 0x8004_6b05);
+        const_assert_eq!(WrMode32::OPCODE.raw(), 0x4004_6b05);
+        const_assert_eq!(RdBitsPerWord::OPCODE.raw(), 0x8001_6b03);
+        const_assert_eq!(WrBitsPerWord::OPCODE.raw(), 0x4001_6b03);
+        const_assert_eq!(RdMaxSpeedHz::OPCODE.raw(), 0x8004_6b04);
+        const_assert_eq!(WrMaxSpeedHz::OPCODE.raw(), 0x4004_6b04);
+    }
+
+    #[test]
+    fn spi_layouts() {
+        // struct spi_ioc_transfer
+        const_assert_eq!(size_of::<Transfer<'_>>(), 32);
+        const_assert_eq!(align_of::<Transfer<'_>>(), align_of::<u64>());
+    }
+}
@@ -509,3 +509,49 @@ pub fn dup2_stderr<Fd: AsFd>(fd: Fd) -> io::Result<()> {
     forget(target);
     Ok(())
 }
+
+/// Utility function to safely `dup2` over all three stdio file descriptors at
+/// once.
+///
+/// Calling [`dup2_stdin`], [`dup2_stdout`], and [`dup2_stderr`] one after
+/// another can clobber a source that is itself a stdio file descriptor, for
+/// example when swapping stdout and stderr. This function first moves any
+/// such source out of the way, so each stream ends up referring to what its
+/// source referred to when this function was called.
+///
+/// This doesn't allocate, so it can be used between `fork` and `exec`, such
+/// as in a `pre_exec` hook.
+#[cfg(not(any(windows, target_os = "espidf", target_os = "wasi")))]
+#[doc(alias = "replace_stdio")]
+pub fn dup2_stdio<StdinFd: AsFd, StdoutFd: AsFd, StderrFd: AsFd>(
+    stdin: StdinFd,
+    stdout: StdoutFd,
+    stderr: StderrFd,
+) -> io::Result<()> {
+    let stdin = stdin.as_fd();
+    let stdout = stdout.as_fd();
+    let stderr = stderr.as_fd();
+
+    let moved_stdin = move_from_stdio(stdin, raw_stdin())?;
+    let moved_stdout = move_from_stdio(stdout, raw_stdout())?;
+    let moved_stderr = move_from_stdio(stderr, raw_stderr())?;
+
+    dup2_stdin(moved_stdin.as_ref().map_or(stdin, AsFd::as_fd))?;
+    dup2_stdout(moved_stdout.as_ref().map_or(stdout, AsFd::as_fd))?;
+    dup2_stderr(moved_stderr.as_ref().map_or(stderr, AsFd::as_fd))
+}
+
+/// If `fd` is a stdio file descriptor other than `target`, duplicate it to a
+/// file descriptor above the stdio range so that it survives the `dup2`s in
+/// [`dup2_stdio`].
+#[cfg(not(any(windows, target_os = "espidf", target_os = "wasi")))]
+fn move_from_stdio(fd: BorrowedFd<'_>, target: RawFd) -> io::Result<Option<OwnedFd>> {
+    use crate::fd::AsRawFd;
+
+    let raw = fd.as_raw_fd();
+    if raw != target && raw <= raw_stderr() {
+        backend::io::syscalls::fcntl_dupfd_cloexec(fd, raw_stderr() + 1).map(Some)
+    } else {
+        Ok(None)
+    }
+}
@@ -9,6 +9,8 @@
 use crate::backend;
 #[cfg(linux_kernel)]
 use crate::backend::c;
+#[cfg(kexec_file_load)]
+use crate::fd::BorrowedFd;
 use crate::ffi::CStr;
 #[cfg(not(any(target_os = "espidf", target_os = "emscripten", target_os = "vita")))]
 use crate::io;
@@ -224,6 +226,50 @@ pub fn reboot(cmd: RebootCommand) -> io::Result<()> {
     backend::system::syscalls::reboot(cmd)
 }
 
+#[cfg(kexec_file_load)]
+bitflags::bitflags! {
+    /// `KEXEC_FILE_*` flags for use with [`kexec_file_load`].
+    #[repr(transparent)]
+    #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    pub struct KexecFileFlags: u32 {
+        /// `KEXEC_FILE_UNLOAD`—Unload the currently loaded kernel.
+        const UNLOAD = 0x1;
+        /// `KEXEC_FILE_ON_CRASH`—Load the kernel into the region reserved
+        /// for crash kernels, to be executed on a kernel panic.
+        const ON_CRASH = 0x2;
+        /// `KEXEC_FILE_NO_INITRAMFS`—Don't load an initramfs.
+        const NO_INITRAMFS = 0x4;
+
+        /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+        const _ = !0;
+    }
+}
+
+/// `kexec_file_load`—Load a new kernel for later execution
+///
+/// Loads the kernel image in `kernel_fd` and, unless `initrd_fd` is `None`,
+/// the initramfs in `initrd_fd`, to be booted with the command line `cmdline`
+/// by a later [`reboot`] with [`RebootCommand::Kexec`]. If `initrd_fd` is
+/// `None`, `flags` must include [`KexecFileFlags::NO_INITRAMFS`].
+///
+/// The kernel verifies the image's signature if it's configured to, which is
+/// the main advantage over `kexec_load`, which takes pre-parsed segments.
+///
+/// # References
+/// - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/kexec_file_load.2.html
+#[cfg(kexec_file_load)]
+#[inline]
+pub fn kexec_file_load<Fd: AsFd>(
+    kernel_fd: Fd,
+    initrd_fd: Option<BorrowedFd<'_>>,
+    cmdline: &CStr,
+    flags: KexecFileFlags,
+) -> io::Result<()> {
+    backend::system::syscalls::kexec_file_load(kernel_fd.as_fd(), initrd_fd, cmdline, flags)
+}
+
 /// `init_module`—Load a kernel module
 ///
 /// # References
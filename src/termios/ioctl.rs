@@ -51,3 +51,150 @@ pub fn ioctl_tiocnxcl<Fd: AsFd>(fd: Fd) -> io::Result<()> {
         ioctl::ioctl(fd, ctl)
     }
 }
+
+#[cfg(linux_kernel)]
+bitflags::bitflags! {
+    /// `TIOCM_*` modem control lines for use with [`ioctl_tiocmget`] and
+    /// [`ioctl_tiocmset`].
+    #[repr(transparent)]
+    #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    pub struct ModemLines: u32 {
+        /// `TIOCM_LE`—Line enable.
+        const LE = c::TIOCM_LE as u32;
+        /// `TIOCM_DTR`—Data terminal ready.
+        const DTR = c::TIOCM_DTR as u32;
+        /// `TIOCM_RTS`—Request to send.
+        const RTS = c::TIOCM_RTS as u32;
+        /// `TIOCM_ST`—Secondary transmit.
+        const ST = c::TIOCM_ST as u32;
+        /// `TIOCM_SR`—Secondary receive.
+        const SR = c::TIOCM_SR as u32;
+        /// `TIOCM_CTS`—Clear to send.
+        const CTS = c::TIOCM_CTS as u32;
+        /// `TIOCM_CAR`—Data carrier detect.
+        #[doc(alias = "TIOCM_CD")]
+        const CAR = c::TIOCM_CAR as u32;
+        /// `TIOCM_RNG`—Ring indicator.
+        #[doc(alias = "TIOCM_RI")]
+        const RNG = c::TIOCM_RNG as u32;
+        /// `TIOCM_DSR`—Data set ready.
+        const DSR = c::TIOCM_DSR as u32;
+
+        /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+        const _ = !0;
+    }
+}
+
+/// `ioctl(fd, TIOCMGET)`—Returns the state of a serial port's modem control
+/// lines.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/TIOCMGET.2const.html
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "TIOCMGET")]
+pub fn ioctl_tiocmget<Fd: AsFd>(fd: Fd) -> io::Result<ModemLines> {
+    // SAFETY: TIOCMGET is a getter opcode that gets a `c_int`.
+    unsafe {
+        let ctl = ioctl::Getter::<ioctl::BadOpcode<{ c::TIOCMGET as _ }>, c::c_int>::new();
+        ioctl::ioctl(fd, ctl).map(|lines| ModemLines::from_bits_retain(lines as u32))
+    }
+}
+
+/// `ioctl(fd, TIOCMSET, &lines)`—Sets the state of a serial port's modem
+/// control lines.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/TIOCMSET.2const.html
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "TIOCMSET")]
+pub fn ioctl_tiocmset<Fd: AsFd>(fd: Fd, lines: ModemLines) -> io::Result<()> {
+    // SAFETY: TIOCMSET is a pointer setter opcode that sets a `c_int`.
+    unsafe {
+        let ctl = ioctl::Setter::<ioctl::BadOpcode<{ c::TIOCMSET as _ }>, c::c_int>::new(
+            lines.bits() as c::c_int,
+        );
+        ioctl::ioctl(fd, ctl)
+    }
+}
+
+/// `ioctl(fd, TIOCMBIS, &lines)`—Raises the given modem control lines.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/TIOCMBIS.2const.html
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "TIOCMBIS")]
+pub fn ioctl_tiocmbis<Fd: AsFd>(fd: Fd, lines: ModemLines) -> io::Result<()> {
+    // SAFETY: TIOCMBIS is a pointer setter opcode that sets a `c_int`.
+    unsafe {
+        let ctl = ioctl::Setter::<ioctl::BadOpcode<{ c::TIOCMBIS as _ }>, c::c_int>::new(
+            lines.bits() as c::c_int,
+        );
+        ioctl::ioctl(fd, ctl)
+    }
+}
+
+/// `ioctl(fd, TIOCMBIC, &lines)`—Lowers the given modem control lines.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/TIOCMBIC.2const.html
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "TIOCMBIC")]
+pub fn ioctl_tiocmbic<Fd: AsFd>(fd: Fd, lines: ModemLines) -> io::Result<()> {
+    // SAFETY: TIOCMBIC is a pointer setter opcode that sets a `c_int`.
+    unsafe {
+        let ctl = ioctl::Setter::<ioctl::BadOpcode<{ c::TIOCMBIC as _ }>, c::c_int>::new(
+            lines.bits() as c::c_int,
+        );
+        ioctl::ioctl(fd, ctl)
+    }
+}
+
+/// `ioctl(fd, TIOCSBRK)`—Starts sending a break, until [`ioctl_tioccbrk`] is
+/// called.
+///
+/// To send a break for a fixed duration, use [`tcsendbreak`].
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/TIOCSBRK.2const.html
+/// [`tcsendbreak`]: crate::termios::tcsendbreak
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "TIOCSBRK")]
+pub fn ioctl_tiocsbrk<Fd: AsFd>(fd: Fd) -> io::Result<()> {
+    // SAFETY: TIOCSBRK is a no-argument setter opcode.
+    unsafe {
+        let ctl = ioctl::NoArg::<ioctl::BadOpcode<{ c::TIOCSBRK as _ }>>::new();
+        ioctl::ioctl(fd, ctl)
+    }
+}
+
+/// `ioctl(fd, TIOCCBRK)`—Stops sending a break.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/TIOCCBRK.2const.html
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "TIOCCBRK")]
+pub fn ioctl_tioccbrk<Fd: AsFd>(fd: Fd) -> io::Result<()> {
+    // SAFETY: TIOCCBRK is a no-argument setter opcode.
+    unsafe {
+        let ctl = ioctl::NoArg::<ioctl::BadOpcode<{ c::TIOCCBRK as _ }>>::new();
+        ioctl::ioctl(fd, ctl)
+    }
+}
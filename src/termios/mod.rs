@@ -16,6 +16,8 @@ mod tc;
 mod tty;
 #[cfg(not(any(target_os = "espidf", target_os = "wasi")))]
 mod types;
+#[cfg(linux_kernel)]
+pub mod vt;
 
 #[cfg(not(any(target_os = "espidf", target_os = "haiku", target_os = "wasi")))]
 pub use ioctl::*;
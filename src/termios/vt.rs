@@ -0,0 +1,258 @@
+//! Linux virtual terminal and console `ioctl` functions.
+//!
+//! The functions in this module take a file descriptor for a virtual
+//! terminal, such as `/dev/tty1`, or for the console, such as `/dev/tty0`.
+//! Most of them require the caller to own the terminal or to have
+//! `CAP_SYS_TTY_CONFIG`.
+//!
+//! # References
+//!  - [Linux]
+//!
+//! [Linux]: https://man7.org/linux/man-pages/man2/ioctl_console.2.html
+
+#![allow(unsafe_code)]
+
+use crate::backend::c;
+use crate::fd::AsFd;
+use crate::{io, ioctl};
+use linux_raw_sys::ioctl::{
+    KDGETMODE, KDGKBMODE, KDSETMODE, KDSKBMODE, VT_ACTIVATE, VT_GETSTATE, VT_OPENQRY, VT_WAITACTIVE,
+};
+
+/// `KD_*`—A console's display mode, for use with [`ioctl_kdgetmode`] and
+/// [`ioctl_kdsetmode`].
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct DisplayMode(u32);
+
+impl DisplayMode {
+    /// `KD_TEXT`—The kernel draws text on the console.
+    #[doc(alias = "KD_TEXT")]
+    pub const TEXT: Self = Self(0);
+
+    /// `KD_GRAPHICS`—The kernel leaves the display to a user-space program.
+    #[doc(alias = "KD_GRAPHICS")]
+    pub const GRAPHICS: Self = Self(1);
+
+    /// Constructs a `DisplayMode` from a raw `KD_*` value.
+    #[inline]
+    pub const fn from_raw(raw: u32) -> Self {
+        Self(raw)
+    }
+
+    /// Returns the raw `KD_*` value.
+    #[inline]
+    pub const fn as_raw(self) -> u32 {
+        self.0
+    }
+}
+
+/// `K_*`—A console's keyboard mode, for use with [`ioctl_kdgkbmode`] and
+/// [`ioctl_kdskbmode`].
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct KeyboardMode(u32);
+
+impl KeyboardMode {
+    /// `K_RAW`—Raw scancodes.
+    #[doc(alias = "K_RAW")]
+    pub const RAW: Self = Self(0);
+
+    /// `K_XLATE`—Translated to characters with the keymap.
+    #[doc(alias = "K_XLATE")]
+    pub const XLATE: Self = Self(1);
+
+    /// `K_MEDIUMRAW`—Keycodes.
+    #[doc(alias = "K_MEDIUMRAW")]
+    pub const MEDIUMRAW: Self = Self(2);
+
+    /// `K_UNICODE`—Translated to UTF-8 with the keymap.
+    #[doc(alias = "K_UNICODE")]
+    pub const UNICODE: Self = Self(3);
+
+    /// `K_OFF`—Keyboard input is disabled.
+    #[doc(alias = "K_OFF")]
+    pub const OFF: Self = Self(4);
+
+    /// Constructs a `KeyboardMode` from a raw `K_*` value.
+    #[inline]
+    pub const fn from_raw(raw: u32) -> Self {
+        Self(raw)
+    }
+
+    /// Returns the raw `K_*` value.
+    #[inline]
+    pub const fn as_raw(self) -> u32 {
+        self.0
+    }
+}
+
+/// `struct vt_stat`—The state of the virtual terminals, returned by
+/// [`ioctl_vt_getstate`].
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct VtState {
+    /// The number of the active virtual terminal, starting at 1.
+    pub active: u16,
+
+    /// A bitmask of open virtual terminals, where bit `n` is virtual
+    /// terminal `n`, for the first 16 terminals.
+    pub state: u16,
+}
+
+#[repr(C)]
+struct RawVtStat {
+    v_active: u16,
+    v_signal: u16,
+    v_state: u16,
+}
+
+/// `ioctl(fd, KDGETMODE)`—Returns a console's display mode.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/ioctl_console.2.html
+#[inline]
+#[doc(alias = "KDGETMODE")]
+pub fn ioctl_kdgetmode<Fd: AsFd>(fd: Fd) -> io::Result<DisplayMode> {
+    // SAFETY: `KDGETMODE` is a getter opcode that gets a `c_int`.
+    unsafe {
+        let ctl = ioctl::Getter::<ioctl::BadOpcode<{ KDGETMODE as _ }>, c::c_int>::new();
+        ioctl::ioctl(fd, ctl).map(|mode| DisplayMode(mode as u32))
+    }
+}
+
+/// `ioctl(fd, KDSETMODE, mode)`—Sets a console's display mode.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/ioctl_console.2.html
+#[inline]
+#[doc(alias = "KDSETMODE")]
+pub fn ioctl_kdsetmode<Fd: AsFd>(fd: Fd, mode: DisplayMode) -> io::Result<()> {
+    // SAFETY: `KDSETMODE` takes an integer argument.
+    unsafe {
+        let ctl =
+            ioctl::IntegerSetter::<ioctl::BadOpcode<{ KDSETMODE as _ }>>::new(mode.0 as usize);
+        ioctl::ioctl(fd, ctl)
+    }
+}
+
+/// `ioctl(fd, KDGKBMODE)`—Returns a console's keyboard mode.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/ioctl_console.2.html
+#[inline]
+#[doc(alias = "KDGKBMODE")]
+pub fn ioctl_kdgkbmode<Fd: AsFd>(fd: Fd) -> io::Result<KeyboardMode> {
+    // SAFETY: `KDGKBMODE` is a getter opcode that gets a `c_int`.
+    unsafe {
+        let ctl = ioctl::Getter::<ioctl::BadOpcode<{ KDGKBMODE as _ }>, c::c_int>::new();
+        ioctl::ioctl(fd, ctl).map(|mode| KeyboardMode(mode as u32))
+    }
+}
+
+/// `ioctl(fd, KDSKBMODE, mode)`—Sets a console's keyboard mode.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/ioctl_console.2.html
+#[inline]
+#[doc(alias = "KDSKBMODE")]
+pub fn ioctl_kdskbmode<Fd: AsFd>(fd: Fd, mode: KeyboardMode) -> io::Result<()> {
+    // SAFETY: `KDSKBMODE` takes an integer argument.
+    unsafe {
+        let ctl =
+            ioctl::IntegerSetter::<ioctl::BadOpcode<{ KDSKBMODE as _ }>>::new(mode.0 as usize);
+        ioctl::ioctl(fd, ctl)
+    }
+}
+
+/// `ioctl(fd, VT_OPENQRY)`—Returns the number of the first virtual terminal
+/// that isn't open.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/ioctl_console.2.html
+#[inline]
+#[doc(alias = "VT_OPENQRY")]
+pub fn ioctl_vt_openqry<Fd: AsFd>(fd: Fd) -> io::Result<u32> {
+    // SAFETY: `VT_OPENQRY` is a getter opcode that gets a `c_int`.
+    unsafe {
+        let ctl = ioctl::Getter::<ioctl::BadOpcode<{ VT_OPENQRY as _ }>, c::c_int>::new();
+        ioctl::ioctl(fd, ctl).map(|vt| vt as u32)
+    }
+}
+
+/// `ioctl(fd, VT_GETSTATE)`—Returns the active virtual terminal and which
+/// virtual terminals are open.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/ioctl_console.2.html
+#[inline]
+#[doc(alias = "VT_GETSTATE")]
+pub fn ioctl_vt_getstate<Fd: AsFd>(fd: Fd) -> io::Result<VtState> {
+    // SAFETY: `VT_GETSTATE` is a getter opcode that gets a `struct vt_stat`.
+    unsafe {
+        let ctl = ioctl::Getter::<ioctl::BadOpcode<{ VT_GETSTATE as _ }>, RawVtStat>::new();
+        ioctl::ioctl(fd, ctl).map(|stat| VtState {
+            active: stat.v_active,
+            state: stat.v_state,
+        })
+    }
+}
+
+/// `ioctl(fd, VT_ACTIVATE, vt)`—Switches to a virtual terminal.
+///
+/// The switch happens asynchronously; use [`ioctl_vt_waitactive`] to wait
+/// for it to complete.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/ioctl_console.2.html
+#[inline]
+#[doc(alias = "VT_ACTIVATE")]
+pub fn ioctl_vt_activate<Fd: AsFd>(fd: Fd, vt: u32) -> io::Result<()> {
+    // SAFETY: `VT_ACTIVATE` takes an integer argument.
+    unsafe {
+        let ctl = ioctl::IntegerSetter::<ioctl::BadOpcode<{ VT_ACTIVATE as _ }>>::new(vt as usize);
+        ioctl::ioctl(fd, ctl)
+    }
+}
+
+/// `ioctl(fd, VT_WAITACTIVE, vt)`—Waits until a virtual terminal is active.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/ioctl_console.2.html
+#[inline]
+#[doc(alias = "VT_WAITACTIVE")]
+pub fn ioctl_vt_waitactive<Fd: AsFd>(fd: Fd, vt: u32) -> io::Result<()> {
+    // SAFETY: `VT_WAITACTIVE` takes an integer argument.
+    unsafe {
+        let ctl =
+            ioctl::IntegerSetter::<ioctl::BadOpcode<{ VT_WAITACTIVE as _ }>>::new(vt as usize);
+        ioctl::ioctl(fd, ctl)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn vt_layouts() {
+        // struct vt_stat { unsigned short v_active, v_signal, v_state; }
+        assert_eq_size!(RawVtStat, [u16; 3]);
+        assert_eq_align!(RawVtStat, u16);
+    }
+}
@@ -31,6 +31,9 @@ pub fn gettid() -> Pid {
 /// in the [manual page][linux_notes]. This call implements the kernel
 /// behavior.
 ///
+/// Switching to a `uid` other than the real or saved user ID requires the
+/// `CAP_SETUID` capability, and fails with [`io::Errno::PERM`] without it.
+///
 /// # References
 ///  - [POSIX]
 ///  - [Linux]
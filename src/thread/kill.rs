@@ -0,0 +1,30 @@
+use crate::thread::Pid;
+use crate::{backend, io};
+
+pub use crate::signal::Signal;
+
+/// `tgkill(getpid(), tid, sig)` or
+/// `rt_tgsigqueueinfo(getpid(), tid, sig, info)`—Sends a signal to a thread
+/// in the current process.
+///
+/// If `value` is `None`, this uses `tgkill`. If `value` is `Some`, this uses
+/// `rt_tgsigqueueinfo` with an `si_code` of `SI_QUEUE`, and the value is
+/// delivered in the `si_value` field of the receiving handler's `siginfo_t`,
+/// like `pthread_sigqueue`.
+///
+/// # References
+///  - [Linux `tgkill`]
+///  - [Linux `rt_tgsigqueueinfo`]
+///
+/// [Linux `tgkill`]: https://man7.org/linux/man-pages/man2/tgkill.2.html
+/// [Linux `rt_tgsigqueueinfo`]: https://man7.org/linux/man-pages/man2/rt_tgsigqueueinfo.2.html
+#[inline]
+#[doc(alias = "tgkill")]
+#[doc(alias = "rt_tgsigqueueinfo")]
+#[doc(alias = "pthread_sigqueue")]
+pub fn signal(tid: Pid, sig: Signal, value: Option<usize>) -> io::Result<()> {
+    match value {
+        None => backend::thread::syscalls::tgkill(tid, sig),
+        Some(value) => backend::thread::syscalls::tgsigqueueinfo(tid, sig, value),
+    }
+}
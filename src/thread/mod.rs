@@ -7,10 +7,15 @@ mod futex;
 #[cfg(linux_kernel)]
 mod id;
 #[cfg(linux_kernel)]
+mod kill;
+#[cfg(linux_kernel)]
 mod libcap;
 #[cfg(linux_kernel)]
 mod prctl;
 #[cfg(linux_kernel)]
+#[cfg(target_has_atomic = "64")]
+mod rseq;
+#[cfg(linux_kernel)]
 mod setns;
 
 #[cfg(not(target_os = "redox"))]
@@ -23,8 +28,13 @@ pub use id::{
     RawGid, RawPid, RawUid, Uid,
 };
 #[cfg(linux_kernel)]
+pub use kill::{signal, Signal};
+#[cfg(linux_kernel)]
 pub use libcap::{capabilities, set_capabilities, CapabilityFlags, CapabilitySets};
 #[cfg(linux_kernel)]
 pub use prctl::*;
 #[cfg(linux_kernel)]
+#[cfg(target_has_atomic = "64")]
+pub use rseq::{rseq_register, rseq_unregister, Rseq};
+#[cfg(linux_kernel)]
 pub use setns::*;
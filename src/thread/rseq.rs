@@ -0,0 +1,131 @@
+//! Linux restartable sequences.
+//!
+//! A thread registers an [`Rseq`] area with the kernel, which then keeps the
+//! area's CPU and NUMA node fields up to date as the thread migrates, and
+//! aborts the thread's active critical section, if any, when it's preempted,
+//! migrated, or interrupted by a signal. This lets per-CPU data structures
+//! avoid atomic read-modify-write instructions.
+//!
+//! Each thread can only have one area registered at a time. glibc 2.35 and
+//! newer register one for every thread it creates, in which case
+//! [`rseq_register`] fails with [`io::Errno::INVAL`], and the glibc-registered
+//! area has to be used instead.
+//!
+//! # References
+//!  - [Linux]
+//!
+//! [Linux]: https://docs.kernel.org/userspace-api/rseq.html
+#![allow(unsafe_code)]
+
+use crate::{backend, io};
+use core::sync::atomic::{AtomicU32, AtomicU64, Ordering};
+
+/// `RSEQ_CPU_ID_UNINITIALIZED`
+const CPU_ID_UNINITIALIZED: u32 = -1_i32 as u32;
+
+/// `RSEQ_FLAG_UNREGISTER`
+const RSEQ_FLAG_UNREGISTER: u32 = 1;
+
+/// `struct rseq`—A restartable sequences area, for use with
+/// [`rseq_register`].
+///
+/// The fields are atomics because the kernel updates them asynchronously,
+/// when the thread is preempted.
+#[repr(C, align(32))]
+#[derive(Debug)]
+pub struct Rseq {
+    /// The CPU the thread is running on, which is always a valid CPU number,
+    /// even before registration.
+    pub cpu_id_start: AtomicU32,
+
+    /// The CPU the thread is running on, or `RSEQ_CPU_ID_UNINITIALIZED`
+    /// (`u32::MAX`) if the area isn't registered.
+    pub cpu_id: AtomicU32,
+
+    /// The address of the `struct rseq_cs` describing the thread's active
+    /// critical section, or 0 if there isn't one.
+    pub rseq_cs: AtomicU64,
+
+    /// Deprecated; must be 0.
+    pub flags: AtomicU32,
+
+    /// The NUMA node the thread is running on (since Linux 6.3).
+    pub node_id: AtomicU32,
+
+    /// A concurrency ID for the thread, which is unique among the threads of
+    /// the process currently running and smaller than the number of CPUs
+    /// (since Linux 6.3).
+    pub mm_cid: AtomicU32,
+}
+
+impl Rseq {
+    /// Constructs a new unregistered `Rseq`.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            cpu_id_start: AtomicU32::new(0),
+            cpu_id: AtomicU32::new(CPU_ID_UNINITIALIZED),
+            rseq_cs: AtomicU64::new(0),
+            flags: AtomicU32::new(0),
+            node_id: AtomicU32::new(0),
+            mm_cid: AtomicU32::new(0),
+        }
+    }
+
+    /// Returns the CPU the thread is running on, or `None` if this area isn't
+    /// registered.
+    #[inline]
+    pub fn cpu_id(&self) -> Option<u32> {
+        match self.cpu_id.load(Ordering::Relaxed) {
+            CPU_ID_UNINITIALIZED => None,
+            cpu => Some(cpu),
+        }
+    }
+}
+
+impl Default for Rseq {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// `rseq(rseq, sizeof(*rseq), 0, sig)`—Registers a restartable sequences
+/// area for the calling thread.
+///
+/// `sig` is the signature that must precede the abort handler of every
+/// critical section, which the kernel checks before jumping to it.
+///
+/// If the thread already has an area registered, this fails with
+/// [`io::Errno::BUSY`] if it's `rseq` with the same `sig`, and with
+/// [`io::Errno::INVAL`] otherwise, such as when it's one registered by glibc.
+///
+/// # Safety
+///
+/// `rseq` must remain valid, and must not be moved, until it's unregistered
+/// with [`rseq_unregister`] or the thread exits.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/userspace-api/rseq.html
+#[inline]
+#[doc(alias = "rseq")]
+pub unsafe fn rseq_register(rseq: &Rseq, sig: u32) -> io::Result<()> {
+    backend::thread::syscalls::rseq(rseq, 0, sig)
+}
+
+/// `rseq(rseq, sizeof(*rseq), RSEQ_FLAG_UNREGISTER, sig)`—Unregisters the
+/// calling thread's restartable sequences area.
+///
+/// `rseq` and `sig` must be the same as were passed to [`rseq_register`].
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/userspace-api/rseq.html
+#[inline]
+pub fn rseq_unregister(rseq: &Rseq, sig: u32) -> io::Result<()> {
+    // SAFETY: Unregistering stops the kernel from accessing `rseq`.
+    unsafe { backend::thread::syscalls::rseq(rseq, RSEQ_FLAG_UNREGISTER, sig) }
+}
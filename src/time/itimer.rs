@@ -0,0 +1,82 @@
+use crate::{backend, io};
+use core::time::Duration;
+
+/// `ITIMER_*` constants for use with [`setitimer`] and [`getitimer`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[repr(u32)]
+#[non_exhaustive]
+pub enum IntervalTimer {
+    /// `ITIMER_REAL`—Counts down in real time, and delivers `SIGALRM`.
+    #[doc(alias = "ITIMER_REAL")]
+    Real = linux_raw_sys::general::ITIMER_REAL,
+
+    /// `ITIMER_VIRTUAL`—Counts down while the process is running in user
+    /// mode, and delivers `SIGVTALRM`.
+    #[doc(alias = "ITIMER_VIRTUAL")]
+    Virtual = linux_raw_sys::general::ITIMER_VIRTUAL,
+
+    /// `ITIMER_PROF`—Counts down while the process is running in user or
+    /// kernel mode, and delivers `SIGPROF`.
+    #[doc(alias = "ITIMER_PROF")]
+    Prof = linux_raw_sys::general::ITIMER_PROF,
+}
+
+/// `struct itimerval`—The setting of an interval timer.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash)]
+#[doc(alias = "itimerval")]
+pub struct Itimerval {
+    /// `it_interval`—The period to rearm the timer with each time it
+    /// expires, or zero to make it a one-shot timer.
+    pub interval: Duration,
+
+    /// `it_value`—The time until the timer next expires, or zero if the
+    /// timer is disarmed.
+    pub value: Duration,
+}
+
+/// `setitimer(which, new_value, &mut old_value)`—Arms or disarms an interval
+/// timer.
+///
+/// Setting `value` to zero disarms the timer. Times are rounded up to the
+/// timer's microsecond resolution, so a nonzero `value` never disarms it. On
+/// success, returns the timer's previous setting.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/setitimer.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/setitimer.2.html
+#[inline]
+pub fn setitimer(which: IntervalTimer, new_value: &Itimerval) -> io::Result<Itimerval> {
+    backend::time::syscalls::setitimer(which, new_value)
+}
+
+/// `getitimer(which, &mut curr_value)`—Queries an interval timer.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/getitimer.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/getitimer.2.html
+#[inline]
+pub fn getitimer(which: IntervalTimer) -> io::Result<Itimerval> {
+    backend::time::syscalls::getitimer(which)
+}
+
+/// Convert a `Duration` into seconds and microseconds for a `timeval`,
+/// rounding up to a whole microsecond.
+pub(crate) fn duration_to_timeval_parts(duration: Duration) -> (u64, u32) {
+    let micros = (duration.subsec_nanos() + 999) / 1000;
+    if micros == 1_000_000 {
+        (duration.as_secs().saturating_add(1), 0)
+    } else {
+        (duration.as_secs(), micros)
+    }
+}
+
+/// Convert seconds and microseconds from a `timeval` into a `Duration`.
+pub(crate) fn timeval_parts_to_duration(secs: i64, micros: i64) -> Duration {
+    Duration::new(secs as u64, (micros * 1000) as u32)
+}
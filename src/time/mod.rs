@@ -1,11 +1,17 @@
 //! Time-related operations.
 
 mod clock;
+#[cfg(linux_kernel)]
+mod itimer;
 #[cfg(any(linux_kernel, target_os = "fuchsia"))]
 mod timerfd;
 
 // TODO: Convert WASI'S clock APIs to use handles rather than ambient clock
 // identifiers, update `wasi-libc`, and then add support in `rustix`.
 pub use clock::*;
+#[cfg(linux_kernel)]
+pub(crate) use itimer::{duration_to_timeval_parts, timeval_parts_to_duration};
+#[cfg(linux_kernel)]
+pub use itimer::{getitimer, setitimer, IntervalTimer, Itimerval};
 #[cfg(any(linux_kernel, target_os = "fuchsia"))]
 pub use timerfd::*;
@@ -0,0 +1,500 @@
+//! Linux USB device filesystem (usbdevfs) functions.
+//!
+//! The functions in this module take a file descriptor opened from a USB
+//! device node such as `/dev/bus/usb/001/002`. Reading from the device node
+//! returns its descriptors.
+//!
+//! # References
+//!  - [Linux]
+//!
+//! [Linux]: https://docs.kernel.org/driver-api/usb/usb.html#the-usb-character-device-nodes
+
+#![allow(unsafe_code)]
+
+use crate::backend::c;
+use crate::fd::{AsFd, BorrowedFd};
+use crate::ioctl::CompileTimeOpcode;
+use crate::{io, ioctl};
+use bitflags::bitflags;
+
+/// `USB_DIR_IN`—The direction bit of request types and endpoint addresses.
+const USB_DIR_IN: u8 = 0x80;
+
+bitflags! {
+    /// `USBDEVFS_CAP_*` constants returned by
+    /// [`ioctl_usbdevfs_get_capabilities`].
+    #[repr(transparent)]
+    #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    pub struct Capabilities: u32 {
+        /// `USBDEVFS_CAP_ZERO_PACKET`
+        const ZERO_PACKET = 0x01;
+        /// `USBDEVFS_CAP_BULK_CONTINUATION`
+        const BULK_CONTINUATION = 0x02;
+        /// `USBDEVFS_CAP_NO_PACKET_SIZE_LIM`
+        const NO_PACKET_SIZE_LIM = 0x04;
+        /// `USBDEVFS_CAP_BULK_SCATTER_GATHER`
+        const BULK_SCATTER_GATHER = 0x08;
+        /// `USBDEVFS_CAP_REAP_AFTER_DISCONNECT`
+        const REAP_AFTER_DISCONNECT = 0x10;
+        /// `USBDEVFS_CAP_MMAP`
+        const MMAP = 0x20;
+        /// `USBDEVFS_CAP_DROP_PRIVILEGES`
+        const DROP_PRIVILEGES = 0x40;
+        /// `USBDEVFS_CAP_CONNINFO_EX`
+        const CONNINFO_EX = 0x80;
+        /// `USBDEVFS_CAP_SUSPEND`
+        const SUSPEND = 0x100;
+
+        /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+        const _ = !0;
+    }
+}
+
+/// The setup packet of a control transfer, for use with
+/// [`ioctl_usbdevfs_control_in`] and [`ioctl_usbdevfs_control_out`].
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct ControlRequest {
+    /// `bmRequestType`—The direction, type, and recipient of the request.
+    ///
+    /// The direction bit, 0x80, must be set for
+    /// [`ioctl_usbdevfs_control_in`] and clear for
+    /// [`ioctl_usbdevfs_control_out`].
+    pub request_type: u8,
+
+    /// `bRequest`—The request.
+    pub request: u8,
+
+    /// `wValue`—A request-specific value.
+    pub value: u16,
+
+    /// `wIndex`—A request-specific index or offset.
+    pub index: u16,
+}
+
+/// `struct usbdevfs_ctrltransfer`
+#[repr(C)]
+struct CtrlTransfer {
+    request_type: u8,
+    request: u8,
+    value: u16,
+    index: u16,
+    length: u16,
+    timeout: u32,
+    data: *mut c::c_void,
+}
+
+/// `struct usbdevfs_bulktransfer`
+#[repr(C)]
+struct BulkTransfer {
+    ep: c::c_uint,
+    len: c::c_uint,
+    timeout: c::c_uint,
+    data: *mut c::c_void,
+}
+
+/// `struct usbdevfs_setinterface`
+#[repr(C)]
+struct RawSetInterface {
+    interface: c::c_uint,
+    altsetting: c::c_uint,
+}
+
+/// `USBDEVFS_CONTROL`
+type Control = ioctl::ReadWriteOpcode<b'U', 0, CtrlTransfer>;
+/// `USBDEVFS_BULK`
+type Bulk = ioctl::ReadWriteOpcode<b'U', 2, BulkTransfer>;
+/// `USBDEVFS_CLAIMINTERFACE`
+type ClaimInterface = ioctl::ReadOpcode<b'U', 15, c::c_uint>;
+/// `USBDEVFS_RELEASEINTERFACE`
+type ReleaseInterface = ioctl::ReadOpcode<b'U', 16, c::c_uint>;
+/// `USBDEVFS_SETINTERFACE`
+type SetInterface = ioctl::ReadOpcode<b'U', 4, RawSetInterface>;
+/// `USBDEVFS_SETCONFIGURATION`
+type SetConfiguration = ioctl::ReadOpcode<b'U', 5, c::c_uint>;
+/// `USBDEVFS_CLEAR_HALT`
+type ClearHalt = ioctl::ReadOpcode<b'U', 21, c::c_uint>;
+/// `USBDEVFS_RESET`
+type Reset = ioctl::NoneOpcode<b'U', 20, ()>;
+/// `USBDEVFS_GET_CAPABILITIES`
+type GetCapabilities = ioctl::ReadOpcode<b'U', 26, u32>;
+
+/// Performs a `USBDEVFS_CONTROL` transfer.
+///
+/// # Safety
+///
+/// `data` must be valid for reads of `len` bytes, and for writes of `len`
+/// bytes if `request` is an IN request.
+unsafe fn control(
+    fd: BorrowedFd<'_>,
+    request: ControlRequest,
+    data: *mut u8,
+    len: usize,
+    timeout_ms: u32,
+) -> io::Result<usize> {
+    let mut transfer = CtrlTransfer {
+        request_type: request.request_type,
+        request: request.request,
+        value: request.value,
+        index: request.index,
+        length: u16::try_from(len).map_err(|_| io::Errno::INVAL)?,
+        timeout: timeout_ms,
+        data: data.cast(),
+    };
+    let opcode = Control::OPCODE;
+    ioctl::ioctl_with_opcode(fd, opcode, (&mut transfer as *mut CtrlTransfer).cast())
+        .map(|len| len as usize)
+}
+
+/// Performs a `USBDEVFS_BULK` transfer.
+///
+/// # Safety
+///
+/// `data` must be valid for reads of `len` bytes, and for writes of `len`
+/// bytes if `endpoint` is an IN endpoint.
+unsafe fn bulk(
+    fd: BorrowedFd<'_>,
+    endpoint: u8,
+    data: *mut u8,
+    len: usize,
+    timeout_ms: u32,
+) -> io::Result<usize> {
+    let mut transfer = BulkTransfer {
+        ep: endpoint.into(),
+        len: len.min(c::c_uint::MAX as usize) as c::c_uint,
+        timeout: timeout_ms,
+        data: data.cast(),
+    };
+    let opcode = Bulk::OPCODE;
+    ioctl::ioctl_with_opcode(fd, opcode, (&mut transfer as *mut BulkTransfer).cast())
+        .map(|len| len as usize)
+}
+
+/// `ioctl(fd, USBDEVFS_CONTROL, transfer)`—Performs a control transfer
+/// that reads data from the device into `buf`.
+///
+/// `timeout_ms` is the timeout in milliseconds, or 0 to wait indefinitely.
+/// On success, returns the number of bytes read.
+///
+/// This fails with `INVAL` if `request.request_type` doesn't have the
+/// direction bit set, or if `buf` is longer than 65535 bytes.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/driver-api/usb/usb.html#usbdevfs-control
+#[inline]
+#[doc(alias = "USBDEVFS_CONTROL")]
+pub fn ioctl_usbdevfs_control_in<Fd: AsFd>(
+    fd: Fd,
+    request: ControlRequest,
+    buf: &mut [u8],
+    timeout_ms: u32,
+) -> io::Result<usize> {
+    if request.request_type & USB_DIR_IN == 0 {
+        return Err(io::Errno::INVAL);
+    }
+
+    // SAFETY: An IN control transfer writes at most `buf.len()` bytes into
+    // `buf`.
+    unsafe { control(fd.as_fd(), request, buf.as_mut_ptr(), buf.len(), timeout_ms) }
+}
+
+/// `ioctl(fd, USBDEVFS_CONTROL, transfer)`—Performs a control transfer
+/// that writes `data` to the device.
+///
+/// `timeout_ms` is the timeout in milliseconds, or 0 to wait indefinitely.
+/// On success, returns the number of bytes written.
+///
+/// This fails with `INVAL` if `request.request_type` has the direction bit
+/// set, or if `data` is longer than 65535 bytes.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/driver-api/usb/usb.html#usbdevfs-control
+#[inline]
+#[doc(alias = "USBDEVFS_CONTROL")]
+pub fn ioctl_usbdevfs_control_out<Fd: AsFd>(
+    fd: Fd,
+    request: ControlRequest,
+    data: &[u8],
+    timeout_ms: u32,
+) -> io::Result<usize> {
+    if request.request_type & USB_DIR_IN != 0 {
+        return Err(io::Errno::INVAL);
+    }
+
+    // SAFETY: An OUT control transfer only reads from `data`.
+    unsafe {
+        control(
+            fd.as_fd(),
+            request,
+            data.as_ptr() as *mut u8,
+            data.len(),
+            timeout_ms,
+        )
+    }
+}
+
+/// `ioctl(fd, USBDEVFS_BULK, transfer)`—Performs a bulk or interrupt
+/// transfer that reads data from an IN endpoint into `buf`.
+///
+/// `timeout_ms` is the timeout in milliseconds, or 0 to wait indefinitely.
+/// On success, returns the number of bytes read.
+///
+/// This fails with `INVAL` if `endpoint` doesn't have the direction bit,
+/// 0x80, set.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/driver-api/usb/usb.html#usbdevfs-bulk
+#[inline]
+#[doc(alias = "USBDEVFS_BULK")]
+pub fn ioctl_usbdevfs_bulk_in<Fd: AsFd>(
+    fd: Fd,
+    endpoint: u8,
+    buf: &mut [u8],
+    timeout_ms: u32,
+) -> io::Result<usize> {
+    if endpoint & USB_DIR_IN == 0 {
+        return Err(io::Errno::INVAL);
+    }
+
+    // SAFETY: A transfer from an IN endpoint writes at most `buf.len()`
+    // bytes into `buf`.
+    unsafe {
+        bulk(
+            fd.as_fd(),
+            endpoint,
+            buf.as_mut_ptr(),
+            buf.len(),
+            timeout_ms,
+        )
+    }
+}
+
+/// `ioctl(fd, USBDEVFS_BULK, transfer)`—Performs a bulk or interrupt
+/// transfer that writes `data` to an OUT endpoint.
+///
+/// `timeout_ms` is the timeout in milliseconds, or 0 to wait indefinitely.
+/// On success, returns the number of bytes written.
+///
+/// This fails with `INVAL` if `endpoint` has the direction bit, 0x80, set.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/driver-api/usb/usb.html#usbdevfs-bulk
+#[inline]
+#[doc(alias = "USBDEVFS_BULK")]
+pub fn ioctl_usbdevfs_bulk_out<Fd: AsFd>(
+    fd: Fd,
+    endpoint: u8,
+    data: &[u8],
+    timeout_ms: u32,
+) -> io::Result<usize> {
+    if endpoint & USB_DIR_IN != 0 {
+        return Err(io::Errno::INVAL);
+    }
+
+    // SAFETY: A transfer to an OUT endpoint only reads from `data`.
+    unsafe {
+        bulk(
+            fd.as_fd(),
+            endpoint,
+            data.as_ptr() as *mut u8,
+            data.len(),
+            timeout_ms,
+        )
+    }
+}
+
+/// `ioctl(fd, USBDEVFS_CLAIMINTERFACE, &interface)`—Claims an interface of
+/// the device, so that its endpoints can be used.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/driver-api/usb/usb.html#usbdevfs-claiminterface
+#[inline]
+#[doc(alias = "USBDEVFS_CLAIMINTERFACE")]
+pub fn ioctl_usbdevfs_claiminterface<Fd: AsFd>(fd: Fd, interface: u32) -> io::Result<()> {
+    // SAFETY: `USBDEVFS_CLAIMINTERFACE` reads a `c_uint`.
+    unsafe {
+        let ctl = ioctl::Setter::<ClaimInterface, c::c_uint>::new(interface as c::c_uint);
+        ioctl::ioctl(fd, ctl)
+    }
+}
+
+/// `ioctl(fd, USBDEVFS_RELEASEINTERFACE, &interface)`—Releases an interface
+/// claimed with [`ioctl_usbdevfs_claiminterface`].
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/driver-api/usb/usb.html#usbdevfs-releaseinterface
+#[inline]
+#[doc(alias = "USBDEVFS_RELEASEINTERFACE")]
+pub fn ioctl_usbdevfs_releaseinterface<Fd: AsFd>(fd: Fd, interface: u32) -> io::Result<()> {
+    // SAFETY: `USBDEVFS_RELEASEINTERFACE` reads a `c_uint`.
+    unsafe {
+        let ctl = ioctl::Setter::<ReleaseInterface, c::c_uint>::new(interface as c::c_uint);
+        ioctl::ioctl(fd, ctl)
+    }
+}
+
+/// `ioctl(fd, USBDEVFS_SETINTERFACE, setting)`—Selects an alternate setting
+/// of an interface.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/driver-api/usb/usb.html#usbdevfs-setinterface
+#[inline]
+#[doc(alias = "USBDEVFS_SETINTERFACE")]
+pub fn ioctl_usbdevfs_setinterface<Fd: AsFd>(
+    fd: Fd,
+    interface: u32,
+    altsetting: u32,
+) -> io::Result<()> {
+    // SAFETY: `USBDEVFS_SETINTERFACE` reads a `struct usbdevfs_setinterface`.
+    unsafe {
+        let ctl = ioctl::Setter::<SetInterface, RawSetInterface>::new(RawSetInterface {
+            interface: interface as c::c_uint,
+            altsetting: altsetting as c::c_uint,
+        });
+        ioctl::ioctl(fd, ctl)
+    }
+}
+
+/// `ioctl(fd, USBDEVFS_SETCONFIGURATION, &config)`—Selects a configuration
+/// of the device, or -1 to unconfigure it.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/driver-api/usb/usb.html#usbdevfs-setconfiguration
+#[inline]
+#[doc(alias = "USBDEVFS_SETCONFIGURATION")]
+pub fn ioctl_usbdevfs_setconfiguration<Fd: AsFd>(fd: Fd, config: i32) -> io::Result<()> {
+    // SAFETY: `USBDEVFS_SETCONFIGURATION` reads a `c_int`.
+    unsafe {
+        let ctl = ioctl::Setter::<SetConfiguration, c::c_int>::new(config);
+        ioctl::ioctl(fd, ctl)
+    }
+}
+
+/// `ioctl(fd, USBDEVFS_CLEAR_HALT, &endpoint)`—Clears the halt condition of
+/// an endpoint.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/driver-api/usb/usb.html#usbdevfs-clear-halt
+#[inline]
+#[doc(alias = "USBDEVFS_CLEAR_HALT")]
+pub fn ioctl_usbdevfs_clear_halt<Fd: AsFd>(fd: Fd, endpoint: u8) -> io::Result<()> {
+    // SAFETY: `USBDEVFS_CLEAR_HALT` reads a `c_uint`.
+    unsafe {
+        let ctl = ioctl::Setter::<ClearHalt, c::c_uint>::new(endpoint.into());
+        ioctl::ioctl(fd, ctl)
+    }
+}
+
+/// `ioctl(fd, USBDEVFS_RESET)`—Resets the device.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/driver-api/usb/usb.html#usbdevfs-reset
+#[inline]
+#[doc(alias = "USBDEVFS_RESET")]
+pub fn ioctl_usbdevfs_reset<Fd: AsFd>(fd: Fd) -> io::Result<()> {
+    // SAFETY: `USBDEVFS_RESET` doesn't take an argument.
+    unsafe {
+        let ctl = ioctl::NoArg::<Reset>::new();
+        ioctl::ioctl(fd, ctl)
+    }
+}
+
+/// `ioctl(fd, USBDEVFS_GET_CAPABILITIES)`—Returns what the usbdevfs
+/// interface of the device supports.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/driver-api/usb/usb.html#the-usb-character-device-nodes
+#[inline]
+#[doc(alias = "USBDEVFS_GET_CAPABILITIES")]
+pub fn ioctl_usbdevfs_get_capabilities<Fd: AsFd>(fd: Fd) -> io::Result<Capabilities> {
+    // SAFETY: `USBDEVFS_GET_CAPABILITIES` is a getter opcode that gets a
+    // `u32`.
+    unsafe {
+        let ctl = ioctl::Getter::<GetCapabilities, u32>::new();
+        ioctl::ioctl(fd, ctl).map(Capabilities::from_bits_retain)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ioctl::RawOpcode;
+    use core::mem::{align_of, size_of};
+
+    #[cfg(not(any(
+        // These have no ioctl opcodes defined in linux_raw_sys.
+        target_arch = "sparc",
+        target_arch = "sparc64"
+    )))]
+    #[test]
+    fn usbdevfs_opcodes() {
+        use linux_raw_sys::ioctl as sys;
+
+        const_assert_eq!(Control::OPCODE.raw(), sys::USBDEVFS_CONTROL as RawOpcode);
+        const_assert_eq!(Bulk::OPCODE.raw(), sys::USBDEVFS_BULK as RawOpcode);
+        const_assert_eq!(
+            ClaimInterface::OPCODE.raw(),
+            sys::USBDEVFS_CLAIMINTERFACE as RawOpcode
+        );
+        const_assert_eq!(
+            ReleaseInterface::OPCODE.raw(),
+            sys::USBDEVFS_RELEASEINTERFACE as RawOpcode
+        );
+        const_assert_eq!(
+            SetInterface::OPCODE.raw(),
+            sys::USBDEVFS_SETINTERFACE as RawOpcode
+        );
+        const_assert_eq!(
+            SetConfiguration::OPCODE.raw(),
+            sys::USBDEVFS_SETCONFIGURATION as RawOpcode
+        );
+        const_assert_eq!(
+            ClearHalt::OPCODE.raw(),
+            sys::USBDEVFS_CLEAR_HALT as RawOpcode
+        );
+        const_assert_eq!(Reset::OPCODE.raw(), sys::USBDEVFS_RESET as RawOpcode);
+        const_assert_eq!(
+            GetCapabilities::OPCODE.raw(),
+            sys::USBDEVFS_GET_CAPABILITIES as RawOpcode
+        );
+    }
+
+    #[test]
+    fn usbdevfs_layouts() {
+        #[cfg(target_pointer_width = "64")]
+        {
+            const_assert_eq!(size_of::<CtrlTransfer>(), 24);
+            const_assert_eq!(size_of::<BulkTransfer>(), 24);
+        }
+        #[cfg(target_pointer_width = "32")]
+        {
+            const_assert_eq!(size_of::<CtrlTransfer>(), 16);
+            const_assert_eq!(size_of::<BulkTransfer>(), 16);
+        }
+        const_assert_eq!(align_of::<CtrlTransfer>(), align_of::<usize>());
+        const_assert_eq!(align_of::<BulkTransfer>(), align_of::<usize>());
+        const_assert_eq!(size_of::<RawSetInterface>(), 8);
+        const_assert_eq!(align_of::<RawSetInterface>(), 4);
+    }
+}
@@ -0,0 +1,515 @@
+//! Linux vhost functions.
+//!
+//! vhost moves the data plane of virtio devices into the kernel. The
+//! functions in this module take a file descriptor opened from a vhost
+//! device node such as `/dev/vhost-net` or `/dev/vhost-vsock`, which must
+//! first be claimed with [`ioctl_vhost_set_owner`].
+//!
+//! # References
+//!  - [Linux]
+//!
+//! [Linux]: https://docs.kernel.org/virt/vhost.html
+
+#![allow(unsafe_code)]
+
+use crate::backend::c;
+use crate::fd::{AsFd, AsRawFd, BorrowedFd};
+use crate::{io, ioctl};
+#[cfg(feature = "alloc")]
+use {crate::alloc::vec, crate::ioctl::CompileTimeOpcode, core::mem::size_of};
+
+/// `VHOST_VIRTIO`
+const VHOST_VIRTIO: u8 = 0xaf;
+
+/// `struct vhost_memory_region`—A region of guest physical memory backed by
+/// memory of this process, for use with [`ioctl_vhost_set_mem_table`].
+#[repr(C)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct MemoryRegion {
+    /// The guest physical address of the start of the region.
+    pub guest_phys_addr: u64,
+
+    /// The size of the region, in bytes.
+    pub memory_size: u64,
+
+    /// The address in this process of the memory backing the region.
+    pub userspace_addr: u64,
+
+    flags_padding: u64,
+}
+
+impl MemoryRegion {
+    /// Constructs a new `MemoryRegion`.
+    #[inline]
+    pub const fn new(guest_phys_addr: u64, memory_size: u64, userspace_addr: u64) -> Self {
+        Self {
+            guest_phys_addr,
+            memory_size,
+            userspace_addr,
+            flags_padding: 0,
+        }
+    }
+}
+
+/// `struct vhost_vring_addr`—The addresses of the parts of a virtqueue, for
+/// use with [`ioctl_vhost_set_vring_addr`].
+#[repr(C)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct VringAddr {
+    /// The index of the virtqueue.
+    pub index: u32,
+
+    /// `VHOST_VRING_F_LOG` (1) to log writes to the used ring, or 0.
+    pub flags: u32,
+
+    /// The address in this process of the descriptor table.
+    pub desc_user_addr: u64,
+
+    /// The address in this process of the used ring.
+    pub used_user_addr: u64,
+
+    /// The address in this process of the available ring.
+    pub avail_user_addr: u64,
+
+    /// The guest physical address of the used ring, for logging.
+    pub log_guest_addr: u64,
+}
+
+/// `struct vhost_vring_state`
+#[repr(C)]
+struct VringState {
+    index: c::c_uint,
+    num: c::c_uint,
+}
+
+/// `struct vhost_vring_file`
+#[repr(C)]
+struct VringFile {
+    index: c::c_uint,
+    fd: c::c_int,
+}
+
+/// `VHOST_SET_OWNER`
+type SetOwner = ioctl::NoneOpcode<VHOST_VIRTIO, 0x01, ()>;
+/// `VHOST_RESET_OWNER`
+type ResetOwner = ioctl::NoneOpcode<VHOST_VIRTIO, 0x02, ()>;
+/// `VHOST_GET_FEATURES`
+type GetFeatures = ioctl::ReadOpcode<VHOST_VIRTIO, 0x00, u64>;
+/// `VHOST_SET_FEATURES`
+type SetFeatures = ioctl::WriteOpcode<VHOST_VIRTIO, 0x00, u64>;
+/// `VHOST_SET_VRING_NUM`
+type SetVringNum = ioctl::WriteOpcode<VHOST_VIRTIO, 0x10, VringState>;
+/// `VHOST_SET_VRING_ADDR`
+type SetVringAddr = ioctl::WriteOpcode<VHOST_VIRTIO, 0x11, VringAddr>;
+/// `VHOST_SET_VRING_BASE`
+type SetVringBase = ioctl::WriteOpcode<VHOST_VIRTIO, 0x12, VringState>;
+/// `VHOST_GET_VRING_BASE`
+type GetVringBase = ioctl::ReadWriteOpcode<VHOST_VIRTIO, 0x12, VringState>;
+/// `VHOST_VSOCK_SET_GUEST_CID`
+type VsockSetGuestCid = ioctl::WriteOpcode<VHOST_VIRTIO, 0x60, u64>;
+/// `VHOST_VSOCK_SET_RUNNING`
+type VsockSetRunning = ioctl::WriteOpcode<VHOST_VIRTIO, 0x61, c::c_int>;
+
+/// `VHOST_SET_MEM_TABLE`, whose `struct vhost_memory` argument is a `u32`
+/// count and padding, followed by the regions.
+#[cfg(feature = "alloc")]
+type SetMemTable = ioctl::WriteOpcode<VHOST_VIRTIO, 0x03, u64>;
+
+/// `_IOW(VHOST_VIRTIO, NUM, struct vhost_vring_file)`—The encoding of the
+/// opcodes that take a `struct vhost_vring_file`.
+type VringFileOpcode<const NUM: u8> = ioctl::WriteOpcode<VHOST_VIRTIO, NUM, VringFile>;
+
+const SET_VRING_KICK: u8 = 0x20;
+const SET_VRING_CALL: u8 = 0x21;
+const SET_VRING_ERR: u8 = 0x22;
+const NET_SET_BACKEND: u8 = 0x30;
+
+/// Performs a vhost `ioctl` that reads a `struct vhost_vring_file`.
+///
+/// # Safety
+///
+/// `NUM` must be a vhost `_IOW` opcode number that reads a
+/// `struct vhost_vring_file`.
+unsafe fn vring_file_ioctl<const NUM: u8>(
+    fd: BorrowedFd<'_>,
+    index: u32,
+    file: Option<BorrowedFd<'_>>,
+) -> io::Result<()> {
+    let ctl = ioctl::Setter::<VringFileOpcode<NUM>, VringFile>::new(VringFile {
+        index: index as c::c_uint,
+        fd: file.map_or(-1, |file| file.as_raw_fd()),
+    });
+    ioctl::ioctl(fd, ctl)
+}
+
+/// `ioctl(fd, VHOST_SET_OWNER)`—Makes this process the owner of a vhost
+/// device.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/virt/vhost.html
+#[inline]
+#[doc(alias = "VHOST_SET_OWNER")]
+pub fn ioctl_vhost_set_owner<Fd: AsFd>(fd: Fd) -> io::Result<()> {
+    // SAFETY: `VHOST_SET_OWNER` doesn't take an argument.
+    unsafe {
+        let ctl = ioctl::NoArg::<SetOwner>::new();
+        ioctl::ioctl(fd, ctl)
+    }
+}
+
+/// `ioctl(fd, VHOST_RESET_OWNER)`—Resets a vhost device and releases its
+/// ownership.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/virt/vhost.html
+#[inline]
+#[doc(alias = "VHOST_RESET_OWNER")]
+pub fn ioctl_vhost_reset_owner<Fd: AsFd>(fd: Fd) -> io::Result<()> {
+    // SAFETY: `VHOST_RESET_OWNER` doesn't take an argument.
+    unsafe {
+        let ctl = ioctl::NoArg::<ResetOwner>::new();
+        ioctl::ioctl(fd, ctl)
+    }
+}
+
+/// `ioctl(fd, VHOST_GET_FEATURES)`—Returns the virtio feature bits the
+/// vhost device supports.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/virt/vhost.html
+#[inline]
+#[doc(alias = "VHOST_GET_FEATURES")]
+pub fn ioctl_vhost_get_features<Fd: AsFd>(fd: Fd) -> io::Result<u64> {
+    // SAFETY: `VHOST_GET_FEATURES` is a getter opcode that gets a `u64`.
+    unsafe {
+        let ctl = ioctl::Getter::<GetFeatures, u64>::new();
+        ioctl::ioctl(fd, ctl)
+    }
+}
+
+/// `ioctl(fd, VHOST_SET_FEATURES, &features)`—Sets the virtio feature bits
+/// negotiated with the guest.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/virt/vhost.html
+#[inline]
+#[doc(alias = "VHOST_SET_FEATURES")]
+pub fn ioctl_vhost_set_features<Fd: AsFd>(fd: Fd, features: u64) -> io::Result<()> {
+    // SAFETY: `VHOST_SET_FEATURES` is a setter opcode that sets a `u64`.
+    unsafe {
+        let ctl = ioctl::Setter::<SetFeatures, u64>::new(features);
+        ioctl::ioctl(fd, ctl)
+    }
+}
+
+/// `ioctl(fd, VHOST_SET_MEM_TABLE, memory)`—Sets the guest physical memory
+/// layout that the vhost device translates virtqueue addresses with.
+///
+/// # Safety
+///
+/// The memory described by `regions` must remain valid for as long as the
+/// vhost device uses it, and the device may read and write it at any time.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/virt/vhost.html
+#[cfg(feature = "alloc")]
+#[inline]
+#[doc(alias = "VHOST_SET_MEM_TABLE")]
+pub unsafe fn ioctl_vhost_set_mem_table<Fd: AsFd>(
+    fd: Fd,
+    regions: &[MemoryRegion],
+) -> io::Result<()> {
+    let nregions = u32::try_from(regions.len()).map_err(|_| io::Errno::INVAL)?;
+
+    // A `struct vhost_memory` is a `u32` count and padding, followed by the
+    // regions. Use a `u64` buffer so that the regions are aligned.
+    let mut buf = vec![0_u64; 1 + regions.len() * (size_of::<MemoryRegion>() / 8)];
+    let base = buf.as_mut_ptr();
+    base.cast::<u32>().write(nregions);
+    base.add(1)
+        .cast::<MemoryRegion>()
+        .copy_from_nonoverlapping(regions.as_ptr(), regions.len());
+
+    ioctl::ioctl_with_opcode(fd.as_fd(), SetMemTable::OPCODE, base.cast()).map(drop)
+}
+
+/// `ioctl(fd, VHOST_SET_VRING_NUM, state)`—Sets the number of descriptors
+/// in a virtqueue.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/virt/vhost.html
+#[inline]
+#[doc(alias = "VHOST_SET_VRING_NUM")]
+pub fn ioctl_vhost_set_vring_num<Fd: AsFd>(fd: Fd, index: u32, num: u32) -> io::Result<()> {
+    // SAFETY: `VHOST_SET_VRING_NUM` reads a `struct vhost_vring_state`.
+    unsafe {
+        let ctl = ioctl::Setter::<SetVringNum, VringState>::new(VringState { index, num });
+        ioctl::ioctl(fd, ctl)
+    }
+}
+
+/// `ioctl(fd, VHOST_SET_VRING_ADDR, addr)`—Sets the addresses of the parts
+/// of a virtqueue.
+///
+/// # Safety
+///
+/// The addresses in `addr` must point to memory that remains valid for as
+/// long as the vhost device uses the virtqueue, and the device may read and
+/// write it at any time.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/virt/vhost.html
+#[inline]
+#[doc(alias = "VHOST_SET_VRING_ADDR")]
+pub unsafe fn ioctl_vhost_set_vring_addr<Fd: AsFd>(fd: Fd, addr: &VringAddr) -> io::Result<()> {
+    let ctl = ioctl::Setter::<SetVringAddr, VringAddr>::new(*addr);
+    ioctl::ioctl(fd, ctl)
+}
+
+/// `ioctl(fd, VHOST_SET_VRING_BASE, state)`—Sets the index of the next
+/// available descriptor that the vhost device will process in a virtqueue.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/virt/vhost.html
+#[inline]
+#[doc(alias = "VHOST_SET_VRING_BASE")]
+pub fn ioctl_vhost_set_vring_base<Fd: AsFd>(fd: Fd, index: u32, base: u32) -> io::Result<()> {
+    // SAFETY: `VHOST_SET_VRING_BASE` reads a `struct vhost_vring_state`.
+    unsafe {
+        let ctl = ioctl::Setter::<SetVringBase, VringState>::new(VringState { index, num: base });
+        ioctl::ioctl(fd, ctl)
+    }
+}
+
+/// `ioctl(fd, VHOST_GET_VRING_BASE, state)`—Stops a virtqueue and returns
+/// the index of the next available descriptor that the vhost device would
+/// have processed.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/virt/vhost.html
+#[inline]
+#[doc(alias = "VHOST_GET_VRING_BASE")]
+pub fn ioctl_vhost_get_vring_base<Fd: AsFd>(fd: Fd, index: u32) -> io::Result<u32> {
+    let mut state = VringState { index, num: 0 };
+    // SAFETY: `VHOST_GET_VRING_BASE` reads and writes a
+    // `struct vhost_vring_state`.
+    unsafe {
+        let ctl = ioctl::Updater::<GetVringBase, VringState>::new(&mut state);
+        ioctl::ioctl(fd, ctl)?;
+    }
+    Ok(state.num)
+}
+
+/// `ioctl(fd, VHOST_SET_VRING_KICK, file)`—Sets the eventfd that the guest
+/// signals when it adds buffers to a virtqueue, or `None` to stop polling.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/virt/vhost.html
+#[inline]
+#[doc(alias = "VHOST_SET_VRING_KICK")]
+pub fn ioctl_vhost_set_vring_kick<Fd: AsFd>(
+    fd: Fd,
+    index: u32,
+    kick: Option<BorrowedFd<'_>>,
+) -> io::Result<()> {
+    // SAFETY: `VHOST_SET_VRING_KICK` reads a `struct vhost_vring_file`.
+    unsafe { vring_file_ioctl::<SET_VRING_KICK>(fd.as_fd(), index, kick) }
+}
+
+/// `ioctl(fd, VHOST_SET_VRING_CALL, file)`—Sets the eventfd that the vhost
+/// device signals when it has used buffers of a virtqueue, or `None` to
+/// stop signaling.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/virt/vhost.html
+#[inline]
+#[doc(alias = "VHOST_SET_VRING_CALL")]
+pub fn ioctl_vhost_set_vring_call<Fd: AsFd>(
+    fd: Fd,
+    index: u32,
+    call: Option<BorrowedFd<'_>>,
+) -> io::Result<()> {
+    // SAFETY: `VHOST_SET_VRING_CALL` reads a `struct vhost_vring_file`.
+    unsafe { vring_file_ioctl::<SET_VRING_CALL>(fd.as_fd(), index, call) }
+}
+
+/// `ioctl(fd, VHOST_SET_VRING_ERR, file)`—Sets the eventfd that the vhost
+/// device signals on errors in a virtqueue, or `None` to stop signaling.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/virt/vhost.html
+#[inline]
+#[doc(alias = "VHOST_SET_VRING_ERR")]
+pub fn ioctl_vhost_set_vring_err<Fd: AsFd>(
+    fd: Fd,
+    index: u32,
+    err: Option<BorrowedFd<'_>>,
+) -> io::Result<()> {
+    // SAFETY: `VHOST_SET_VRING_ERR` reads a `struct vhost_vring_file`.
+    unsafe { vring_file_ioctl::<SET_VRING_ERR>(fd.as_fd(), index, err) }
+}
+
+/// `ioctl(fd, VHOST_NET_SET_BACKEND, file)`—Sets the socket or tap device
+/// that a vhost-net virtqueue sends and receives packets on, or `None` to
+/// detach it.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/virt/vhost.html
+#[inline]
+#[doc(alias = "VHOST_NET_SET_BACKEND")]
+pub fn ioctl_vhost_net_set_backend<Fd: AsFd>(
+    fd: Fd,
+    index: u32,
+    backend: Option<BorrowedFd<'_>>,
+) -> io::Result<()> {
+    // SAFETY: `VHOST_NET_SET_BACKEND` reads a `struct vhost_vring_file`.
+    unsafe { vring_file_ioctl::<NET_SET_BACKEND>(fd.as_fd(), index, backend) }
+}
+
+/// `ioctl(fd, VHOST_VSOCK_SET_GUEST_CID, &cid)`—Sets the context ID of the
+/// guest of a vhost-vsock device.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/virt/vhost.html
+#[inline]
+#[doc(alias = "VHOST_VSOCK_SET_GUEST_CID")]
+pub fn ioctl_vhost_vsock_set_guest_cid<Fd: AsFd>(fd: Fd, cid: u64) -> io::Result<()> {
+    // SAFETY: `VHOST_VSOCK_SET_GUEST_CID` is a setter opcode that sets a
+    // `u64`.
+    unsafe {
+        let ctl = ioctl::Setter::<VsockSetGuestCid, u64>::new(cid);
+        ioctl::ioctl(fd, ctl)
+    }
+}
+
+/// `ioctl(fd, VHOST_VSOCK_SET_RUNNING, &running)`—Starts or stops a
+/// vhost-vsock device.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/virt/vhost.html
+#[inline]
+#[doc(alias = "VHOST_VSOCK_SET_RUNNING")]
+pub fn ioctl_vhost_vsock_set_running<Fd: AsFd>(fd: Fd, running: bool) -> io::Result<()> {
+    // SAFETY: `VHOST_VSOCK_SET_RUNNING` is a setter opcode that sets a
+    // `c_int`.
+    unsafe {
+        let ctl = ioctl::Setter::<VsockSetRunning, c::c_int>::new(c::c_int::from(running));
+        ioctl::ioctl(fd, ctl)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ioctl::{CompileTimeOpcode, RawOpcode};
+    use core::mem::{align_of, size_of};
+
+    #[cfg(not(any(
+        // These have no ioctl opcodes defined in linux_raw_sys.
+        target_arch = "sparc",
+        target_arch = "sparc64"
+    )))]
+    #[test]
+    fn vhost_opcodes() {
+        use linux_raw_sys::ioctl as sys;
+
+        const_assert_eq!(SetOwner::OPCODE.raw(), sys::VHOST_SET_OWNER as RawOpcode);
+        const_assert_eq!(
+            ResetOwner::OPCODE.raw(),
+            sys::VHOST_RESET_OWNER as RawOpcode
+        );
+        const_assert_eq!(
+            GetFeatures::OPCODE.raw(),
+            sys::VHOST_GET_FEATURES as RawOpcode
+        );
+        const_assert_eq!(
+            SetFeatures::OPCODE.raw(),
+            sys::VHOST_SET_FEATURES as RawOpcode
+        );
+        const_assert_eq!(
+            SetVringNum::OPCODE.raw(),
+            sys::VHOST_SET_VRING_NUM as RawOpcode
+        );
+        const_assert_eq!(
+            SetVringAddr::OPCODE.raw(),
+            sys::VHOST_SET_VRING_ADDR as RawOpcode
+        );
+        const_assert_eq!(
+            SetVringBase::OPCODE.raw(),
+            sys::VHOST_SET_VRING_BASE as RawOpcode
+        );
+        const_assert_eq!(
+            VsockSetGuestCid::OPCODE.raw(),
+            sys::VHOST_VSOCK_SET_GUEST_CID as RawOpcode
+        );
+        const_assert_eq!(
+            VsockSetRunning::OPCODE.raw(),
+            sys::VHOST_VSOCK_SET_RUNNING as RawOpcode
+        );
+        const_assert_eq!(
+            GetVringBase::OPCODE.raw(),
+            sys::VHOST_GET_VRING_BASE as RawOpcode
+        );
+        #[cfg(feature = "alloc")]
+        const_assert_eq!(
+            SetMemTable::OPCODE.raw(),
+            sys::VHOST_SET_MEM_TABLE as RawOpcode
+        );
+        const_assert_eq!(
+            VringFileOpcode::<SET_VRING_KICK>::OPCODE.raw(),
+            sys::VHOST_SET_VRING_KICK as RawOpcode
+        );
+        const_assert_eq!(
+            VringFileOpcode::<SET_VRING_CALL>::OPCODE.raw(),
+            sys::VHOST_SET_VRING_CALL as RawOpcode
+        );
+        const_assert_eq!(
+            VringFileOpcode::<SET_VRING_ERR>::OPCODE.raw(),
+            sys::VHOST_SET_VRING_ERR as RawOpcode
+        );
+        const_assert_eq!(
+            VringFileOpcode::<NET_SET_BACKEND>::OPCODE.raw(),
+            sys::VHOST_NET_SET_BACKEND as RawOpcode
+        );
+    }
+
+    #[test]
+    fn vhost_layouts() {
+        const_assert_eq!(size_of::<MemoryRegion>(), 32);
+        const_assert_eq!(align_of::<MemoryRegion>(), align_of::<u64>());
+        const_assert_eq!(size_of::<VringAddr>(), 40);
+        const_assert_eq!(align_of::<VringAddr>(), align_of::<u64>());
+        const_assert_eq!(size_of::<VringState>(), 8);
+        const_assert_eq!(size_of::<VringFile>(), 8);
+    }
+}
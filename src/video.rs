@@ -0,0 +1,770 @@
+//! Linux Video4Linux2 (V4L2) functions.
+//!
+//! The functions in this module take a file descriptor opened from a video
+//! device node such as `/dev/video0`. They cover the core of a streaming
+//! capture or output session with single-planar formats and memory-mapped
+//! buffers: querying the device, negotiating a format, requesting and
+//! mapping buffers, queueing and dequeueing them, and starting and stopping
+//! the stream.
+//!
+//! # References
+//!  - [Linux]
+//!
+//! [Linux]: https://docs.kernel.org/userspace-api/media/v4l/v4l2.html
+
+#![allow(unsafe_code)]
+
+use crate::backend::c;
+use crate::fd::AsFd;
+use crate::{io, ioctl};
+use bitflags::bitflags;
+
+/// `V4L2_BUF_TYPE_*`—The type of a buffer or data stream.
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct BufType(u32);
+
+impl BufType {
+    /// `V4L2_BUF_TYPE_VIDEO_CAPTURE`
+    #[doc(alias = "V4L2_BUF_TYPE_VIDEO_CAPTURE")]
+    pub const VIDEO_CAPTURE: Self = Self(1);
+
+    /// `V4L2_BUF_TYPE_VIDEO_OUTPUT`
+    #[doc(alias = "V4L2_BUF_TYPE_VIDEO_OUTPUT")]
+    pub const VIDEO_OUTPUT: Self = Self(2);
+
+    /// `V4L2_BUF_TYPE_VIDEO_OVERLAY`
+    #[doc(alias = "V4L2_BUF_TYPE_VIDEO_OVERLAY")]
+    pub const VIDEO_OVERLAY: Self = Self(3);
+
+    /// `V4L2_BUF_TYPE_VIDEO_CAPTURE_MPLANE`
+    #[doc(alias = "V4L2_BUF_TYPE_VIDEO_CAPTURE_MPLANE")]
+    pub const VIDEO_CAPTURE_MPLANE: Self = Self(9);
+
+    /// `V4L2_BUF_TYPE_VIDEO_OUTPUT_MPLANE`
+    #[doc(alias = "V4L2_BUF_TYPE_VIDEO_OUTPUT_MPLANE")]
+    pub const VIDEO_OUTPUT_MPLANE: Self = Self(10);
+
+    /// `V4L2_BUF_TYPE_META_CAPTURE`
+    #[doc(alias = "V4L2_BUF_TYPE_META_CAPTURE")]
+    pub const META_CAPTURE: Self = Self(13);
+
+    /// Constructs a `BufType` from a raw `V4L2_BUF_TYPE_*` value.
+    #[inline]
+    pub const fn from_raw(raw: u32) -> Self {
+        Self(raw)
+    }
+
+    /// Returns the raw `V4L2_BUF_TYPE_*` value.
+    #[inline]
+    pub const fn as_raw(self) -> u32 {
+        self.0
+    }
+}
+
+/// `V4L2_MEMORY_*`—How the memory of a buffer is provided.
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Memory(u32);
+
+impl Memory {
+    /// `V4L2_MEMORY_MMAP`—Buffers are allocated by the driver and mapped
+    /// with [`mmap_buffer`].
+    #[doc(alias = "V4L2_MEMORY_MMAP")]
+    pub const MMAP: Self = Self(1);
+
+    /// `V4L2_MEMORY_USERPTR`—Buffers are allocated by the application.
+    #[doc(alias = "V4L2_MEMORY_USERPTR")]
+    pub const USERPTR: Self = Self(2);
+
+    /// `V4L2_MEMORY_DMABUF`—Buffers are DMA-BUF file descriptors.
+    #[doc(alias = "V4L2_MEMORY_DMABUF")]
+    pub const DMABUF: Self = Self(4);
+
+    /// Constructs a `Memory` from a raw `V4L2_MEMORY_*` value.
+    #[inline]
+    pub const fn from_raw(raw: u32) -> Self {
+        Self(raw)
+    }
+
+    /// Returns the raw `V4L2_MEMORY_*` value.
+    #[inline]
+    pub const fn as_raw(self) -> u32 {
+        self.0
+    }
+}
+
+/// `V4L2_PIX_FMT_*`—A pixel format, identified by a four-character code.
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct PixelFormat(u32);
+
+impl PixelFormat {
+    /// `V4L2_PIX_FMT_YUYV`—Packed YUV 4:2:2.
+    #[doc(alias = "V4L2_PIX_FMT_YUYV")]
+    pub const YUYV: Self = Self::from_fourcc(*b"YUYV");
+
+    /// `V4L2_PIX_FMT_MJPEG`—Motion JPEG.
+    #[doc(alias = "V4L2_PIX_FMT_MJPEG")]
+    pub const MJPEG: Self = Self::from_fourcc(*b"MJPG");
+
+    /// `V4L2_PIX_FMT_NV12`—Semi-planar YUV 4:2:0.
+    #[doc(alias = "V4L2_PIX_FMT_NV12")]
+    pub const NV12: Self = Self::from_fourcc(*b"NV12");
+
+    /// `V4L2_PIX_FMT_RGB24`—Packed 8-bit RGB.
+    #[doc(alias = "V4L2_PIX_FMT_RGB24")]
+    pub const RGB24: Self = Self::from_fourcc(*b"RGB3");
+
+    /// `V4L2_PIX_FMT_GREY`—8-bit greyscale.
+    #[doc(alias = "V4L2_PIX_FMT_GREY")]
+    pub const GREY: Self = Self::from_fourcc(*b"GREY");
+
+    /// `v4l2_fourcc(a, b, c, d)`—Constructs a `PixelFormat` from its
+    /// four-character code.
+    #[inline]
+    pub const fn from_fourcc(code: [u8; 4]) -> Self {
+        Self(u32::from_le_bytes(code))
+    }
+
+    /// Returns the four-character code of this format.
+    #[inline]
+    pub const fn fourcc(self) -> [u8; 4] {
+        self.0.to_le_bytes()
+    }
+
+    /// Constructs a `PixelFormat` from a raw `V4L2_PIX_FMT_*` value.
+    #[inline]
+    pub const fn from_raw(raw: u32) -> Self {
+        Self(raw)
+    }
+
+    /// Returns the raw `V4L2_PIX_FMT_*` value.
+    #[inline]
+    pub const fn as_raw(self) -> u32 {
+        self.0
+    }
+}
+
+bitflags! {
+    /// `V4L2_CAP_*` constants returned by [`ioctl_vidioc_querycap`].
+    #[repr(transparent)]
+    #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    pub struct Capabilities: u32 {
+        /// `V4L2_CAP_VIDEO_CAPTURE`
+        const VIDEO_CAPTURE = 0x0000_0001;
+        /// `V4L2_CAP_VIDEO_OUTPUT`
+        const VIDEO_OUTPUT = 0x0000_0002;
+        /// `V4L2_CAP_VIDEO_OVERLAY`
+        const VIDEO_OVERLAY = 0x0000_0004;
+        /// `V4L2_CAP_VIDEO_CAPTURE_MPLANE`
+        const VIDEO_CAPTURE_MPLANE = 0x0000_1000;
+        /// `V4L2_CAP_VIDEO_OUTPUT_MPLANE`
+        const VIDEO_OUTPUT_MPLANE = 0x0000_2000;
+        /// `V4L2_CAP_VIDEO_M2M_MPLANE`
+        const VIDEO_M2M_MPLANE = 0x0000_4000;
+        /// `V4L2_CAP_VIDEO_M2M`
+        const VIDEO_M2M = 0x0000_8000;
+        /// `V4L2_CAP_TUNER`
+        const TUNER = 0x0001_0000;
+        /// `V4L2_CAP_AUDIO`
+        const AUDIO = 0x0002_0000;
+        /// `V4L2_CAP_RADIO`
+        const RADIO = 0x0004_0000;
+        /// `V4L2_CAP_EXT_PIX_FORMAT`
+        const EXT_PIX_FORMAT = 0x0020_0000;
+        /// `V4L2_CAP_META_CAPTURE`
+        const META_CAPTURE = 0x0080_0000;
+        /// `V4L2_CAP_READWRITE`—The device supports `read` and `write`.
+        const READWRITE = 0x0100_0000;
+        /// `V4L2_CAP_STREAMING`—The device supports streaming I/O.
+        const STREAMING = 0x0400_0000;
+        /// `V4L2_CAP_META_OUTPUT`
+        const META_OUTPUT = 0x0800_0000;
+        /// `V4L2_CAP_TOUCH`
+        const TOUCH = 0x1000_0000;
+        /// `V4L2_CAP_IO_MC`
+        const IO_MC = 0x2000_0000;
+        /// `V4L2_CAP_DEVICE_CAPS`—The `device_caps` field is valid.
+        const DEVICE_CAPS = 0x8000_0000;
+
+        /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+        const _ = !0;
+    }
+}
+
+bitflags! {
+    /// `V4L2_BUF_FLAG_*` constants for use with [`Buffer`].
+    #[repr(transparent)]
+    #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    pub struct BufferFlags: u32 {
+        /// `V4L2_BUF_FLAG_MAPPED`
+        const MAPPED = 0x0000_0001;
+        /// `V4L2_BUF_FLAG_QUEUED`
+        const QUEUED = 0x0000_0002;
+        /// `V4L2_BUF_FLAG_DONE`
+        const DONE = 0x0000_0004;
+        /// `V4L2_BUF_FLAG_KEYFRAME`
+        const KEYFRAME = 0x0000_0008;
+        /// `V4L2_BUF_FLAG_PFRAME`
+        const PFRAME = 0x0000_0010;
+        /// `V4L2_BUF_FLAG_BFRAME`
+        const BFRAME = 0x0000_0020;
+        /// `V4L2_BUF_FLAG_ERROR`—The buffer's data may be corrupted.
+        const ERROR = 0x0000_0040;
+        /// `V4L2_BUF_FLAG_TIMECODE`
+        const TIMECODE = 0x0000_0100;
+        /// `V4L2_BUF_FLAG_PREPARED`
+        const PREPARED = 0x0000_0400;
+        /// `V4L2_BUF_FLAG_TIMESTAMP_MONOTONIC`
+        const TIMESTAMP_MONOTONIC = 0x0000_2000;
+        /// `V4L2_BUF_FLAG_TIMESTAMP_COPY`
+        const TIMESTAMP_COPY = 0x0000_4000;
+        /// `V4L2_BUF_FLAG_LAST`—The last buffer of a stream.
+        const LAST = 0x0010_0000;
+
+        /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+        const _ = !0;
+    }
+}
+
+/// `struct v4l2_capability`—A description of a device, returned by
+/// [`ioctl_vidioc_querycap`].
+#[repr(C)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Capability {
+    /// The name of the driver, NUL-terminated.
+    pub driver: [u8; 16],
+
+    /// The name of the device, NUL-terminated.
+    pub card: [u8; 32],
+
+    /// The location of the device, NUL-terminated.
+    pub bus_info: [u8; 32],
+
+    /// The version of the driver.
+    pub version: u32,
+
+    /// The capabilities of the physical device as a whole.
+    pub capabilities: Capabilities,
+
+    /// The capabilities of this device node.
+    pub device_caps: Capabilities,
+
+    reserved: [u32; 3],
+}
+
+/// `struct v4l2_pix_format`—A single-planar image format, for use with
+/// [`ioctl_vidioc_g_fmt`] and [`ioctl_vidioc_s_fmt`].
+#[repr(C)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct PixFormat {
+    /// The width of the image, in pixels.
+    pub width: u32,
+
+    /// The height of the image, in pixels.
+    pub height: u32,
+
+    /// The pixel format, a [`PixelFormat`] value.
+    pub pixelformat: u32,
+
+    /// The field order, a `V4L2_FIELD_*` value.
+    pub field: u32,
+
+    /// The distance between lines, in bytes.
+    pub bytesperline: u32,
+
+    /// The size of a buffer that holds an image, in bytes.
+    pub sizeimage: u32,
+
+    /// The color space, a `V4L2_COLORSPACE_*` value.
+    pub colorspace: u32,
+
+    /// Private driver data, or `V4L2_PIX_FMT_PRIV_MAGIC` if the extended
+    /// fields are valid.
+    pub priv_: u32,
+
+    /// Format flags, `V4L2_PIX_FMT_FLAG_*` values.
+    pub flags: u32,
+
+    /// The Y'CbCr or HSV encoding.
+    pub ycbcr_enc: u32,
+
+    /// The quantization range.
+    pub quantization: u32,
+
+    /// The transfer function.
+    pub xfer_func: u32,
+}
+
+/// `struct v4l2_format`
+#[repr(C)]
+struct Format {
+    type_: u32,
+    fmt: FormatUnion,
+}
+
+/// The `fmt` union of `struct v4l2_format`, which has pointer alignment
+/// because some of its members contain pointers.
+#[repr(C)]
+union FormatUnion {
+    pix: PixFormat,
+    raw_data: [u8; 200],
+    _align: [*mut c::c_void; 0],
+}
+
+/// `struct v4l2_requestbuffers`
+#[repr(C)]
+struct RequestBuffers {
+    count: u32,
+    type_: u32,
+    memory: u32,
+    capabilities: u32,
+    flags: u8,
+    reserved: [u8; 3],
+}
+
+/// `struct v4l2_timecode`
+#[repr(C)]
+struct Timecode {
+    type_: u32,
+    flags: u32,
+    frames: u8,
+    seconds: u8,
+    minutes: u8,
+    hours: u8,
+    userbits: [u8; 4],
+}
+
+/// `struct v4l2_buffer`
+#[repr(C)]
+struct RawBuffer {
+    index: u32,
+    type_: u32,
+    bytesused: u32,
+    flags: u32,
+    field: u32,
+    timestamp_sec: c::c_long,
+    timestamp_usec: c::c_long,
+    timecode: Timecode,
+    sequence: u32,
+    memory: u32,
+    m: usize,
+    length: u32,
+    reserved2: u32,
+    request_fd: i32,
+}
+
+/// `struct v4l2_buffer`—A buffer, for use with [`ioctl_vidioc_querybuf`],
+/// [`ioctl_vidioc_qbuf`], and [`ioctl_vidioc_dqbuf`].
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Buffer {
+    /// The index of the buffer.
+    pub index: u32,
+
+    /// The type of the buffer.
+    pub type_: BufType,
+
+    /// The number of bytes of data in the buffer.
+    pub bytesused: u32,
+
+    /// Buffer flags.
+    pub flags: BufferFlags,
+
+    /// The field order, a `V4L2_FIELD_*` value.
+    pub field: u32,
+
+    /// The seconds part of the time the buffer was captured.
+    pub timestamp_sec: c::c_long,
+
+    /// The microseconds part of the time the buffer was captured.
+    pub timestamp_usec: c::c_long,
+
+    /// The sequence number of the frame.
+    pub sequence: u32,
+
+    /// How the memory of the buffer is provided.
+    pub memory: Memory,
+
+    /// For [`Memory::MMAP`], the offset to pass to `mmap`; for
+    /// [`Memory::USERPTR`], the address of the buffer; and for
+    /// [`Memory::DMABUF`], the file descriptor of the buffer.
+    pub m: usize,
+
+    /// The size of the buffer, in bytes.
+    pub length: u32,
+}
+
+impl Buffer {
+    /// Constructs a `Buffer` with the given type, memory, and index, and all
+    /// other fields zeroed.
+    #[inline]
+    pub const fn new(type_: BufType, memory: Memory, index: u32) -> Self {
+        Self {
+            index,
+            type_,
+            bytesused: 0,
+            flags: BufferFlags::empty(),
+            field: 0,
+            timestamp_sec: 0,
+            timestamp_usec: 0,
+            sequence: 0,
+            memory,
+            m: 0,
+            length: 0,
+        }
+    }
+
+    fn from_raw(raw: &RawBuffer) -> Self {
+        Self {
+            index: raw.index,
+            type_: BufType(raw.type_),
+            bytesused: raw.bytesused,
+            flags: BufferFlags::from_bits_retain(raw.flags),
+            field: raw.field,
+            timestamp_sec: raw.timestamp_sec,
+            timestamp_usec: raw.timestamp_usec,
+            sequence: raw.sequence,
+            memory: Memory(raw.memory),
+            m: raw.m,
+            length: raw.length,
+        }
+    }
+
+    fn to_raw(self) -> RawBuffer {
+        RawBuffer {
+            index: self.index,
+            type_: self.type_.0,
+            bytesused: self.bytesused,
+            flags: self.flags.bits(),
+            field: self.field,
+            timestamp_sec: self.timestamp_sec,
+            timestamp_usec: self.timestamp_usec,
+            timecode: Timecode {
+                type_: 0,
+                flags: 0,
+                frames: 0,
+                seconds: 0,
+                minutes: 0,
+                hours: 0,
+                userbits: [0; 4],
+            },
+            sequence: self.sequence,
+            memory: self.memory.0,
+            m: self.m,
+            length: self.length,
+            reserved2: 0,
+            request_fd: 0,
+        }
+    }
+}
+
+/// `VIDIOC_QUERYCAP`
+type QueryCap = ioctl::ReadOpcode<b'V', 0, Capability>;
+/// `VIDIOC_G_FMT`
+type GFmt = ioctl::ReadWriteOpcode<b'V', 4, Format>;
+/// `VIDIOC_S_FMT`
+type SFmt = ioctl::ReadWriteOpcode<b'V', 5, Format>;
+/// `VIDIOC_REQBUFS`
+type ReqBufs = ioctl::ReadWriteOpcode<b'V', 8, RequestBuffers>;
+/// `VIDIOC_QUERYBUF`
+type QueryBuf = ioctl::ReadWriteOpcode<b'V', 9, RawBuffer>;
+/// `VIDIOC_QBUF`
+type QBuf = ioctl::ReadWriteOpcode<b'V', 15, RawBuffer>;
+/// `VIDIOC_DQBUF`
+type DqBuf = ioctl::ReadWriteOpcode<b'V', 17, RawBuffer>;
+/// `VIDIOC_STREAMON`
+type StreamOn = ioctl::WriteOpcode<b'V', 18, c::c_int>;
+/// `VIDIOC_STREAMOFF`
+type StreamOff = ioctl::WriteOpcode<b'V', 19, c::c_int>;
+
+/// `ioctl(fd, VIDIOC_QUERYCAP)`—Returns a description of a device.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/userspace-api/media/v4l/vidioc-querycap.html
+#[inline]
+#[doc(alias = "VIDIOC_QUERYCAP")]
+pub fn ioctl_vidioc_querycap<Fd: AsFd>(fd: Fd) -> io::Result<Capability> {
+    // SAFETY: `VIDIOC_QUERYCAP` is a getter opcode that gets a
+    // `struct v4l2_capability`.
+    unsafe {
+        let ctl = ioctl::Getter::<QueryCap, Capability>::new();
+        ioctl::ioctl(fd, ctl)
+    }
+}
+
+/// `ioctl(fd, VIDIOC_G_FMT, format)`—Returns the current image format for a
+/// single-planar buffer type.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/userspace-api/media/v4l/vidioc-g-fmt.html
+#[inline]
+#[doc(alias = "VIDIOC_G_FMT")]
+pub fn ioctl_vidioc_g_fmt<Fd: AsFd>(fd: Fd, type_: BufType) -> io::Result<PixFormat> {
+    let mut format = Format {
+        type_: type_.0,
+        fmt: FormatUnion { raw_data: [0; 200] },
+    };
+    // SAFETY: `VIDIOC_G_FMT` reads and writes a `struct v4l2_format`.
+    unsafe {
+        let ctl = ioctl::Updater::<GFmt, Format>::new(&mut format);
+        ioctl::ioctl(fd, ctl)?;
+        Ok(format.fmt.pix)
+    }
+}
+
+/// `ioctl(fd, VIDIOC_S_FMT, format)`—Sets the image format for a
+/// single-planar buffer type.
+///
+/// The driver may adjust the format to one it supports; the format it
+/// chose is returned.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/userspace-api/media/v4l/vidioc-g-fmt.html
+#[inline]
+#[doc(alias = "VIDIOC_S_FMT")]
+pub fn ioctl_vidioc_s_fmt<Fd: AsFd>(
+    fd: Fd,
+    type_: BufType,
+    pix: &PixFormat,
+) -> io::Result<PixFormat> {
+    let mut format = Format {
+        type_: type_.0,
+        fmt: FormatUnion { raw_data: [0; 200] },
+    };
+    format.fmt.pix = *pix;
+    // SAFETY: `VIDIOC_S_FMT` reads and writes a `struct v4l2_format`.
+    unsafe {
+        let ctl = ioctl::Updater::<SFmt, Format>::new(&mut format);
+        ioctl::ioctl(fd, ctl)?;
+        Ok(format.fmt.pix)
+    }
+}
+
+/// `ioctl(fd, VIDIOC_REQBUFS, reqbufs)`—Allocates buffers for streaming
+/// I/O, and returns the number of buffers allocated.
+///
+/// Passing a `count` of zero frees the buffers.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/userspace-api/media/v4l/vidioc-reqbufs.html
+#[inline]
+#[doc(alias = "VIDIOC_REQBUFS")]
+pub fn ioctl_vidioc_reqbufs<Fd: AsFd>(
+    fd: Fd,
+    type_: BufType,
+    memory: Memory,
+    count: u32,
+) -> io::Result<u32> {
+    let mut reqbufs = RequestBuffers {
+        count,
+        type_: type_.0,
+        memory: memory.0,
+        capabilities: 0,
+        flags: 0,
+        reserved: [0; 3],
+    };
+    // SAFETY: `VIDIOC_REQBUFS` reads and writes a
+    // `struct v4l2_requestbuffers`.
+    unsafe {
+        let ctl = ioctl::Updater::<ReqBufs, RequestBuffers>::new(&mut reqbufs);
+        ioctl::ioctl(fd, ctl)?;
+    }
+    Ok(reqbufs.count)
+}
+
+/// `ioctl(fd, VIDIOC_QUERYBUF, buffer)`—Returns the status of a buffer,
+/// including, for [`Memory::MMAP`] buffers, where to map it.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/userspace-api/media/v4l/vidioc-querybuf.html
+#[inline]
+#[doc(alias = "VIDIOC_QUERYBUF")]
+pub fn ioctl_vidioc_querybuf<Fd: AsFd>(
+    fd: Fd,
+    type_: BufType,
+    memory: Memory,
+    index: u32,
+) -> io::Result<Buffer> {
+    let mut raw = Buffer::new(type_, memory, index).to_raw();
+    // SAFETY: `VIDIOC_QUERYBUF` reads and writes a `struct v4l2_buffer`.
+    unsafe {
+        let ctl = ioctl::Updater::<QueryBuf, RawBuffer>::new(&mut raw);
+        ioctl::ioctl(fd, ctl)?;
+    }
+    Ok(Buffer::from_raw(&raw))
+}
+
+/// `ioctl(fd, VIDIOC_QBUF, buffer)`—Enqueues a buffer to be filled, for
+/// capture, or emptied, for output.
+///
+/// On success, `buffer` is updated with the driver's view of it.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/userspace-api/media/v4l/vidioc-qbuf.html
+#[inline]
+#[doc(alias = "VIDIOC_QBUF")]
+pub fn ioctl_vidioc_qbuf<Fd: AsFd>(fd: Fd, buffer: &mut Buffer) -> io::Result<()> {
+    let mut raw = buffer.to_raw();
+    // SAFETY: `VIDIOC_QBUF` reads and writes a `struct v4l2_buffer`.
+    unsafe {
+        let ctl = ioctl::Updater::<QBuf, RawBuffer>::new(&mut raw);
+        ioctl::ioctl(fd, ctl)?;
+    }
+    *buffer = Buffer::from_raw(&raw);
+    Ok(())
+}
+
+/// `ioctl(fd, VIDIOC_DQBUF, buffer)`—Dequeues a filled, for capture, or
+/// emptied, for output, buffer.
+///
+/// This blocks until a buffer is available, unless `fd` is in non-blocking
+/// mode, in which case it fails with `AGAIN`.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/userspace-api/media/v4l/vidioc-qbuf.html
+#[inline]
+#[doc(alias = "VIDIOC_DQBUF")]
+pub fn ioctl_vidioc_dqbuf<Fd: AsFd>(fd: Fd, type_: BufType, memory: Memory) -> io::Result<Buffer> {
+    let mut raw = Buffer::new(type_, memory, 0).to_raw();
+    // SAFETY: `VIDIOC_DQBUF` reads and writes a `struct v4l2_buffer`.
+    unsafe {
+        let ctl = ioctl::Updater::<DqBuf, RawBuffer>::new(&mut raw);
+        ioctl::ioctl(fd, ctl)?;
+    }
+    Ok(Buffer::from_raw(&raw))
+}
+
+/// `ioctl(fd, VIDIOC_STREAMON, &type_)`—Starts streaming.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/userspace-api/media/v4l/vidioc-streamon.html
+#[inline]
+#[doc(alias = "VIDIOC_STREAMON")]
+pub fn ioctl_vidioc_streamon<Fd: AsFd>(fd: Fd, type_: BufType) -> io::Result<()> {
+    // SAFETY: `VIDIOC_STREAMON` is a pointer setter opcode that sets a
+    // `c_int`.
+    unsafe {
+        let ctl = ioctl::Setter::<StreamOn, c::c_int>::new(type_.0 as c::c_int);
+        ioctl::ioctl(fd, ctl)
+    }
+}
+
+/// `ioctl(fd, VIDIOC_STREAMOFF, &type_)`—Stops streaming, and dequeues all
+/// buffers.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/userspace-api/media/v4l/vidioc-streamon.html
+#[inline]
+#[doc(alias = "VIDIOC_STREAMOFF")]
+pub fn ioctl_vidioc_streamoff<Fd: AsFd>(fd: Fd, type_: BufType) -> io::Result<()> {
+    // SAFETY: `VIDIOC_STREAMOFF` is a pointer setter opcode that sets a
+    // `c_int`.
+    unsafe {
+        let ctl = ioctl::Setter::<StreamOff, c::c_int>::new(type_.0 as c::c_int);
+        ioctl::ioctl(fd, ctl)
+    }
+}
+
+/// `mmap(NULL, buffer.length, PROT_READ | PROT_WRITE, MAP_SHARED, fd,
+/// buffer.m)`—Maps a [`Memory::MMAP`] buffer returned by
+/// [`ioctl_vidioc_querybuf`] into memory.
+///
+/// # Safety
+///
+/// The mapping is shared with the device, which writes to it while the
+/// buffer is queued. It must be unmapped with [`munmap`] using
+/// `buffer.length` as the length.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/userspace-api/media/v4l/mmap.html
+/// [`munmap`]: crate::mm::munmap
+#[cfg(feature = "mm")]
+#[inline]
+pub unsafe fn mmap_buffer<Fd: AsFd>(fd: Fd, buffer: &Buffer) -> io::Result<*mut c::c_void> {
+    use crate::mm::{mmap, MapFlags, ProtFlags};
+
+    if buffer.memory != Memory::MMAP {
+        return Err(io::Errno::INVAL);
+    }
+    mmap(
+        core::ptr::null_mut(),
+        buffer.length as usize,
+        ProtFlags::READ | ProtFlags::WRITE,
+        MapFlags::SHARED,
+        fd,
+        buffer.m as u64,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ioctl::{CompileTimeOpcode, RawOpcode};
+    use core::mem::{align_of, size_of};
+
+    #[cfg(not(any(
+        // These have no ioctl opcodes defined in linux_raw_sys.
+        target_arch = "sparc",
+        target_arch = "sparc64"
+    )))]
+    #[test]
+    fn video_opcodes() {
+        use linux_raw_sys::ioctl as sys;
+
+        const_assert_eq!(QueryCap::OPCODE.raw(), sys::VIDIOC_QUERYCAP as RawOpcode);
+        const_assert_eq!(GFmt::OPCODE.raw(), sys::VIDIOC_G_FMT as RawOpcode);
+        const_assert_eq!(SFmt::OPCODE.raw(), sys::VIDIOC_S_FMT as RawOpcode);
+        const_assert_eq!(ReqBufs::OPCODE.raw(), sys::VIDIOC_REQBUFS as RawOpcode);
+        const_assert_eq!(QueryBuf::OPCODE.raw(), sys::VIDIOC_QUERYBUF as RawOpcode);
+        const_assert_eq!(QBuf::OPCODE.raw(), sys::VIDIOC_QBUF as RawOpcode);
+        const_assert_eq!(DqBuf::OPCODE.raw(), sys::VIDIOC_DQBUF as RawOpcode);
+        const_assert_eq!(StreamOn::OPCODE.raw(), sys::VIDIOC_STREAMON as RawOpcode);
+        const_assert_eq!(StreamOff::OPCODE.raw(), sys::VIDIOC_STREAMOFF as RawOpcode);
+    }
+
+    #[test]
+    fn video_layouts() {
+        const_assert_eq!(size_of::<Capability>(), 104);
+        const_assert_eq!(align_of::<Capability>(), 4);
+        const_assert_eq!(size_of::<PixFormat>(), 48);
+        const_assert_eq!(align_of::<PixFormat>(), 4);
+        const_assert_eq!(size_of::<RequestBuffers>(), 20);
+        const_assert_eq!(align_of::<RequestBuffers>(), 4);
+        const_assert_eq!(size_of::<Timecode>(), 16);
+        const_assert_eq!(align_of::<Timecode>(), 4);
+
+        #[cfg(target_pointer_width = "64")]
+        {
+            const_assert_eq!(size_of::<Format>(), 208);
+            const_assert_eq!(size_of::<RawBuffer>(), 88);
+        }
+        #[cfg(target_pointer_width = "32")]
+        {
+            const_assert_eq!(size_of::<Format>(), 204);
+            const_assert_eq!(size_of::<RawBuffer>(), 68);
+        }
+        const_assert_eq!(align_of::<Format>(), align_of::<usize>());
+        const_assert_eq!(align_of::<RawBuffer>(), align_of::<usize>());
+    }
+}
@@ -0,0 +1,64 @@
+use rustix::aio::{io_destroy, io_getevents, io_setup, io_submit, IoEvent, Iocb, Timespec};
+use std::fs::File;
+
+#[test]
+fn test_aio_write_read() {
+    let file = tempfile::tempfile().unwrap();
+    let ctx = io_setup(8).unwrap();
+
+    let data = b"hello, aio";
+    let mut write = Iocb::pwrite(&file, data, 4);
+    write.set_user_data(1);
+    let mut fsync = Iocb::fdatasync(&file);
+    fsync.set_user_data(2);
+    assert_eq!(unsafe { io_submit(&ctx, &mut [&mut write]) }.unwrap(), 1);
+
+    let mut events = [IoEvent::default(); 4];
+    let n = io_getevents(&ctx, 1, &mut events, None).unwrap();
+    assert_eq!(n, 1);
+    assert_eq!(events[0].user_data(), 1);
+    assert_eq!(events[0].iocb(), &write as *const Iocb);
+    assert_eq!(events[0].result().unwrap(), data.len());
+
+    // Fsync isn't supported on all filesystems, so don't check its result.
+    assert_eq!(unsafe { io_submit(&ctx, &mut [&mut fsync]) }.unwrap(), 1);
+    assert_eq!(io_getevents(&ctx, 1, &mut events, None).unwrap(), 1);
+    assert_eq!(events[0].user_data(), 2);
+
+    let mut buf = [0_u8; 16];
+    let mut read = Iocb::pread(&file, &mut buf, 0);
+    read.set_user_data(3);
+    assert_eq!(unsafe { io_submit(&ctx, &mut [&mut read]) }.unwrap(), 1);
+    let n = io_getevents(&ctx, 1, &mut events, None).unwrap();
+    assert_eq!(n, 1);
+    assert_eq!(events[0].user_data(), 3);
+    assert_eq!(events[0].result().unwrap(), 4 + data.len());
+    assert_eq!(&buf[..4], &[0; 4]);
+    assert_eq!(&buf[4..4 + data.len()], data);
+
+    // Nothing is in flight, so a zero timeout returns immediately.
+    let timeout = Timespec {
+        tv_sec: 0,
+        tv_nsec: 0,
+    };
+    assert_eq!(
+        io_getevents(&ctx, 1, &mut events, Some(&timeout)).unwrap(),
+        0
+    );
+
+    io_destroy(ctx).unwrap();
+}
+
+#[test]
+fn test_aio_error() {
+    let ctx = io_setup(1).unwrap();
+
+    // Reading from a write-only file fails at submission time.
+    let file = File::create(tempfile::NamedTempFile::new().unwrap().path()).unwrap();
+    let mut buf = [0_u8; 4];
+    let mut read = Iocb::pread(&file, &mut buf, 0);
+    assert_eq!(
+        unsafe { io_submit(&ctx, &mut [&mut read]) },
+        Err(rustix::io::Errno::BADF)
+    );
+}
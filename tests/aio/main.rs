@@ -0,0 +1,6 @@
+//! Tests for [`rustix::aio`].
+
+#![cfg(feature = "aio")]
+#![cfg(linux_kernel)]
+
+mod basic;
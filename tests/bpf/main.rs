@@ -0,0 +1,6 @@
+//! Tests for [`rustix::bpf`].
+
+#![cfg(feature = "bpf")]
+#![cfg(linux_kernel)]
+
+mod maps;
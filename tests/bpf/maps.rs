@@ -0,0 +1,66 @@
+use rustix::bpf::{
+    map_create, map_delete_elem, map_get_next_key, map_lookup_elem, map_update_elem, prog_load,
+    MapFlags, MapType, ProgType, UpdateFlags,
+};
+use rustix::cstr;
+use rustix::io::Errno;
+
+#[test]
+fn test_bpf_hash_map() {
+    let map = match map_create(MapType::HASH, 4, 8, 16, MapFlags::empty()) {
+        Ok(map) => map,
+        Err(Errno::NOSYS | Errno::PERM | Errno::ACCESS) => return,
+        Err(err) => panic!("{:?}", err),
+    };
+
+    let key = 7_u32.to_ne_bytes();
+    let value = 0x1234_5678_9abc_def0_u64.to_ne_bytes();
+    let mut buf = [0_u8; 8];
+
+    unsafe {
+        assert_eq!(map_lookup_elem(&map, &key, &mut buf), Err(Errno::NOENT));
+        map_update_elem(&map, &key, &value, UpdateFlags::NOEXIST).unwrap();
+        assert_eq!(
+            map_update_elem(&map, &key, &value, UpdateFlags::NOEXIST),
+            Err(Errno::EXIST)
+        );
+        map_lookup_elem(&map, &key, &mut buf).unwrap();
+        assert_eq!(buf, value);
+
+        let mut next = [0_u8; 4];
+        map_get_next_key(&map, None, &mut next).unwrap();
+        assert_eq!(next, key);
+        assert_eq!(
+            map_get_next_key(&map, Some(&key), &mut next),
+            Err(Errno::NOENT)
+        );
+
+        map_delete_elem(&map, &key).unwrap();
+        assert_eq!(map_delete_elem(&map, &key), Err(Errno::NOENT));
+    }
+}
+
+#[test]
+fn test_bpf_prog_load() {
+    // `r0 = 0; exit`
+    let insns = [
+        0xb7, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, //
+        0x95, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, //
+    ];
+    let mut log = [0_u8; 4096];
+    match prog_load(
+        ProgType::SOCKET_FILTER,
+        &insns,
+        cstr!("GPL"),
+        Some(&mut log),
+    ) {
+        Ok(_prog) => {}
+        Err(Errno::NOSYS | Errno::PERM | Errno::ACCESS) => {}
+        Err(err) => panic!("{:?}", err),
+    }
+
+    assert_eq!(
+        prog_load(ProgType::SOCKET_FILTER, &insns[..12], cstr!("GPL"), None).unwrap_err(),
+        Errno::INVAL
+    );
+}
@@ -0,0 +1,52 @@
+#[test]
+fn test_chflags() {
+    use rustix::fs::{chflags, fchflags, fstat, openat, stat, FileFlags, Mode, OFlags, CWD};
+
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(CWD, tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+    let file = openat(
+        &dir,
+        "file",
+        OFlags::CREATE | OFlags::WRONLY | OFlags::CLOEXEC,
+        Mode::RUSR | Mode::WUSR,
+    )
+    .unwrap();
+
+    let before = FileFlags::from_bits_retain(fstat(&file).unwrap().st_flags as u32);
+    assert!(!before.contains(FileFlags::UF_NODUMP));
+
+    fchflags(&file, before | FileFlags::UF_NODUMP).unwrap();
+    let after = FileFlags::from_bits_retain(fstat(&file).unwrap().st_flags as u32);
+    assert!(after.contains(FileFlags::UF_NODUMP));
+
+    chflags(tmp.path().join("file"), before).unwrap();
+    let reverted =
+        FileFlags::from_bits_retain(stat(tmp.path().join("file")).unwrap().st_flags as u32);
+    assert!(!reverted.contains(FileFlags::UF_NODUMP));
+}
+
+#[cfg(any(apple, freebsdlike, target_os = "netbsd"))]
+#[test]
+fn test_lchflags() {
+    use rustix::fs::{lchflags, lstat, stat, symlink, FileFlags};
+
+    let tmp = tempfile::tempdir().unwrap();
+    let _ = std::fs::File::create(tmp.path().join("file")).unwrap();
+    symlink("file", tmp.path().join("link")).unwrap();
+
+    let before =
+        FileFlags::from_bits_retain(lstat(tmp.path().join("link")).unwrap().st_flags as u32);
+    match lchflags(tmp.path().join("link"), before | FileFlags::UF_NODUMP) {
+        Ok(()) => (),
+        // Some filesystems don't support flags on symlinks.
+        Err(rustix::io::Errno::OPNOTSUPP) => return,
+        Err(err) => panic!("{:?}", err),
+    }
+
+    let link = FileFlags::from_bits_retain(lstat(tmp.path().join("link")).unwrap().st_flags as u32);
+    assert!(link.contains(FileFlags::UF_NODUMP));
+
+    // The target of the link is unchanged.
+    let file = FileFlags::from_bits_retain(stat(tmp.path().join("file")).unwrap().st_flags as u32);
+    assert!(!file.contains(FileFlags::UF_NODUMP));
+}
@@ -0,0 +1,73 @@
+#[test]
+fn test_fchown() {
+    use rustix::fs::{fchown, fstat, openat, Gid, Mode, OFlags, Uid, CWD};
+
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(CWD, tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+    let file = openat(
+        &dir,
+        "file",
+        OFlags::CREATE | OFlags::WRONLY | OFlags::CLOEXEC,
+        Mode::RUSR | Mode::WUSR,
+    )
+    .unwrap();
+
+    let before = fstat(&file).unwrap();
+    let (owner, group) = unsafe { (Uid::from_raw(before.st_uid), Gid::from_raw(before.st_gid)) };
+
+    // Changing to the current owner and group is always permitted.
+    fchown(&file, Some(owner), Some(group)).unwrap();
+    fchown(&file, None, Some(group)).unwrap();
+    fchown(&file, Some(owner), None).unwrap();
+    fchown(&file, None, None).unwrap();
+
+    let after = fstat(&file).unwrap();
+    assert_eq!(after.st_uid, before.st_uid);
+    assert_eq!(after.st_gid, before.st_gid);
+}
+
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+#[test]
+fn test_chownat() {
+    use rustix::fs::{chownat, openat, statat, symlinkat, AtFlags, Gid, Mode, OFlags, Uid, CWD};
+
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(CWD, tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+
+    let _ = openat(
+        &dir,
+        "file",
+        OFlags::CREATE | OFlags::WRONLY | OFlags::CLOEXEC,
+        Mode::RUSR | Mode::WUSR,
+    )
+    .unwrap();
+    symlinkat("file", &dir, "link").unwrap();
+
+    let before = statat(&dir, "file", AtFlags::empty()).unwrap();
+    let (owner, group) = unsafe { (Uid::from_raw(before.st_uid), Gid::from_raw(before.st_gid)) };
+
+    chownat(&dir, "file", Some(owner), Some(group), AtFlags::empty()).unwrap();
+
+    // With `SYMLINK_NOFOLLOW`, the link itself is changed.
+    chownat(
+        &dir,
+        "link",
+        Some(owner),
+        Some(group),
+        AtFlags::SYMLINK_NOFOLLOW,
+    )
+    .unwrap();
+
+    let after = statat(&dir, "file", AtFlags::empty()).unwrap();
+    assert_eq!(after.st_uid, before.st_uid);
+    assert_eq!(after.st_gid, before.st_gid);
+
+    let link = statat(&dir, "link", AtFlags::SYMLINK_NOFOLLOW).unwrap();
+    assert_eq!(link.st_uid, before.st_uid);
+    assert_eq!(link.st_gid, before.st_gid);
+
+    assert_eq!(
+        chownat(&dir, "missing", Some(owner), None, AtFlags::empty()),
+        Err(rustix::io::Errno::NOENT)
+    );
+}
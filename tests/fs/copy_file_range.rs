@@ -0,0 +1,55 @@
+use rustix::fs::{copy_file_range, openat, seek, Mode, OFlags, SeekFrom, CWD};
+use rustix::io::{pread, write};
+
+#[test]
+fn test_copy_file_range() {
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(CWD, tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+    let src = openat(
+        &dir,
+        "src",
+        OFlags::RDWR | OFlags::CREATE | OFlags::TRUNC,
+        Mode::RUSR | Mode::WUSR,
+    )
+    .unwrap();
+    let dst = openat(
+        &dir,
+        "dst",
+        OFlags::RDWR | OFlags::CREATE | OFlags::TRUNC,
+        Mode::RUSR | Mode::WUSR,
+    )
+    .unwrap();
+    write(&src, b"hello, world").unwrap();
+
+    // With explicit offsets, the offsets are advanced and the file offsets
+    // are left alone.
+    let mut off_in = 7;
+    let mut off_out = 0;
+    match copy_file_range(&src, Some(&mut off_in), &dst, Some(&mut off_out), 5) {
+        Ok(n) => assert_eq!(n, 5),
+        Err(rustix::io::Errno::NOSYS) => return,
+        Err(err) => panic!("{:?}", err),
+    }
+    assert_eq!(off_in, 12);
+    assert_eq!(off_out, 5);
+    assert_eq!(seek(&src, SeekFrom::Current(0)), Ok(12));
+    assert_eq!(seek(&dst, SeekFrom::Current(0)), Ok(0));
+
+    // Without offsets, the file offsets are used.
+    seek(&src, SeekFrom::Start(0)).unwrap();
+    seek(&dst, SeekFrom::Start(5)).unwrap();
+    assert_eq!(copy_file_range(&src, None, &dst, None, 5), Ok(5));
+    assert_eq!(seek(&src, SeekFrom::Current(0)), Ok(5));
+    assert_eq!(seek(&dst, SeekFrom::Current(0)), Ok(10));
+
+    // At the end of the input, nothing is copied.
+    let mut off_in = 12;
+    assert_eq!(
+        copy_file_range(&src, Some(&mut off_in), &dst, None, 5),
+        Ok(0)
+    );
+
+    let mut buf = [0_u8; 16];
+    assert_eq!(pread(&dst, &mut buf, 0), Ok(10));
+    assert_eq!(&buf[..10], b"worldhello");
+}
@@ -171,3 +171,36 @@ fn dir_iterator_handles_dir_removal_after_open() {
 
     assert!(dir.next().is_none());
 }
+
+#[test]
+fn test_dir_entry_type_and_ino() {
+    use rustix::fs::{statat, AtFlags, Dir, FileType, Mode, OFlags, CWD};
+
+    let tmp = tempfile::tempdir().unwrap();
+    let t = rustix::fs::openat(CWD, tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+    rustix::fs::mkdirat(&t, "dir", Mode::RWXU).unwrap();
+    rustix::fs::symlinkat("dir", &t, "link").unwrap();
+    let _file =
+        rustix::fs::openat(&t, "file", OFlags::CREATE | OFlags::WRONLY, Mode::RUSR).unwrap();
+
+    let mut seen = 0;
+    for entry in Dir::read_from(&t).unwrap() {
+        let entry = entry.unwrap();
+        let expected = match entry.file_name().to_bytes() {
+            b"dir" => FileType::Directory,
+            b"link" => FileType::Symlink,
+            b"file" => FileType::RegularFile,
+            _ => continue,
+        };
+        seen += 1;
+
+        // Some filesystems don't report the type, in which case it's
+        // `Unknown`.
+        let file_type = entry.file_type();
+        assert!(file_type == expected || file_type == FileType::Unknown);
+
+        let stat = statat(&t, entry.file_name(), AtFlags::SYMLINK_NOFOLLOW).unwrap();
+        assert_eq!(entry.ino(), stat.st_ino as u64);
+    }
+    assert_eq!(seen, 3);
+}
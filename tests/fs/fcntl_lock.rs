@@ -31,3 +31,83 @@ fn test_fcntl_lock() {
     drop(f);
     drop(g);
 }
+
+#[test]
+fn test_fcntl_setlk_range() {
+    use rustix::fs::{fcntl_getlk, fcntl_setlk, fcntl_setlkw, Flock, FlockType};
+
+    let f = tempfile::tempfile().unwrap();
+    fcntl_setlk(&f, &Flock::new(FlockType::WriteLock, 0, 10)).unwrap();
+    fcntl_setlkw(&f, &Flock::new(FlockType::ReadLock, 10, 10)).unwrap();
+
+    // A process's own locks never conflict with its other locks.
+    assert_eq!(
+        fcntl_getlk(&f, &Flock::new(FlockType::WriteLock, 0, 0)).unwrap(),
+        None
+    );
+
+    fcntl_setlk(&f, &Flock::new(FlockType::Unlocked, 0, 0)).unwrap();
+}
+
+#[cfg(linux_kernel)]
+#[test]
+fn test_fcntl_ofd_lock() {
+    use rustix::fs::{
+        fcntl_ofd_getlk, fcntl_ofd_setlk, fcntl_setlk, openat, Flock, FlockOffsetType, FlockType,
+        Mode, OFlags, CWD,
+    };
+    use rustix::io::Errno;
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("file");
+    let open = || {
+        openat(
+            CWD,
+            &path,
+            OFlags::RDWR | OFlags::CREATE | OFlags::CLOEXEC,
+            Mode::RUSR | Mode::WUSR,
+        )
+        .unwrap()
+    };
+    let f = open();
+    let g = open();
+
+    // Open file description locks conflict across open file descriptions,
+    // even within one process.
+    fcntl_ofd_setlk(&f, &Flock::new(FlockType::WriteLock, 0, 10)).unwrap();
+    assert_eq!(
+        fcntl_ofd_setlk(&g, &Flock::new(FlockType::ReadLock, 5, 10)),
+        Err(Errno::AGAIN)
+    );
+    fcntl_ofd_setlk(&g, &Flock::new(FlockType::ReadLock, 10, 10)).unwrap();
+
+    let conflict = fcntl_ofd_getlk(&g, &Flock::new(FlockType::ReadLock, 0, 0))
+        .unwrap()
+        .unwrap();
+    assert_eq!(conflict.typ, FlockType::WriteLock);
+    assert_eq!(conflict.offset_type, FlockOffsetType::Set);
+    assert_eq!(conflict.start, 0);
+    assert_eq!(conflict.length, 10);
+    assert_eq!(conflict.pid, None);
+
+    fcntl_ofd_setlk(&f, &Flock::new(FlockType::Unlocked, 0, 0)).unwrap();
+    fcntl_ofd_setlk(&g, &Flock::new(FlockType::Unlocked, 0, 0)).unwrap();
+    assert_eq!(
+        fcntl_ofd_getlk(&g, &Flock::new(FlockType::WriteLock, 0, 0)).unwrap(),
+        None
+    );
+
+    // Process-associated locks conflict with open file description locks,
+    // and are reported with the owning process.
+    fcntl_setlk(&f, &Flock::new(FlockType::ReadLock, 20, 5)).unwrap();
+    let conflict = fcntl_ofd_getlk(&g, &Flock::new(FlockType::WriteLock, 0, 0))
+        .unwrap()
+        .unwrap();
+    assert_eq!(conflict.typ, FlockType::ReadLock);
+    assert_eq!(conflict.start, 20);
+    assert_eq!(conflict.length, 5);
+    assert_eq!(
+        conflict.pid,
+        rustix::fs::Pid::from_raw(std::process::id() as _)
+    );
+}
@@ -90,12 +90,20 @@ fn test_file() {
         target_os = "haiku",
         target_os = "redox",
     )))]
-    rustix::fs::fadvise(&file, 0, 10, rustix::fs::Advice::Normal).unwrap();
+    for advice in [
+        rustix::fs::Advice::Normal,
+        rustix::fs::Advice::Sequential,
+        rustix::fs::Advice::Random,
+        rustix::fs::Advice::NoReuse,
+        rustix::fs::Advice::WillNeed,
+        rustix::fs::Advice::DontNeed,
+    ] {
+        rustix::fs::fadvise(&file, 0, 10, advice).unwrap();
+    }
 
     rustix::fs::fsync(&file).unwrap();
 
     #[cfg(not(any(
-        apple,
         target_os = "dragonfly",
         target_os = "espidf",
         target_os = "haiku",
@@ -135,13 +143,7 @@ fn test_file() {
     assert!(stat.st_size > 0);
     assert!(stat.st_blocks > 0);
 
-    #[cfg(not(any(
-        solarish,
-        target_os = "haiku",
-        target_os = "netbsd",
-        target_os = "redox",
-        target_os = "wasi",
-    )))]
+    #[cfg(not(any(solarish, target_os = "haiku", target_os = "redox", target_os = "wasi")))]
     {
         let statfs = rustix::fs::fstatfs(&file).unwrap();
         assert!(statfs.f_blocks > 0);
@@ -159,6 +161,33 @@ fn test_file() {
     assert_ne!(rustix::io::ioctl_fionread(&file).unwrap(), 0);
 }
 
+#[cfg(not(any(target_os = "emscripten", target_os = "redox")))]
+#[test]
+fn test_accessat_symlink_nofollow() {
+    use rustix::fs::{accessat, openat, symlinkat, Access, AtFlags, Mode, OFlags, CWD};
+
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(CWD, tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+    symlinkat("missing", &dir, "dangling").unwrap();
+
+    // Following the link fails, because its target doesn't exist.
+    assert_eq!(
+        accessat(&dir, "dangling", Access::EXISTS, AtFlags::empty()),
+        Err(rustix::io::Errno::NOENT)
+    );
+
+    // Not following it succeeds, because the link itself exists.
+    #[allow(unreachable_patterns)]
+    match accessat(&dir, "dangling", Access::EXISTS, AtFlags::SYMLINK_NOFOLLOW) {
+        Ok(()) => (),
+        // Linux before 5.8 has no `faccessat2`, and Android avoids it.
+        Err(
+            rustix::io::Errno::NOSYS | rustix::io::Errno::NOTSUP | rustix::io::Errno::OPNOTSUPP,
+        ) => {}
+        Err(err) => panic!("{:?}", err),
+    }
+}
+
 #[test]
 fn test_setfl_append() {
     use rustix::fs::{Mode, OFlags};
@@ -190,3 +219,56 @@ fn test_setfl_append() {
     assert_eq!(rustix::io::read(&file, &mut buf), Ok(19));
     assert_eq!(&buf, b"uvwdefghijklmnopxyz\0\0\0\0\0\0\0\0\0\0\0\0\0");
 }
+
+// Apple's emulation of `fallocate` sets the file size to `offset + len`, even
+// if the file is already longer, and NetBSD's `posix_fallocate` isn't
+// supported by most filesystems.
+#[cfg(not(any(
+    apple,
+    netbsdlike,
+    solarish,
+    target_os = "aix",
+    target_os = "dragonfly",
+    target_os = "espidf",
+    target_os = "nto",
+    target_os = "redox",
+    target_os = "vita",
+)))]
+#[test]
+fn test_fallocate_extends() {
+    use rustix::fs::{FallocateFlags, Mode, OFlags};
+
+    let tmp = tempfile::tempdir().unwrap();
+    let file = rustix::fs::open(
+        tmp.path().join("test.file"),
+        OFlags::RDWR | OFlags::CREATE,
+        Mode::RWXU,
+    )
+    .unwrap();
+    assert_eq!(rustix::io::write(&file, b"abc"), Ok(3));
+
+    // Allocating past the end extends the file.
+    rustix::fs::fallocate(&file, FallocateFlags::empty(), 0, 4096).unwrap();
+    assert_eq!(rustix::fs::fstat(&file).unwrap().st_size, 4096);
+
+    // Allocating within the file leaves its size and contents alone.
+    rustix::fs::fallocate(&file, FallocateFlags::empty(), 1, 2).unwrap();
+    assert_eq!(rustix::fs::fstat(&file).unwrap().st_size, 4096);
+    let mut buf = [0xff_u8; 4];
+    assert_eq!(rustix::io::pread(&file, &mut buf, 0), Ok(4));
+    assert_eq!(&buf, b"abc\0");
+}
+
+#[cfg(linux_kernel)]
+#[test]
+fn test_readahead() {
+    use rustix::fs::{openat, readahead, Mode, OFlags, CWD};
+
+    let file = openat(CWD, "Cargo.toml", OFlags::RDONLY, Mode::empty()).unwrap();
+    readahead(&file, 0, 4096).unwrap();
+    readahead(&file, 1 << 40, 4096).unwrap();
+
+    // `readahead` needs a regular file.
+    let dir = openat(CWD, ".", OFlags::RDONLY, Mode::empty()).unwrap();
+    assert_eq!(readahead(&dir, 0, 4096), Err(rustix::io::Errno::INVAL));
+}
@@ -32,3 +32,35 @@ fn test_flock() {
     drop(f);
     drop(g);
 }
+
+#[cfg(not(any(target_os = "redox", target_os = "solaris")))]
+#[test]
+fn test_flock_nonblocking() {
+    use rustix::fs::{flock, openat, FlockOperation, Mode, OFlags, CWD};
+    use rustix::io::Errno;
+
+    let f = openat(CWD, "Cargo.toml", OFlags::RDONLY, Mode::empty()).unwrap();
+    let g = openat(CWD, "Cargo.toml", OFlags::RDONLY, Mode::empty()).unwrap();
+
+    // Separate opens conflict with each other.
+    flock(&f, FlockOperation::NonBlockingLockExclusive).unwrap();
+    assert_eq!(
+        flock(&g, FlockOperation::NonBlockingLockShared),
+        Err(Errno::WOULDBLOCK)
+    );
+    assert_eq!(
+        flock(&g, FlockOperation::NonBlockingLockExclusive),
+        Err(Errno::WOULDBLOCK)
+    );
+    flock(&f, FlockOperation::NonBlockingUnlock).unwrap();
+    flock(&g, FlockOperation::NonBlockingLockShared).unwrap();
+    flock(&f, FlockOperation::NonBlockingLockShared).unwrap();
+    assert_eq!(
+        flock(&f, FlockOperation::NonBlockingLockExclusive),
+        Err(Errno::WOULDBLOCK)
+    );
+
+    // Closing a file releases its lock.
+    drop(g);
+    flock(&f, FlockOperation::NonBlockingLockExclusive).unwrap();
+}
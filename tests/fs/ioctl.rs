@@ -1,13 +1,20 @@
+/// Returns a read-only source file, an empty destination file, and a
+/// directory, for testing the `FICLONE` family of `ioctl`s.
+#[cfg(all(linux_kernel, not(any(target_arch = "sparc", target_arch = "sparc64"))))]
+fn clone_files() -> (std::fs::File, std::fs::File, std::fs::File) {
+    let src = std::fs::File::open("Cargo.toml").unwrap();
+    let dest = tempfile::tempfile().unwrap();
+    let dir = std::fs::File::open(std::env::temp_dir()).unwrap();
+    (src, dest, dir)
+}
+
 // Sparc lacks `FICLONE`.
 #[cfg(all(linux_kernel, not(any(target_arch = "sparc", target_arch = "sparc64"))))]
 #[test]
 fn test_ioctl_ficlone() {
     use rustix::io;
 
-    let src = std::fs::File::open("Cargo.toml").unwrap();
-    let dest = tempfile::tempfile().unwrap();
-    let dir = tempfile::tempdir().unwrap();
-    let dir = std::fs::File::open(dir.path()).unwrap();
+    let (src, dest, dir) = clone_files();
 
     // `src` isn't opened for writing, so passing it as the output fails.
     assert_eq!(rustix::fs::ioctl_ficlone(&src, &src), Err(io::Errno::BADF));
@@ -23,3 +30,254 @@ fn test_ioctl_ficlone() {
         Err(err) => panic!("{:?}", err),
     }
 }
+
+// Sparc lacks `FICLONERANGE`.
+#[cfg(all(linux_kernel, not(any(target_arch = "sparc", target_arch = "sparc64"))))]
+#[test]
+fn test_ioctl_ficlonerange() {
+    use rustix::io;
+
+    let (src, dest, dir) = clone_files();
+
+    // `src` isn't opened for writing, so passing it as the output fails.
+    assert_eq!(
+        rustix::fs::ioctl_ficlonerange(&src, &src, 0, 0, 0),
+        Err(io::Errno::BADF)
+    );
+
+    // `FICLONERANGE` operates on regular files, not directories.
+    assert_eq!(
+        rustix::fs::ioctl_ficlonerange(&dir, &dir, 0, 0, 0),
+        Err(io::Errno::ISDIR)
+    );
+
+    // Now try something that might succeed, though be prepared for filesystems
+    // that don't support this. A zero length clones to the end of `src`.
+    match rustix::fs::ioctl_ficlonerange(&dest, &src, 0, 0, 0) {
+        Ok(()) | Err(io::Errno::OPNOTSUPP | io::Errno::XDEV) => (),
+        Err(err) => panic!("{:?}", err),
+    }
+}
+
+/// Creates three 4096-byte files in `dir` for testing deduplication: a
+/// read-only source, and writable files with the same and with different
+/// contents.
+#[cfg(all(
+    linux_kernel,
+    feature = "alloc",
+    not(any(target_arch = "sparc", target_arch = "sparc64"))
+))]
+fn dedupe_files(dir: &std::path::Path) -> (std::fs::File, std::fs::File, std::fs::File) {
+    let data = [0xa5_u8; 4096];
+    std::fs::write(dir.join("src"), data).unwrap();
+    std::fs::write(dir.join("same"), data).unwrap();
+    std::fs::write(dir.join("differs"), [0x5a_u8; 4096]).unwrap();
+    let writable = |name| {
+        std::fs::OpenOptions::new()
+            .write(true)
+            .open(dir.join(name))
+            .unwrap()
+    };
+    (
+        std::fs::File::open(dir.join("src")).unwrap(),
+        writable("same"),
+        writable("differs"),
+    )
+}
+
+// Sparc lacks `FIDEDUPERANGE`.
+#[cfg(all(
+    linux_kernel,
+    feature = "alloc",
+    not(any(target_arch = "sparc", target_arch = "sparc64"))
+))]
+#[test]
+fn test_ioctl_fideduperange() {
+    use rustix::fs::{ioctl_fideduperange, DedupeDest, DedupeResult};
+    use rustix::io;
+
+    let dir = tempfile::tempdir().unwrap();
+    let (src, same, differs) = dedupe_files(dir.path());
+
+    // The number of destinations must fit in a `u16`.
+    let dests = vec![
+        DedupeDest {
+            fd: rustix::fd::AsFd::as_fd(&same),
+            offset: 0,
+        };
+        0x1_0000
+    ];
+    assert_eq!(
+        ioctl_fideduperange(&src, 0, 4096, &dests),
+        Err(io::Errno::INVAL)
+    );
+
+    let dests = [
+        DedupeDest {
+            fd: rustix::fd::AsFd::as_fd(&same),
+            offset: 0,
+        },
+        DedupeDest {
+            fd: rustix::fd::AsFd::as_fd(&differs),
+            offset: 0,
+        },
+    ];
+
+    // Be prepared for filesystems that don't support this.
+    match ioctl_fideduperange(&src, 0, 4096, &dests) {
+        Ok(results) => {
+            assert_eq!(results.len(), 2);
+            assert_eq!(
+                results[0],
+                Ok(DedupeResult::Same {
+                    bytes_deduped: 4096
+                })
+            );
+            assert_eq!(results[1], Ok(DedupeResult::Differs));
+        }
+        Err(io::Errno::OPNOTSUPP) | Err(io::Errno::INVAL) => (),
+        Err(err) => panic!("{:?}", err),
+    }
+}
+
+#[cfg(all(
+    linux_kernel,
+    feature = "alloc",
+    not(any(target_arch = "sparc", target_arch = "sparc64"))
+))]
+#[test]
+fn test_dedupe() {
+    use rustix::fs::{dedupe, DedupeDest, DedupeResult};
+    use rustix::io;
+
+    let dir = tempfile::tempdir().unwrap();
+    let (src, same, differs) = dedupe_files(dir.path());
+
+    // More destinations than fit in one `FIDEDUPERANGE` request.
+    let mut dests = vec![
+        DedupeDest {
+            fd: rustix::fd::AsFd::as_fd(&same),
+            offset: 0,
+        };
+        200
+    ];
+    dests[150].fd = rustix::fd::AsFd::as_fd(&differs);
+
+    // A length of 0 means up to the end of the source.
+    assert_eq!(
+        dedupe(&src, 4096, 0, &dests).unwrap(),
+        vec![Ok(DedupeResult::Same { bytes_deduped: 0 }); 200]
+    );
+
+    // Be prepared for filesystems that don't support this.
+    match dedupe(&src, 0, 0, &dests) {
+        Ok(results) => {
+            assert_eq!(results.len(), 200);
+            for (i, result) in results.into_iter().enumerate() {
+                match result {
+                    Ok(result) if i == 150 => assert_eq!(result, DedupeResult::Differs),
+                    Ok(result) => assert_eq!(
+                        result,
+                        DedupeResult::Same {
+                            bytes_deduped: 4096
+                        }
+                    ),
+                    Err(io::Errno::OPNOTSUPP) | Err(io::Errno::INVAL) => (),
+                    Err(err) => panic!("{:?}", err),
+                }
+            }
+        }
+        Err(io::Errno::OPNOTSUPP) | Err(io::Errno::INVAL) => (),
+        Err(err) => panic!("{:?}", err),
+    }
+}
+
+#[cfg(linux_kernel)]
+#[test]
+fn test_ioctl_getflags_setflags() {
+    use rustix::fs::{ioctl_getflags, ioctl_setflags, openat, IFlags, Mode, OFlags, CWD};
+    use rustix::io;
+
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(CWD, tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+    let file = openat(
+        &dir,
+        "file",
+        OFlags::RDWR | OFlags::CREATE | OFlags::TRUNC,
+        Mode::RUSR | Mode::WUSR,
+    )
+    .unwrap();
+
+    let flags = match ioctl_getflags(&file) {
+        Ok(flags) => flags,
+        // Skip the test if the filesystem doesn't support inode flags.
+        Err(io::Errno::NOTTY | io::Errno::NOTSUP | io::Errno::INVAL) => return,
+        Err(err) => panic!("{:?}", err),
+    };
+    assert!(!flags.contains(IFlags::NOATIME));
+
+    match ioctl_setflags(&file, flags | IFlags::NOATIME) {
+        Ok(()) => {}
+        Err(io::Errno::NOTTY | io::Errno::NOTSUP | io::Errno::INVAL) => return,
+        Err(err) => panic!("{:?}", err),
+    }
+    assert!(ioctl_getflags(&file).unwrap().contains(IFlags::NOATIME));
+
+    ioctl_setflags(&file, flags).unwrap();
+    assert_eq!(ioctl_getflags(&file).unwrap(), flags);
+}
+
+// Sparc lacks `FS_IOC_FIEMAP`.
+#[cfg(all(
+    linux_kernel,
+    feature = "alloc",
+    not(any(target_arch = "sparc", target_arch = "sparc64"))
+))]
+#[test]
+fn test_ioctl_fiemap() {
+    use rustix::fs::{ioctl_fiemap, openat, FiemapExtentFlags, FiemapFlags, Mode, OFlags, CWD};
+    use rustix::io::{self, pwrite};
+
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(CWD, tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+    let file = openat(
+        &dir,
+        "file",
+        OFlags::RDWR | OFlags::CREATE | OFlags::TRUNC,
+        Mode::RUSR | Mode::WUSR,
+    )
+    .unwrap();
+
+    // Write two blocks of data with a hole between them.
+    let data = [0xa5_u8; 4096];
+    pwrite(&file, &data, 0).unwrap();
+    pwrite(&file, &data, 1 << 20).unwrap();
+
+    let extents = match ioctl_fiemap(&file, 0, u64::MAX, FiemapFlags::SYNC) {
+        Ok(extents) => extents,
+        // Skip the test if the filesystem doesn't support FIEMAP.
+        Err(io::Errno::NOTTY | io::Errno::OPNOTSUPP) => return,
+        Err(err) => panic!("{:?}", err),
+    };
+
+    assert!(extents.len() >= 2, "{:?}", extents);
+    assert_eq!(extents[0].logical, 0);
+    assert!(extents[0].length < 1 << 20);
+    let last = extents.last().unwrap();
+    assert!(last.flags.contains(FiemapExtentFlags::LAST));
+    assert!(last.logical <= 1 << 20 && last.logical + last.length >= (1 << 20) + 4096);
+
+    // Mapping just the hole returns no extents.
+    let extents = ioctl_fiemap(&file, 1 << 16, 1 << 16, FiemapFlags::empty()).unwrap();
+    assert!(extents.is_empty(), "{:?}", extents);
+
+    // Map more extents than fit in a single `ioctl` call.
+    for i in 2..100 {
+        pwrite(&file, &data, i << 20).unwrap();
+    }
+    let extents = ioctl_fiemap(&file, 0, u64::MAX, FiemapFlags::SYNC).unwrap();
+    assert_eq!(extents.len(), 100, "{:?}", extents);
+    assert!(extents
+        .windows(2)
+        .all(|w| w[0].logical + w[0].length <= w[1].logical));
+}
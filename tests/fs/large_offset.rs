@@ -0,0 +1,94 @@
+//! Tests for file offsets that don't fit in 32 bits.
+//!
+//! On 32-bit targets, these are split across pairs of registers, in an order
+//! and with alignment padding that vary by architecture, so check that the
+//! high half reaches the kernel.
+
+use rustix::fs::{fstat, openat, Mode, OFlags, CWD};
+
+const LARGE: u64 = (1 << 32) + 5;
+
+fn tmpfile(dir: &tempfile::TempDir, name: &str) -> rustix::fd::OwnedFd {
+    let dir = openat(CWD, dir.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+    openat(
+        &dir,
+        name,
+        OFlags::RDWR | OFlags::CREATE | OFlags::TRUNC,
+        Mode::RUSR | Mode::WUSR,
+    )
+    .unwrap()
+}
+
+#[cfg(linux_kernel)]
+#[test]
+fn large_offset_fallocate() {
+    use rustix::fs::{fallocate, FallocateFlags};
+
+    let tmp = tempfile::tempdir().unwrap();
+    let file = tmpfile(&tmp, "file");
+
+    // Not all filesystems support `fallocate`, or files this large.
+    match fallocate(&file, FallocateFlags::empty(), LARGE, 3) {
+        Ok(()) => (),
+        Err(rustix::io::Errno::OPNOTSUPP | rustix::io::Errno::FBIG) => return,
+        Err(err) => panic!("{:?}", err),
+    }
+    assert_eq!(fstat(&file).unwrap().st_size as u64, LARGE + 3);
+}
+
+#[cfg(linux_kernel)]
+#[test]
+fn large_offset_copy_file_range() {
+    use rustix::fs::{copy_file_range, ftruncate};
+    use rustix::io::{pread, pwrite};
+
+    let tmp = tempfile::tempdir().unwrap();
+    let src = tmpfile(&tmp, "src");
+    let dst = tmpfile(&tmp, "dst");
+
+    match pwrite(&src, b"large", LARGE) {
+        Ok(_) => (),
+        Err(rustix::io::Errno::FBIG) => return,
+        Err(err) => panic!("{:?}", err),
+    }
+    ftruncate(&dst, LARGE + 16).unwrap();
+
+    let mut off_in = LARGE;
+    let mut off_out = LARGE + 8;
+    match copy_file_range(&src, Some(&mut off_in), &dst, Some(&mut off_out), 5) {
+        Ok(n) => assert_eq!(n, 5),
+        Err(rustix::io::Errno::NOSYS) => return,
+        Err(err) => panic!("{:?}", err),
+    }
+    assert_eq!(off_in, LARGE + 5);
+    assert_eq!(off_out, LARGE + 13);
+
+    let mut buf = [0_u8; 5];
+    assert_eq!(pread(&dst, &mut buf, LARGE + 8), Ok(5));
+    assert_eq!(&buf, b"large");
+}
+
+#[cfg(linux_kernel)]
+#[test]
+fn large_offset_sendfile() {
+    use rustix::fs::sendfile;
+    use rustix::io::{pread, pwrite};
+
+    let tmp = tempfile::tempdir().unwrap();
+    let src = tmpfile(&tmp, "src");
+    let dst = tmpfile(&tmp, "dst");
+
+    match pwrite(&src, b"large", LARGE) {
+        Ok(_) => (),
+        Err(rustix::io::Errno::FBIG) => return,
+        Err(err) => panic!("{:?}", err),
+    }
+
+    let mut offset = LARGE;
+    assert_eq!(sendfile(&dst, &src, Some(&mut offset), 5), Ok(5));
+    assert_eq!(offset, LARGE + 5);
+
+    let mut buf = [0_u8; 5];
+    assert_eq!(pread(&dst, &mut buf, 0), Ok(5));
+    assert_eq!(&buf, b"large");
+}
@@ -56,3 +56,62 @@ fn test_linkat() {
         statat(&dir, "another", AtFlags::empty()).unwrap().st_ino
     );
 }
+
+#[cfg(linux_kernel)]
+#[test]
+fn test_linkat_empty_path() {
+    use rustix::fs::{fstat, linkat, openat, statat, AtFlags, Mode, OFlags, CWD};
+
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(CWD, tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+
+    // Create an anonymous file, and give it a name.
+    let file = match openat(&dir, ".", OFlags::WRONLY | OFlags::TMPFILE, Mode::RUSR) {
+        Ok(file) => file,
+        // Not all filesystems support `O_TMPFILE`.
+        Err(rustix::io::Errno::OPNOTSUPP | rustix::io::Errno::ISDIR) => return,
+        Err(err) => panic!("{:?}", err),
+    };
+    match linkat(&file, "", &dir, "named", AtFlags::EMPTY_PATH) {
+        Ok(()) => (),
+        // Without `CAP_DAC_READ_SEARCH`, this can only succeed with the
+        // "procfs" fallback.
+        Err(rustix::io::Errno::NOENT) if cfg!(not(feature = "procfs")) => return,
+        Err(err) => panic!("{:?}", err),
+    }
+
+    assert_eq!(
+        fstat(&file).unwrap().st_ino,
+        statat(&dir, "named", AtFlags::empty()).unwrap().st_ino
+    );
+}
+
+#[cfg(linux_kernel)]
+#[test]
+fn test_link_tmpfile() {
+    use rustix::fs::{link_tmpfile, openat, statat, AtFlags, Mode, OFlags, CWD};
+    use rustix::io::{write, Errno};
+
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(CWD, tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+
+    let file = match openat(&dir, ".", OFlags::WRONLY | OFlags::TMPFILE, Mode::RUSR) {
+        Ok(file) => file,
+        // Not all filesystems support `O_TMPFILE`.
+        Err(Errno::OPNOTSUPP | Errno::ISDIR) => return,
+        Err(err) => panic!("{:?}", err),
+    };
+    write(&file, b"hello").unwrap();
+
+    match link_tmpfile(&file, &dir, "named") {
+        Ok(()) => (),
+        // Without `CAP_DAC_READ_SEARCH`, this can only succeed with the
+        // "procfs" fallback.
+        Err(Errno::NOENT) if cfg!(not(feature = "procfs")) => return,
+        Err(err) => panic!("{:?}", err),
+    }
+    assert_eq!(statat(&dir, "named", AtFlags::empty()).unwrap().st_size, 5);
+
+    // An existing name is never replaced.
+    assert_eq!(link_tmpfile(&file, &dir, "named"), Err(Errno::EXIST));
+}
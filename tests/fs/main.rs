@@ -4,7 +4,13 @@
 #![cfg(not(windows))]
 #![cfg_attr(core_c_str, feature(core_c_str))]
 
+#[cfg(bsd)]
+mod chflags;
 mod chmodat;
+#[cfg(not(target_os = "espidf"))]
+mod chownat;
+#[cfg(linux_kernel)]
+mod copy_file_range;
 mod cwd;
 #[cfg(not(target_os = "redox"))]
 mod dir;
@@ -23,12 +29,18 @@ mod futimens;
 mod invalid_offset;
 #[cfg(not(target_os = "redox"))]
 mod ioctl;
+#[cfg(linux_kernel)]
+mod large_offset;
 mod linkat;
 mod long_paths;
 #[cfg(not(any(target_os = "haiku", target_os = "redox", target_os = "wasi")))]
 mod makedev;
+#[cfg(any(apple, linux_kernel))]
+mod metadata;
 mod mkdirat;
 mod mknodat;
+#[cfg(all(feature = "alloc", not(any(target_os = "redox", target_os = "wasi"))))]
+mod mkostempat;
 #[cfg(linux_kernel)]
 mod openat;
 #[cfg(linux_kernel)]
@@ -40,6 +52,8 @@ mod renameat;
 #[cfg(any(linux_kernel, target_os = "freebsd"))]
 mod seals;
 mod seek;
+#[cfg(any(apple, freebsdlike, linux_kernel))]
+mod sendfile;
 #[cfg(not(any(target_os = "haiku", target_os = "redox", target_os = "wasi")))]
 mod statfs;
 #[cfg(linux_kernel)]
@@ -47,7 +61,8 @@ mod statx;
 mod symlinkat;
 #[cfg(not(any(solarish, target_os = "redox", target_os = "wasi")))]
 mod sync;
+mod truncate;
 mod utimensat;
-#[cfg(any(apple, linux_kernel))]
+#[cfg(any(apple, linux_kernel, target_os = "freebsd"))]
 mod xattr;
 mod y2038;
@@ -0,0 +1,62 @@
+#[test]
+fn test_apply_metadata() {
+    use rustix::fs::{
+        apply_metadata, fgetxattr, fstat, openat, FileMetadata, Gid, Mode, OFlags, Timespec,
+        Timestamps, Uid, CWD,
+    };
+
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(CWD, tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+    let file = openat(
+        &dir,
+        "file",
+        OFlags::CREATE | OFlags::WRONLY | OFlags::CLOEXEC,
+        Mode::from_raw_mode(0o644),
+    )
+    .unwrap();
+
+    let before = fstat(&file).unwrap();
+    let (owner, group) = unsafe { (Uid::from_raw(before.st_uid), Gid::from_raw(before.st_gid)) };
+
+    // Changing the owner clears the setuid bit, so the mode must be set
+    // after the owner for the setuid bit to stick.
+    let metadata = FileMetadata {
+        owner: Some(owner),
+        group: Some(group),
+        mode: Some(Mode::from_raw_mode(0o4755)),
+        times: Some(Timestamps {
+            last_access: Timespec {
+                tv_sec: 44000,
+                tv_nsec: 0,
+            },
+            last_modification: Timespec {
+                tv_sec: 46000,
+                tv_nsec: 0,
+            },
+        }),
+        ..FileMetadata::default()
+    };
+    apply_metadata(&file, &metadata).unwrap();
+
+    let after = fstat(&file).unwrap();
+    assert_eq!(after.st_mode as u32 & 0o7777, 0o4755);
+    assert_eq!(after.st_mtime as u64, 46000);
+    assert_eq!(after.st_atime as u64, 44000);
+
+    // Filesystems aren't required to support `user.*` extended attributes.
+    let name = rustix::cstr!("user.test");
+    let xattrs = [(name, &b"value"[..])];
+    let metadata = FileMetadata {
+        xattrs: &xattrs,
+        ..FileMetadata::default()
+    };
+    match apply_metadata(&file, &metadata) {
+        Ok(()) => {
+            let mut buf = [0_u8; 16];
+            let len = fgetxattr(&file, name, &mut buf).unwrap();
+            assert_eq!(&buf[..len], b"value");
+        }
+        Err(rustix::io::Errno::OPNOTSUPP) => (),
+        Err(err) => panic!("{:?}", err),
+    }
+}
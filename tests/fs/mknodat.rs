@@ -24,3 +24,25 @@ fn test_mknodat() {
     accessat(&dir, "file", Access::EXISTS, AtFlags::empty()).unwrap();
     unlinkat(&dir, "file", AtFlags::empty()).unwrap();
 }
+
+#[cfg(not(any(apple, target_os = "redox", target_os = "wasi")))]
+#[test]
+fn test_mkfifoat() {
+    use rustix::fs::{mkfifoat, openat, statat, AtFlags, FileType, Mode, OFlags, CWD};
+
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(CWD, tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+
+    mkfifoat(&dir, "fifo", Mode::RUSR | Mode::WUSR).unwrap();
+    let stat = statat(&dir, "fifo", AtFlags::empty()).unwrap();
+    assert_eq!(FileType::from_raw_mode(stat.st_mode), FileType::Fifo);
+    assert_eq!(
+        Mode::from_raw_mode(stat.st_mode) & (Mode::RUSR | Mode::WUSR),
+        Mode::RUSR | Mode::WUSR
+    );
+
+    assert_eq!(
+        mkfifoat(&dir, "fifo", Mode::RUSR),
+        Err(rustix::io::Errno::EXIST)
+    );
+}
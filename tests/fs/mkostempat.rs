@@ -0,0 +1,33 @@
+#[test]
+fn test_mkostempat() {
+    use rustix::fs::{
+        fcntl_getfd, mkostempat, openat, statat, AtFlags, FdFlags, FileType, Mode, OFlags, CWD,
+    };
+
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(CWD, tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+
+    let (fd, name) = mkostempat(&dir, "tmp.XXXXXX", OFlags::empty()).unwrap();
+    let name = name.to_str().unwrap();
+    assert!(name.starts_with("tmp."));
+    assert_eq!(name.len(), "tmp.XXXXXX".len());
+    assert_ne!(name, "tmp.XXXXXX");
+    assert!(fcntl_getfd(&fd).unwrap().contains(FdFlags::CLOEXEC));
+
+    let stat = statat(&dir, name, AtFlags::empty()).unwrap();
+    assert_eq!(FileType::from_raw_mode(stat.st_mode), FileType::RegularFile);
+    assert_eq!(stat.st_mode as u32 & 0o777, 0o600);
+
+    let (_fd, other) = mkostempat(&dir, "tmp.XXXXXX", OFlags::empty()).unwrap();
+    assert_ne!(other.to_str().unwrap(), name);
+
+    // The template must end in six `X`s.
+    assert_eq!(
+        mkostempat(&dir, "tmp.XXXXX", OFlags::empty()).unwrap_err(),
+        rustix::io::Errno::INVAL
+    );
+    assert_eq!(
+        mkostempat(&dir, "tmp.XXXXXXy", OFlags::empty()).unwrap_err(),
+        rustix::io::Errno::INVAL
+    );
+}
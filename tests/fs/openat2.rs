@@ -154,14 +154,17 @@ fn test_openat2() {
         ResolveFlags::empty(),
     )
     .unwrap();
-    let _ = openat2_more(
-        &dir,
-        "..",
-        OFlags::RDONLY | OFlags::CLOEXEC,
-        Mode::empty(),
-        ResolveFlags::BENEATH,
-    )
-    .unwrap_err();
+    assert_eq!(
+        openat2_more(
+            &dir,
+            "..",
+            OFlags::RDONLY | OFlags::CLOEXEC,
+            Mode::empty(),
+            ResolveFlags::BENEATH,
+        )
+        .unwrap_err(),
+        io::Errno::XDEV
+    );
 
     // Test `IN_ROOT`.
     let _ = openat2_more(
@@ -115,11 +115,14 @@ fn test_renameat_with() {
     )
     .unwrap();
 
-    #[cfg(all(target_os = "linux", target_env = "gnu"))]
+    #[cfg(linux_kernel)]
     {
         let green = statat(&dir, "green", AtFlags::empty()).unwrap();
 
-        renameat_with(&dir, "red", &dir, "green", RenameFlags::NOREPLACE).unwrap_err();
+        assert_eq!(
+            renameat_with(&dir, "red", &dir, "green", RenameFlags::NOREPLACE),
+            Err(rustix::io::Errno::EXIST)
+        );
         let renamed = statat(&dir, "red", AtFlags::empty()).unwrap();
         assert!(same(&before, &renamed));
         let orig = statat(&dir, "green", AtFlags::empty()).unwrap();
@@ -130,5 +133,15 @@ fn test_renameat_with() {
         assert!(same(&green, &renamed));
         let orig = statat(&dir, "green", AtFlags::empty()).unwrap();
         assert!(same(&before, &orig));
+
+        // Exchanging requires both paths to exist.
+        assert_eq!(
+            renameat_with(&dir, "red", &dir, "blue", RenameFlags::EXCHANGE),
+            Err(rustix::io::Errno::NOENT)
+        );
+
+        renameat_with(&dir, "red", &dir, "blue", RenameFlags::NOREPLACE).unwrap();
+        let renamed = statat(&dir, "blue", AtFlags::empty()).unwrap();
+        assert!(same(&green, &renamed));
     }
 }
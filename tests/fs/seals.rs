@@ -37,3 +37,36 @@ fn test_seals() {
     // We sealed shrinking, so this should fail.
     ftruncate(&mut file, 0).unwrap_err();
 }
+
+#[test]
+fn test_seals_immutable() {
+    use rustix::fs::{
+        fcntl_add_seals, fcntl_get_seals, ftruncate, memfd_create, MemfdFlags, SealFlags,
+    };
+    use rustix::io::{pwrite, Errno};
+
+    let fd = match memfd_create("test", MemfdFlags::CLOEXEC | MemfdFlags::ALLOW_SEALING) {
+        Ok(fd) => fd,
+        Err(Errno::NOSYS) => return,
+        Err(err) => panic!("{:?}", err),
+    };
+    pwrite(&fd, b"shared", 0).unwrap();
+
+    let all = SealFlags::SEAL | SealFlags::SHRINK | SealFlags::GROW | SealFlags::WRITE;
+    fcntl_add_seals(&fd, all).unwrap();
+    assert_eq!(fcntl_get_seals(&fd).unwrap(), all);
+
+    // The contents can no longer change, and no more seals can be added.
+    assert_eq!(pwrite(&fd, b"S", 0), Err(Errno::PERM));
+    assert_eq!(ftruncate(&fd, 0), Err(Errno::PERM));
+    #[cfg(linux_kernel)]
+    assert_eq!(
+        fcntl_add_seals(&fd, SealFlags::FUTURE_WRITE),
+        Err(Errno::PERM)
+    );
+
+    // Without `ALLOW_SEALING`, the `SEAL` seal is set from the start.
+    let fd = memfd_create("test", MemfdFlags::CLOEXEC).unwrap();
+    assert_eq!(fcntl_get_seals(&fd).unwrap(), SealFlags::SEAL);
+    assert_eq!(fcntl_add_seals(&fd, SealFlags::GROW), Err(Errno::PERM));
+}
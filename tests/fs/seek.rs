@@ -57,6 +57,30 @@ fn test_seek_holes() {
         seek(&file, SeekFrom::Data(hole_size as i64 * 2)),
         Ok(hole_size * 2)
     );
+
+    // `Data` and `Hole` offsets are from the start of the file, not the
+    // current position.
+    assert_eq!(
+        seek(&file, SeekFrom::Start(hole_size * 2)),
+        Ok(hole_size * 2)
+    );
+    assert_eq!(seek(&file, SeekFrom::Data(0)), Ok(0));
+    assert_eq!(
+        seek(&file, SeekFrom::Start(hole_size * 2)),
+        Ok(hole_size * 2)
+    );
+    assert_eq!(seek(&file, SeekFrom::Hole(0)), Ok(hole_size));
+
+    // Seeking past the end of the file fails.
+    let end = hole_size as i64 * 2 + 6;
+    assert_eq!(
+        seek(&file, SeekFrom::Data(end)),
+        Err(rustix::io::Errno::NXIO)
+    );
+    assert_eq!(
+        seek(&file, SeekFrom::Hole(end + 1)),
+        Err(rustix::io::Errno::NXIO)
+    );
 }
 
 #[test]
@@ -77,3 +101,29 @@ fn test_seek_offsets() {
         }
     }
 }
+
+#[test]
+fn test_seek_from_std() {
+    use rustix::fs::{seek, SeekFrom};
+
+    let f = tempfile::tempfile().unwrap();
+
+    // Offsets past 4 GiB and negative relative offsets are passed through
+    // intact, even on 32-bit platforms.
+    let far = 1_u64 << 33;
+    assert_eq!(seek(&f, std::io::SeekFrom::Start(far).into()), Ok(far));
+    assert_eq!(
+        seek(&f, std::io::SeekFrom::Current(-(1 << 32)).into()),
+        Ok(far - (1 << 32))
+    );
+    assert_eq!(seek(&f, std::io::SeekFrom::End(0).into()), Ok(0));
+    assert_eq!(
+        seek(&f, std::io::SeekFrom::Current(-1).into()),
+        Err(rustix::io::Errno::INVAL)
+    );
+
+    assert_eq!(
+        SeekFrom::from(std::io::SeekFrom::Current(-7)),
+        SeekFrom::Current(-7)
+    );
+}
@@ -0,0 +1,43 @@
+use rustix::fs::{openat, seek, sendfile, Mode, OFlags, SeekFrom, CWD};
+use std::io::Read;
+use std::net::{TcpListener, TcpStream};
+
+#[test]
+fn test_sendfile() {
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(CWD, tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+    let file = openat(
+        &dir,
+        "file",
+        OFlags::RDWR | OFlags::CREATE | OFlags::TRUNC,
+        Mode::RUSR | Mode::WUSR,
+    )
+    .unwrap();
+    rustix::io::write(&file, b"hello, world").unwrap();
+    seek(&file, SeekFrom::Start(0)).unwrap();
+
+    // The BSD `sendfile`s only support sending to stream sockets.
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let sender = TcpStream::connect(listener.local_addr().unwrap()).unwrap();
+    let (mut receiver, _) = listener.accept().unwrap();
+
+    // With an explicit offset, the offset is advanced and the file offset is
+    // left alone.
+    let mut offset = 7;
+    assert_eq!(sendfile(&sender, &file, Some(&mut offset), 5), Ok(5));
+    assert_eq!(offset, 12);
+    assert_eq!(seek(&file, SeekFrom::Current(0)), Ok(0));
+
+    // Without an offset, the file offset is used.
+    assert_eq!(sendfile(&sender, &file, None, 5), Ok(5));
+    assert_eq!(seek(&file, SeekFrom::Current(0)), Ok(5));
+
+    // At the end of the file, nothing is sent.
+    assert_eq!(sendfile(&sender, &file, Some(&mut offset), 5), Ok(0));
+    assert_eq!(sendfile(&sender, &file, None, 0), Ok(0));
+    drop(sender);
+
+    let mut buf = Vec::new();
+    receiver.read_to_end(&mut buf).unwrap();
+    assert_eq!(buf, b"worldhello");
+}
@@ -37,3 +37,39 @@ fn test_statx_reserved() {
         Err(err) => assert_eq!(err, rustix::io::Errno::INVAL),
     }
 }
+
+#[test]
+fn test_statx_btime_mnt_id() {
+    use rustix::fs::{AtFlags, StatxAttributes, StatxFlags};
+
+    let f = std::fs::File::open(".").unwrap();
+
+    let result = match rustix::fs::statx(
+        &f,
+        "Cargo.toml",
+        AtFlags::empty(),
+        StatxFlags::BASIC_STATS | StatxFlags::BTIME | StatxFlags::MNT_ID,
+    ) {
+        Err(rustix::io::Errno::NOSYS) => return,
+        otherwise => otherwise.unwrap(),
+    };
+    let mask = StatxFlags::from_bits_retain(result.stx_mask);
+    assert!(mask.contains(StatxFlags::SIZE));
+
+    // Not all filesystems record a birth time, and mount IDs were added in
+    // Linux 5.8, so only check them when the kernel says they're present.
+    if mask.contains(StatxFlags::BTIME) {
+        assert!(result.stx_btime.tv_sec != 0 || result.stx_btime.tv_nsec != 0);
+    }
+    if mask.contains(StatxFlags::MNT_ID) {
+        assert_ne!(result.stx_mnt_id, 0);
+    }
+
+    // A regular file is never a mount root unless something's bind-mounted
+    // on it, which the tests don't do.
+    let supported = StatxAttributes::from_bits_retain(result.stx_attributes_mask);
+    let attributes = StatxAttributes::from_bits_retain(result.stx_attributes);
+    if supported.contains(StatxAttributes::MOUNT_ROOT) {
+        assert!(!attributes.contains(StatxAttributes::MOUNT_ROOT));
+    }
+}
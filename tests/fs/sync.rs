@@ -9,3 +9,30 @@ fn test_syncfs() {
     let f = std::fs::File::open("Cargo.toml").unwrap();
     rustix::fs::syncfs(&f).unwrap();
 }
+
+#[cfg(linux_kernel)]
+#[test]
+fn test_sync_file_range() {
+    use rustix::fs::{sync_file_range, SyncFileRangeFlags};
+    use std::io::Write;
+
+    let mut f = tempfile::tempfile().unwrap();
+    f.write_all(&[0_u8; 8192]).unwrap();
+
+    sync_file_range(&f, 0, 4096, SyncFileRangeFlags::WRITE).unwrap();
+    sync_file_range(
+        &f,
+        0,
+        0,
+        SyncFileRangeFlags::WAIT_BEFORE
+            | SyncFileRangeFlags::WRITE
+            | SyncFileRangeFlags::WAIT_AFTER,
+    )
+    .unwrap();
+
+    // Offsets that are negative as an `loff_t` are rejected.
+    assert_eq!(
+        sync_file_range(&f, u64::MAX, 0, SyncFileRangeFlags::WRITE),
+        Err(rustix::io::Errno::INVAL)
+    );
+}
@@ -0,0 +1,22 @@
+#[test]
+fn test_truncate() {
+    use rustix::fs::{open, stat, truncate, Mode, OFlags};
+
+    let tmp = tempfile::tempdir().unwrap();
+
+    let _ = open(
+        tmp.path().join("file"),
+        OFlags::CREATE | OFlags::WRONLY,
+        Mode::RUSR | Mode::WUSR,
+    )
+    .unwrap();
+    assert_eq!(stat(tmp.path().join("file")).unwrap().st_size, 0);
+
+    truncate(tmp.path().join("file"), 4096).unwrap();
+    assert_eq!(stat(tmp.path().join("file")).unwrap().st_size, 4096);
+
+    truncate(tmp.path().join("file"), 1).unwrap();
+    assert_eq!(stat(tmp.path().join("file")).unwrap().st_size, 1);
+
+    truncate(tmp.path().join("absent"), 0).unwrap_err();
+}
@@ -127,3 +127,63 @@ fn test_utimensat_notdir() {
         rustix::io::Errno::NOTDIR
     );
 }
+
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+#[test]
+fn test_utimensat_symlink_nofollow() {
+    use rustix::fs::{
+        openat, statat, symlinkat, utimensat, AtFlags, Mode, OFlags, StatExt, Timespec, Timestamps,
+        CWD, TIMESPEC_OMIT,
+    };
+
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(
+        CWD,
+        tmp.path(),
+        OFlags::RDONLY | OFlags::CLOEXEC,
+        Mode::empty(),
+    )
+    .unwrap();
+
+    let _ = openat(
+        &dir,
+        "file",
+        OFlags::CREATE | OFlags::WRONLY | OFlags::CLOEXEC,
+        Mode::empty(),
+    )
+    .unwrap();
+    symlinkat("file", &dir, "link").unwrap();
+    let file_before = statat(&dir, "file", AtFlags::empty()).unwrap();
+    let link_before = statat(&dir, "link", AtFlags::SYMLINK_NOFOLLOW).unwrap();
+
+    // Set the modification time of the symlink itself, leaving its access
+    // time alone.
+    let times = Timestamps {
+        last_access: TIMESPEC_OMIT,
+        last_modification: Timespec {
+            tv_sec: 46000,
+            tv_nsec: 0,
+        },
+    };
+    utimensat(&dir, "link", &times, AtFlags::SYMLINK_NOFOLLOW).unwrap();
+
+    let file_after = statat(&dir, "file", AtFlags::empty()).unwrap();
+    let link_after = statat(&dir, "link", AtFlags::SYMLINK_NOFOLLOW).unwrap();
+    assert_eq!(link_after.mtime(), 46000);
+    assert_eq!(link_after.atime(), link_before.atime());
+    assert_eq!(file_after.mtime(), file_before.mtime());
+
+    // Omitting both timestamps changes nothing.
+    utimensat(&dir, "link", &Timestamps::OMIT, AtFlags::SYMLINK_NOFOLLOW).unwrap();
+    let link_omit = statat(&dir, "link", AtFlags::SYMLINK_NOFOLLOW).unwrap();
+    assert_eq!(link_omit.mtime(), 46000);
+
+    // Setting both to now moves them past the old modification time.
+    utimensat(&dir, "link", &Timestamps::NOW, AtFlags::SYMLINK_NOFOLLOW).unwrap();
+    let link_now = statat(&dir, "link", AtFlags::SYMLINK_NOFOLLOW).unwrap();
+    assert!(link_now.mtime() > 46000);
+    assert_eq!(
+        statat(&dir, "file", AtFlags::empty()).unwrap().mtime(),
+        file_before.mtime()
+    );
+}
@@ -5,9 +5,9 @@ fn xattr_basic() {
     use rustix::fs::XattrFlags;
 
     // The error code when an attribute doesn't exist.
-    #[cfg(not(apple))]
+    #[cfg(not(any(apple, target_os = "freebsd")))]
     let enodata = libc::ENODATA;
-    #[cfg(apple)]
+    #[cfg(any(apple, target_os = "freebsd"))]
     let enodata = libc::ENOATTR;
 
     assert_eq!(
@@ -119,3 +119,40 @@ fn xattr_basic() {
         enodata
     );
 }
+
+#[test]
+fn xattr_vec() {
+    use rustix::fs::XattrFlags;
+
+    let tmp = tempfile::NamedTempFile::new().unwrap();
+    match rustix::fs::fsetxattr(&tmp, "user.test", b"value", XattrFlags::CREATE) {
+        Ok(()) => (),
+        // Not all filesystems support user attributes.
+        Err(rustix::io::Errno::NOTSUP) => return,
+        Err(err) => panic!("{:?}", err),
+    }
+
+    assert_eq!(
+        rustix::fs::getxattr_vec(tmp.path(), "user.test", Vec::new()).unwrap(),
+        b"value"
+    );
+    assert_eq!(
+        rustix::fs::lgetxattr_vec(tmp.path(), "user.test", Vec::new()).unwrap(),
+        b"value"
+    );
+
+    // A reused buffer that's too small is grown, and one that's too large is
+    // truncated.
+    let value = rustix::fs::fgetxattr_vec(&tmp, "user.test", Vec::with_capacity(2)).unwrap();
+    assert_eq!(value, b"value");
+    let value = rustix::fs::fgetxattr_vec(&tmp, "user.test", Vec::with_capacity(64)).unwrap();
+    assert_eq!(value, b"value");
+
+    // Other attributes, such as security labels, may also be listed.
+    let list = rustix::fs::listxattr_vec(tmp.path(), Vec::new()).unwrap();
+    assert!(list.split(|b| *b == 0).any(|name| name == b"user.test"));
+    let list = rustix::fs::llistxattr_vec(tmp.path(), list).unwrap();
+    assert!(list.split(|b| *b == 0).any(|name| name == b"user.test"));
+    let list = rustix::fs::flistxattr_vec(&tmp, list).unwrap();
+    assert!(list.split(|b| *b == 0).any(|name| name == b"user.test"));
+}
@@ -0,0 +1,15 @@
+use rustix::gpio::{LineConfig, LineFlags};
+use rustix::io::Errno;
+
+#[test]
+fn test_line_config_attrs() {
+    let mut config = LineConfig::new(LineFlags::INPUT);
+    for i in 0..10 {
+        config.add_debounce_period_us(1000, 1 << i).unwrap();
+    }
+    assert_eq!(
+        config.add_flags(LineFlags::OUTPUT, 1 << 10),
+        Err(Errno::INVAL)
+    );
+    assert_eq!(config.add_output_values(1, 1), Err(Errno::INVAL));
+}
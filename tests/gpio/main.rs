@@ -0,0 +1,6 @@
+//! Tests for [`rustix::gpio`].
+
+#![cfg(feature = "gpio")]
+#![cfg(linux_kernel)]
+
+mod lines;
@@ -0,0 +1,35 @@
+use rustix::input::{read_events, EventType, InputEvent};
+
+#[cfg(feature = "pipe")]
+#[test]
+fn test_read_events() {
+    let (reader, writer) = rustix::pipe::pipe().unwrap();
+
+    let mut bytes = Vec::new();
+    for (code, value) in [(30_u16, 1_i32), (30, 0)] {
+        let event = InputEvent {
+            sec: 1,
+            usec: 2,
+            type_: EventType::KEY.as_raw(),
+            code,
+            value,
+        };
+        // SAFETY: `InputEvent` is a plain `repr(C)` struct with no padding.
+        bytes.extend_from_slice(unsafe {
+            core::slice::from_raw_parts(
+                (&event as *const InputEvent).cast::<u8>(),
+                core::mem::size_of::<InputEvent>(),
+            )
+        });
+    }
+    rustix::io::write(&writer, &bytes).unwrap();
+
+    let mut events = [InputEvent::default(); 4];
+    assert_eq!(read_events(&reader, &mut events).unwrap(), 2);
+    assert_eq!(events[0].event_type(), EventType::KEY);
+    assert_eq!(events[0].code, 30);
+    assert_eq!(events[0].value, 1);
+    assert_eq!(events[1].value, 0);
+    assert_eq!(events[1].sec, 1);
+    assert_eq!(events[1].usec, 2);
+}
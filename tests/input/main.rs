@@ -0,0 +1,7 @@
+//! Tests for [`rustix::input`].
+
+#![cfg(feature = "input")]
+#![cfg(linux_kernel)]
+
+mod evdev;
+mod uinput;
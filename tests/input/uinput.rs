@@ -0,0 +1,23 @@
+use rustix::input::uinput::write_events;
+use rustix::input::{EventType, InputEvent};
+
+#[cfg(feature = "pipe")]
+#[test]
+fn test_write_events() {
+    let (reader, writer) = rustix::pipe::pipe().unwrap();
+
+    let events = [
+        InputEvent {
+            type_: EventType::KEY.as_raw(),
+            code: 30,
+            value: 1,
+            ..InputEvent::default()
+        },
+        InputEvent::default(),
+    ];
+    assert_eq!(write_events(&writer, &events).unwrap(), 2);
+
+    let mut read = [InputEvent::default(); 2];
+    assert_eq!(rustix::input::read_events(&reader, &mut read).unwrap(), 2);
+    assert_eq!(read, events);
+}
@@ -48,3 +48,30 @@ fn test_dup() {
         Ok(12)
     );
 }
+
+// Apple platforms, DragonFly BSD, Haiku, and Redox emulate `dup3` with `dup2`,
+// which ignores the flags.
+#[cfg(all(
+    feature = "fs",
+    not(any(
+        apple,
+        target_os = "aix",
+        target_os = "dragonfly",
+        target_os = "haiku",
+        target_os = "redox",
+    ))
+))]
+#[test]
+fn test_dup3_cloexec() {
+    use rustix::io::{fcntl_getfd, DupFlags, FdFlags};
+
+    let file = std::fs::File::open("Cargo.toml").unwrap();
+    let mut new = rustix::io::dup(&file).unwrap();
+    assert_eq!(fcntl_getfd(&new), Ok(FdFlags::empty()));
+
+    rustix::io::dup3(&file, &mut new, DupFlags::CLOEXEC).unwrap();
+    assert_eq!(fcntl_getfd(&new), Ok(FdFlags::CLOEXEC));
+
+    rustix::io::dup3(&file, &mut new, DupFlags::empty()).unwrap();
+    assert_eq!(fcntl_getfd(&new), Ok(FdFlags::empty()));
+}
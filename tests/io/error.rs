@@ -12,3 +12,55 @@ fn test_error() {
         windows_sys::Win32::Networking::WinSock::WSAEINVAL
     );
 }
+
+#[cfg(linux_kernel)]
+#[test]
+fn test_error_kind_str() {
+    use rustix::io::Errno;
+
+    assert_eq!(Errno::PERM.kind_str(), Some("Operation not permitted"));
+    assert_eq!(Errno::NOENT.kind_str(), Some("No such file or directory"));
+    assert_eq!(Errno::WOULDBLOCK.kind_str(), Errno::AGAIN.kind_str());
+    assert_eq!(Errno::from_raw_os_error(4095).kind_str(), None);
+
+    assert_eq!(
+        Errno::INVAL.to_string(),
+        format!(
+            "Invalid argument (os error {})",
+            Errno::INVAL.raw_os_error()
+        )
+    );
+    assert_eq!(
+        Errno::from_raw_os_error(4095).to_string(),
+        std::io::Error::from_raw_os_error(4095).to_string()
+    );
+}
+
+#[cfg(not(windows))]
+#[test]
+fn test_error_kind() {
+    use rustix::io::Errno;
+    use std::io::ErrorKind;
+
+    assert_eq!(Errno::NOENT.kind(), ErrorKind::NotFound);
+    assert_eq!(Errno::ACCESS.kind(), ErrorKind::PermissionDenied);
+    assert_eq!(Errno::PERM.kind(), ErrorKind::PermissionDenied);
+    assert_eq!(Errno::EXIST.kind(), ErrorKind::AlreadyExists);
+    assert_eq!(Errno::AGAIN.kind(), ErrorKind::WouldBlock);
+    assert_eq!(Errno::INTR.kind(), ErrorKind::Interrupted);
+    assert_eq!(Errno::INVAL.kind(), ErrorKind::InvalidInput);
+    assert_eq!(Errno::PIPE.kind(), ErrorKind::BrokenPipe);
+    assert_eq!(Errno::TIMEDOUT.kind(), ErrorKind::TimedOut);
+
+    // `kind` agrees with std's classification, and the conversions to and
+    // from `std::io::Error` round-trip.
+    for errno in [Errno::NOENT, Errno::CONNREFUSED, Errno::NOMEM, Errno::NOTTY] {
+        let io_err = std::io::Error::from(errno);
+        assert_eq!(errno.kind(), io_err.kind());
+        assert_eq!(Errno::from_io_error(&io_err), Some(errno));
+    }
+    assert_eq!(
+        Errno::from_io_error(&std::io::Error::new(ErrorKind::Other, "custom")),
+        None
+    );
+}
@@ -13,3 +13,38 @@ fn test_ioctls() {
         file.metadata().unwrap().len()
     );
 }
+
+#[cfg(all(feature = "fs", feature = "pipe"))]
+#[cfg(not(windows))]
+#[test]
+fn test_set_nonblocking() {
+    use rustix::fs::{fcntl_getfl, OFlags};
+
+    let (reader, _writer) = rustix::pipe::pipe().unwrap();
+    assert!(!fcntl_getfl(&reader).unwrap().contains(OFlags::NONBLOCK));
+
+    rustix::io::set_nonblocking(&reader, true).unwrap();
+    assert!(fcntl_getfl(&reader).unwrap().contains(OFlags::NONBLOCK));
+    assert_eq!(
+        rustix::io::read(&reader, &mut [0_u8; 1]),
+        Err(rustix::io::Errno::AGAIN)
+    );
+
+    rustix::io::set_nonblocking(&reader, false).unwrap();
+    assert!(!fcntl_getfl(&reader).unwrap().contains(OFlags::NONBLOCK));
+}
+
+#[cfg(any(bsd, linux_kernel, solarish))]
+#[test]
+fn test_fioclex() {
+    use rustix::io::{fcntl_getfd, FdFlags};
+
+    let file = std::fs::File::open("Cargo.toml").unwrap();
+    assert!(fcntl_getfd(&file).unwrap().contains(FdFlags::CLOEXEC));
+
+    rustix::io::ioctl_fionclex(&file).unwrap();
+    assert!(!fcntl_getfd(&file).unwrap().contains(FdFlags::CLOEXEC));
+
+    rustix::io::ioctl_fioclex(&file).unwrap();
+    assert!(fcntl_getfd(&file).unwrap().contains(FdFlags::CLOEXEC));
+}
@@ -68,6 +68,40 @@ fn test_readwrite_p() {
     assert_eq!(&buf, b"world");
 }
 
+#[cfg(feature = "fs")]
+#[test]
+fn test_read_exact() {
+    use rustix::fs::{openat, seek, Mode, OFlags, SeekFrom, CWD};
+    use rustix::io::{pread_exact, pwrite, read_exact, Errno};
+
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(CWD, tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+    let file = openat(
+        &dir,
+        "file",
+        OFlags::RDWR | OFlags::CREATE | OFlags::TRUNC,
+        Mode::RUSR | Mode::WUSR,
+    )
+    .unwrap();
+
+    pwrite(&file, b"hello world", 0).unwrap();
+
+    let mut buf = [0_u8; 5];
+    pread_exact(&file, &mut buf, 6).unwrap();
+    assert_eq!(&buf, b"world");
+
+    // Reaching the end of the file early is an error.
+    assert_eq!(pread_exact(&file, &mut buf, 7), Err(Errno::IO));
+
+    read_exact(&file, &mut buf).unwrap();
+    assert_eq!(&buf, b"hello");
+    seek(&file, SeekFrom::Start(8)).unwrap();
+    assert_eq!(read_exact(&file, &mut buf), Err(Errno::IO));
+
+    // An empty buffer never reads.
+    read_exact(&file, &mut []).unwrap();
+}
+
 #[cfg(feature = "fs")]
 #[test]
 fn test_readwrite_p_uninit() {
@@ -121,6 +155,40 @@ fn test_readwrite_v() {
     assert_eq!(&buf, b"world");
 }
 
+#[cfg(not(target_os = "espidf"))] // no writev
+#[cfg(feature = "fs")]
+#[test]
+fn test_writev_from_iter() {
+    use rustix::fs::{openat, seek, Mode, OFlags, SeekFrom, CWD};
+    use rustix::io::{read, writev_from_iter};
+
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(CWD, tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+    let file = openat(
+        &dir,
+        "file",
+        OFlags::RDWR | OFlags::CREATE | OFlags::TRUNC,
+        Mode::RUSR | Mode::WUSR,
+    )
+    .unwrap();
+
+    // The two halves of a wrapped-around ring buffer.
+    let ring = *b"worldhello ";
+    let (tail, head) = ring.split_at(5);
+    assert_eq!(writev_from_iter(&file, [head, tail]).unwrap(), 11);
+    assert_eq!(writev_from_iter(&file, core::iter::empty()).unwrap(), 0);
+
+    // Buffers beyond the limit are left in the iterator.
+    let mut bufs = core::iter::repeat(&b"x"[..]).take(2000);
+    let n = writev_from_iter(&file, &mut bufs).unwrap();
+    assert_eq!(n + bufs.count(), 2000);
+
+    seek(&file, SeekFrom::Start(0)).unwrap();
+    let mut buf = [0_u8; 11];
+    read(&file, &mut buf).unwrap();
+    assert_eq!(&buf, b"hello world");
+}
+
 #[cfg(feature = "fs")]
 #[test]
 fn test_readwrite() {
@@ -418,3 +486,111 @@ fn test_p_offsets() {
         }
     }
 }
+
+#[cfg(feature = "fs")]
+#[test]
+fn test_offset_cursor() {
+    use rustix::fs::{openat, seek, Mode, OFlags, SeekFrom, CWD};
+    use rustix::io::OffsetCursor;
+
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(CWD, tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+    let file = openat(
+        &dir,
+        "file",
+        OFlags::RDWR | OFlags::CREATE | OFlags::TRUNC,
+        Mode::RUSR | Mode::WUSR,
+    )
+    .unwrap();
+
+    let mut writer = OffsetCursor::new(&file, 100);
+    assert_eq!(writer.write(b"hello").unwrap(), 5);
+    assert_eq!(writer.write(b"world").unwrap(), 5);
+    assert_eq!(writer.offset(), 110);
+
+    let mut reader = OffsetCursor::new(&file, 100);
+    let mut buf = [0_u8; 5];
+    reader.read_exact(&mut buf).unwrap();
+    assert_eq!(&buf, b"hello");
+    assert_eq!(reader.read(&mut buf).unwrap(), 5);
+    assert_eq!(&buf, b"world");
+    assert_eq!(reader.read(&mut buf).unwrap(), 0);
+    assert_eq!(reader.offset(), 110);
+
+    // A failed `read_exact` leaves the offset alone.
+    reader.set_offset(108);
+    assert_eq!(reader.read_exact(&mut buf), Err(rustix::io::Errno::IO));
+    assert_eq!(reader.offset(), 108);
+
+    // The file position isn't used.
+    assert_eq!(seek(&file, SeekFrom::Current(0)).unwrap(), 0);
+
+    // With `std`, the cursor works with `std::io` adapters.
+    let mut reader = OffsetCursor::new(&file, 100);
+    let mut s = String::new();
+    std::io::Read::read_to_string(&mut reader, &mut s).unwrap();
+    assert_eq!(s, "helloworld");
+}
+
+#[cfg(feature = "fs")]
+#[test]
+fn test_fd_reader_writer() {
+    use rustix::fs::{openat, Mode, OFlags, CWD};
+    use rustix::io::{FdReader, FdWriter};
+    use std::io::{Read, Seek, SeekFrom, Write};
+
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(CWD, tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+    let file = openat(
+        &dir,
+        "file",
+        OFlags::RDWR | OFlags::CREATE | OFlags::TRUNC,
+        Mode::RUSR | Mode::WUSR,
+    )
+    .unwrap();
+
+    let mut writer = FdWriter::new(&file);
+    write!(writer, "hello world").unwrap();
+    writer.flush().unwrap();
+
+    let mut reader = FdReader::new(&file);
+    assert_eq!(reader.seek(SeekFrom::Start(6)).unwrap(), 6);
+    let mut s = String::new();
+    reader.read_to_string(&mut s).unwrap();
+    assert_eq!(s, "world");
+    assert_eq!(reader.stream_position().unwrap(), 11);
+}
+
+#[cfg(linux_kernel)]
+#[cfg(feature = "fs")]
+#[test]
+fn test_append_write() {
+    use rustix::fs::{openat, seek, Mode, OFlags, SeekFrom, CWD};
+    use rustix::io::{append_write, pread, write};
+
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(CWD, tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+    let file = openat(
+        &dir,
+        "file",
+        OFlags::RDWR | OFlags::CREATE | OFlags::TRUNC,
+        Mode::RUSR | Mode::WUSR,
+    )
+    .unwrap();
+
+    write(&file, b"hello").unwrap();
+    seek(&file, SeekFrom::Start(1)).unwrap();
+
+    match append_write(&file, b"world") {
+        Ok(n) => assert_eq!(n, 5),
+        // Skip the rest of the test if we don't have `RWF_APPEND`.
+        Err(rustix::io::Errno::NOSYS | rustix::io::Errno::NOTSUP) => return,
+        Err(err) => panic!("{:?}", err),
+    }
+
+    // The data went to the end, and the file offset is unchanged.
+    assert_eq!(seek(&file, SeekFrom::Current(0)).unwrap(), 1);
+    let mut buf = [0_u8; 10];
+    assert_eq!(pread(&file, &mut buf, 0).unwrap(), 10);
+    assert_eq!(&buf, b"helloworld");
+}
@@ -0,0 +1,64 @@
+use rustix::cstr;
+use rustix::io::Errno;
+use rustix::keyctl::*;
+
+#[test]
+fn test_keyctl_keys() {
+    // Use a thread keyring, which is private to this test's thread, rather
+    // than modifying the session keyring shared by the whole test process.
+    let keyring = match keyctl_get_keyring_id(KeySerial::THREAD_KEYRING, true) {
+        Ok(keyring) => keyring,
+        // Keyrings may be disabled or filtered out by a seccomp policy.
+        Err(Errno::NOSYS | Errno::PERM | Errno::ACCESS) => return,
+        Err(err) => panic!("{:?}", err),
+    };
+    assert!(keyring.as_raw() > 0);
+
+    let key = add_key(
+        cstr!("user"),
+        cstr!("rustix:test_keyctl_keys"),
+        b"hello",
+        keyring,
+    )
+    .unwrap();
+    assert!(key.as_raw() > 0);
+
+    let mut buf = [0_u8; 16];
+    assert_eq!(keyctl_read(key, &mut buf).unwrap(), 5);
+    assert_eq!(&buf[..5], b"hello");
+
+    // A too-small buffer is reported with the full payload length.
+    let mut small = [0_u8; 2];
+    assert_eq!(keyctl_read(key, &mut small).unwrap(), 5);
+
+    keyctl_update(key, b"goodbye").unwrap();
+    assert_eq!(keyctl_read(key, &mut buf).unwrap(), 7);
+    assert_eq!(&buf[..7], b"goodbye");
+
+    let found = keyctl_search(
+        keyring,
+        cstr!("user"),
+        cstr!("rustix:test_keyctl_keys"),
+        None,
+    )
+    .unwrap();
+    assert_eq!(found, key);
+
+    let found = request_key(cstr!("user"), cstr!("rustix:test_keyctl_keys"), None, None).unwrap();
+    assert_eq!(found, key);
+
+    keyctl_setperm(key, KeyPerm::POS_ALL | KeyPerm::USR_VIEW).unwrap();
+    keyctl_read(key, &mut buf).unwrap();
+
+    keyctl_unlink(key, keyring).unwrap();
+    assert_eq!(
+        keyctl_search(
+            keyring,
+            cstr!("user"),
+            cstr!("rustix:test_keyctl_keys"),
+            None,
+        )
+        .unwrap_err(),
+        Errno::NOKEY
+    );
+}
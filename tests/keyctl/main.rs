@@ -0,0 +1,6 @@
+//! Tests for [`rustix::keyctl`].
+
+#![cfg(feature = "keyctl")]
+#![cfg(linux_kernel)]
+
+mod keys;
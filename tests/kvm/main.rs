@@ -0,0 +1,6 @@
+//! Tests for [`rustix::kvm`].
+
+#![cfg(feature = "kvm")]
+#![cfg(linux_kernel)]
+
+mod vm;
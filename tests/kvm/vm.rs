@@ -0,0 +1,69 @@
+#![cfg(all(feature = "fs", feature = "mm", target_arch = "x86_64"))]
+
+use rustix::io::Errno;
+use rustix::kvm::{
+    ioctl_kvm_create_vcpu, ioctl_kvm_create_vm, ioctl_kvm_get_vcpu_mmap_size, ioctl_kvm_run,
+};
+
+fn open_kvm() -> Option<rustix::fd::OwnedFd> {
+    use rustix::fs::{open, Mode, OFlags};
+
+    match open("/dev/kvm", OFlags::RDWR | OFlags::CLOEXEC, Mode::empty()) {
+        Ok(kvm) => Some(kvm),
+        Err(Errno::NOENT | Errno::ACCESS | Errno::PERM | Errno::NODEV | Errno::NXIO) => None,
+        Err(err) => panic!("{:?}", err),
+    }
+}
+
+/// Run a guest that halts immediately at the x86 reset vector.
+#[test]
+fn test_kvm_run_hlt() {
+    use rustix::kvm::{
+        ioctl_kvm_set_user_memory_region, mmap_run, ExitReason, MemoryRegionFlags,
+        UserspaceMemoryRegion,
+    };
+    use rustix::mm::{mmap_anonymous, munmap, MapFlags, ProtFlags};
+
+    let kvm = match open_kvm() {
+        Some(kvm) => kvm,
+        None => return,
+    };
+    let vm = ioctl_kvm_create_vm(&kvm, 0).unwrap();
+
+    const MEM_SIZE: usize = 0x1_0000;
+    unsafe {
+        let mem = mmap_anonymous(
+            core::ptr::null_mut(),
+            MEM_SIZE,
+            ProtFlags::READ | ProtFlags::WRITE,
+            MapFlags::PRIVATE,
+        )
+        .unwrap();
+        // Fill the memory with `hlt` instructions.
+        core::ptr::write_bytes(mem.cast::<u8>(), 0xf4, MEM_SIZE);
+
+        // The reset vector is 16 bytes below 4 GiB.
+        let region = UserspaceMemoryRegion {
+            slot: 0,
+            flags: MemoryRegionFlags::empty(),
+            guest_phys_addr: 0x1_0000_0000 - MEM_SIZE as u64,
+            memory_size: MEM_SIZE as u64,
+            userspace_addr: mem as u64,
+        };
+        ioctl_kvm_set_user_memory_region(&vm, &region).unwrap();
+
+        let vcpu = ioctl_kvm_create_vcpu(&vm, 0).unwrap();
+        let size = ioctl_kvm_get_vcpu_mmap_size(&kvm).unwrap();
+        let run = mmap_run(&vcpu, size).unwrap();
+
+        ioctl_kvm_run(&vcpu).unwrap();
+        assert_eq!((*run).exit_reason(), ExitReason::HLT);
+        assert!((*run).io().is_none());
+        assert!((*run).mmio_mut().is_none());
+
+        munmap(run.cast(), size).unwrap();
+        drop(vcpu);
+        drop(vm);
+        munmap(mem, MEM_SIZE).unwrap();
+    }
+}
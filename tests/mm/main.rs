@@ -7,5 +7,7 @@
 mod mlock;
 #[cfg(not(any(windows, target_os = "wasi")))]
 mod mmap;
+#[cfg(linux_kernel)]
+mod pkey;
 #[cfg(not(any(windows, target_os = "wasi")))]
 mod prot;
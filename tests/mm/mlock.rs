@@ -79,3 +79,20 @@ fn test_mlock_with_onfault() {
         }
     }
 }
+
+#[cfg(linux_kernel)]
+#[test]
+fn test_mlockall_onfault() {
+    use rustix::mm::{mlockall, munlockall, MlockAllFlags};
+
+    // Only lock the pages that are already mapped, and only as they're faulted
+    // in, so that this doesn't affect allocations in other tests.
+    match mlockall(MlockAllFlags::CURRENT | MlockAllFlags::ONFAULT) {
+        Ok(()) => munlockall().unwrap(),
+        // Tests won't always have enough memory or permissions, and that's ok.
+        // `MCL_ONFAULT` is new in Linux 4.4.
+        Err(rustix::io::Errno::PERM | rustix::io::Errno::NOMEM | rustix::io::Errno::INVAL) => {}
+        // But they shouldn't fail otherwise.
+        Err(other) => panic!("{:?}", other),
+    }
+}
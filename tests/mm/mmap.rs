@@ -187,3 +187,141 @@ fn test_mremap() {
         munmap(addr.offset(4096), 4096).unwrap();
     }
 }
+
+#[cfg(all(linux_kernel, feature = "param"))]
+#[test]
+fn test_mincore() {
+    use rustix::mm::{mincore, mmap_anonymous, munmap, MapFlags, ProtFlags};
+    use rustix::param::page_size;
+    use std::ptr::null_mut;
+
+    let page_size = page_size();
+    let len = page_size * 4;
+
+    let addr = unsafe {
+        mmap_anonymous(
+            null_mut(),
+            len,
+            ProtFlags::READ | ProtFlags::WRITE,
+            MapFlags::PRIVATE,
+        )
+        .unwrap()
+    };
+
+    unsafe {
+        // Touch the first and third pages so that they're resident.
+        addr.cast::<u8>().write(1);
+        addr.cast::<u8>().add(page_size * 2).write(1);
+    }
+
+    let mut vec = [0_u8; 4];
+    mincore(addr, len, &mut vec).unwrap();
+    let resident: Vec<bool> = vec.iter().map(|byte| byte & 1 != 0).collect();
+    assert_eq!(resident, [true, false, true, false]);
+
+    // A `vec` with fewer than one byte per page is rejected.
+    assert_eq!(
+        mincore(addr, len, &mut vec[..3]),
+        Err(rustix::io::Errno::INVAL)
+    );
+
+    unsafe {
+        munmap(addr, len).unwrap();
+    }
+}
+
+#[cfg(linux_kernel)]
+#[test]
+fn test_hugetlb_flags() {
+    use rustix::mm::MapFlags;
+
+    assert_eq!(
+        MapFlags::hugetlb_with_size_log2(21),
+        Some(MapFlags::HUGETLB | MapFlags::HUGE_2MB)
+    );
+    assert_eq!(MapFlags::hugetlb_with_size_log2(15), None);
+    assert_eq!(MapFlags::hugetlb_with_size_log2(64), None);
+}
+
+#[cfg(linux_kernel)]
+#[test]
+fn test_madvise_hugepage() {
+    use rustix::mm::{madvise, mmap_anonymous, munmap, Advice, MapFlags, ProtFlags};
+    use std::ptr::null_mut;
+
+    let len = 4 << 20;
+
+    unsafe {
+        let addr = mmap_anonymous(
+            null_mut(),
+            len,
+            ProtFlags::READ | ProtFlags::WRITE,
+            MapFlags::PRIVATE,
+        )
+        .unwrap();
+
+        match madvise(addr, len, Advice::LinuxHugepage) {
+            Ok(()) => madvise(addr, len, Advice::LinuxNoHugepage).unwrap(),
+            // Kernels built without transparent hugepage support reject this.
+            Err(rustix::io::Errno::INVAL) => {}
+            Err(err) => panic!("{:?}", err),
+        }
+
+        munmap(addr, len).unwrap();
+    }
+}
+
+#[test]
+fn test_shared_anonymous_map() {
+    use rustix::mm::{munmap, shared_anonymous_map};
+
+    let addr = shared_anonymous_map(8192).unwrap();
+
+    unsafe {
+        let bytes = std::slice::from_raw_parts_mut(addr.cast::<u8>(), 8192);
+        assert!(bytes.iter().all(|byte| *byte == 0));
+        bytes[4096] = 1;
+        assert_eq!(bytes[4096], 1);
+
+        munmap(addr, 8192).unwrap();
+    }
+}
+
+#[cfg(all(linux_kernel, feature = "process"))]
+#[test]
+fn test_process_madvise() {
+    use rustix::mm::{
+        mmap_anonymous, munmap, process_madvise, Advice, MapFlags, ProtFlags, RemoteIoVec,
+    };
+    use rustix::process::{getpid, pidfd_open, PidfdFlags};
+    use std::ptr::null_mut;
+
+    let pidfd = match pidfd_open(getpid(), PidfdFlags::empty()) {
+        Ok(pidfd) => pidfd,
+        Err(rustix::io::Errno::NOSYS) => return,
+        Err(err) => panic!("{:?}", err),
+    };
+
+    unsafe {
+        let addr = mmap_anonymous(
+            null_mut(),
+            8192,
+            ProtFlags::READ | ProtFlags::WRITE,
+            MapFlags::PRIVATE,
+        )
+        .unwrap();
+        addr.cast::<u8>().write(1);
+
+        match process_madvise(&pidfd, &[RemoteIoVec::new(addr, 8192)], Advice::LinuxCold) {
+            Ok(n) => assert_eq!(n, 8192),
+            // `process_madvise` is new in Linux 5.10, `MADV_COLD` in 5.4,
+            // and it requires `CAP_SYS_NICE`.
+            Err(rustix::io::Errno::NOSYS | rustix::io::Errno::INVAL | rustix::io::Errno::PERM) => {}
+            Err(err) => panic!("{:?}", err),
+        }
+
+        assert_eq!(addr.cast::<u8>().read(), 1);
+
+        munmap(addr, 8192).unwrap();
+    }
+}
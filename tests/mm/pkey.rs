@@ -0,0 +1,80 @@
+use rustix::mm::{pkey_alloc, pkey_free, PkeyRights};
+
+/// Allocates a protection key, or returns `None` if the CPU or kernel doesn't
+/// support them.
+fn alloc(rights: PkeyRights) -> Option<u32> {
+    match pkey_alloc(rights) {
+        Ok(pkey) => Some(pkey),
+        Err(rustix::io::Errno::INVAL | rustix::io::Errno::NOSPC | rustix::io::Errno::NOSYS) => None,
+        Err(err) => panic!("{:?}", err),
+    }
+}
+
+#[test]
+fn test_pkey_alloc_free() {
+    let pkey = match alloc(PkeyRights::empty()) {
+        Some(pkey) => pkey,
+        None => return,
+    };
+    assert_ne!(pkey, 0);
+    pkey_free(pkey).unwrap();
+
+    assert_eq!(pkey_free(u32::MAX), Err(rustix::io::Errno::INVAL));
+}
+
+#[test]
+fn test_pkey_mprotect() {
+    use rustix::mm::{mmap_anonymous, munmap, pkey_mprotect, MapFlags, MprotectFlags, ProtFlags};
+    use std::ptr::null_mut;
+
+    let pkey = match alloc(PkeyRights::empty()) {
+        Some(pkey) => pkey,
+        None => return,
+    };
+
+    unsafe {
+        let addr = mmap_anonymous(null_mut(), 8192, ProtFlags::READ, MapFlags::PRIVATE).unwrap();
+
+        pkey_mprotect(addr, 8192, MprotectFlags::READ | MprotectFlags::WRITE, pkey).unwrap();
+        addr.cast::<u8>().write(1);
+
+        munmap(addr, 8192).unwrap();
+    }
+
+    pkey_free(pkey).unwrap();
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[test]
+fn test_pkey_get_unsupported() {
+    // This shouldn't raise `SIGILL` on CPUs without protection keys.
+    match rustix::mm::pkey_get(0) {
+        Ok(_) | Err(rustix::io::Errno::NOSYS) => (),
+        Err(err) => panic!("{:?}", err),
+    }
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[test]
+fn test_pkey_get_set() {
+    use rustix::mm::{pkey_get, pkey_set};
+
+    let pkey = match alloc(PkeyRights::DISABLE_WRITE) {
+        Some(pkey) => pkey,
+        None => return,
+    };
+
+    assert_eq!(pkey_get(pkey).unwrap(), PkeyRights::DISABLE_WRITE);
+    unsafe {
+        pkey_set(pkey, PkeyRights::DISABLE_ACCESS).unwrap();
+    }
+    assert_eq!(pkey_get(pkey).unwrap(), PkeyRights::DISABLE_ACCESS);
+    unsafe {
+        pkey_set(pkey, PkeyRights::empty()).unwrap();
+    }
+    assert_eq!(pkey_get(pkey).unwrap(), PkeyRights::empty());
+
+    assert_eq!(pkey_get(16), Err(rustix::io::Errno::INVAL));
+
+    pkey_free(pkey).unwrap();
+}
@@ -3,5 +3,35 @@
 #![cfg(feature = "mount")]
 #![cfg(linux_kernel)]
 
-// At this time, we have no tests for the `mount` functions, because they
-// all require elevated privileges.
+// At this time, we have few tests for the `mount` functions, because most
+// of them require elevated privileges.
+
+#[test]
+fn test_mount_setattr_invalid_flags() {
+    use rustix::fd::AsFd;
+    use rustix::io;
+    use rustix::mount::{
+        mount_setattr, MountAttr, MountAttrFlags, MountPropagationFlags, MountSetattrFlags,
+    };
+
+    let dir = std::fs::File::open(".").unwrap();
+    let attr = MountAttr {
+        attr_set: MountAttrFlags::empty(),
+        attr_clr: MountAttrFlags::empty(),
+        propagation: MountPropagationFlags::empty(),
+        userns_fd: None,
+    };
+
+    // The kernel rejects unknown flags before checking for privileges.
+    match mount_setattr(
+        dir.as_fd(),
+        "",
+        MountSetattrFlags::from_bits_retain(!0),
+        &attr,
+    ) {
+        Err(io::Errno::INVAL) => (),
+        // Linux before 5.12 doesn't have `mount_setattr`.
+        Err(io::Errno::NOSYS) => (),
+        other => panic!("{:?}", other),
+    }
+}
@@ -90,3 +90,55 @@ fn test_unix_addr() {
             .is_none());
     }
 }
+
+#[cfg(linux_kernel)]
+#[test]
+fn test_unix_addr_max_len() {
+    use rustix::net::{SocketAddrAny, SocketAddrStorage, SocketAddrUnix};
+
+    // Linux's `sun_path` is 108 bytes, and a path may fill it without a NUL
+    // terminator.
+    for len in [107, 108] {
+        let path = "a".repeat(len);
+        let addr = SocketAddrUnix::new(path.as_str()).unwrap();
+        assert_eq!(addr.path().unwrap().to_bytes(), path.as_bytes());
+
+        unsafe {
+            let mut encoded = std::mem::MaybeUninit::<SocketAddrStorage>::uninit();
+            let len = SocketAddrAny::Unix(addr.clone()).write(encoded.as_mut_ptr());
+            let decoded = SocketAddrAny::read(encoded.as_ptr(), len).unwrap();
+            assert_eq!(decoded, SocketAddrAny::Unix(addr));
+        }
+    }
+    assert_eq!(
+        SocketAddrUnix::new("a".repeat(109).as_str()).unwrap_err(),
+        rustix::io::Errno::NAMETOOLONG
+    );
+}
+
+#[cfg(linux_kernel)]
+#[test]
+fn test_unix_bind_max_len() {
+    use rustix::net::{
+        bind_unix, getsockname, socket, AddressFamily, SocketAddrAny, SocketAddrUnix, SocketType,
+    };
+    use std::os::unix::ffi::OsStrExt;
+
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = tmp.path().as_os_str().as_bytes();
+    let mut path = dir.to_vec();
+    path.push(b'/');
+    path.resize(108, b'a');
+
+    let addr = SocketAddrUnix::new(path.as_slice()).unwrap();
+    let sock = socket(AddressFamily::UNIX, SocketType::STREAM, None).unwrap();
+    bind_unix(&sock, &addr).unwrap();
+
+    match getsockname(&sock).unwrap() {
+        SocketAddrAny::Unix(name) => {
+            assert_eq!(name.path().unwrap().to_bytes(), path.as_slice());
+            assert_eq!(name, addr);
+        }
+        other => panic!("{:?}", other),
+    }
+}
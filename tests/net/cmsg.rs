@@ -32,4 +32,161 @@ fn test_buffer_sizes() {
     assert!(cmsg_space!(ScmRights(2)) < cmsg_space!(ScmRights(1), ScmRights(1)));
     assert!(cmsg_space!(ScmRights(1)) * 2 >= cmsg_space!(ScmRights(1), ScmRights(1)));
     assert!(cmsg_space!(ScmRights(1), ScmRights(0)) >= cmsg_space!(ScmRights(1)));
+
+    #[cfg(linux_kernel)]
+    {
+        assert!(cmsg_space!(IpPktInfo(1)) > 0);
+        assert!(cmsg_space!(IpPktInfo(1), Ipv6PktInfo(1)) > cmsg_space!(Ipv6PktInfo(1)));
+    }
+}
+
+#[cfg(linux_kernel)]
+#[test]
+fn test_ip_pktinfo() {
+    use rustix::cmsg_space;
+    use rustix::io::{IoSlice, IoSliceMut};
+    use rustix::net::{
+        bind_v4, getsockname, recvfrom, recvmsg, sendmsg_v4, sendto_v4, socket, sockopt,
+        AddressFamily, Ipv4Addr, Ipv4PktInfo, RecvAncillaryBuffer, RecvAncillaryMessage, RecvFlags,
+        SendAncillaryBuffer, SendAncillaryMessage, SendFlags, SocketAddrAny, SocketAddrV4,
+        SocketType,
+    };
+
+    let server = socket(AddressFamily::INET, SocketType::DGRAM, None).unwrap();
+    bind_v4(&server, &SocketAddrV4::new(Ipv4Addr::UNSPECIFIED, 0)).unwrap();
+    let port = match getsockname(&server).unwrap() {
+        SocketAddrAny::V4(addr) => addr.port(),
+        other => panic!("{:?}", other),
+    };
+    assert!(!sockopt::get_ip_pktinfo(&server).unwrap());
+    sockopt::set_ip_pktinfo(&server, true).unwrap();
+    assert!(sockopt::get_ip_pktinfo(&server).unwrap());
+
+    let client = socket(AddressFamily::INET, SocketType::DGRAM, None).unwrap();
+    let server_addr = SocketAddrV4::new(Ipv4Addr::LOCALHOST, port);
+    sendto_v4(&client, b"ping", SendFlags::empty(), &server_addr).unwrap();
+
+    // The server learns that the datagram was sent to the loopback address.
+    let mut buf = [0_u8; 16];
+    let mut space = [0; cmsg_space!(IpPktInfo(1))];
+    let mut cmsg_buffer = RecvAncillaryBuffer::new(&mut space);
+    let msg = recvmsg(
+        &server,
+        &mut [IoSliceMut::new(&mut buf)],
+        &mut cmsg_buffer,
+        RecvFlags::empty(),
+    )
+    .unwrap();
+    assert_eq!(&buf[..msg.bytes], b"ping");
+    let client_addr = match msg.address {
+        Some(SocketAddrAny::V4(addr)) => addr,
+        other => panic!("{:?}", other),
+    };
+    let info = match cmsg_buffer.drain().next() {
+        Some(RecvAncillaryMessage::IpPktInfo(info)) => info,
+        _ => panic!("no IP_PKTINFO message"),
+    };
+    assert_eq!(info.addr, Ipv4Addr::LOCALHOST);
+    assert_eq!(info.spec_dst, Ipv4Addr::LOCALHOST);
+    assert_ne!(info.ifindex, 0);
+
+    // Reply from that address.
+    let mut space = [0; cmsg_space!(IpPktInfo(1))];
+    let mut cmsg_buffer = SendAncillaryBuffer::new(&mut space);
+    assert!(
+        cmsg_buffer.push(SendAncillaryMessage::IpPktInfo(Ipv4PktInfo {
+            ifindex: 0,
+            spec_dst: info.spec_dst,
+            addr: Ipv4Addr::UNSPECIFIED,
+        }))
+    );
+    sendmsg_v4(
+        &server,
+        &client_addr,
+        &[IoSlice::new(b"pong")],
+        &mut cmsg_buffer,
+        SendFlags::empty(),
+    )
+    .unwrap();
+
+    let (n, from) = recvfrom(&client, &mut buf, RecvFlags::empty()).unwrap();
+    assert_eq!(&buf[..n], b"pong");
+    assert_eq!(from, Some(SocketAddrAny::V4(server_addr)));
+}
+
+#[cfg(linux_kernel)]
+#[test]
+fn test_ipv6_pktinfo() {
+    use rustix::cmsg_space;
+    use rustix::io::{IoSlice, IoSliceMut};
+    use rustix::net::{
+        bind_v6, getsockname, recvfrom, recvmsg, sendmsg_v6, sendto_v6, socket, sockopt,
+        AddressFamily, Ipv6Addr, Ipv6PktInfo, RecvAncillaryBuffer, RecvAncillaryMessage, RecvFlags,
+        SendAncillaryBuffer, SendAncillaryMessage, SendFlags, SocketAddrAny, SocketAddrV6,
+        SocketType,
+    };
+
+    let server = socket(AddressFamily::INET6, SocketType::DGRAM, None).unwrap();
+    // IPv6 may be disabled.
+    if bind_v6(&server, &SocketAddrV6::new(Ipv6Addr::UNSPECIFIED, 0, 0, 0)).is_err() {
+        return;
+    }
+    let port = match getsockname(&server).unwrap() {
+        SocketAddrAny::V6(addr) => addr.port(),
+        other => panic!("{:?}", other),
+    };
+    assert!(!sockopt::get_ipv6_recvpktinfo(&server).unwrap());
+    sockopt::set_ipv6_recvpktinfo(&server, true).unwrap();
+    assert!(sockopt::get_ipv6_recvpktinfo(&server).unwrap());
+
+    let client = socket(AddressFamily::INET6, SocketType::DGRAM, None).unwrap();
+    let server_addr = SocketAddrV6::new(Ipv6Addr::LOCALHOST, port, 0, 0);
+    if sendto_v6(&client, b"ping", SendFlags::empty(), &server_addr).is_err() {
+        return;
+    }
+
+    // The server learns that the datagram was sent to the loopback address.
+    let mut buf = [0_u8; 16];
+    let mut space = [0; cmsg_space!(Ipv6PktInfo(1))];
+    let mut cmsg_buffer = RecvAncillaryBuffer::new(&mut space);
+    let msg = recvmsg(
+        &server,
+        &mut [IoSliceMut::new(&mut buf)],
+        &mut cmsg_buffer,
+        RecvFlags::empty(),
+    )
+    .unwrap();
+    assert_eq!(&buf[..msg.bytes], b"ping");
+    let client_addr = match msg.address {
+        Some(SocketAddrAny::V6(addr)) => addr,
+        other => panic!("{:?}", other),
+    };
+    let info = match cmsg_buffer.drain().next() {
+        Some(RecvAncillaryMessage::Ipv6PktInfo(info)) => info,
+        _ => panic!("no IPV6_PKTINFO message"),
+    };
+    assert_eq!(info.addr, Ipv6Addr::LOCALHOST);
+    assert_ne!(info.ifindex, 0);
+
+    // Reply from that address.
+    let mut space = [0; cmsg_space!(Ipv6PktInfo(1))];
+    let mut cmsg_buffer = SendAncillaryBuffer::new(&mut space);
+    assert!(
+        cmsg_buffer.push(SendAncillaryMessage::Ipv6PktInfo(Ipv6PktInfo {
+            addr: info.addr,
+            ifindex: 0,
+        }))
+    );
+    sendmsg_v6(
+        &server,
+        &client_addr,
+        &[IoSlice::new(b"pong")],
+        &mut cmsg_buffer,
+        SendFlags::empty(),
+    )
+    .unwrap();
+
+    let (n, from) = recvfrom(&client, &mut buf, RecvFlags::empty()).unwrap();
+    assert_eq!(&buf[..n], b"pong");
+    assert_eq!(from, Some(SocketAddrAny::V6(server_addr)));
 }
@@ -725,3 +725,45 @@ fn net_dgram_v6_acceptfrom() {
 
     assert_eq!(request, &response[..n]);
 }
+
+/// Test `recv_to_vec`, which appends exactly one datagram at a time.
+#[cfg(not(any(target_os = "espidf", target_os = "vita")))]
+#[test]
+fn net_dgram_v4_recv_to_vec() {
+    let localhost = IpAddr::V4(Ipv4Addr::LOCALHOST);
+    let addr = SocketAddr::new(localhost, 0);
+    let listener = rustix::net::socket(AddressFamily::INET, SocketType::DGRAM, None).unwrap();
+    rustix::net::bind(&listener, &addr).expect("bind");
+
+    let local_addr = rustix::net::getsockname(&listener).unwrap();
+    let sender = rustix::net::socket(AddressFamily::INET, SocketType::DGRAM, None).unwrap();
+    rustix::net::connect_any(&sender, &local_addr).expect("connect");
+
+    // Stay under the 9216-byte default maximum datagram size on macOS and the
+    // BSDs.
+    let large = vec![0xa5_u8; 8000];
+    rustix::net::send(&sender, b"first", SendFlags::empty()).expect("send");
+    rustix::net::send(&sender, &large, SendFlags::empty()).expect("send");
+    rustix::net::send(&sender, b"", SendFlags::empty()).expect("send");
+    drop(sender);
+
+    let mut buf = b"prefix:".to_vec();
+    let n = rustix::net::recv_to_vec(&listener, &mut buf, RecvFlags::empty()).expect("recv");
+    assert_eq!(n, 5);
+    assert_eq!(buf, b"prefix:first");
+
+    buf.clear();
+    let n = rustix::net::recv_to_vec(&listener, &mut buf, RecvFlags::empty()).expect("recv");
+    assert_eq!(n, large.len());
+    assert_eq!(buf, large);
+
+    buf.clear();
+    let n = rustix::net::recv_to_vec(&listener, &mut buf, RecvFlags::empty()).expect("recv");
+    assert_eq!(n, 0);
+    assert!(buf.is_empty());
+
+    assert_eq!(
+        rustix::net::recv_to_vec(&listener, &mut buf, RecvFlags::DONTWAIT),
+        Err(rustix::io::Errno::WOULDBLOCK)
+    );
+}
@@ -13,6 +13,8 @@ mod dgram;
 #[cfg(feature = "event")]
 mod poll;
 mod sockopt;
+#[cfg(all(linux_kernel, feature = "fs", feature = "alloc"))]
+mod tun;
 #[cfg(unix)]
 mod unix;
 #[cfg(unix)]
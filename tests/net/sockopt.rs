@@ -372,6 +372,11 @@ fn test_sockopts_ipv4() {
             sockopt::get_ip_original_dst(&s),
             Err(io::Errno::NOENT | io::Errno::NOPROTOOPT)
         ));
+        #[cfg(linux_kernel)]
+        assert!(matches!(
+            sockopt::get_original_dst(&s),
+            Err(io::Errno::NOENT | io::Errno::NOPROTOOPT)
+        ));
     }
 
     test_sockopts_tcp(&s);
@@ -489,6 +494,10 @@ fn test_sockopts_ipv6() {
             sockopt::get_ipv6_original_dst(&s),
             Err(io::Errno::NOENT | io::Errno::NOPROTOOPT)
         ));
+        assert!(matches!(
+            sockopt::get_original_dst(&s),
+            Err(io::Errno::NOENT | io::Errno::NOPROTOOPT)
+        ));
     }
 
     test_sockopts_tcp(&s);
@@ -0,0 +1,37 @@
+use rustix::fs::{open, Mode, OFlags};
+use rustix::io::Errno;
+use rustix::net::tun::{ioctl_tungetiff, ioctl_tunsetiff, ioctl_tunsetpersist, TunFlags};
+
+#[test]
+fn test_tun() {
+    let fd = match open(
+        "/dev/net/tun",
+        OFlags::RDWR | OFlags::CLOEXEC,
+        Mode::empty(),
+    ) {
+        Ok(fd) => fd,
+        Err(Errno::NOENT | Errno::NODEV | Errno::ACCESS | Errno::PERM) => return,
+        Err(err) => panic!("{:?}", err),
+    };
+
+    // Creating a device requires `CAP_NET_ADMIN`.
+    match ioctl_tunsetiff(&fd, "rustix%d", TunFlags::TUN | TunFlags::NO_PI) {
+        Ok(()) => {}
+        Err(Errno::PERM | Errno::ACCESS) => return,
+        Err(err) => panic!("{:?}", err),
+    }
+
+    let (name, flags) = ioctl_tungetiff(&fd).unwrap();
+    assert!(name.starts_with("rustix"), "{}", name);
+    assert!(flags.contains(TunFlags::TUN | TunFlags::NO_PI));
+    assert!(!flags.contains(TunFlags::TAP));
+
+    // The device isn't persistent, so it's removed when `fd` is closed.
+    ioctl_tunsetpersist(&fd, false).unwrap();
+
+    // Names that don't fit in `IFNAMSIZ` are rejected up front.
+    assert_eq!(
+        ioctl_tunsetiff(&fd, "a-name-that-is-too-long", TunFlags::TUN),
+        Err(Errno::INVAL)
+    );
+}
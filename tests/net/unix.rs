@@ -896,3 +896,45 @@ fn test_unix_msg_with_combo() {
     client.join().unwrap();
     server.join().unwrap();
 }
+
+#[test]
+fn test_unix_recvv() {
+    use rustix::io::IoSliceMut;
+    use rustix::net::{recvv, send, RecvFlags, SendFlags, SocketFlags};
+
+    let (send_sock, recv_sock) = rustix::net::socketpair(
+        AddressFamily::UNIX,
+        SocketType::STREAM,
+        SocketFlags::CLOEXEC,
+        None,
+    )
+    .unwrap();
+
+    send(&send_sock, b"hello world", SendFlags::empty()).unwrap();
+
+    let mut head = [0_u8; 6];
+    let mut tail = [0_u8; 5];
+
+    // Peek, leaving the data in the socket.
+    let result = recvv(
+        &recv_sock,
+        &mut [IoSliceMut::new(&mut head), IoSliceMut::new(&mut tail)],
+        RecvFlags::PEEK,
+    )
+    .unwrap();
+    assert_eq!(result.bytes, 11);
+    assert_eq!(&head, b"hello ");
+    assert_eq!(&tail, b"world");
+
+    head.fill(0);
+    tail.fill(0);
+    let result = recvv(
+        &recv_sock,
+        &mut [IoSliceMut::new(&mut head), IoSliceMut::new(&mut tail)],
+        RecvFlags::WAITALL,
+    )
+    .unwrap();
+    assert_eq!(result.bytes, 11);
+    assert_eq!(&head, b"hello ");
+    assert_eq!(&tail, b"world");
+}
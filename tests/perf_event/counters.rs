@@ -0,0 +1,37 @@
+use core::sync::atomic::{AtomicU64, Ordering};
+use rustix::io::{read, Errno};
+use rustix::perf_event::{
+    ioctl_disable, ioctl_enable, ioctl_id, ioctl_reset, perf_event_open, AttrFlags, PerfEventAttr,
+    PerfEventOpenFlags, ReadFormat, SoftwareEvent,
+};
+
+#[test]
+fn test_perf_event_task_clock() {
+    let attr = PerfEventAttr::software(SoftwareEvent::TASK_CLOCK)
+        .read_format(ReadFormat::ID)
+        .flags(AttrFlags::DISABLED | AttrFlags::EXCLUDE_KERNEL | AttrFlags::EXCLUDE_HV);
+    let fd = match perf_event_open(&attr, None, None, None, PerfEventOpenFlags::FD_CLOEXEC) {
+        Ok(fd) => fd,
+        // perf events may be disabled by `perf_event_paranoid`, seccomp, or
+        // the lack of kernel support.
+        Err(Errno::NOSYS | Errno::PERM | Errno::ACCESS | Errno::NOENT) => return,
+        Err(err) => panic!("{:?}", err),
+    };
+
+    let id = ioctl_id(&fd).unwrap();
+
+    ioctl_reset(&fd, false).unwrap();
+    ioctl_enable(&fd, false).unwrap();
+    let counter = AtomicU64::new(0);
+    for _ in 0..1_000_000 {
+        counter.fetch_add(1, Ordering::Relaxed);
+    }
+    ioctl_disable(&fd, false).unwrap();
+
+    let mut buf = [0_u8; 16];
+    assert_eq!(read(&fd, &mut buf).unwrap(), 16);
+    let count = u64::from_ne_bytes(buf[..8].try_into().unwrap());
+    let read_id = u64::from_ne_bytes(buf[8..].try_into().unwrap());
+    assert!(count > 0);
+    assert_eq!(read_id, id);
+}
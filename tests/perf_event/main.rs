@@ -0,0 +1,6 @@
+//! Tests for [`rustix::perf_event`].
+
+#![cfg(feature = "perf_event")]
+#![cfg(linux_kernel)]
+
+mod counters;
@@ -4,5 +4,6 @@
 #![cfg(not(windows))]
 
 mod basic;
+mod size;
 mod splice;
 mod tee;
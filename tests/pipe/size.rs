@@ -0,0 +1,21 @@
+#[cfg(linux_kernel)]
+#[test]
+fn test_pipe_size() {
+    use rustix::pipe::{fcntl_getpipe_size, fcntl_setpipe_size, pipe};
+
+    let (reader, writer) = pipe().unwrap();
+
+    let initial = fcntl_getpipe_size(&reader).unwrap();
+    assert_eq!(fcntl_getpipe_size(&writer).unwrap(), initial);
+
+    fcntl_setpipe_size(&writer, initial * 2).unwrap();
+    assert!(fcntl_getpipe_size(&reader).unwrap() >= initial * 2);
+
+    // Sizes which don't fit in a C `int` are rejected like sizes which exceed
+    // the system limit.
+    #[cfg(target_pointer_width = "64")]
+    assert_eq!(
+        fcntl_setpipe_size(&writer, usize::MAX),
+        Err(rustix::io::Errno::PERM)
+    );
+}
@@ -0,0 +1,15 @@
+use rustix::process::alarm;
+
+#[test]
+fn test_alarm() {
+    // Use a long time so that the alarm never fires during the test, since
+    // `SIGALRM` would terminate the process.
+    assert_eq!(alarm(1000), 0);
+    let remaining = alarm(2000);
+    assert!(remaining > 990 && remaining <= 1000, "{}", remaining);
+
+    // Cancel the alarm.
+    let remaining = alarm(0);
+    assert!(remaining > 1990 && remaining <= 2000, "{}", remaining);
+    assert_eq!(alarm(0), 0);
+}
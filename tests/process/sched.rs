@@ -12,3 +12,10 @@ fn test_sched_getcpu() {
     let n = rustix::process::sched_getcpu();
     assert!(n < rustix::process::CpuSet::MAX_CPU);
 }
+
+#[cfg(linux_kernel)]
+#[test]
+fn test_getcpu() {
+    let (cpu, _node) = rustix::process::getcpu();
+    assert!(cpu < rustix::process::CpuSet::MAX_CPU);
+}
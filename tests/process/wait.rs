@@ -150,3 +150,94 @@ fn test_waitid() {
     #[cfg(not(any(target_os = "fuchsia", target_os = "netbsd")))]
     assert_eq!(status.terminating_signal(), Some(SIGKILL as _));
 }
+
+#[test]
+#[serial]
+fn test_waitpid_killed() {
+    let child = Command::new("yes")
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .expect("failed to execute child");
+    unsafe { kill(child.id() as _, SIGKILL) };
+
+    let pid = process::Pid::from_child(&child);
+    let status = process::waitpid(Some(pid), process::WaitOptions::empty())
+        .expect("failed to wait")
+        .unwrap();
+    assert!(status.signaled());
+    assert_eq!(status.terminating_signal(), Some(SIGKILL as _));
+    assert!(!status.dumped());
+    assert_eq!(status.exit_status(), None);
+}
+
+#[cfg(linux_kernel)]
+#[test]
+#[serial]
+fn test_waitpid_ptrace_group_stop() {
+    use libc::{ptrace, PTRACE_CONT, PTRACE_SEIZE};
+
+    let child = Command::new("yes")
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .expect("failed to execute child");
+    let pid = process::Pid::from_child(&child);
+
+    // Tests won't always have permission to trace the child.
+    if unsafe { ptrace(PTRACE_SEIZE, child.id(), 0, 0) } != 0 {
+        unsafe { kill(child.id() as _, SIGKILL) };
+        process::waitpid(Some(pid), process::WaitOptions::empty()).unwrap();
+        return;
+    }
+
+    unsafe { kill(child.id() as _, SIGSTOP) };
+
+    // First, the tracer sees the signal-delivery stop.
+    let status = process::waitpid(Some(pid), process::WaitOptions::empty())
+        .expect("failed to wait")
+        .unwrap();
+    assert!(status.stopped());
+    assert_eq!(status.stopping_signal(), Some(SIGSTOP as _));
+    assert_eq!(status.ptrace_event(), None);
+    assert!(!status.group_stopped());
+
+    // Once the signal is delivered, the tracee enters a group stop.
+    unsafe { ptrace(PTRACE_CONT, child.id(), 0, SIGSTOP) };
+    let status = process::waitpid(Some(pid), process::WaitOptions::empty())
+        .expect("failed to wait")
+        .unwrap();
+    assert!(status.stopped());
+    assert_eq!(status.stopping_signal(), Some(SIGSTOP as _));
+    assert_eq!(status.ptrace_event(), Some(libc::PTRACE_EVENT_STOP as _));
+    assert!(status.group_stopped());
+
+    unsafe { kill(child.id() as _, SIGKILL) };
+    let status = process::waitpid(Some(pid), process::WaitOptions::empty())
+        .expect("failed to wait")
+        .unwrap();
+    assert!(status.signaled());
+    assert_eq!(status.ptrace_event(), None);
+}
+
+#[cfg(any(linux_kernel, bsd))]
+#[test]
+#[serial]
+fn test_wait4() {
+    let child = Command::new("true")
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .expect("failed to execute child");
+
+    let pid = process::Pid::from_child(&child);
+    let (wait_pid, status, rusage) = process::wait4(Some(pid), process::WaitOptions::empty())
+        .expect("failed to wait")
+        .unwrap();
+    assert_eq!(wait_pid, pid);
+    assert!(status.exited());
+    assert_eq!(status.exit_status(), Some(0));
+    assert!(rusage.maxrss > 0);
+    assert!(rusage.utime.tv_nsec < 1_000_000_000);
+    assert!(rusage.stime.tv_nsec < 1_000_000_000);
+}
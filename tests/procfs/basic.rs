@@ -14,3 +14,96 @@ fn test_status_twice() {
     let fd = rustix::procfs::proc_self_status().unwrap();
     drop(fd);
 }
+
+/// Walk `/proc/self/fd` and decode the flags of each file descriptor.
+#[test]
+fn test_proc_self_fd_flags() {
+    use rustix::fd::{AsFd, BorrowedFd};
+    use rustix::fs::{fcntl_getfd, fcntl_getfl, openat, Dir, Mode, OFlags, CWD};
+    use rustix::io::FdFlags;
+
+    let file = openat(
+        CWD,
+        "Cargo.toml",
+        OFlags::RDONLY | OFlags::NONBLOCK | OFlags::CLOEXEC,
+        Mode::empty(),
+    )
+    .unwrap();
+
+    let proc_self_fd = rustix::procfs::proc_self_fd().unwrap();
+    let mut dir = Dir::read_from(proc_self_fd.as_fd()).unwrap();
+    let mut found = false;
+    while let Some(entry) = dir.read() {
+        let entry = entry.unwrap();
+        let raw = match entry.file_name().to_str().unwrap().parse() {
+            Ok(raw) => raw,
+            Err(_) => continue,
+        };
+        if raw != file.as_raw_fd() {
+            continue;
+        }
+
+        // SAFETY: `file` is open for the duration of the borrow.
+        let fd = unsafe { BorrowedFd::borrow_raw(raw) };
+        assert_eq!(fcntl_getfd(fd).unwrap(), FdFlags::CLOEXEC);
+        let flags = fcntl_getfl(fd).unwrap();
+        assert_eq!(flags & OFlags::RWMODE, OFlags::RDONLY);
+        assert!(flags.contains(OFlags::NONBLOCK));
+        found = true;
+    }
+    assert!(found);
+}
+
+#[test]
+fn test_proc_self_open_fds() {
+    use rustix::fs::{openat, Mode, OFlags, CWD};
+
+    let file = openat(
+        CWD,
+        "Cargo.toml",
+        OFlags::RDONLY | OFlags::NONBLOCK | OFlags::CLOEXEC,
+        Mode::empty(),
+    )
+    .unwrap();
+
+    let open_fds = rustix::procfs::proc_self_open_fds().unwrap();
+    let entry = open_fds
+        .iter()
+        .find(|entry| entry.fd == file.as_raw_fd())
+        .unwrap();
+    assert!(entry.flags.contains(OFlags::NONBLOCK | OFlags::CLOEXEC));
+    assert!(entry.target.to_bytes().ends_with(b"/Cargo.toml"));
+}
+
+#[cfg(feature = "process")]
+#[test]
+fn test_proc_pid() {
+    use rustix::io::read;
+    use rustix::procfs::{proc_pid, proc_pid_cmdline, proc_pid_cwd, proc_pid_exe, proc_pid_fd};
+
+    let proc_pid = proc_pid(rustix::process::getpid()).unwrap();
+
+    let cmdline = proc_pid_cmdline(&proc_pid).unwrap();
+    let mut buf = [0_u8; 4096];
+    let n = read(&cmdline, &mut buf).unwrap();
+    let arg0 = buf[..n].split(|b| *b == 0).next().unwrap();
+    let exe = std::env::args_os().next().unwrap();
+    assert_eq!(
+        arg0,
+        std::os::unix::ffi::OsStrExt::as_bytes(exe.as_os_str())
+    );
+
+    let cwd = proc_pid_cwd(&proc_pid).unwrap();
+    assert_eq!(
+        cwd.to_bytes(),
+        std::os::unix::ffi::OsStrExt::as_bytes(std::env::current_dir().unwrap().as_os_str())
+    );
+
+    let exe = proc_pid_exe(&proc_pid).unwrap();
+    assert_eq!(
+        exe.to_bytes(),
+        std::os::unix::ffi::OsStrExt::as_bytes(std::env::current_exe().unwrap().as_os_str())
+    );
+
+    let _ = proc_pid_fd(&proc_pid).unwrap();
+}
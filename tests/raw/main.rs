@@ -0,0 +1,40 @@
+//! Tests for [`rustix::raw`].
+
+#![cfg(not(windows))]
+#![cfg(any(feature = "fs", feature = "net"))]
+
+#[test]
+fn test_raw_fd() {
+    let fd: rustix::raw::RawFd = rustix::fd::AsRawFd::as_raw_fd(&std::io::stdin());
+    assert_eq!(fd, 0);
+}
+
+#[cfg(linux_kernel)]
+#[test]
+fn test_raw_timespec() {
+    let ts = rustix::raw::timespec {
+        tv_sec: 1,
+        tv_nsec: 2,
+    };
+    assert_eq!(ts.tv_sec, 1);
+    assert_eq!(ts.tv_nsec, 2);
+}
+
+/// On 64-bit Linux, `fs::Stat` is the raw struct itself.
+#[cfg(all(
+    feature = "fs",
+    linux_kernel,
+    target_pointer_width = "64",
+    not(any(target_arch = "mips64", target_arch = "mips64r6"))
+))]
+#[test]
+fn test_raw_stat() {
+    let stat: rustix::raw::stat = rustix::fs::fstat(std::io::stdin()).unwrap();
+    assert_ne!(stat.st_mode, 0);
+}
+
+#[cfg(all(feature = "net", linux_kernel))]
+#[test]
+fn test_raw_sockaddr_storage() {
+    assert_eq!(core::mem::size_of::<rustix::raw::sockaddr_storage>(), 128);
+}
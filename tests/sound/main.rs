@@ -0,0 +1,6 @@
+//! Tests for [`rustix::sound`].
+
+#![cfg(feature = "sound")]
+#![cfg(linux_kernel)]
+
+mod pcm;
@@ -0,0 +1,25 @@
+use rustix::sound::{Access, Format, HwParams};
+
+#[test]
+fn test_hw_params_setters() {
+    let mut params = HwParams::any();
+    assert_eq!(params.access(), None);
+    assert_eq!(params.format(), None);
+    assert_eq!(params.channels(), None);
+    assert_eq!(params.rate(), None);
+
+    params.set_access(Access::RW_INTERLEAVED);
+    params.set_format(Format::S24_3LE);
+    params.set_channels(2);
+    params.set_rate(48000);
+    params.set_period_size(1024);
+    params.set_periods(4);
+    params.set_buffer_size(4096);
+    assert_eq!(params.access(), Some(Access::RW_INTERLEAVED));
+    assert_eq!(params.format(), Some(Format::S24_3LE));
+    assert_eq!(params.channels(), Some(2));
+    assert_eq!(params.rate(), Some(48000));
+    assert_eq!(params.period_size(), Some(1024));
+    assert_eq!(params.periods(), Some(4));
+    assert_eq!(params.buffer_size(), Some(4096));
+}
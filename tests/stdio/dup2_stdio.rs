@@ -0,0 +1,18 @@
+use std::env;
+use std::process::Command;
+
+/// Use `dup2_stdio` to replace stdio file descriptors with each other.
+#[test]
+fn dup2_stdio() {
+    // This test modifies the stdio file descriptors, so we run it in a
+    // separate process so that it doesn't interfere with the test harness.
+    assert!(Command::new(env::var("CARGO").unwrap())
+        .arg("run")
+        .arg("--example")
+        .arg("dup2_stdio")
+        .arg("--features")
+        .arg("fs,pipe,stdio")
+        .status()
+        .unwrap()
+        .success());
+}
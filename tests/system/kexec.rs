@@ -0,0 +1,17 @@
+#[test]
+#[cfg(feature = "fs")]
+fn test_kexec_file_load_invalid_flags() {
+    use rustix::fs::{open, Mode, OFlags};
+    use rustix::io::Errno;
+    use rustix::system::{kexec_file_load, KexecFileFlags};
+
+    let file = open("/dev/null", OFlags::RDONLY | OFlags::CLOEXEC, Mode::empty()).unwrap();
+
+    // Use an unknown flag so that this fails before the kernel unloads any
+    // currently loaded kernel; without `CAP_SYS_BOOT` it fails even earlier.
+    let flags = KexecFileFlags::NO_INITRAMFS | KexecFileFlags::from_bits_retain(0x8000_0000);
+    match kexec_file_load(&file, None, rustix::cstr!(""), flags) {
+        Err(Errno::INVAL | Errno::PERM | Errno::NOSYS) => {}
+        other => panic!("{:?}", other),
+    }
+}
@@ -3,6 +3,8 @@
 #![cfg(feature = "system")]
 #![cfg(not(any(windows, target_os = "wasi")))]
 
+#[cfg(kexec_file_load)]
+mod kexec;
 #[cfg(target_os = "linux")]
 mod reboot;
 #[cfg(linux_kernel)]
@@ -178,3 +178,70 @@ fn test_termios_tcgetattr_not_tty() {
         rustix::io::Errno::NOTTY
     );
 }
+
+#[cfg(linux_kernel)]
+#[test]
+fn test_termios_modem_lines_and_break() {
+    use rustix::pty::*;
+    use rustix::termios::*;
+
+    let pty = match openpt(OpenptFlags::empty()) {
+        Ok(pty) => pty,
+        Err(rustix::io::Errno::NOSYS) => return,
+        Err(err) => panic!("{:?}", err),
+    };
+
+    // Pseudoterminals don't have modem control lines.
+    match ioctl_tiocmget(&pty) {
+        Ok(_lines) => {}
+        Err(rustix::io::Errno::INVAL | rustix::io::Errno::NOTTY) => {}
+        Err(err) => panic!("{:?}", err),
+    }
+    match ioctl_tiocmbis(&pty, ModemLines::DTR | ModemLines::RTS) {
+        Ok(()) => {}
+        Err(rustix::io::Errno::INVAL | rustix::io::Errno::NOTTY) => {}
+        Err(err) => panic!("{:?}", err),
+    }
+
+    ioctl_tiocsbrk(&pty).unwrap();
+    ioctl_tioccbrk(&pty).unwrap();
+}
+
+#[cfg(linux_kernel)]
+#[test]
+fn test_termios_vt_ioctls_on_pty() {
+    use rustix::pty::*;
+    use rustix::termios::vt::*;
+
+    let pty = match openpt(OpenptFlags::empty()) {
+        Ok(pty) => pty,
+        Err(rustix::io::Errno::NOSYS) => return,
+        Err(err) => panic!("{:?}", err),
+    };
+
+    // A pseudoterminal isn't a virtual terminal.
+    assert!(matches!(
+        ioctl_kdgetmode(&pty),
+        Err(rustix::io::Errno::NOTTY | rustix::io::Errno::INVAL)
+    ));
+    assert!(matches!(
+        ioctl_vt_getstate(&pty),
+        Err(rustix::io::Errno::NOTTY | rustix::io::Errno::INVAL)
+    ));
+}
+
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+#[test]
+fn test_termios_exclusive() {
+    use rustix::pty::*;
+    use rustix::termios::*;
+
+    let pty = match openpt(OpenptFlags::empty()) {
+        Ok(pty) => pty,
+        Err(rustix::io::Errno::NOSYS) => return,
+        Err(err) => panic!("{:?}", err),
+    };
+
+    ioctl_tiocexcl(&pty).unwrap();
+    ioctl_tiocnxcl(&pty).unwrap();
+}
@@ -0,0 +1,42 @@
+use core::sync::atomic::{AtomicIsize, AtomicUsize, Ordering};
+use rustix::thread::{self, Signal};
+
+static CODE: AtomicIsize = AtomicIsize::new(0);
+static VALUE: AtomicUsize = AtomicUsize::new(0);
+
+extern "C" fn handler(_sig: libc::c_int, info: *mut libc::siginfo_t, _context: *mut libc::c_void) {
+    unsafe {
+        CODE.store((*info).si_code as isize, Ordering::SeqCst);
+        if (*info).si_code == libc::SI_QUEUE {
+            VALUE.store((*info).si_value().sival_ptr as usize, Ordering::SeqCst);
+        }
+    }
+}
+
+#[test]
+fn test_signal() {
+    unsafe {
+        let mut action: libc::sigaction = core::mem::zeroed();
+        action.sa_sigaction = handler
+            as extern "C" fn(libc::c_int, *mut libc::siginfo_t, *mut libc::c_void)
+            as libc::sighandler_t;
+        action.sa_flags = libc::SA_SIGINFO;
+        assert_eq!(
+            libc::sigaction(libc::SIGUSR2, &action, core::ptr::null_mut()),
+            0
+        );
+    }
+
+    // A signal sent to the calling thread is delivered before the syscall
+    // returns.
+    thread::signal(thread::gettid(), Signal::Usr2, None).unwrap();
+    assert_eq!(CODE.load(Ordering::SeqCst), libc::SI_TKILL as isize);
+
+    thread::signal(thread::gettid(), Signal::Usr2, Some(0xdead_beef)).unwrap();
+    assert_eq!(CODE.load(Ordering::SeqCst), libc::SI_QUEUE as isize);
+    assert_eq!(VALUE.load(Ordering::SeqCst), 0xdead_beef);
+
+    unsafe {
+        libc::signal(libc::SIGUSR2, libc::SIG_DFL);
+    }
+}
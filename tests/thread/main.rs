@@ -8,8 +8,13 @@ mod clocks;
 #[cfg(linux_kernel)]
 mod id;
 #[cfg(linux_kernel)]
+mod kill;
+#[cfg(linux_kernel)]
 mod libcap;
 #[cfg(linux_kernel)]
 mod prctl;
 #[cfg(linux_kernel)]
+#[cfg(target_has_atomic = "64")]
+mod rseq;
+#[cfg(linux_kernel)]
 mod setns;
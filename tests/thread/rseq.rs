@@ -0,0 +1,40 @@
+use rustix::thread::{rseq_register, rseq_unregister, Rseq};
+
+/// An arbitrary signature for the abort handlers of critical sections.
+const SIG: u32 = 0x5305_3053;
+
+#[test]
+fn test_rseq_layout() {
+    assert_eq!(core::mem::size_of::<Rseq>(), 32);
+    assert_eq!(core::mem::align_of::<Rseq>(), 32);
+}
+
+#[test]
+fn test_rseq_register() {
+    std::thread::spawn(|| {
+        let rseq = Box::new(Rseq::new());
+        assert_eq!(rseq.cpu_id(), None);
+
+        match unsafe { rseq_register(&rseq, SIG) } {
+            Ok(()) => {}
+            // glibc has already registered an area for this thread, or the
+            // kernel is older than Linux 4.18.
+            Err(rustix::io::Errno::INVAL | rustix::io::Errno::NOSYS) => return,
+            Err(err) => panic!("{:?}", err),
+        }
+
+        // Registering the same area again is reported as busy.
+        assert_eq!(
+            unsafe { rseq_register(&rseq, SIG) },
+            Err(rustix::io::Errno::BUSY)
+        );
+
+        let cpu = rseq.cpu_id().unwrap();
+        assert!((cpu as usize) < rustix::process::CpuSet::MAX_CPU);
+
+        rseq_unregister(&rseq, SIG).unwrap();
+        assert_eq!(rseq.cpu_id(), None);
+    })
+    .join()
+    .unwrap();
+}
@@ -0,0 +1,27 @@
+use core::time::Duration;
+use rustix::time::{getitimer, setitimer, IntervalTimer, Itimerval};
+
+#[test]
+fn test_itimer() {
+    // Use long times so that the timer never fires during the test, since
+    // `SIGALRM` would terminate the process.
+    let new_value = Itimerval {
+        interval: Duration::new(2000, 500_000_000),
+        value: Duration::from_secs(1000),
+    };
+    let old = setitimer(IntervalTimer::Real, &new_value).unwrap();
+    assert_eq!(old, Itimerval::default());
+
+    let curr = getitimer(IntervalTimer::Real).unwrap();
+    assert_eq!(curr.interval, new_value.interval);
+    assert!(curr.value <= new_value.value);
+    assert!(curr.value > Duration::from_secs(900));
+
+    // Disarm the timer.
+    let old = setitimer(IntervalTimer::Real, &Itimerval::default()).unwrap();
+    assert_eq!(old.interval, new_value.interval);
+    assert_eq!(
+        getitimer(IntervalTimer::Real).unwrap(),
+        Itimerval::default()
+    );
+}
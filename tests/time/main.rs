@@ -6,6 +6,8 @@
 mod clocks;
 #[cfg(not(any(target_os = "redox", target_os = "wasi")))]
 mod dynamic_clocks;
+#[cfg(linux_kernel)]
+mod itimer;
 #[cfg(not(any(target_os = "redox", target_os = "wasi")))]
 mod monotonic;
 #[cfg(not(any(
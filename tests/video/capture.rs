@@ -0,0 +1,8 @@
+use rustix::video::PixelFormat;
+
+#[test]
+fn test_pixel_format_fourcc() {
+    assert_eq!(PixelFormat::YUYV.as_raw(), 0x5659_5559);
+    assert_eq!(PixelFormat::MJPEG.fourcc(), *b"MJPG");
+    assert_eq!(PixelFormat::from_fourcc(*b"GREY"), PixelFormat::GREY);
+}
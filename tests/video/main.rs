@@ -0,0 +1,6 @@
+//! Tests for [`rustix::video`].
+
+#![cfg(feature = "video")]
+#![cfg(linux_kernel)]
+
+mod capture;